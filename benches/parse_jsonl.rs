@@ -0,0 +1,116 @@
+//! Compare collecting a session's JSONL entries before building the
+//! conversation with streaming them straight into the builder.
+//!
+//! Run with `cargo bench --bench parse_jsonl`. Set `ENGRAM_BENCH_TURNS` to
+//! change the size of the synthetic session (default 20000 turns).
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::io::Write;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+use engram::parser::{conversation, jsonl};
+
+/// Tracks live and peak heap usage so each run can report its high-water mark
+struct PeakAlloc;
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for PeakAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            let now = CURRENT.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+            PEAK.fetch_max(now, Ordering::Relaxed);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+}
+
+#[global_allocator]
+static ALLOC: PeakAlloc = PeakAlloc;
+
+/// Write a session with `turns` user/assistant exchanges, each padded with a
+/// progress entry and a large tool result like real sessions have
+fn write_session(path: &std::path::Path, turns: usize) {
+    let file = std::fs::File::create(path).unwrap();
+    let mut out = std::io::BufWriter::new(file);
+    let tool_output = "x".repeat(2_000);
+
+    for i in 0..turns {
+        let user = serde_json::json!({
+            "type": "user",
+            "timestamp": format!("2026-01-01T00:00:{:02}Z", i % 60),
+            "message": {"role": "user", "content": format!("Question {} about retries", i)},
+        });
+        let progress = serde_json::json!({"type": "progress", "data": {"turn": i}});
+        let assistant = serde_json::json!({
+            "type": "assistant",
+            "timestamp": format!("2026-01-01T00:00:{:02}Z", i % 60),
+            "message": {
+                "role": "assistant",
+                "model": "bench-model",
+                "usage": {"input_tokens": 100, "output_tokens": 50},
+                "content": [
+                    {"type": "text", "text": format!("Answer {}: use exponential backoff.", i)},
+                    {"type": "tool_use", "id": format!("t{}", i), "name": "Bash", "input": {"command": "cargo test"}},
+                    {"type": "tool_result", "tool_use_id": format!("t{}", i), "content": tool_output},
+                ],
+            },
+        });
+        for entry in [user, progress, assistant] {
+            writeln!(out, "{}", entry).unwrap();
+        }
+    }
+    out.flush().unwrap();
+}
+
+fn measure<T>(label: &str, run: impl FnOnce() -> T) -> T {
+    let baseline = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(baseline, Ordering::Relaxed);
+    let start = Instant::now();
+    let result = run();
+    let elapsed = start.elapsed();
+    let peak = PEAK.load(Ordering::Relaxed).saturating_sub(baseline);
+    println!(
+        "{:<10} {:>8.1} ms   peak heap {:>8.1} MB",
+        label,
+        elapsed.as_secs_f64() * 1000.0,
+        peak as f64 / (1024.0 * 1024.0)
+    );
+    result
+}
+
+fn main() {
+    let turns: usize = std::env::var("ENGRAM_BENCH_TURNS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(20_000);
+
+    let dir = tempfile::TempDir::new().unwrap();
+    let path = dir.path().join("session.jsonl");
+    write_session(&path, turns);
+    let size = std::fs::metadata(&path).unwrap().len();
+    println!(
+        "synthetic session: {} turns, {:.1} MB\n",
+        turns,
+        size as f64 / (1024.0 * 1024.0)
+    );
+
+    let collected = measure("collect", || {
+        let entries = jsonl::parse_jsonl(&path).unwrap();
+        conversation::build_conversation(&entries, "bench", "bench")
+    });
+    let streamed = measure("stream", || {
+        let entries = jsonl::stream_jsonl(&path).unwrap();
+        conversation::build_conversation_streaming(entries, "bench", "bench").unwrap()
+    });
+
+    assert_eq!(collected.turns.len(), streamed.turns.len());
+}
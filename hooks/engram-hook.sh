@@ -5,11 +5,14 @@
 #
 # To install, run: engram hooks install
 
+# Binary to run; `engram hooks install` rewrites this to its own path
+ENGRAM="engram"
+
 # Fast path: capture observation (reads stdin, exits immediately)
 # We tee stdin so the debounced ingest can still proceed
 STDIN_DATA=$(cat)
 
-echo "$STDIN_DATA" | engram observe >/dev/null 2>&1 &
+echo "$STDIN_DATA" | "$ENGRAM" observe >/dev/null 2>&1 &
 
 # Debounced ingest
 LOCKFILE="/tmp/engram-hook.lock"
@@ -25,6 +28,6 @@ fi
 touch "$LOCKFILE"
 
 # Run ingest in background (archive only, no LLM — fast and silent)
-engram ingest --skip-knowledge --since 5m >/dev/null 2>&1 &
+"$ENGRAM" ingest --skip-knowledge --since 5m >/dev/null 2>&1 &
 
 exit 0
@@ -5,12 +5,15 @@
 #
 # CLAUDE_PROJECT_DIR is set by Claude Code hooks (the project directory path)
 
+# Binary to run; `engram hooks install` rewrites this to its own path
+ENGRAM="engram"
+
 PROJECT_DIR="${CLAUDE_PROJECT_DIR:-$(pwd)}"
 PROJECT_NAME="$(basename "$PROJECT_DIR")"
 
 [ -z "$PROJECT_NAME" ] && exit 0
 
-engram inject "$PROJECT_NAME" >/dev/null 2>&1
+"$ENGRAM" inject "$PROJECT_NAME" >/dev/null 2>&1
 
 # Auto-start daemon if not running
 DAEMON_PID_FILE="${HOME}/memory/daemon.pid"
@@ -23,7 +26,7 @@ if [ -f "$DAEMON_PID_FILE" ]; then
 fi
 
 if [ "$_daemon_running" = false ]; then
-    engram daemon start >/dev/null 2>&1 &
+    "$ENGRAM" daemon start >/dev/null 2>&1 &
 fi
 
 exit 0
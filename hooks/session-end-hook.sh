@@ -5,11 +5,14 @@
 #
 # The next SessionStart will inject the freshly generated context.
 
+# Binary to run; `engram hooks install` rewrites this to its own path
+ENGRAM="engram"
+
 PROJECT_DIR="${CLAUDE_PROJECT_DIR:-$(pwd)}"
 PROJECT_NAME="$(basename "$PROJECT_DIR")"
 [ -z "$PROJECT_NAME" ] && exit 0
 
 # Full ingest of recent sessions (with LLM extraction, runs in background)
-engram ingest --project "$PROJECT_NAME" --since 1d >/dev/null 2>&1 &
+"$ENGRAM" ingest --project "$PROJECT_NAME" --since 1d >/dev/null 2>&1 &
 
 exit 0
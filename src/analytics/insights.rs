@@ -12,6 +12,10 @@ pub struct Insights {
     pub stale_knowledge: Vec<String>,
     pub usage_trend: String,
     pub tokens_ingested: u64,
+    /// LLM tokens spent by ingest/regen runs
+    pub llm_tokens_used: u64,
+    /// LLM tokens per day (`YYYY-MM-DD`, oldest first)
+    pub llm_tokens_by_day: Vec<(String, u64)>,
     pub command_breakdown: Vec<(String, usize)>,
 }
 
@@ -26,6 +30,8 @@ pub fn generate_insights(events: &[UsageEvent]) -> Insights {
             stale_knowledge: Vec::new(),
             usage_trend: "no data".to_string(),
             tokens_ingested: 0,
+            llm_tokens_used: 0,
+            llm_tokens_by_day: Vec::new(),
             command_breakdown: Vec::new(),
         };
     }
@@ -103,9 +109,22 @@ pub fn generate_insights(events: &[UsageEvent]) -> Insights {
         .filter_map(|e| e.tokens_consumed)
         .sum();
 
+    // LLM spend from LlmUsage events, bucketed by day for the trend
+    let mut llm_by_day: std::collections::BTreeMap<String, u64> = Default::default();
+    for event in events
+        .iter()
+        .filter(|e| matches!(e.event_type, crate::analytics::tracker::EventType::LlmUsage))
+    {
+        *llm_by_day
+            .entry(event.timestamp.format("%Y-%m-%d").to_string())
+            .or_insert(0) += event.tokens_consumed.unwrap_or(0);
+    }
+    let llm_tokens_used: u64 = llm_by_day.values().sum();
+    let llm_tokens_by_day: Vec<(String, u64)> = llm_by_day.into_iter().collect();
+
     // Command breakdown sorted by count descending
     let mut command_breakdown: Vec<(String, usize)> = event_counts.into_iter().collect();
-    command_breakdown.sort_by(|a, b| b.1.cmp(&a.1));
+    command_breakdown.sort_by_key(|c| std::cmp::Reverse(c.1));
 
     Insights {
         total_events: events.len(),
@@ -116,6 +135,8 @@ pub fn generate_insights(events: &[UsageEvent]) -> Insights {
         stale_knowledge,
         usage_trend: trend,
         tokens_ingested,
+        llm_tokens_used,
+        llm_tokens_by_day,
         command_breakdown,
     }
 }
@@ -142,6 +163,10 @@ pub fn format_insights(insights: &Insights) -> String {
     if insights.tokens_ingested > 0 {
         output.push_str(&format!("Tokens ingested: {}\n", insights.tokens_ingested));
     }
+    if insights.llm_tokens_used > 0 {
+        output.push_str(&format!("LLM tokens used: {}\n", insights.llm_tokens_used));
+        output.push_str(&format_llm_trend(&insights.llm_tokens_by_day));
+    }
 
     if !insights.command_breakdown.is_empty() {
         output.push_str("\n📋 Command Usage Breakdown:\n");
@@ -167,6 +192,14 @@ pub fn format_insights(insights: &Insights) -> String {
     output
 }
 
+/// Indented "date: tokens" lines for the per-day LLM spend.
+pub fn format_llm_trend(by_day: &[(String, u64)]) -> String {
+    by_day
+        .iter()
+        .map(|(day, tokens)| format!("  {}: {}\n", day, tokens))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -191,6 +224,7 @@ mod tests {
                 results_count: None,
                 session_id: None,
                 tokens_consumed: None,
+                knowledge_ids: Vec::new(),
             },
             UsageEvent {
                 timestamp: chrono::Utc::now(),
@@ -201,6 +235,7 @@ mod tests {
                 results_count: None,
                 session_id: None,
                 tokens_consumed: None,
+                knowledge_ids: Vec::new(),
             },
         ];
 
@@ -209,4 +244,37 @@ mod tests {
         assert_eq!(insights.unique_projects, 1);
         assert_eq!(insights.most_active_project, Some("proj-a".to_string()));
     }
+
+    #[test]
+    fn test_llm_tokens_by_day() {
+        let event = |day: &str, tokens: u64| UsageEvent {
+            timestamp: chrono::DateTime::parse_from_rfc3339(&format!("{}T12:00:00Z", day))
+                .unwrap()
+                .with_timezone(&chrono::Utc),
+            event_type: EventType::LlmUsage,
+            project: "proj-a".to_string(),
+            query: Some("ingest".to_string()),
+            category: None,
+            results_count: Some(1),
+            session_id: None,
+            tokens_consumed: Some(tokens),
+            knowledge_ids: Vec::new(),
+        };
+        let events = vec![
+            event("2026-03-02", 500),
+            event("2026-03-01", 1000),
+            event("2026-03-02", 250),
+        ];
+
+        let insights = generate_insights(&events);
+        assert_eq!(insights.llm_tokens_used, 1750);
+        assert_eq!(
+            insights.llm_tokens_by_day,
+            vec![
+                ("2026-03-01".to_string(), 1000),
+                ("2026-03-02".to_string(), 750)
+            ]
+        );
+        assert!(format_insights(&insights).contains("LLM tokens used: 1750"));
+    }
 }
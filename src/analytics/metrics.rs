@@ -112,6 +112,7 @@ mod tests {
                 results_count: None,
                 session_id: None,
                 tokens_consumed: None,
+                knowledge_ids: Vec::new(),
             },
             UsageEvent {
                 timestamp: chrono::Utc::now(),
@@ -122,6 +123,7 @@ mod tests {
                 results_count: None,
                 session_id: None,
                 tokens_consumed: None,
+                knowledge_ids: Vec::new(),
             },
         ];
 
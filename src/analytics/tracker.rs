@@ -19,6 +19,8 @@ pub enum EventType {
     Inject,
     Ingest,
     Ask,
+    /// Per-run LLM token totals from `ingest`/`regen`; `query` holds the operation
+    LlmUsage,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -32,6 +34,9 @@ pub struct UsageEvent {
     pub session_id: Option<String>,
     #[serde(default)]
     pub tokens_consumed: Option<u64>,
+    /// Knowledge blocks surfaced by this event (recall/inject), as `session_id` or `category:session_id`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub knowledge_ids: Vec<String>,
 }
 
 pub struct EventTracker {
@@ -100,10 +105,39 @@ impl EventTracker {
             }
         }
 
-        events.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+        events.sort_by_key(|e| std::cmp::Reverse(e.timestamp));
         Ok(events)
     }
 
+    /// Knowledge IDs from the most recent recall or inject event for `project`.
+    ///
+    /// When `session` is given, events tagged with that session win; otherwise
+    /// (or if none match) the latest surfacing event is used.
+    pub fn latest_knowledge_ids(
+        &self,
+        project: &str,
+        session: Option<&str>,
+        since_days: u32,
+    ) -> Result<Vec<String>> {
+        let events = self.get_events(Some(project), since_days)?;
+        let surfacing: Vec<&UsageEvent> = events
+            .iter()
+            .filter(|e| matches!(e.event_type, EventType::Recall | EventType::Inject))
+            .filter(|e| !e.knowledge_ids.is_empty())
+            .collect();
+
+        let by_session = session.and_then(|sid| {
+            surfacing
+                .iter()
+                .find(|e| e.session_id.as_deref() == Some(sid))
+        });
+
+        Ok(by_session
+            .or_else(|| surfacing.first())
+            .map(|e| e.knowledge_ids.clone())
+            .unwrap_or_default())
+    }
+
     pub fn clear_old_events(&self, days_to_keep: u32) -> Result<usize> {
         if !self.analytics_dir.exists() {
             return Ok(0);
@@ -160,6 +194,7 @@ mod tests {
             results_count: None,
             session_id: None,
             tokens_consumed: None,
+            knowledge_ids: Vec::new(),
         };
 
         tracker.track(event.clone()).unwrap();
@@ -185,6 +220,7 @@ mod tests {
                     results_count: Some(5),
                     session_id: None,
                     tokens_consumed: None,
+                    knowledge_ids: Vec::new(),
                 })
                 .unwrap();
         }
@@ -192,4 +228,49 @@ mod tests {
         let events = tracker.get_events(Some("proj-a"), 1).unwrap();
         assert_eq!(events.len(), 2);
     }
+
+    #[test]
+    fn test_latest_knowledge_ids() {
+        let temp = TempDir::new().unwrap();
+        let tracker = EventTracker::new(temp.path());
+        let now = Utc::now();
+
+        let surfaced =
+            |offset: i64, kind: EventType, session: Option<&str>, ids: &[&str]| UsageEvent {
+                timestamp: now - chrono::Duration::seconds(offset),
+                event_type: kind,
+                project: "proj".to_string(),
+                query: None,
+                category: None,
+                results_count: None,
+                session_id: session.map(String::from),
+                tokens_consumed: None,
+                knowledge_ids: ids.iter().map(|s| s.to_string()).collect(),
+            };
+
+        tracker
+            .track(surfaced(30, EventType::Recall, Some("s-old"), &["a", "b"]))
+            .unwrap();
+        tracker
+            .track(surfaced(20, EventType::Inject, None, &["decisions:c"]))
+            .unwrap();
+        tracker
+            .track(surfaced(10, EventType::Search, None, &["ignored"]))
+            .unwrap();
+
+        assert_eq!(
+            tracker.latest_knowledge_ids("proj", None, 1).unwrap(),
+            vec!["decisions:c"]
+        );
+        assert_eq!(
+            tracker
+                .latest_knowledge_ids("proj", Some("s-old"), 1)
+                .unwrap(),
+            vec!["a", "b"]
+        );
+        assert!(tracker
+            .latest_knowledge_ids("other", None, 1)
+            .unwrap()
+            .is_empty());
+    }
 }
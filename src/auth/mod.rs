@@ -1,3 +1,4 @@
+pub mod oauth;
 pub mod providers;
 
 use std::collections::HashMap;
@@ -28,6 +29,41 @@ pub struct ProviderCredential {
     pub endpoint: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub model: Option<String>,
+    /// OAuth refresh token (`type: "oauth"` only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+    /// When the OAuth access token in `key` expires
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Device-flow endpoints, kept so the token can be refreshed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oauth: Option<oauth::OAuthConfig>,
+}
+
+impl ProviderCredential {
+    /// Whether `key` holds an OAuth access token rather than an API key
+    pub fn is_oauth(&self) -> bool {
+        self.cred_type == "oauth"
+    }
+
+    /// Whether this is an OAuth credential whose access token has (nearly) expired
+    /// and can be refreshed.
+    pub fn needs_refresh(&self) -> bool {
+        self.is_oauth()
+            && self.refresh_token.is_some()
+            && self.oauth.is_some()
+            && self.expires_at.is_some_and(oauth::is_expired)
+    }
+
+    /// Store a newly issued OAuth token, keeping the old refresh token if the
+    /// server didn't rotate it.
+    pub fn apply_token(&mut self, token: oauth::TokenResponse) {
+        self.expires_at = token.expires_at();
+        self.key = token.access_token;
+        if token.refresh_token.is_some() {
+            self.refresh_token = token.refresh_token;
+        }
+    }
 }
 
 impl AuthStore {
@@ -104,8 +140,8 @@ pub fn resolve_provider(
 
     // 1. If explicit provider specified on CLI
     if let Some(name) = explicit {
-        let provider = Provider::from_str_loose(name)
-            .ok_or_else(|| MemoryError::Auth(format!("Unknown provider: {}", name)))?;
+        let provider =
+            Provider::from_str_loose(name).ok_or_else(|| unknown_provider_error(name))?;
         return resolve_for_provider(provider, &store, env_endpoint, env_model);
     }
 
@@ -132,6 +168,33 @@ pub fn resolve_provider(
     resolve_for_provider(Provider::Ollama, &store, env_endpoint, env_model)
 }
 
+/// Check that a custom provider endpoint is an absolute http(s) URL and normalise
+/// it (no trailing slash, since request paths are appended to it).
+pub fn validate_endpoint(endpoint: &str) -> Result<String> {
+    let endpoint = endpoint.trim().trim_end_matches('/');
+    match reqwest::Url::parse(endpoint) {
+        Ok(url) if matches!(url.scheme(), "http" | "https") && url.host().is_some() => {
+            Ok(endpoint.to_string())
+        }
+        _ => Err(MemoryError::Auth(format!(
+            "Invalid endpoint '{}': expected an http(s) URL such as https://gateway.example.com/v1",
+            endpoint
+        ))),
+    }
+}
+
+/// Error for an unrecognised `--provider` value, spelling out the valid names
+/// and how the provider is picked when the flag is omitted.
+fn unknown_provider_error(name: &str) -> MemoryError {
+    MemoryError::Auth(format!(
+        "Unknown provider: '{}'. Valid providers: {}.\n\
+         Resolution order: --provider > ANTHROPIC_API_KEY/OPENAI_API_KEY/GEMINI_API_KEY \
+         > default from 'engram auth' > any stored credential > ollama",
+        name,
+        Provider::accepted_names()
+    ))
+}
+
 /// Resolve a specific provider with env/auth.json credentials
 fn resolve_for_provider(
     provider: Provider,
@@ -139,13 +202,20 @@ fn resolve_for_provider(
     env_endpoint: Option<String>,
     env_model: Option<String>,
 ) -> Result<ResolvedProvider> {
-    // API key: env var > auth.json
-    let api_key = if !provider.env_var_name().is_empty() {
+    // API key: env var > auth.json (expired OAuth tokens are refreshed first)
+    let env_key = if !provider.env_var_name().is_empty() {
         std::env::var(provider.env_var_name()).ok()
     } else {
         None
-    }
-    .or_else(|| store.get(provider).map(|c| c.key.clone()));
+    };
+    let (api_key, oauth) = match (env_key, store.get(provider)) {
+        (Some(key), _) => (Some(key), false),
+        (None, Some(cred)) if cred.needs_refresh() => {
+            (Some(refresh_credential(provider, cred)?), true)
+        }
+        (None, Some(cred)) => (Some(cred.key.clone()), cred.is_oauth()),
+        (None, None) => (None, false),
+    };
 
     // Endpoint: env override > auth.json > provider default
     let endpoint = env_endpoint
@@ -172,9 +242,34 @@ fn resolve_for_provider(
         endpoint,
         model,
         api_key,
+        oauth,
     })
 }
 
+/// Refresh an expired OAuth credential, save it to auth.json and return the new
+/// access token.
+fn refresh_credential(provider: Provider, cred: &ProviderCredential) -> Result<String> {
+    let (Some(config), Some(refresh_token)) = (&cred.oauth, &cred.refresh_token) else {
+        return Ok(cred.key.clone());
+    };
+    let token = oauth::refresh_token_blocking(config, refresh_token).map_err(|e| {
+        MemoryError::Auth(format!(
+            "OAuth token for {} expired and could not be refreshed ({}). \
+             Run: engram auth login --provider {} --device-code ...",
+            provider.display_name(),
+            e,
+            provider
+        ))
+    })?;
+
+    let mut refreshed = cred.clone();
+    refreshed.apply_token(token);
+    let mut store = AuthStore::load()?;
+    store.set(provider, refreshed.clone());
+    store.save()?;
+    Ok(refreshed.key)
+}
+
 /// Check if any provider env var is set
 fn detect_from_env() -> Option<Provider> {
     if std::env::var("ANTHROPIC_API_KEY").is_ok() {
@@ -188,3 +283,19 @@ fn detect_from_env() -> Option<Provider> {
     }
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_endpoint() {
+        assert_eq!(
+            validate_endpoint("https://gateway.example.com/v1/").unwrap(),
+            "https://gateway.example.com/v1"
+        );
+        assert!(validate_endpoint("http://localhost:11434/v1").is_ok());
+        assert!(validate_endpoint("gateway.example.com/v1").is_err());
+        assert!(validate_endpoint("ftp://gateway.example.com").is_err());
+    }
+}
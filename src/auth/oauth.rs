@@ -0,0 +1,248 @@
+// OAuth device authorization grant (RFC 8628)
+//
+// Login asks the provider's device authorization endpoint for a user code,
+// sends the user to the verification URL, and polls the token endpoint until
+// the user approves. The access token is stored as a `cred_type: "oauth"`
+// credential together with its refresh token and expiry, and
+// `resolve_provider` refreshes it transparently once it is about to expire.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+
+use crate::error::{MemoryError, Result};
+
+/// Refresh tokens this long before they actually expire.
+const EXPIRY_MARGIN_SECS: i64 = 60;
+
+/// Endpoints and client registration for a provider's device flow.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct OAuthConfig {
+    pub client_id: String,
+    pub device_authorization_url: String,
+    pub token_url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+}
+
+/// Response from the device authorization endpoint.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct DeviceAuthorization {
+    pub device_code: String,
+    pub user_code: String,
+    pub verification_uri: String,
+    #[serde(default)]
+    pub verification_uri_complete: Option<String>,
+    pub expires_in: u64,
+    #[serde(default = "default_interval")]
+    pub interval: u64,
+}
+
+fn default_interval() -> u64 {
+    5
+}
+
+/// Successful response from the token endpoint.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct TokenResponse {
+    pub access_token: String,
+    #[serde(default)]
+    pub refresh_token: Option<String>,
+    #[serde(default)]
+    pub expires_in: Option<i64>,
+}
+
+impl TokenResponse {
+    /// Absolute expiry time, if the server reported one.
+    pub fn expires_at(&self) -> Option<DateTime<Utc>> {
+        self.expires_in
+            .map(|secs| Utc::now() + chrono::Duration::seconds(secs))
+    }
+}
+
+/// Error body returned by the token endpoint while polling.
+#[derive(Debug, serde::Deserialize)]
+struct TokenError {
+    error: String,
+    #[serde(default)]
+    error_description: Option<String>,
+}
+
+/// Whether a token expiring at `expires_at` should be refreshed now.
+pub fn is_expired(expires_at: DateTime<Utc>) -> bool {
+    expires_at <= Utc::now() + chrono::Duration::seconds(EXPIRY_MARGIN_SECS)
+}
+
+fn http_client() -> Result<reqwest::Client> {
+    reqwest::Client::builder()
+        .timeout(Duration::from_secs(30))
+        .build()
+        .map_err(|e| MemoryError::Auth(format!("HTTP client: {}", e)))
+}
+
+/// Start a device flow: returns the user code and verification URL to show.
+pub async fn request_device_code(config: &OAuthConfig) -> Result<DeviceAuthorization> {
+    let mut form = vec![("client_id", config.client_id.as_str())];
+    if let Some(ref scope) = config.scope {
+        form.push(("scope", scope.as_str()));
+    }
+
+    let resp = http_client()?
+        .post(&config.device_authorization_url)
+        .header("Accept", "application/json")
+        .form(&form)
+        .send()
+        .await?;
+    if !resp.status().is_success() {
+        return Err(MemoryError::Auth(format!(
+            "Device authorization failed ({}): {}",
+            resp.status(),
+            resp.text().await.unwrap_or_default()
+        )));
+    }
+    resp.json()
+        .await
+        .map_err(|e| MemoryError::Auth(format!("Invalid device authorization response: {}", e)))
+}
+
+/// Poll the token endpoint until the user approves, denies, or the code expires.
+pub async fn poll_for_token(
+    config: &OAuthConfig,
+    device: &DeviceAuthorization,
+) -> Result<TokenResponse> {
+    let client = http_client()?;
+    let deadline = std::time::Instant::now() + Duration::from_secs(device.expires_in);
+    let mut interval = device.interval.max(1);
+
+    loop {
+        tokio::time::sleep(Duration::from_secs(interval)).await;
+        if std::time::Instant::now() >= deadline {
+            return Err(MemoryError::Auth(
+                "Device code expired before it was approved".into(),
+            ));
+        }
+
+        let resp = client
+            .post(&config.token_url)
+            .header("Accept", "application/json")
+            .form(&[
+                ("grant_type", "urn:ietf:params:oauth:grant-type:device_code"),
+                ("device_code", device.device_code.as_str()),
+                ("client_id", config.client_id.as_str()),
+            ])
+            .send()
+            .await?;
+        let body = resp.text().await?;
+
+        if let Ok(token) = serde_json::from_str::<TokenResponse>(&body) {
+            return Ok(token);
+        }
+        let error: TokenError = serde_json::from_str(&body)
+            .map_err(|_| MemoryError::Auth(format!("Unexpected token response: {}", body)))?;
+        match error.error.as_str() {
+            "authorization_pending" => {}
+            "slow_down" => interval += 5,
+            "access_denied" => return Err(MemoryError::Auth("Login was denied".into())),
+            "expired_token" => {
+                return Err(MemoryError::Auth(
+                    "Device code expired before it was approved".into(),
+                ))
+            }
+            other => {
+                return Err(MemoryError::Auth(format!(
+                    "Token request failed: {}{}",
+                    other,
+                    error
+                        .error_description
+                        .map(|d| format!(" ({})", d))
+                        .unwrap_or_default()
+                )))
+            }
+        }
+    }
+}
+
+/// Exchange a refresh token for a new access token.
+pub async fn refresh_token(config: &OAuthConfig, refresh_token: &str) -> Result<TokenResponse> {
+    let resp = http_client()?
+        .post(&config.token_url)
+        .header("Accept", "application/json")
+        .form(&[
+            ("grant_type", "refresh_token"),
+            ("refresh_token", refresh_token),
+            ("client_id", config.client_id.as_str()),
+        ])
+        .send()
+        .await?;
+    if !resp.status().is_success() {
+        return Err(MemoryError::Auth(format!(
+            "Token refresh failed ({}): {}",
+            resp.status(),
+            resp.text().await.unwrap_or_default()
+        )));
+    }
+    resp.json()
+        .await
+        .map_err(|e| MemoryError::Auth(format!("Invalid token response: {}", e)))
+}
+
+/// [`refresh_token`] for synchronous callers. Runs on its own thread so it is
+/// safe to call from inside an async runtime as well.
+pub fn refresh_token_blocking(config: &OAuthConfig, token: &str) -> Result<TokenResponse> {
+    std::thread::scope(|scope| {
+        scope
+            .spawn(|| {
+                tokio::runtime::Builder::new_current_thread()
+                    .enable_all()
+                    .build()
+                    .map_err(|e| MemoryError::Config(format!("tokio runtime: {}", e)))?
+                    .block_on(refresh_token(config, token))
+            })
+            .join()
+            .unwrap_or_else(|_| Err(MemoryError::Auth("Token refresh panicked".into())))
+    })
+}
+
+/// Best-effort attempt to open `url` in the user's browser.
+pub fn open_in_browser(url: &str) -> bool {
+    #[cfg(target_os = "macos")]
+    let mut command = std::process::Command::new("open");
+    #[cfg(target_os = "windows")]
+    let mut command = {
+        let mut c = std::process::Command::new("cmd");
+        c.args(["/C", "start", ""]);
+        c
+    };
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    let mut command = std::process::Command::new("xdg-open");
+
+    command
+        .arg(url)
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .status()
+        .map(|s| s.success())
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_expired_uses_margin() {
+        assert!(is_expired(Utc::now() - chrono::Duration::seconds(1)));
+        assert!(is_expired(Utc::now() + chrono::Duration::seconds(30)));
+        assert!(!is_expired(Utc::now() + chrono::Duration::hours(1)));
+    }
+
+    #[test]
+    fn test_device_authorization_defaults_interval() {
+        let device: DeviceAuthorization = serde_json::from_str(
+            r#"{"device_code":"d","user_code":"ABCD-EFGH","verification_uri":"https://example.com/device","expires_in":900}"#,
+        )
+        .unwrap();
+        assert_eq!(device.interval, 5);
+        assert!(device.verification_uri_complete.is_none());
+    }
+}
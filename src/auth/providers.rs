@@ -1,5 +1,7 @@
 use std::fmt;
 
+use crate::error::{MemoryError, Result};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Provider {
@@ -84,17 +86,83 @@ impl Provider {
         !matches!(self, Provider::Anthropic)
     }
 
-    pub fn from_str_loose(s: &str) -> Option<Provider> {
-        match s.to_lowercase().as_str() {
-            "anthropic" | "claude" => Some(Provider::Anthropic),
-            "openai" | "gpt" => Some(Provider::OpenAI),
-            "ollama" | "local" => Some(Provider::Ollama),
-            "gemini" | "google" => Some(Provider::Gemini),
-            "vscode" | "vs-code" | "copilot" => Some(Provider::VSCode),
-            "openrouter" | "or" => Some(Provider::OpenRouter),
-            _ => None,
+    /// Bundled model names offered when the provider can't list its models
+    /// (no endpoint, missing key, request failed). Not exhaustive — any model
+    /// name can still be typed in. Empty for local/proxy providers whose models
+    /// depend on the user's setup.
+    pub fn known_models(&self) -> &'static [&'static str] {
+        match self {
+            Provider::Anthropic => &[
+                "claude-opus-4-1-20250805",
+                "claude-opus-4-20250514",
+                "claude-sonnet-4-5-20250929",
+                "claude-sonnet-4-20250514",
+                "claude-3-7-sonnet-20250219",
+                "claude-haiku-4-5-20251001",
+                "claude-3-5-haiku-20241022",
+            ],
+            Provider::OpenAI => &[
+                "gpt-4.1",
+                "gpt-4.1-mini",
+                "gpt-4.1-nano",
+                "gpt-4o",
+                "gpt-4o-mini",
+                "o3",
+                "o4-mini",
+            ],
+            Provider::Gemini => &[
+                "gemini-2.5-pro",
+                "gemini-2.5-flash",
+                "gemini-2.5-flash-lite",
+                "gemini-2.0-flash",
+                "gemini-2.0-flash-lite",
+            ],
+            Provider::Ollama | Provider::VSCode | Provider::OpenRouter => &[],
+        }
+    }
+
+    /// Alternative names accepted by [`Provider::from_str_loose`] besides the
+    /// canonical lowercase name.
+    pub fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            Provider::Anthropic => &["claude"],
+            Provider::OpenAI => &["gpt"],
+            Provider::Ollama => &["local"],
+            Provider::Gemini => &["google"],
+            Provider::VSCode => &["vs-code", "copilot"],
+            Provider::OpenRouter => &["or"],
         }
     }
+
+    /// Parse a provider name or alias, case-insensitively.
+    pub fn from_str_loose(s: &str) -> Option<Provider> {
+        let s = s.trim().to_lowercase();
+        Provider::all()
+            .iter()
+            .copied()
+            .find(|p| p.to_string() == s || p.aliases().contains(&s.as_str()))
+    }
+
+    /// Like [`Provider::from_str_loose`], but with an error listing every
+    /// accepted name.
+    pub fn parse(s: &str) -> Result<Provider> {
+        Provider::from_str_loose(s).ok_or_else(|| {
+            MemoryError::Auth(format!(
+                "Unknown provider: '{}'. Valid providers: {}",
+                s,
+                Provider::accepted_names()
+            ))
+        })
+    }
+
+    /// Every provider with its aliases, e.g. `anthropic (claude), openai (gpt), ...`.
+    pub fn accepted_names() -> String {
+        Provider::all()
+            .iter()
+            .map(|p| format!("{} ({})", p, p.aliases().join(", ")))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
 }
 
 impl fmt::Display for Provider {
@@ -117,4 +185,133 @@ pub struct ResolvedProvider {
     pub endpoint: String,
     pub model: String,
     pub api_key: Option<String>,
+    /// `api_key` is an OAuth access token rather than an API key
+    pub oauth: bool,
+}
+
+/// Anthropic API version sent with every request
+const ANTHROPIC_VERSION: &str = "2023-06-01";
+/// Beta flag Anthropic requires for requests authenticated with OAuth tokens
+const ANTHROPIC_OAUTH_BETA: &str = "oauth-2025-04-20";
+
+/// Attach an Anthropic credential: API keys go in `x-api-key`, OAuth access
+/// tokens in a Bearer header together with the OAuth beta flag.
+pub fn anthropic_auth(
+    req: reqwest::RequestBuilder,
+    key: &str,
+    oauth: bool,
+) -> reqwest::RequestBuilder {
+    let req = req.header("anthropic-version", ANTHROPIC_VERSION);
+    if oauth {
+        req.bearer_auth(key)
+            .header("anthropic-beta", ANTHROPIC_OAUTH_BETA)
+    } else {
+        req.header("x-api-key", key)
+    }
+}
+
+/// Attach a Gemini credential: API keys go in the `key` query parameter,
+/// OAuth access tokens in a Bearer header.
+pub fn gemini_auth(
+    req: reqwest::RequestBuilder,
+    key: &str,
+    oauth: bool,
+) -> reqwest::RequestBuilder {
+    if oauth {
+        req.bearer_auth(key)
+    } else {
+        req.query(&[("key", key)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_oauth_tokens_are_sent_as_bearer() {
+        let client = reqwest::Client::new();
+        let build = |req: reqwest::RequestBuilder| req.build().unwrap();
+
+        let api_key = build(anthropic_auth(
+            client.post("https://a.test/v1/messages"),
+            "sk",
+            false,
+        ));
+        assert_eq!(api_key.headers()["x-api-key"], "sk");
+        assert!(api_key.headers().get("authorization").is_none());
+
+        let oauth = build(anthropic_auth(
+            client.post("https://a.test/v1/messages"),
+            "tok",
+            true,
+        ));
+        assert_eq!(oauth.headers()["authorization"], "Bearer tok");
+        assert_eq!(oauth.headers()["anthropic-beta"], ANTHROPIC_OAUTH_BETA);
+        assert_eq!(oauth.headers()["anthropic-version"], ANTHROPIC_VERSION);
+        assert!(oauth.headers().get("x-api-key").is_none());
+
+        let gemini_key = build(gemini_auth(
+            client.get("https://g.test/v1beta/models"),
+            "k",
+            false,
+        ));
+        assert_eq!(gemini_key.url().query(), Some("key=k"));
+        let gemini_oauth = build(gemini_auth(
+            client.get("https://g.test/v1beta/models"),
+            "tok",
+            true,
+        ));
+        assert_eq!(gemini_oauth.url().query(), None);
+        assert_eq!(gemini_oauth.headers()["authorization"], "Bearer tok");
+    }
+
+    #[test]
+    fn test_from_str_loose_names_and_aliases() {
+        let cases = [
+            ("anthropic", Provider::Anthropic),
+            ("claude", Provider::Anthropic),
+            ("openai", Provider::OpenAI),
+            ("gpt", Provider::OpenAI),
+            ("ollama", Provider::Ollama),
+            ("local", Provider::Ollama),
+            ("gemini", Provider::Gemini),
+            ("google", Provider::Gemini),
+            ("vscode", Provider::VSCode),
+            ("vs-code", Provider::VSCode),
+            ("copilot", Provider::VSCode),
+            ("openrouter", Provider::OpenRouter),
+            ("or", Provider::OpenRouter),
+            (" Claude ", Provider::Anthropic),
+            ("OPENAI", Provider::OpenAI),
+        ];
+        for (name, expected) in cases {
+            assert_eq!(Provider::from_str_loose(name), Some(expected), "{}", name);
+        }
+        assert_eq!(Provider::from_str_loose("cohere"), None);
+    }
+
+    #[test]
+    fn test_parse_error_lists_every_provider_and_alias() {
+        let err = Provider::parse("cohere").unwrap_err().to_string();
+        assert!(err.contains("'cohere'"));
+        for provider in Provider::all() {
+            assert!(err.contains(&provider.to_string()), "{}", err);
+            for alias in provider.aliases() {
+                assert!(err.contains(alias), "{}", err);
+            }
+        }
+    }
+
+    #[test]
+    fn test_known_models_include_default() {
+        for provider in Provider::all() {
+            let known = provider.known_models();
+            assert!(
+                known.is_empty() || known.contains(&provider.default_model()),
+                "{} default model missing from known_models",
+                provider
+            );
+        }
+    }
 }
@@ -1,5 +1,10 @@
 use clap::{Parser, Subcommand};
 
+use crate::commands::hooks::HookEvent;
+use crate::config::Category;
+use crate::hive::PackScope;
+use crate::renderer::markdown::SummaryStyle;
+
 #[derive(Parser)]
 #[command(
     name = "engram",
@@ -7,9 +12,18 @@ use clap::{Parser, Subcommand};
     version
 )]
 pub struct Cli {
-    /// Enable verbose output
+    /// Verbose output; repeat for more detailed logs (-v info, -vv debug, -vvv trace).
+    /// `RUST_LOG` overrides the log level when set.
+    #[arg(global = true, long, short, action = clap::ArgAction::Count)]
+    pub verbose: u8,
+
+    /// Suppress decorative output (success banners, hints); print only data and errors
     #[arg(global = true, long, short)]
-    pub verbose: bool,
+    pub quiet: bool,
+
+    /// Disable colored output (also honors NO_COLOR and non-TTY stdout)
+    #[arg(global = true, long)]
+    pub no_color: bool,
 
     #[command(subcommand)]
     pub command: Commands,
@@ -31,7 +45,8 @@ pub enum Commands {
         #[arg(long)]
         project: Option<String>,
 
-        /// Only process recent sessions (e.g., "1d", "2h", "30m")
+        /// Only process recent sessions (e.g., "1d", "2h", "30m"), or sessions modified after
+        /// the one with this session ID
         #[arg(long)]
         since: Option<String>,
 
@@ -39,13 +54,37 @@ pub enum Commands {
         #[arg(long)]
         skip_knowledge: bool,
 
-        /// LLM provider override (anthropic, openai, ollama)
+        /// LLM provider override (anthropic, openai, ollama, gemini, vscode, openrouter)
         #[arg(long)]
         provider: Option<String>,
 
         /// Time-to-live for extracted entries (e.g., "7d", "2w")
         #[arg(long)]
         ttl: Option<String>,
+
+        /// Collapse long tool outputs in archived conversations
+        #[arg(long)]
+        compact: bool,
+
+        /// Only process projects that have no knowledge yet
+        #[arg(long)]
+        only_new_projects: bool,
+
+        /// Keep potential secrets (API keys, tokens) in extracted knowledge instead of redacting them
+        #[arg(long)]
+        no_secret_scan: bool,
+
+        /// Per-session summary verbosity (default: config.json `summary_style`, else brief)
+        #[arg(long, value_enum)]
+        summary_style: Option<SummaryStyle>,
+
+        /// Include code snippets from assistant replies in session summaries
+        #[arg(long)]
+        summary_code: bool,
+
+        /// Leave code snippets out of session summaries, overriding config.json
+        #[arg(long, conflicts_with = "summary_code")]
+        no_summary_code: bool,
     },
 
     /// Full-text search across all memory
@@ -68,12 +107,28 @@ pub enum Commands {
         /// Search global memory (_global knowledge store)
         #[arg(long)]
         global: bool,
+
+        /// Print only the paths of matching files, one per line (like `grep -l`)
+        #[arg(long, short = 'l')]
+        files_only: bool,
+
+        /// Stop printing after this many matching files (default: unlimited)
+        #[arg(long, short = 'n')]
+        max_results: Option<usize>,
+
+        /// Show conversation matches as their enclosing turn (role + message) instead of a line window
+        #[arg(long, conflicts_with = "files_only")]
+        turns: bool,
     },
 
     /// Show project context (knowledge summary)
     Recall {
         /// Project name
         project: String,
+
+        /// Keep entries in file (chronological) order instead of newest first
+        #[arg(long)]
+        chronological: bool,
     },
 
     /// Output context.md to stdout (for piping into prompts)
@@ -86,7 +141,19 @@ pub enum Commands {
     Status,
 
     /// List all projects with activity
-    Projects,
+    Projects {
+        /// Sort order: name (A–Z), size (largest first), recent (newest first)
+        #[arg(long, value_parser = ["name", "size", "recent"])]
+        sort: Option<String>,
+
+        /// Only show projects whose name contains this substring (case-insensitive)
+        #[arg(long)]
+        filter: Option<String>,
+
+        /// Only show projects that have a generated context.md
+        #[arg(long)]
+        with_knowledge: bool,
+    },
 
     /// Interactive TUI for browsing and managing memories
     Tui,
@@ -104,9 +171,9 @@ pub enum Commands {
         /// Use semantic search to inject only what is relevant to current git context
         #[arg(long)]
         smart: bool,
-        /// Token budget for smart inject (default: 1500)
-        #[arg(long, default_value = "1500")]
-        budget: usize,
+        /// Token budget; trims packs, then older blocks to fit (smart inject default: 1500)
+        #[arg(long)]
+        budget: Option<usize>,
         /// Line budget for compact inject (default: 180). Scales all sections proportionally.
         /// Useful for long-context models: e.g. --lines 500 for ~3x more context.
         #[arg(long)]
@@ -114,6 +181,16 @@ pub enum Commands {
         /// Measure and report token efficiency vs. full-context baseline
         #[arg(long)]
         measure_tokens: bool,
+        /// Keep content outside the engram-managed markers and only replace the managed block
+        #[arg(long)]
+        append: bool,
+        /// When several Claude Code project directories match the name, inject into all of them
+        /// instead of asking which one
+        #[arg(long)]
+        all_matches: bool,
+        /// Keep raw knowledge entries in file (chronological) order instead of newest first
+        #[arg(long)]
+        chronological: bool,
     },
 
     /// Manage Claude Code hooks for automatic memory integration
@@ -133,13 +210,21 @@ pub enum Commands {
         /// Project name
         project: String,
 
-        /// LLM provider override (anthropic, openai, ollama)
+        /// LLM provider override (anthropic, openai, ollama, gemini, vscode, openrouter)
         #[arg(long)]
         provider: Option<String>,
 
+        /// LLM model override for this run (e.g. a larger model for a one-off job)
+        #[arg(long)]
+        model: Option<String>,
+
         /// Persist expired entry cleanup to disk (default: filter in-memory only)
         #[arg(long)]
         persist_cleanup: bool,
+
+        /// Print the system and context prompts instead of calling the LLM
+        #[arg(long, conflicts_with = "persist_cleanup")]
+        dry_run: bool,
     },
 
     /// Add a manual knowledge entry to a project
@@ -147,9 +232,9 @@ pub enum Commands {
         /// Project name
         project: String,
 
-        /// Knowledge category: decisions, solutions, patterns, bugs, insights, questions, procedures, or preferences
-        #[arg(value_parser = ["decisions", "solutions", "patterns", "bugs", "insights", "questions", "procedures", "preferences"])]
-        category: String,
+        /// Knowledge category
+        #[arg(value_enum)]
+        category: Category,
 
         /// The knowledge content to add
         content: String,
@@ -183,8 +268,8 @@ pub enum Commands {
         dry_run: bool,
 
         /// Only drain entries of this category (e.g. decisions, bugs)
-        #[arg(long)]
-        category: Option<String>,
+        #[arg(long, value_enum)]
+        category: Option<Category>,
     },
 
     /// Promote an inbox entry into project/global long-term memory
@@ -195,9 +280,9 @@ pub enum Commands {
         /// Inbox session ID to promote (for example: abc123:decisions)
         session_id: String,
 
-        /// Target category: decisions, solutions, patterns, bugs, insights, questions, procedures, or preferences
-        #[arg(value_parser = ["decisions", "solutions", "patterns", "bugs", "insights", "questions", "procedures", "preferences"])]
-        category: String,
+        /// Target category
+        #[arg(value_enum)]
+        category: Category,
 
         /// Promote to global memory instead of project memory
         #[arg(long)]
@@ -218,11 +303,53 @@ pub enum Commands {
         project: String,
 
         /// Topic to search for (case-insensitive substring match)
-        query: String,
+        #[arg(required_unless_present = "label")]
+        query: Option<String>,
+
+        /// Only show entries whose session ID starts with this label
+        #[arg(long)]
+        label: Option<String>,
 
         /// Include expired entries in results (marked with [EXPIRED])
         #[arg(long)]
         all: bool,
+
+        /// Stop printing after this many matching blocks (default: unlimited)
+        #[arg(long, short = 'n')]
+        max_results: Option<usize>,
+    },
+
+    /// Summarize a project's knowledge: entries per category, expiry, size, indexes
+    Stats {
+        /// Project name
+        project: String,
+
+        /// Print the summary as JSON for scripting
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Chronological work log of knowledge added across projects (the TUI Timeline, on stdout)
+    Timeline {
+        /// Only show this project
+        project: Option<String>,
+
+        /// How many days back to show (0 = all history)
+        #[arg(long, default_value = "7")]
+        days: u32,
+
+        /// Print entries as JSON for scripting
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Restore knowledge removed by `forget` or `consolidate --auto-merge` from the trash
+    Restore {
+        /// Project name
+        project: String,
+
+        /// Trash entry to restore (lists the project's trash when omitted)
+        id: Option<String>,
     },
 
     /// Remove knowledge for a project
@@ -237,6 +364,10 @@ pub enum Commands {
         #[arg(long)]
         topic: Option<String>,
 
+        /// Remove every session whose ID starts with this label
+        #[arg(long, conflicts_with_all = ["session_id", "topic"])]
+        label: Option<String>,
+
         /// Wipe all knowledge for the project
         #[arg(long)]
         all: bool,
@@ -260,6 +391,14 @@ pub enum Commands {
         /// Summarize stale entries with LLM instead of deleting (requires --stale)
         #[arg(long)]
         summarize: bool,
+
+        /// Remove every entry older than a duration (e.g. "90d"), regardless of TTL
+        #[arg(long, conflicts_with_all = ["session_id", "topic", "label", "all", "expired", "stale"])]
+        older_than: Option<String>,
+
+        /// Show what --older-than would remove without writing
+        #[arg(long, requires = "older_than")]
+        dry_run: bool,
     },
 
     /// Manage LLM provider authentication
@@ -270,7 +409,7 @@ pub enum Commands {
 
     /// Run as MCP server (Model Context Protocol)
     Mcp {
-        /// LLM provider override (anthropic, openai, ollama)
+        /// LLM provider override (anthropic, openai, ollama, gemini, vscode, openrouter)
         #[arg(long)]
         provider: Option<String>,
     },
@@ -280,17 +419,46 @@ pub enum Commands {
         /// Project name
         project: String,
 
-        /// Output format
-        #[arg(value_parser = ["markdown", "json", "html"])]
+        /// Output format (jsonl: one record per session block, re-importable with `import`)
+        #[arg(value_parser = ["markdown", "json", "html", "jsonl"])]
         format: String,
 
-        /// Output file path (default: stdout)
+        /// Output file path (default or "-": stdout)
         #[arg(short, long)]
         output: Option<String>,
 
         /// Include conversation archives (not just knowledge)
         #[arg(long)]
         include_conversations: bool,
+
+        /// Only export these sections (repeatable; default: all)
+        #[arg(long = "category", value_parser = ["context", "decisions", "solutions", "patterns", "workflows"])]
+        categories: Vec<String>,
+
+        /// Only export knowledge blocks newer than this (e.g., "7d", "2w")
+        #[arg(long)]
+        since: Option<String>,
+
+        /// Replace potential secrets (API keys, tokens, passwords) with [REDACTED] markers
+        #[arg(long)]
+        anonymize: bool,
+    },
+
+    /// Import knowledge from an `export` file (markdown, json or jsonl)
+    Import {
+        /// Project to import into
+        project: String,
+
+        /// Path to the exported file
+        input: String,
+
+        /// Input format (default: detected from the file contents)
+        #[arg(long, value_parser = ["markdown", "json", "jsonl"])]
+        format: Option<String>,
+
+        /// Show what would be imported without writing
+        #[arg(long)]
+        dry_run: bool,
     },
 
     /// Sync knowledge with GitHub Gists
@@ -313,6 +481,26 @@ pub enum Commands {
         /// Embedding provider (openai, gemini, ollama)
         #[arg(long)]
         provider: Option<String>,
+
+        /// Embedding model (default: stored embed model, else the provider's default)
+        #[arg(long)]
+        model: Option<String>,
+
+        /// Ollama server URL, e.g. http://gpu-box:11434 (default: http://localhost:11434)
+        #[arg(long)]
+        endpoint: Option<String>,
+
+        /// How to split knowledge into chunks (default: the existing index's strategy, else block)
+        #[arg(long, value_parser = ["block", "paragraph", "window"])]
+        chunk_strategy: Option<String>,
+
+        /// Words per chunk for the window strategy
+        #[arg(long, requires = "chunk_strategy")]
+        window_size: Option<usize>,
+
+        /// Words shared between consecutive windows
+        #[arg(long, requires = "chunk_strategy")]
+        window_overlap: Option<usize>,
     },
 
     /// Semantic search using embeddings
@@ -328,9 +516,9 @@ pub enum Commands {
         #[arg(long, default_value = "10")]
         top: usize,
 
-        /// Minimum similarity threshold (0.0 - 1.0)
+        /// Minimum similarity threshold (0.0 - 1.0), or "auto" to derive one from the scores
         #[arg(long, default_value = "0.5")]
-        threshold: f32,
+        threshold: String,
 
         /// Only include chunks from this time window (e.g. "7d", "2h", "30m")
         #[arg(long)]
@@ -343,6 +531,26 @@ pub enum Commands {
         /// Only include chunks whose session_id or text contains this string (e.g. "src/auth")
         #[arg(long)]
         file: Option<String>,
+
+        /// Show each result's source file, session and full chunk text
+        #[arg(long)]
+        explain: bool,
+
+        /// Re-rank the results by asking the LLM to judge relevance (one extra LLM call)
+        #[arg(long)]
+        rerank: bool,
+
+        /// Embedding provider for the query (default: the one recorded in the project's index)
+        #[arg(long)]
+        provider: Option<String>,
+
+        /// Embedding model for the query
+        #[arg(long)]
+        model: Option<String>,
+
+        /// Ollama server URL for the query embedding
+        #[arg(long)]
+        endpoint: Option<String>,
     },
 
     /// Detect and consolidate duplicate/similar knowledge
@@ -354,16 +562,25 @@ pub enum Commands {
         #[arg(long, default_value = "0.9")]
         threshold: f32,
 
-        /// Automatically merge duplicates without confirmation
+        /// Merge each duplicate group into its most important/newest entry, removing the rest
+        /// from the knowledge files and rebuilding the embedding index
         #[arg(long)]
         auto_merge: bool,
 
         /// Detect contradictions using LLM
         #[arg(long)]
         find_contradictions: bool,
+
+        /// LLM provider override (anthropic, openai, ollama, gemini, vscode, openrouter)
+        #[arg(long)]
+        provider: Option<String>,
+
+        /// LLM model override for this run (e.g. a larger model for a one-off job)
+        #[arg(long)]
+        model: Option<String>,
     },
 
-    /// Self-diagnose and fix issues (health check)
+    /// Self-diagnose and fix issues (health check; `--verbose` shows fix commands)
     Doctor {
         /// Project name (optional - checks all if not specified)
         project: Option<String>,
@@ -372,9 +589,13 @@ pub enum Commands {
         #[arg(long)]
         fix: bool,
 
-        /// Show detailed diagnostic information
-        #[arg(long)]
-        verbose: bool,
+        /// Show the recorded health score history instead of running checks
+        #[arg(long, conflicts_with = "fix")]
+        trend: bool,
+
+        /// Number of recent scores to show with --trend
+        #[arg(long, default_value = "20", requires = "trend")]
+        last: usize,
     },
 
     /// Show usage analytics and insights
@@ -397,12 +618,12 @@ pub enum Commands {
 
     /// Show knowledge changes over time
     Diff {
-        /// Project name
+        /// Project name, or "all" for a change summary across every project
         project: String,
 
-        /// Knowledge category
-        #[arg(value_parser = ["decisions", "solutions", "patterns", "bugs", "insights", "questions", "procedures", "preferences"])]
-        category: String,
+        /// Knowledge category (optional with "all")
+        #[arg(value_enum)]
+        category: Option<Category>,
 
         /// Compare with specific version ID
         #[arg(long)]
@@ -413,6 +634,24 @@ pub enum Commands {
         history: bool,
     },
 
+    /// Prune stored knowledge versions used by `diff`
+    PruneVersions {
+        /// Project name (default: every project)
+        project: Option<String>,
+
+        /// Versions to keep per category
+        #[arg(long, default_value = "10")]
+        keep: usize,
+
+        /// Only remove versions older than a duration (e.g. "30d", "6w")
+        #[arg(long)]
+        older_than: Option<String>,
+
+        /// Show what would be removed without deleting
+        #[arg(long)]
+        dry_run: bool,
+    },
+
     /// View and manage reinforcement learning progress
     Learn {
         #[command(subcommand)]
@@ -456,10 +695,18 @@ pub enum Commands {
         #[arg(long, default_value = "0.15")]
         threshold: f32,
 
-        /// LLM provider override (anthropic, openai, ollama)
+        /// Token budget for retrieved knowledge; the best entries that fit are sent (default: 6000)
+        #[arg(long, default_value_t = crate::commands::ask::DEFAULT_ASK_BUDGET)]
+        budget: usize,
+
+        /// LLM provider override (anthropic, openai, ollama, gemini, vscode, openrouter)
         #[arg(long)]
         provider: Option<String>,
 
+        /// LLM model override for this run (e.g. a larger model for a one-off job)
+        #[arg(long)]
+        model: Option<String>,
+
         /// Use graph-augmented retrieval (traverses 2-hop entity neighbors for multi-hop QA)
         #[arg(long)]
         use_graph: bool,
@@ -512,16 +759,35 @@ pub enum GraphCommand {
     /// Build knowledge graph from project knowledge
     Build {
         /// Project name
-        project: String,
+        #[arg(required_unless_present = "all", conflicts_with = "all")]
+        project: Option<String>,
+
+        /// Build graphs for every project with knowledge
+        #[arg(long)]
+        all: bool,
+
+        /// Maximum concurrent LLM builds with --all
+        #[arg(long, default_value = "3", value_parser = clap::value_parser!(u64).range(1..=16))]
+        concurrency: u64,
 
         /// LLM provider override
         #[arg(long)]
         provider: Option<String>,
+
+        /// LLM model override for this run (e.g. a larger model for a one-off job)
+        #[arg(long)]
+        model: Option<String>,
+    },
+
+    /// Merge project graphs into a cross-project graph (saved to _global/graph.json)
+    Merge {
+        /// Projects to merge (default: every project with a graph)
+        projects: Vec<String>,
     },
 
     /// Query the knowledge graph
     Query {
-        /// Project name
+        /// Project name ("global" for the merged cross-project graph)
         project: String,
 
         /// Concept to explore
@@ -534,14 +800,14 @@ pub enum GraphCommand {
 
     /// Visualize the knowledge graph
     Viz {
-        /// Project name
+        /// Project name ("global" for the merged cross-project graph)
         project: String,
 
         /// Output format
         #[arg(value_parser = ["dot", "svg", "ascii"])]
         format: String,
 
-        /// Output file (default: stdout)
+        /// Output file (default or "-": stdout; required for svg)
         #[arg(short, long)]
         output: Option<String>,
 
@@ -560,6 +826,10 @@ pub enum GraphCommand {
 
         /// Target concept
         to: String,
+
+        /// Weight edges by relationship strength (prefers strong connections)
+        #[arg(long)]
+        weighted: bool,
     },
 
     /// Find most connected concepts (hubs)
@@ -627,6 +897,20 @@ pub enum SyncCommand {
         version: Option<String>,
     },
 
+    /// Compare local knowledge with a gist or git repo without changing either
+    Status {
+        /// Project name
+        project: String,
+
+        /// Gist ID to compare against
+        #[arg(long, conflicts_with = "repo", required_unless_present = "repo")]
+        gist_id: Option<String>,
+
+        /// Git repository path to compare against (working tree, no fetch)
+        #[arg(long)]
+        repo: Option<String>,
+    },
+
     /// Push knowledge to a git repository
     PushRepo {
         /// Project name
@@ -659,6 +943,14 @@ pub enum SyncCommand {
         /// Branch to use
         #[arg(long, default_value = "main")]
         branch: String,
+
+        /// Merge session blocks into local files that have their own changes
+        #[arg(long, conflicts_with = "force")]
+        merge: bool,
+
+        /// Overwrite local files even if that drops local sessions
+        #[arg(long)]
+        force: bool,
     },
 
     /// Initialize a git repository for knowledge sharing
@@ -671,12 +963,21 @@ pub enum SyncCommand {
 #[derive(Subcommand)]
 pub enum HooksCommand {
     /// Install hooks into Claude Code settings
-    Install,
+    Install {
+        /// Comma-separated hook events to install; others are removed
+        #[arg(
+            long,
+            value_enum,
+            value_delimiter = ',',
+            default_value = "session-start,post-tool-use,stop"
+        )]
+        events: Vec<HookEvent>,
+    },
 
     /// Remove hooks from Claude Code settings
     Uninstall,
 
-    /// Show hook installation status
+    /// Show hook installation status (`--verbose` lists every registered command)
     Status,
 }
 
@@ -684,17 +985,45 @@ pub enum HooksCommand {
 pub enum AuthCommand {
     /// Log in to an LLM provider
     Login {
-        /// Provider name (anthropic, openai, ollama)
+        /// Provider name (anthropic, openai, ollama, gemini, vscode, openrouter)
         #[arg(long)]
         provider: Option<String>,
 
         /// Set as default provider
         #[arg(long)]
         set_default: bool,
+
+        /// Custom API endpoint for this provider (proxy or OpenAI-compatible gateway)
+        #[arg(long)]
+        endpoint: Option<String>,
+
+        /// Log in with an OAuth device code instead of an API key
+        #[arg(long, requires_all = ["client_id", "device_url", "token_url"])]
+        device_code: bool,
+
+        /// OAuth client ID (with --device-code)
+        #[arg(long, requires = "device_code")]
+        client_id: Option<String>,
+
+        /// OAuth device authorization endpoint (with --device-code)
+        #[arg(long, requires = "device_code")]
+        device_url: Option<String>,
+
+        /// OAuth token endpoint (with --device-code)
+        #[arg(long, requires = "device_code")]
+        token_url: Option<String>,
+
+        /// OAuth scope to request (with --device-code)
+        #[arg(long, requires = "device_code")]
+        scope: Option<String>,
     },
 
     /// List configured providers
-    List,
+    List {
+        /// Also show the model each provider is set to
+        #[arg(long)]
+        show_models: bool,
+    },
 
     /// Remove provider credentials
     Logout {
@@ -713,7 +1042,7 @@ pub enum AuthCommand {
 
     /// Set model override for a provider (saved to auth.json)
     Model {
-        /// Provider name (anthropic, openai, ollama, gemini)
+        /// Provider name (anthropic, openai, ollama, gemini, vscode, openrouter)
         provider: String,
         /// Model name to use for this provider
         model: String,
@@ -767,6 +1096,10 @@ pub enum LearnCommand {
         /// Apply automatically without confirmation
         #[arg(long)]
         auto: bool,
+
+        /// Minimum learning signals per entry before its adjustments are applied
+        #[arg(long, default_value_t = crate::learning::adaptation::DEFAULT_MIN_OBSERVATIONS)]
+        min_samples: u32,
     },
 
     /// Reset learning state to defaults
@@ -775,6 +1108,25 @@ pub enum LearnCommand {
         project: String,
     },
 
+    /// Export learned parameters as JSON (for backup or sharing)
+    Export {
+        /// Project name
+        project: String,
+
+        /// Output file path (default or "-": stdout)
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+
+    /// Import learned parameters from a JSON file produced by `learn export`
+    Import {
+        /// Project name
+        project: String,
+
+        /// Path to the exported JSON file
+        input: String,
+    },
+
     /// Run learning simulation
     Simulate {
         /// Project name
@@ -835,9 +1187,13 @@ pub enum HiveCommand {
         #[arg(long)]
         registry: Option<String>,
 
-        /// Installation scope (user/project)
-        #[arg(long, default_value = "user")]
-        scope: String,
+        /// Installation scope: global (all projects) or project (one project only)
+        #[arg(long, value_enum, default_value = "global")]
+        scope: PackScope,
+
+        /// Project to install for (required with --scope project)
+        #[arg(long, required_if_eq("scope", "project"))]
+        project: Option<String>,
     },
 
     /// Uninstall a knowledge pack
@@ -853,6 +1209,10 @@ pub enum HiveCommand {
     Update {
         /// Pack name (updates all if not specified)
         pack: Option<String>,
+
+        /// Only report which packs would change (installed → registry version); refreshes registries but leaves installed packs untouched
+        #[arg(long)]
+        check: bool,
     },
 
     /// Browse available knowledge packs
@@ -864,12 +1224,32 @@ pub enum HiveCommand {
         /// Filter by keyword
         #[arg(long)]
         keyword: Option<String>,
+
+        /// Print packs as JSON (with installed status) for scripting
+        #[arg(long)]
+        json: bool,
+
+        /// Maximum number of packs to show
+        #[arg(long)]
+        limit: Option<usize>,
+
+        /// Number of packs to skip (for paging through results)
+        #[arg(long, default_value = "0")]
+        offset: usize,
+
+        /// Fetch registries now instead of using listings cached in the last 24 hours
+        #[arg(long)]
+        refresh: bool,
     },
 
     /// Search for knowledge packs
     Search {
         /// Search query
         query: String,
+
+        /// Fetch registries now instead of using listings cached in the last 24 hours
+        #[arg(long)]
+        refresh: bool,
     },
 }
 
@@ -938,6 +1318,26 @@ pub enum PackCommand {
         /// Pack directory path
         path: String,
     },
+
+    /// Show what `hive update` would change in an installed pack
+    Diff {
+        /// Installed pack name
+        name: String,
+    },
+
+    /// Summarize a pack's knowledge from its registry without installing it
+    Preview {
+        /// Pack name
+        name: String,
+
+        /// Look only in this registry
+        #[arg(long)]
+        registry: Option<String>,
+
+        /// Entries to show per category
+        #[arg(long, default_value = "3")]
+        entries: usize,
+    },
 }
 
 #[derive(Subcommand)]
@@ -946,6 +1346,10 @@ pub enum RegistryCommand {
     Add {
         /// Registry URL (supports GitHub shorthand: owner/repo)
         url: String,
+
+        /// Hex-encoded Ed25519 public key; packs from this registry must be signed with it
+        #[arg(long)]
+        public_key: Option<String>,
     },
 
     /// Remove a registry
@@ -969,15 +1373,31 @@ pub enum MemCommand {
     /// Initialize VCS for a project
     Init {
         /// Project name (defaults to basename of current directory)
-        #[arg(long)]
         project: Option<String>,
+
+        /// Same as the positional project (kept for existing scripts)
+        #[arg(
+            long = "project",
+            value_name = "PROJECT",
+            conflicts_with = "project",
+            hide = true
+        )]
+        project_flag: Option<String>,
     },
 
     /// Show staged / unstaged sessions
     Status {
         /// Project name (defaults to basename of current directory)
-        #[arg(long)]
         project: Option<String>,
+
+        /// Same as the positional project (kept for existing scripts)
+        #[arg(
+            long = "project",
+            value_name = "PROJECT",
+            conflicts_with = "project",
+            hide = true
+        )]
+        project_flag: Option<String>,
     },
 
     /// Stage specific session IDs for the next commit
@@ -1012,7 +1432,7 @@ pub enum MemCommand {
         session: Option<String>,
     },
 
-    /// Show commit log
+    /// Show commit log (`--verbose` adds full session lists and category hashes)
     Log {
         /// Project name
         project: String,
@@ -1021,10 +1441,6 @@ pub enum MemCommand {
         #[arg(long, default_value = "10")]
         limit: usize,
 
-        /// Show full session lists and category hashes
-        #[arg(long)]
-        verbose: bool,
-
         /// Filter commits whose message or session IDs match this pattern
         #[arg(long)]
         grep: Option<String>,
@@ -1035,7 +1451,7 @@ pub enum MemCommand {
         /// Project name
         project: String,
 
-        /// Branch name or commit hash (default: HEAD)
+        /// Branch, tag or commit hash (default: HEAD)
         target: Option<String>,
 
         /// Limit to a single category
@@ -1057,12 +1473,29 @@ pub enum MemCommand {
         delete: Option<String>,
     },
 
-    /// Checkout a branch or commit (restores knowledge files)
+    /// List, create or delete tags (named pointers to a commit)
+    Tag {
+        /// Project name
+        project: String,
+
+        /// Tag to create (lists tags when omitted)
+        name: Option<String>,
+
+        /// Branch, tag or commit hash to tag (default: HEAD)
+        #[arg(long, value_name = "REF", requires = "name")]
+        at: Option<String>,
+
+        /// Delete a tag
+        #[arg(short = 'd', long, value_name = "NAME", conflicts_with = "name")]
+        delete: Option<String>,
+    },
+
+    /// Checkout a branch, tag or commit (restores knowledge files)
     Checkout {
         /// Project name
         project: String,
 
-        /// Branch name or commit hash to check out
+        /// Branch, tag or commit hash to check out
         target: String,
 
         /// Preview changes without applying them
@@ -1085,6 +1518,10 @@ pub enum MemCommand {
         /// To ref (default: working state)
         to: Option<String>,
 
+        /// Diff HEAD against staged sessions instead of the working state
+        #[arg(long, conflicts_with_all = ["from", "to"])]
+        staged: bool,
+
         /// Limit diff to a single category
         #[arg(long)]
         category: Option<String>,
@@ -1099,7 +1536,7 @@ pub enum DaemonCommand {
         #[arg(long, default_value = "15", value_parser = clap::value_parser!(u64).range(1..=1440))]
         interval: u64,
 
-        /// LLM provider override (anthropic, openai, ollama)
+        /// LLM provider override (anthropic, openai, ollama, gemini, vscode, openrouter)
         #[arg(long)]
         provider: Option<String>,
     },
@@ -1110,6 +1547,13 @@ pub enum DaemonCommand {
     /// Show daemon status
     Status,
 
+    /// Change the ingest interval (applied by a running daemon after its current cycle)
+    SetInterval {
+        /// Minutes between ingest runs (1-1440)
+        #[arg(value_parser = clap::value_parser!(u64).range(1..=1440))]
+        minutes: u64,
+    },
+
     /// Show daemon logs
     Logs {
         /// Number of lines to show (default: 50)
@@ -1125,7 +1569,7 @@ pub enum DaemonCommand {
     #[command(hide = true)]
     Run {
         /// Polling interval in minutes
-        #[arg(long, default_value = "15")]
+        #[arg(long, default_value = "15", value_parser = clap::value_parser!(u64).range(1..=1440))]
         interval: u64,
 
         /// LLM provider override
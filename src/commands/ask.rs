@@ -10,12 +10,43 @@ use crate::extractor::knowledge::{
 use crate::inject::{build_raw_context, smart_search_sync, SmartEntry};
 use crate::llm::client::LlmClient;
 use crate::llm::prompts::{ask_prompt, SYSTEM_QA_ASSISTANT};
+use crate::output::status;
 
 /// Categories that benefit from recursive (index→select→fetch) retrieval.
 pub const RECURSIVE_CATEGORIES: &[&str] = &["decisions", "patterns", "procedures"];
 /// Categories that benefit from HyDE+semantic retrieval.
 pub const STANDARD_CATEGORIES: &[&str] = &["insights", "bugs", "solutions"];
 
+/// Token budget for retrieved knowledge when `ask --budget` is not given.
+pub const DEFAULT_ASK_BUDGET: usize = 6000;
+
+/// Pick the highest-scoring entries, at most `top_k`, whose combined size fits
+/// in `budget` tokens. Entries that would overflow the budget are skipped so a
+/// smaller, lower-ranked one can still fit; the best entry is always kept so an
+/// oversized match doesn't leave the prompt empty.
+pub fn select_within_budget(
+    mut entries: Vec<SmartEntry>,
+    top_k: usize,
+    budget: usize,
+) -> Vec<SmartEntry> {
+    entries.sort_by(|a, b| b.score.total_cmp(&a.score));
+
+    let mut selected = Vec::new();
+    let mut used = 0;
+    for entry in entries {
+        if selected.len() >= top_k {
+            break;
+        }
+        let tokens = entry.estimated_tokens();
+        if !selected.is_empty() && used + tokens > budget {
+            continue;
+        }
+        used += tokens;
+        selected.push(entry);
+    }
+    selected
+}
+
 /// Strip a leading `category:` prefix from an ID returned by the LLM selector.
 /// The index format is `[category:session_id]`, so the model often echoes the prefix.
 pub fn strip_category_prefix(id: &str) -> String {
@@ -45,6 +76,7 @@ pub fn cmd_ask(
     project: &str,
     top_k: usize,
     threshold: f32,
+    budget: usize,
     verbose: bool,
     use_graph: bool,
     concise: bool,
@@ -189,11 +221,13 @@ pub fn cmd_ask(
         }
     }
 
-    // 3. Build context string (raw context.md fallback if still empty)
+    // 3. Build context string from the best entries that fit the token budget
+    // (raw context.md fallback if still empty)
+    let found = entries.len();
+    let entries = select_within_budget(entries, top_k, budget);
     let context_str = if !entries.is_empty() {
         entries
             .iter()
-            .take(top_k)
             .map(|e| format!("[{}:{}]\n{}", e.category, e.session_id, e.content.trim()))
             .collect::<Vec<_>>()
             .join("\n\n---\n\n")
@@ -217,12 +251,14 @@ pub fn cmd_ask(
 
     if verbose {
         eprintln!(
-            "{} {} entries (semantic: {})",
+            "{} {} of {} entries within {} tokens (semantic: {})",
             "Ask:".cyan(),
-            entries.len().max(1),
+            entries.len(),
+            found,
+            budget,
             used_semantic
         );
-        for e in entries.iter().take(top_k) {
+        for e in &entries {
             let age_str = e
                 .timestamp
                 .as_deref()
@@ -256,10 +292,25 @@ pub fn cmd_ask(
     };
     let answer = rt.block_on(async { client.chat(system, &prompt).await })?;
 
-    // 5. Output
+    // 5. Output, citing the knowledge the answer was built from
     println!("{}", answer);
+    if !concise {
+        if entries.is_empty() {
+            println!("\n{} full project context", "Sources:".dimmed());
+        } else {
+            println!("\n{}", "Sources:".dimmed());
+            for e in &entries {
+                println!(
+                    "  [{}:{}] {}",
+                    e.category,
+                    e.session_id,
+                    e.preview.chars().take(80).collect::<String>().dimmed()
+                );
+            }
+        }
+    }
     if !used_semantic {
-        println!(
+        status!(
             "\n{} Run 'engram embed {}' to enable semantic search.",
             "Hint:".dimmed(),
             project
@@ -277,6 +328,7 @@ pub fn cmd_ask(
         results_count: Some(entries.len()),
         session_id: None,
         tokens_consumed: None,
+        knowledge_ids: Vec::new(),
     });
 
     Ok(())
@@ -370,7 +422,17 @@ pub fn cmd_ask_recursive(
     let index = build_project_index(project, &config.memory_dir, CATEGORIES);
     if index.is_empty() {
         // No knowledge — fall through to regular ask which will print a helpful message
-        return cmd_ask(config, query, project, 12, 0.15, verbose, false, concise);
+        return cmd_ask(
+            config,
+            query,
+            project,
+            12,
+            0.15,
+            DEFAULT_ASK_BUDGET,
+            verbose,
+            false,
+            concise,
+        );
     }
 
     if verbose {
@@ -416,7 +478,17 @@ pub fn cmd_ask_recursive(
                 "Recursive:".yellow()
             );
         }
-        return cmd_ask(config, query, project, 12, 0.15, verbose, false, concise);
+        return cmd_ask(
+            config,
+            query,
+            project,
+            12,
+            0.15,
+            DEFAULT_ASK_BUDGET,
+            verbose,
+            false,
+            concise,
+        );
     }
 
     // 5. Build context and answer
@@ -451,6 +523,7 @@ pub fn cmd_ask_recursive(
         results_count: Some(entries.len()),
         session_id: None,
         tokens_consumed: None,
+        knowledge_ids: Vec::new(),
     });
 
     Ok(())
@@ -586,7 +659,15 @@ pub fn cmd_ask_hybrid(
     // If nothing found, fall back to regular ask
     if merged.is_empty() {
         return cmd_ask(
-            config, query, project, top_k, threshold, verbose, false, concise,
+            config,
+            query,
+            project,
+            top_k,
+            threshold,
+            DEFAULT_ASK_BUDGET,
+            verbose,
+            false,
+            concise,
         );
     }
 
@@ -623,6 +704,7 @@ pub fn cmd_ask_hybrid(
         results_count: Some(merged.len()),
         session_id: None,
         tokens_consumed: None,
+        knowledge_ids: Vec::new(),
     });
 
     Ok(())
@@ -660,6 +742,49 @@ mod tests {
         assert_eq!(strip_category_prefix("unknown:abc-123"), "unknown:abc-123");
     }
 
+    // ── select_within_budget ────────────────────────────────────────────────
+
+    fn entry(id: &str, score: f32, chars: usize) -> SmartEntry {
+        SmartEntry {
+            category: "decisions".to_string(),
+            session_id: id.to_string(),
+            preview: String::new(),
+            content: "x".repeat(chars),
+            score,
+            selected: true,
+            timestamp: None,
+        }
+    }
+
+    #[test]
+    fn test_select_within_budget_ranks_and_skips_oversized() {
+        // low ~50, big ~500 and top ~100 tokens
+        let entries = vec![
+            entry("low", 0.2, 200),
+            entry("big", 0.8, 2000),
+            entry("top", 0.9, 400),
+        ];
+        let ids = |selected: Vec<SmartEntry>| {
+            selected
+                .into_iter()
+                .map(|e| e.session_id)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            ids(select_within_budget(entries.clone(), 10, 1000)),
+            ["top", "big", "low"]
+        );
+        // "big" doesn't fit after "top", but "low" still does
+        assert_eq!(
+            ids(select_within_budget(entries.clone(), 10, 200)),
+            ["top", "low"]
+        );
+        assert_eq!(ids(select_within_budget(entries.clone(), 1, 1000)), ["top"]);
+        // The best entry is kept even when it alone exceeds the budget
+        assert_eq!(ids(select_within_budget(entries, 10, 10)), ["top"]);
+    }
+
     // ── parse_selected_ids ──────────────────────────────────────────────────
 
     #[test]
@@ -1,18 +1,19 @@
 use crate::auth::{self, AuthStore, ProviderCredential};
 use crate::error::{self, MemoryError, Result};
+use crate::output::status;
 use colored::Colorize;
 
-pub fn cmd_auth_login(provider_name: Option<String>, set_default: bool) -> Result<()> {
+pub fn cmd_auth_login(
+    provider_name: Option<String>,
+    set_default: bool,
+    endpoint: Option<String>,
+    device_code: Option<auth::oauth::OAuthConfig>,
+) -> Result<()> {
     use auth::providers::Provider;
     use dialoguer::{Password, Select};
 
     let provider = if let Some(name) = provider_name {
-        Provider::from_str_loose(&name).ok_or_else(|| {
-            error::MemoryError::Auth(format!(
-                "Unknown provider: {}. Use: anthropic, openai, ollama",
-                name
-            ))
-        })?
+        Provider::parse(&name)?
     } else {
         // Interactive selection
         let items: Vec<&str> = Provider::all().iter().map(|p| p.display_name()).collect();
@@ -25,18 +26,48 @@ pub fn cmd_auth_login(provider_name: Option<String>, set_default: bool) -> Resul
         Provider::all()[selection]
     };
 
+    let endpoint = endpoint.map(|e| auth::validate_endpoint(&e)).transpose()?;
+
+    if let Some(config) = device_code {
+        return login_with_device_code(provider, config, endpoint, set_default);
+    }
+
     if !provider.requires_auth() {
         println!(
             "{} {} does not require authentication.",
             "Note:".cyan(),
             provider.display_name()
         );
-        // Set as default if requested, or if no default is currently set
         let mut store = auth::AuthStore::load()?;
+        if let Some(endpoint) = endpoint {
+            match store.providers.get_mut(&provider.to_string()) {
+                Some(cred) => cred.endpoint = Some(endpoint.clone()),
+                None => store.set(
+                    provider,
+                    ProviderCredential {
+                        cred_type: "api".to_string(),
+                        key: String::new(),
+                        endpoint: Some(endpoint.clone()),
+                        model: None,
+                        refresh_token: None,
+                        expires_at: None,
+                        oauth: None,
+                    },
+                ),
+            }
+            store.save()?;
+            status!(
+                "{} Set endpoint for {} to {}",
+                "Done!".green().bold(),
+                provider.display_name(),
+                endpoint.cyan()
+            );
+        }
+        // Set as default if requested, or if no default is currently set
         if set_default || store.default_provider.is_none() {
             store.default_provider = Some(provider.to_string());
             store.save()?;
-            println!(
+            status!(
                 "{} Set {} as default provider.",
                 "Done!".green().bold(),
                 provider.display_name()
@@ -55,16 +86,81 @@ pub fn cmd_auth_login(provider_name: Option<String>, set_default: bool) -> Resul
         return Err(error::MemoryError::Auth("API key cannot be empty".into()));
     }
 
-    let mut store = auth::AuthStore::load()?;
-    store.set(
+    save_login(
         provider,
         auth::ProviderCredential {
             cred_type: "api".to_string(),
             key,
-            endpoint: None,
+            endpoint,
             model: None,
+            refresh_token: None,
+            expires_at: None,
+            oauth: None,
         },
-    );
+        set_default,
+    )
+}
+
+/// Log in through the OAuth device flow and store the token as an `oauth` credential.
+fn login_with_device_code(
+    provider: auth::providers::Provider,
+    config: auth::oauth::OAuthConfig,
+    endpoint: Option<String>,
+    set_default: bool,
+) -> Result<()> {
+    use auth::oauth;
+
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| MemoryError::Config(format!("tokio runtime: {}", e)))?;
+
+    let token = rt.block_on(async {
+        let device = oauth::request_device_code(&config).await?;
+        let url = device
+            .verification_uri_complete
+            .as_deref()
+            .unwrap_or(&device.verification_uri);
+
+        println!(
+            "Open {} and enter the code {}",
+            device.verification_uri.cyan(),
+            device.user_code.yellow().bold()
+        );
+        if oauth::open_in_browser(url) {
+            println!("  (opened in your browser)");
+        }
+        println!("Waiting for approval...");
+
+        oauth::poll_for_token(&config, &device).await
+    })?;
+
+    // Keep endpoint/model overrides from an existing credential
+    let existing = auth::AuthStore::load()?.get(provider).cloned();
+    let mut cred = auth::ProviderCredential {
+        cred_type: "oauth".to_string(),
+        key: String::new(),
+        endpoint: endpoint.or_else(|| existing.as_ref().and_then(|c| c.endpoint.clone())),
+        model: existing.as_ref().and_then(|c| c.model.clone()),
+        refresh_token: None,
+        expires_at: None,
+        oauth: Some(config),
+    };
+    cred.apply_token(token);
+
+    save_login(provider, cred, set_default)
+}
+
+/// Store a login credential, make it the default when asked (or when no default
+/// exists yet) and report it.
+fn save_login(
+    provider: auth::providers::Provider,
+    cred: auth::ProviderCredential,
+    set_default: bool,
+) -> Result<()> {
+    let endpoint = cred.endpoint.clone();
+    let mut store = auth::AuthStore::load()?;
+    store.set(provider, cred);
 
     if set_default {
         store.default_provider = Some(provider.to_string());
@@ -75,11 +171,14 @@ pub fn cmd_auth_login(provider_name: Option<String>, set_default: bool) -> Resul
 
     store.save()?;
 
-    println!(
+    status!(
         "{} Logged in to {}.",
         "Done!".green().bold(),
         provider.display_name()
     );
+    if let Some(endpoint) = endpoint {
+        println!("  Endpoint: {}", endpoint.cyan());
+    }
     if store.default_provider.as_deref() == Some(&provider.to_string()) {
         println!("  Set as default provider.");
     }
@@ -87,7 +186,7 @@ pub fn cmd_auth_login(provider_name: Option<String>, set_default: bool) -> Resul
     Ok(())
 }
 
-pub fn cmd_auth_list() -> Result<()> {
+pub fn cmd_auth_list(show_models: bool) -> Result<()> {
     use auth::providers::Provider;
 
     let store = auth::AuthStore::load()?;
@@ -107,6 +206,8 @@ pub fn cmd_auth_list() -> Result<()> {
 
         let status = if env_key.is_some() {
             "env var".green().to_string()
+        } else if stored.is_some_and(|c| c.cred_type == "oauth") {
+            "auth.json (oauth)".cyan().to_string()
         } else if stored.is_some() {
             "auth.json".cyan().to_string()
         } else if !provider.requires_auth() {
@@ -123,6 +224,17 @@ pub fn cmd_auth_list() -> Result<()> {
             default_marker,
             status
         );
+
+        if show_models {
+            match stored.and_then(|c| c.model.as_deref()) {
+                Some(model) => println!("    model: {}", model.yellow()),
+                None => println!(
+                    "    model: {} {}",
+                    provider.default_model(),
+                    "(default)".dimmed()
+                ),
+            }
+        }
     }
 
     Ok(())
@@ -131,18 +243,13 @@ pub fn cmd_auth_list() -> Result<()> {
 pub fn cmd_auth_logout(provider_name: &str) -> Result<()> {
     use auth::providers::Provider;
 
-    let provider = Provider::from_str_loose(provider_name).ok_or_else(|| {
-        error::MemoryError::Auth(format!(
-            "Unknown provider: {}. Use: anthropic, openai, ollama",
-            provider_name
-        ))
-    })?;
+    let provider = Provider::parse(provider_name)?;
 
     let mut store = auth::AuthStore::load()?;
     store.remove(provider);
     store.save()?;
 
-    println!(
+    status!(
         "{} Removed credentials for {}.",
         "Done!".green().bold(),
         provider.display_name()
@@ -157,8 +264,7 @@ pub fn cmd_auth_test(provider_name: Option<String>) -> Result<()> {
 
     let results = match provider_name {
         Some(name) => {
-            let p = Provider::from_str_loose(&name)
-                .ok_or_else(|| MemoryError::Auth(format!("Unknown provider: {}", name)))?;
+            let p = Provider::parse(&name)?;
             vec![test_provider_sync(p)]
         }
         None => test_all_providers_sync(),
@@ -187,14 +293,23 @@ pub fn cmd_auth_test(provider_name: Option<String>) -> Result<()> {
             );
         }
     }
+
+    // Non-zero exit so scripts can gate on working credentials
+    let failed = results.iter().filter(|r| !r.success).count();
+    if failed > 0 {
+        return Err(MemoryError::Auth(format!(
+            "{} of {} provider tests failed",
+            failed,
+            results.len()
+        )));
+    }
     Ok(())
 }
 
 pub fn cmd_auth_model(provider_name: &str, model: &str) -> Result<()> {
     use auth::providers::Provider;
 
-    let provider = Provider::from_str_loose(provider_name)
-        .ok_or_else(|| MemoryError::Auth(format!("Unknown provider: {}", provider_name)))?;
+    let provider = Provider::parse(provider_name)?;
     let mut store = AuthStore::load()?;
     if let Some(cred) = store.providers.get_mut(&provider.to_string()) {
         cred.model = Some(model.to_string());
@@ -207,11 +322,14 @@ pub fn cmd_auth_model(provider_name: &str, model: &str) -> Result<()> {
                 key: String::new(),
                 endpoint: None,
                 model: Some(model.to_string()),
+                refresh_token: None,
+                expires_at: None,
+                oauth: None,
             },
         );
     }
     store.save()?;
-    println!(
+    status!(
         "{} Set model for {} to {}",
         "Done!".green().bold(),
         provider.display_name().cyan(),
@@ -221,7 +339,7 @@ pub fn cmd_auth_model(provider_name: &str, model: &str) -> Result<()> {
 }
 
 pub fn cmd_auth_models(provider_name: Option<String>, embed: bool) -> Result<()> {
-    use crate::commands::provider_test::{fetch_embed_models_sync, fetch_models_sync};
+    use crate::commands::provider_test::{fetch_embed_models_sync, fetch_models_cached};
     use auth::providers::Provider;
 
     // --embed: list embedding models for the given provider name (string, not Provider enum)
@@ -263,8 +381,7 @@ pub fn cmd_auth_models(provider_name: Option<String>, embed: bool) -> Result<()>
 
     // LLM models
     let provider = match provider_name {
-        Some(ref name) => Provider::from_str_loose(name)
-            .ok_or_else(|| MemoryError::Auth(format!("Unknown provider: {}", name)))?,
+        Some(ref name) => Provider::parse(name)?,
         None => {
             let env_endpoint = std::env::var("ENGRAM_LLM_ENDPOINT").ok();
             let env_model = std::env::var("ENGRAM_LLM_MODEL").ok();
@@ -273,20 +390,26 @@ pub fn cmd_auth_models(provider_name: Option<String>, embed: bool) -> Result<()>
         }
     };
 
-    if !provider.supports_model_list() {
-        return Err(MemoryError::Auth(format!(
-            "{} does not expose a /models endpoint. Try: openai, ollama, vscode, openrouter",
+    let models: Vec<String> = if provider.supports_model_list() {
+        println!(
+            "{} Fetching LLM models from {}...",
+            "→".cyan(),
+            provider.display_name().cyan()
+        );
+        // Always query the provider here; the result also refreshes the TUI's model cache
+        fetch_models_cached(provider, true)?.models
+    } else {
+        println!(
+            "{} {} has no /models endpoint; showing the bundled list (other model names work too).",
+            "Note:".yellow(),
             provider.display_name()
-        )));
-    }
-
-    println!(
-        "{} Fetching LLM models from {}...",
-        "→".cyan(),
-        provider.display_name().cyan()
-    );
-
-    let models = fetch_models_sync(provider)?;
+        );
+        provider
+            .known_models()
+            .iter()
+            .map(|m| m.to_string())
+            .collect()
+    };
     if models.is_empty() {
         println!("{} No models returned.", "Note:".yellow());
         return Ok(());
@@ -317,7 +440,7 @@ pub fn cmd_auth_embed(provider_name: &str) -> Result<()> {
     let mut store = AuthStore::load()?;
     store.embed_provider = Some(provider_name.to_string());
     store.save()?;
-    println!(
+    status!(
         "{} Set embedding provider to {}",
         "Done!".green().bold(),
         provider_name.cyan()
@@ -333,7 +456,7 @@ pub fn cmd_auth_embed_model(provider_name: &str, model: &str) -> Result<()> {
     }
     store.embed_model = Some(model.to_string());
     store.save()?;
-    println!(
+    status!(
         "{} Set embedding model to {} (provider: {})",
         "Done!".green().bold(),
         model.yellow(),
@@ -0,0 +1,565 @@
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+use crate::error::{MemoryError, Result};
+use crate::extractor::knowledge::{
+    filter_since, parse_session_blocks, partition_by_expiry, ttl_tags, SessionBlock,
+};
+use crate::output::status;
+
+/// Format tag written in the header line of a JSONL export
+const JSONL_FORMAT: &str = "engram-jsonl";
+const JSONL_VERSION: u32 = 1;
+
+/// One line of a JSONL export: a header first, then one record per session block.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum JsonlRecord {
+    Header {
+        format: String,
+        version: u32,
+        project: String,
+        exported_at: String,
+    },
+    Block(BlockRecord),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BlockRecord {
+    pub project: String,
+    pub category: String,
+    pub session_id: String,
+    pub timestamp: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<String>,
+    pub content: String,
+}
+
+impl BlockRecord {
+    fn from_block(project: &str, category: &str, block: SessionBlock) -> Self {
+        BlockRecord {
+            project: project.to_string(),
+            category: category.to_string(),
+            session_id: block.session_id,
+            timestamp: block.timestamp,
+            ttl: block.ttl,
+            confidence: block.confidence,
+            content: block.content.trim().to_string(),
+        }
+    }
+}
+
+/// Knowledge export formats `import` understands.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImportFormat {
+    Markdown,
+    Json,
+    Jsonl,
+}
+
+impl ImportFormat {
+    /// Pick a format from an explicit name, falling back to sniffing the content.
+    pub fn resolve(name: Option<&str>, content: &str) -> Result<Self> {
+        match name {
+            Some("markdown") => Ok(ImportFormat::Markdown),
+            Some("json") => Ok(ImportFormat::Json),
+            Some("jsonl") => Ok(ImportFormat::Jsonl),
+            Some(other) => Err(MemoryError::Config(format!(
+                "Unknown import format: {}",
+                other
+            ))),
+            None => {
+                let first = content.lines().find(|l| !l.trim().is_empty()).unwrap_or("");
+                if first.contains(&format!("\"{}\"", JSONL_FORMAT)) {
+                    Ok(ImportFormat::Jsonl)
+                } else if first.trim_start().starts_with('{') {
+                    Ok(ImportFormat::Json)
+                } else {
+                    Ok(ImportFormat::Markdown)
+                }
+            }
+        }
+    }
+}
+
+/// Sections of the markdown/json exports that hold session blocks.
+/// `context` is left out: it is regenerated from the other files.
+const EXPORT_SECTIONS: &[&str] = &["decisions", "solutions", "patterns", "workflows"];
+
+/// Knowledge files that aren't session-block data: never exported as blocks
+/// and rejected on import
+const NON_BLOCK_FILES: &[&str] = &["context", "preferences"];
+
+fn records_from_section(project: &str, category: &str, text: &str) -> Vec<BlockRecord> {
+    let (_, blocks) = parse_session_blocks(text);
+    blocks
+        .into_iter()
+        .map(|block| {
+            let mut record = BlockRecord::from_block(project, category, block);
+            // Markdown exports separate sections with horizontal rules
+            record.content = record.content.trim_end_matches("---").trim().to_string();
+            record
+        })
+        .collect()
+}
+
+/// Parse the output of `export <project> json`.
+pub fn parse_json_export(project: &str, input: &str) -> Result<Vec<BlockRecord>> {
+    let value: serde_json::Value = serde_json::from_str(input)?;
+    let knowledge = value
+        .get("knowledge")
+        .and_then(|k| k.as_object())
+        .filter(|_| value.get("tool").and_then(|t| t.as_str()) == Some("engram"))
+        .ok_or_else(|| {
+            MemoryError::Config("Not an engram JSON export (missing 'knowledge')".into())
+        })?;
+
+    Ok(EXPORT_SECTIONS
+        .iter()
+        .filter_map(|section| {
+            let text = knowledge.get(*section)?.as_str()?;
+            Some(records_from_section(project, section, text))
+        })
+        .flatten()
+        .collect())
+}
+
+/// Parse the output of `export <project> markdown`. Each section starts with
+/// its knowledge file title (`# Decisions`, ...); everything else is skipped.
+pub fn parse_markdown_export(project: &str, input: &str) -> Result<Vec<BlockRecord>> {
+    let is_export = input
+        .lines()
+        .find(|l| !l.trim().is_empty())
+        .is_some_and(|l| l.starts_with("# ") && l.trim_end().ends_with("- Knowledge Export"));
+    if !is_export {
+        return Err(MemoryError::Config(
+            "Not an engram markdown export (missing '# <project> - Knowledge Export' title)".into(),
+        ));
+    }
+
+    let mut sections: Vec<(&str, String)> = Vec::new();
+    let mut current: Option<&str> = None;
+    for line in input.lines() {
+        if let Some(title) = line.strip_prefix("# ") {
+            let title = title.trim().to_lowercase();
+            current = EXPORT_SECTIONS.iter().copied().find(|s| *s == title);
+            if let Some(section) = current {
+                sections.push((section, String::new()));
+            }
+            continue;
+        }
+        if line.starts_with("## ") && !line.starts_with("## Session: ") {
+            // "## Project Context" / "## Conversations" end a knowledge section
+            current = None;
+            continue;
+        }
+        if current.is_some() {
+            if let Some((_, text)) = sections.last_mut() {
+                text.push_str(line);
+                text.push('\n');
+            }
+        }
+    }
+
+    Ok(sections
+        .iter()
+        .flat_map(|(section, text)| records_from_section(project, section, text))
+        .collect())
+}
+
+/// Serialize a project's active knowledge blocks as JSONL.
+///
+/// Every `*.md` knowledge file except `context.md` (which is regenerated) is
+/// included, so the output is a complete backup of the project's sessions.
+pub fn export_jsonl(
+    knowledge_dir: &Path,
+    project: &str,
+    categories: &[String],
+    cutoff: Option<DateTime<Utc>>,
+) -> Result<String> {
+    let header = JsonlRecord::Header {
+        format: JSONL_FORMAT.to_string(),
+        version: JSONL_VERSION,
+        project: project.to_string(),
+        exported_at: Utc::now().to_rfc3339(),
+    };
+    let mut out = serde_json::to_string(&header)?;
+    out.push('\n');
+
+    let mut files: Vec<_> = std::fs::read_dir(knowledge_dir)?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|ext| ext == "md"))
+        .collect();
+    files.sort();
+
+    for path in files {
+        let Some(category) = path.file_stem().map(|s| s.to_string_lossy().to_string()) else {
+            continue;
+        };
+        if NON_BLOCK_FILES.contains(&category.as_str())
+            || (!categories.is_empty() && !categories.contains(&category))
+        {
+            continue;
+        }
+
+        let raw = std::fs::read_to_string(&path)?;
+        let (_, blocks) = parse_session_blocks(&raw);
+        let (mut active, _) = partition_by_expiry(blocks);
+        if let Some(cutoff) = cutoff {
+            active = filter_since(active, cutoff);
+        }
+
+        for block in active {
+            let record = JsonlRecord::Block(BlockRecord::from_block(project, &category, block));
+            out.push_str(&serde_json::to_string(&record)?);
+            out.push('\n');
+        }
+    }
+
+    Ok(out)
+}
+
+/// Parse a JSONL export, validating the header. Returns the exported project
+/// name and its block records.
+pub fn parse_jsonl(input: &str) -> Result<(String, Vec<BlockRecord>)> {
+    let mut lines = input
+        .lines()
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty());
+
+    let project = match lines.next() {
+        Some((_, line)) => match serde_json::from_str::<JsonlRecord>(line) {
+            Ok(JsonlRecord::Header {
+                format,
+                version,
+                project,
+                ..
+            }) if format == JSONL_FORMAT && version <= JSONL_VERSION => project,
+            _ => {
+                return Err(MemoryError::Config(format!(
+                    "Not an engram JSONL export (expected a '{}' v{} header line)",
+                    JSONL_FORMAT, JSONL_VERSION
+                )))
+            }
+        },
+        None => return Err(MemoryError::Config("Import file is empty".into())),
+    };
+
+    let mut blocks = Vec::new();
+    for (idx, line) in lines {
+        match serde_json::from_str::<JsonlRecord>(line) {
+            Ok(JsonlRecord::Block(block)) => blocks.push(block),
+            Ok(JsonlRecord::Header { .. }) => {
+                return Err(MemoryError::Config(format!(
+                    "Line {}: unexpected second header",
+                    idx + 1
+                )))
+            }
+            Err(e) => return Err(MemoryError::Config(format!("Line {}: {}", idx + 1, e))),
+        }
+    }
+
+    Ok((project, blocks))
+}
+
+/// Result of importing block records into a project.
+#[derive(Debug, Default, PartialEq)]
+pub struct ImportSummary {
+    pub added: usize,
+    /// Blocks whose session ID already exists in the target file
+    pub skipped: usize,
+}
+
+/// Reject categories that aren't plain knowledge file names, or that name a
+/// file which isn't made of importable session blocks.
+fn validate_import_category(category: &str) -> Result<()> {
+    let valid = !category.is_empty()
+        && category
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !valid || NON_BLOCK_FILES.contains(&category) {
+        return Err(MemoryError::Config(format!(
+            "Invalid category '{}' in import",
+            category
+        )));
+    }
+    Ok(())
+}
+
+/// Append block records to the project's category files, skipping sessions
+/// that are already present so re-importing the same backup is a no-op.
+///
+/// Every record is validated before any file is written, so a bad record
+/// never leaves a partial import behind. Dry runs count exactly what a real
+/// import would add, including duplicates within the input.
+pub fn import_blocks(
+    knowledge_dir: &Path,
+    blocks: &[BlockRecord],
+    dry_run: bool,
+) -> Result<ImportSummary> {
+    for block in blocks {
+        validate_import_category(&block.category)?;
+    }
+
+    let mut summary = ImportSummary::default();
+    // (category file, content after the import, whether it changed)
+    let mut files: Vec<(std::path::PathBuf, String, bool)> = Vec::new();
+    for block in blocks {
+        let path = knowledge_dir.join(format!("{}.md", block.category));
+        let idx = match files.iter().position(|(p, _, _)| *p == path) {
+            Some(idx) => idx,
+            None => {
+                let content = std::fs::read_to_string(&path).unwrap_or_default();
+                files.push((path, content, false));
+                files.len() - 1
+            }
+        };
+        let (_, existing, changed) = &mut files[idx];
+        let (_, current) = parse_session_blocks(existing);
+        if current.iter().any(|b| b.session_id == block.session_id) {
+            summary.skipped += 1;
+            continue;
+        }
+
+        summary.added += 1;
+        *changed = true;
+        if existing.is_empty() {
+            let mut title = block.category[..1].to_uppercase();
+            title.push_str(&block.category[1..]);
+            *existing = format!("# {}\n", title);
+        }
+        let mut tags = String::new();
+        if let Some(ttl) = &block.ttl {
+            tags.push_str(&ttl_tags(&block.timestamp, ttl));
+        }
+        if let Some(confidence) = &block.confidence {
+            tags.push_str(&format!(" [confidence:{}]", confidence));
+        }
+        existing.push_str(&format!(
+            "\n\n{}{}\n\n{}\n",
+            crate::extractor::knowledge::session_header(&block.session_id, &block.timestamp),
+            tags,
+            block.content
+        ));
+    }
+
+    if !dry_run {
+        for (path, content, changed) in &files {
+            if *changed {
+                std::fs::create_dir_all(knowledge_dir)?;
+                std::fs::write(path, content)?;
+            }
+        }
+    }
+
+    Ok(summary)
+}
+
+pub fn cmd_import(
+    config: &Config,
+    project: &str,
+    input: &str,
+    format: Option<&str>,
+    dry_run: bool,
+) -> Result<()> {
+    let content = std::fs::read_to_string(input)?;
+    let blocks = match ImportFormat::resolve(format, &content)? {
+        ImportFormat::Jsonl => parse_jsonl(&content)?.1,
+        ImportFormat::Json => parse_json_export(project, &content)?,
+        ImportFormat::Markdown => parse_markdown_export(project, &content)?,
+    };
+
+    let knowledge_dir = config.memory_dir.join("knowledge").join(project);
+    let summary = import_blocks(&knowledge_dir, &blocks, dry_run)?;
+
+    if dry_run {
+        println!(
+            "{} Would import {} block(s) into '{}' ({} already present)",
+            "Dry run:".cyan().bold(),
+            summary.added,
+            project,
+            summary.skipped
+        );
+        return Ok(());
+    }
+
+    // New blocks make the synthesized context stale
+    if summary.added > 0 {
+        let context_path = knowledge_dir.join("context.md");
+        if context_path.exists() {
+            std::fs::remove_file(context_path)?;
+        }
+    }
+
+    status!(
+        "{} Imported {} block(s) into '{}' ({} already present)",
+        "Done!".green().bold(),
+        summary.added,
+        project,
+        summary.skipped
+    );
+    if summary.added > 0 {
+        status!(
+            "  Run {} to rebuild context.md",
+            format!("engram regen {}", project).cyan()
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DECISIONS: &str = "# Decisions\n\n## Session: s1 (2024-01-01T00:00:00Z) [ttl:never]\n\nUse Postgres\n\n## Session: s2 (2024-01-02T00:00:00Z) [confidence:high]\n\nUse sqlx\n";
+
+    #[test]
+    fn test_jsonl_round_trip() {
+        let src = tempfile::TempDir::new().unwrap();
+        std::fs::write(src.path().join("decisions.md"), DECISIONS).unwrap();
+        std::fs::write(src.path().join("context.md"), "# Context\nsummary").unwrap();
+
+        let jsonl = export_jsonl(src.path(), "demo", &[], None).unwrap();
+        assert_eq!(jsonl.lines().count(), 3);
+
+        let (project, blocks) = parse_jsonl(&jsonl).unwrap();
+        assert_eq!(project, "demo");
+        assert_eq!(blocks[0].ttl.as_deref(), Some("never"));
+        assert_eq!(blocks[1].confidence.as_deref(), Some("high"));
+
+        let dst = tempfile::TempDir::new().unwrap();
+        let summary = import_blocks(dst.path(), &blocks, false).unwrap();
+        assert_eq!(summary.added, 2);
+
+        let restored = std::fs::read_to_string(dst.path().join("decisions.md")).unwrap();
+        let (_, restored_blocks) = parse_session_blocks(&restored);
+        assert_eq!(restored_blocks.len(), 2);
+        assert_eq!(restored_blocks[0].ttl.as_deref(), Some("never"));
+        assert_eq!(restored_blocks[1].content.trim(), "Use sqlx");
+        assert!(!dst.path().join("context.md").exists());
+    }
+
+    #[test]
+    fn test_import_skips_existing_sessions() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(dir.path().join("decisions.md"), DECISIONS).unwrap();
+        let jsonl = export_jsonl(dir.path(), "demo", &[], None).unwrap();
+        let (_, blocks) = parse_jsonl(&jsonl).unwrap();
+
+        let summary = import_blocks(dir.path(), &blocks, false).unwrap();
+        assert_eq!(
+            summary,
+            ImportSummary {
+                added: 0,
+                skipped: 2
+            }
+        );
+        let unchanged = std::fs::read_to_string(dir.path().join("decisions.md")).unwrap();
+        assert_eq!(unchanged, DECISIONS);
+    }
+
+    #[test]
+    fn test_parse_markdown_export_skips_context_and_rules() {
+        let md = "# demo - Knowledge Export\n\n**Exported:** now\n\n---\n\n## Project Context\n\n# Context\n\n## Session: ctx (2024-01-01T00:00:00Z)\nnot imported\n\n---\n\n# Decisions\n\n## Session: s1 (2024-01-01T00:00:00Z) [ttl:7d]\n\nUse Postgres\n\n---\n\n# Patterns\n\n## Session: p1 (2024-01-03T00:00:00Z)\n\nSmall PRs\n\n---\n\n# Workflows\n\n## Session: w1 (2024-01-04T00:00:00Z)\n\nRelease: tag, build, publish\n\n## Conversations\n\n### Session: s1\n\nhello\n";
+        let blocks = parse_markdown_export("demo", md).unwrap();
+        assert_eq!(blocks.len(), 3);
+        assert_eq!(blocks[0].category, "decisions");
+        assert_eq!(blocks[0].content, "Use Postgres");
+        assert_eq!(blocks[0].ttl.as_deref(), Some("7d"));
+        assert_eq!(blocks[1].category, "patterns");
+        assert_eq!(blocks[1].content, "Small PRs");
+        assert_eq!(blocks[2].category, "workflows");
+
+        assert!(parse_markdown_export("demo", "# Notes\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_json_export_and_format_detection() {
+        let json = serde_json::json!({
+            "project": "demo",
+            "tool": "engram",
+            "knowledge": { "context": "", "decisions": DECISIONS, "solutions": "", "patterns": "" },
+        })
+        .to_string();
+        assert_eq!(
+            ImportFormat::resolve(None, &json).unwrap(),
+            ImportFormat::Json
+        );
+        let blocks = parse_json_export("other", &json).unwrap();
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].project, "other");
+        assert!(parse_json_export("demo", "{\"knowledge\": {}}").is_err());
+
+        assert_eq!(
+            ImportFormat::resolve(None, "# demo - Knowledge Export").unwrap(),
+            ImportFormat::Markdown
+        );
+    }
+
+    #[test]
+    fn test_parse_jsonl_rejects_missing_header_and_bad_category() {
+        let line = r#"{"type":"block","project":"p","category":"../x","session_id":"a","timestamp":"t","content":"c"}"#;
+        assert!(parse_jsonl(line).is_err());
+
+        let input = format!(
+            "{}\n{}\n",
+            r#"{"type":"header","format":"engram-jsonl","version":1,"project":"p","exported_at":"t"}"#,
+            line
+        );
+        let (_, blocks) = parse_jsonl(&input).unwrap();
+        let dir = tempfile::TempDir::new().unwrap();
+        assert!(import_blocks(dir.path(), &blocks, false).is_err());
+    }
+
+    #[test]
+    fn test_import_rejects_preferences_and_dedupes_dry_run() {
+        let src = tempfile::TempDir::new().unwrap();
+        std::fs::write(src.path().join("decisions.md"), DECISIONS).unwrap();
+        std::fs::write(src.path().join("preferences.md"), DECISIONS).unwrap();
+        let jsonl = export_jsonl(src.path(), "demo", &[], None).unwrap();
+        let (_, mut blocks) = parse_jsonl(&jsonl).unwrap();
+        assert!(blocks.iter().all(|b| b.category == "decisions"));
+
+        // The same session twice in one archive is only added once
+        blocks.push(blocks[0].clone());
+        let dst = tempfile::TempDir::new().unwrap();
+        let preview = import_blocks(dst.path(), &blocks, true).unwrap();
+        assert!(!dst.path().join("decisions.md").exists());
+        let summary = import_blocks(dst.path(), &blocks, false).unwrap();
+        assert_eq!(
+            preview,
+            ImportSummary {
+                added: 2,
+                skipped: 1
+            }
+        );
+        assert_eq!(summary, preview);
+
+        let mut prefs = blocks[0].clone();
+        prefs.category = "preferences".to_string();
+        assert!(import_blocks(dst.path(), &[prefs], false).is_err());
+    }
+
+    #[test]
+    fn test_import_validates_every_record_before_writing() {
+        let src = tempfile::TempDir::new().unwrap();
+        std::fs::write(src.path().join("decisions.md"), DECISIONS).unwrap();
+        let (_, mut blocks) =
+            parse_jsonl(&export_jsonl(src.path(), "demo", &[], None).unwrap()).unwrap();
+        let mut bad = blocks[0].clone();
+        bad.category = "../escape".to_string();
+        blocks.push(bad);
+
+        let dst = tempfile::TempDir::new().unwrap();
+        assert!(import_blocks(dst.path(), &blocks, false).is_err());
+        assert!(!dst.path().join("decisions.md").exists());
+    }
+}
@@ -8,6 +8,7 @@ use crate::error::{MemoryError, Result};
 use crate::health;
 use crate::hive;
 use crate::learning;
+use crate::output::status;
 use crate::parser;
 
 fn truncate_text(text: &str, max_len: usize) -> String {
@@ -19,6 +20,111 @@ fn truncate_text(text: &str, max_len: usize) -> String {
     }
 }
 
+/// Knowledge entries to drop for `consolidate --auto-merge`, as `(category, session_id)`.
+///
+/// Each duplicate group keeps its entry with the highest persisted importance
+/// (untagged counts as 0.5), breaking ties by the newest timestamp. Chunks without
+/// a session (context.md) or whose entry is no longer in the knowledge files are
+/// ignored, as are groups that collapse to a single entry.
+///
+/// An entry can span several chunks (window/paragraph chunking, or a long block
+/// split up), so it is only dropped when every one of its chunks duplicates a
+/// chunk of the entry that is kept.
+fn plan_merges(
+    store: &embeddings::EmbeddingStore,
+    groups: &[Vec<(f32, usize)>],
+    knowledge_dir: &Path,
+) -> Result<Vec<(String, String)>> {
+    use crate::extractor::knowledge::parse_session_blocks;
+    use std::collections::{BTreeSet, HashMap};
+
+    // (category, session_id) -> (importance, timestamp)
+    let mut entries: HashMap<(String, String), (f32, String)> = HashMap::new();
+    for (cat, file) in crate::config::CATEGORIES
+        .iter()
+        .zip(crate::config::CATEGORY_FILES.iter())
+    {
+        let path = knowledge_dir.join(file);
+        if !path.exists() {
+            continue;
+        }
+        let content = std::fs::read_to_string(&path)?;
+        let (_preamble, blocks) = parse_session_blocks(&content);
+        for block in blocks {
+            let rank = (block.importance.unwrap_or(0.5), block.timestamp);
+            entries.insert((cat.to_string(), block.session_id), rank);
+        }
+    }
+
+    // Entry each chunk belongs to, and the chunks of each entry
+    let chunk_keys: Vec<Option<(String, String)>> = store
+        .chunks
+        .iter()
+        .map(|c| Some((c.metadata.category.clone(), c.metadata.session_id.clone()?)))
+        .collect();
+    let mut chunks_of: HashMap<&(String, String), Vec<usize>> = HashMap::new();
+    for (idx, key) in chunk_keys.iter().enumerate() {
+        if let Some(key) = key {
+            chunks_of.entry(key).or_default().push(idx);
+        }
+    }
+    let duplicates_kept = |idx: usize, keep: &(String, String)| {
+        groups.iter().any(|g| {
+            g.iter().any(|(_, i)| *i == idx)
+                && g.iter().any(|(_, i)| chunk_keys[*i].as_ref() == Some(keep))
+        })
+    };
+
+    let mut removed: BTreeSet<(String, String)> = BTreeSet::new();
+    let mut plan = Vec::new();
+    for group in groups {
+        let members: BTreeSet<(String, String)> = group
+            .iter()
+            .filter_map(|(_, idx)| {
+                let key = chunk_keys[*idx].clone()?;
+                (entries.contains_key(&key) && !removed.contains(&key)).then_some(key)
+            })
+            .collect();
+        if members.len() < 2 {
+            continue;
+        }
+
+        let rank = |key: &(String, String)| {
+            let (importance, timestamp) = &entries[key];
+            let ts = chrono::DateTime::parse_from_rfc3339(timestamp)
+                .map(|t| t.timestamp())
+                .unwrap_or(i64::MIN);
+            ((importance * 1000.0).round() as i64, ts)
+        };
+        let keep = members
+            .iter()
+            .max_by_key(|key| rank(key))
+            .cloned()
+            .expect("group has members");
+        for key in members {
+            let fully_duplicated = chunks_of
+                .get(&key)
+                .is_some_and(|chunks| chunks.iter().all(|&c| duplicates_kept(c, &keep)));
+            if key != keep && fully_duplicated {
+                removed.insert(key.clone());
+                plan.push(key);
+            }
+        }
+    }
+    Ok(plan)
+}
+
+/// Count session blocks across a project's category files.
+fn count_entries(knowledge_dir: &Path) -> usize {
+    use crate::extractor::knowledge::parse_session_blocks;
+
+    crate::config::CATEGORY_FILES
+        .iter()
+        .filter_map(|file| std::fs::read_to_string(knowledge_dir.join(file)).ok())
+        .map(|content| parse_session_blocks(&content).1.len())
+        .sum()
+}
+
 pub fn cmd_consolidate(
     config: &Config,
     project: &str,
@@ -112,6 +218,78 @@ pub fn cmd_consolidate(
 
         if !auto_merge {
             println!("To merge duplicates, run with: {}", "--auto-merge".cyan());
+        } else {
+            let knowledge_dir = config.memory_dir.join("knowledge").join(project);
+            let plan = plan_merges(&store, &duplicate_groups, &knowledge_dir)?;
+            if plan.is_empty() {
+                println!(
+                    "{} Nothing to merge: duplicates share a session or are already gone.",
+                    "✓".green()
+                );
+            } else {
+                let before = count_entries(&knowledge_dir);
+                let mut trash =
+                    crate::trash::TrashBatch::new(&config.memory_dir, project, "consolidate --auto-merge");
+                for (cat, file) in crate::config::CATEGORIES
+                    .iter()
+                    .zip(crate::config::CATEGORY_FILES.iter())
+                {
+                    let ids: Vec<&str> = plan
+                        .iter()
+                        .filter(|(c, _)| c == cat)
+                        .map(|(_, id)| id.as_str())
+                        .collect();
+                    if ids.is_empty() {
+                        continue;
+                    }
+                    trash.remove_sessions(&knowledge_dir.join(file), &ids)?;
+                }
+                let trash_id = trash.commit()?;
+                let after = count_entries(&knowledge_dir);
+
+                let context_path = knowledge_dir.join("context.md");
+                if context_path.exists() {
+                    std::fs::remove_file(&context_path)?;
+                }
+
+                println!(
+                    "{} Merged {} duplicate entr{}: {} → {} entries",
+                    "Merged".green().bold(),
+                    plan.len(),
+                    if plan.len() == 1 { "y" } else { "ies" },
+                    before,
+                    after
+                );
+                for (cat, id) in &plan {
+                    println!("  - [{}] {}", cat.cyan(), id);
+                }
+                if let Some(id) = trash_id {
+                    status!(
+                        "  Removed entries moved to trash; undo with '{}'.",
+                        format!("engram restore {} {}", project, id).cyan()
+                    );
+                }
+
+                let provider = embeddings::EmbeddingProvider::from_config(config);
+                match embeddings::search::SemanticSearch::build_index(
+                    &config.memory_dir,
+                    project,
+                    &provider,
+                )
+                .await
+                {
+                    Ok(_) => status!("{} Rebuilt embedding index", "✓".green()),
+                    Err(e) => tracing::warn!(
+                        project,
+                        error = %e,
+                        "could not rebuild embeddings after merging; run 'engram embed'"
+                    ),
+                }
+                status!(
+                    "  Run '{}' to regenerate context.",
+                    format!("engram regen {}", project).cyan()
+                );
+            }
         }
 
         // Contradiction detection via LLM on semantically related but non-identical chunks
@@ -196,7 +374,7 @@ pub fn cmd_consolidate(
         if let Err(e) =
             learning::post_consolidate_hook(config, project, duplicate_groups.len(), auto_merge)
         {
-            eprintln!("Learning hook failed: {}", e);
+            tracing::warn!(project, error = %e, "post-consolidate learning hook failed");
         }
 
         Ok(())
@@ -225,6 +403,13 @@ pub fn cmd_doctor(
     println!("{}", "=".repeat(60));
     println!();
 
+    // One history sample per project and run: the post-fix score when fixes applied
+    let record_score = |proj: &str, score: u8| {
+        if let Err(e) = health::record_health_score(&config.memory_dir, proj, score) {
+            tracing::warn!(project = %proj, error = %e, "failed to record health history");
+        }
+    };
+
     for proj in &projects_to_check {
         let report = health::check_project_health(&config.memory_dir, proj)?;
 
@@ -238,6 +423,7 @@ pub fn cmd_doctor(
 
         if report.issues.is_empty() {
             println!("   {} No issues found!\n", "✓".green());
+            record_score(proj, report.score);
             continue;
         }
 
@@ -289,6 +475,8 @@ pub fn cmd_doctor(
 
         println!();
 
+        let mut final_score = report.score;
+
         // Auto-fix if requested
         if auto_fix {
             let rt = tokio::runtime::Builder::new_current_thread()
@@ -310,15 +498,17 @@ pub fn cmd_doctor(
             // Track learning signals from health improvements
             if !fixed.is_empty() {
                 let updated_report = health::check_project_health(&config.memory_dir, proj)?;
+                final_score = updated_report.score;
                 if let Err(e) =
                     learning::post_doctor_fix_hook(config, proj, report.score, updated_report.score)
                 {
-                    eprintln!("Learning hook failed: {}", e);
+                    tracing::warn!(project = %proj, error = %e, "doctor learning hook failed");
                 }
             }
 
             println!();
         }
+        record_score(proj, final_score);
     }
 
     if !auto_fix {
@@ -349,7 +539,9 @@ pub fn cmd_doctor(
             }
             if auto_fix {
                 print!("   {} Reinstalling hooks... ", "🔧".yellow());
-                match crate::commands::hooks::cmd_hooks_install() {
+                match crate::commands::hooks::cmd_hooks_install(
+                    &crate::commands::hooks::events_to_repair(&home),
+                ) {
                     Ok(()) => println!("{}", "ok".green()),
                     Err(e) => println!("{}: {}", "error".red(), e),
                 }
@@ -360,6 +552,33 @@ pub fn cmd_doctor(
         }
     }
 
+    // Conversation index (used by status and the TUI) vs. sessions on disk
+    match crate::state::ConversationIndex::load(&config.memory_dir) {
+        Some(index) if !index.is_stale(&config.memory_dir) => {
+            println!("   {} Conversation index up to date\n", "✓".green());
+        }
+        _ if !config.memory_dir.join("conversations").is_dir() => {}
+        _ => {
+            println!(
+                "   {} Conversation index is missing or stale [{}]",
+                "⚠".yellow(),
+                "WARNING".yellow()
+            );
+            if auto_fix {
+                print!("   {} Rebuilding conversation index... ", "🔧".yellow());
+                match crate::state::ConversationIndex::rebuild(&config.memory_dir)
+                    .and_then(|index| index.save(&config.memory_dir).map(|_| index))
+                {
+                    Ok(index) => println!("{} ({} sessions)", "ok".green(), index.sessions.len()),
+                    Err(e) => println!("{}: {}", "error".red(), e),
+                }
+            } else {
+                println!("   💡 Run {} to rebuild it", "engram doctor --fix".cyan());
+            }
+            println!();
+        }
+    }
+
     // Check installed packs health
     println!("{}", "📦 Installed Packs Health".green().bold());
     println!("{}", "=".repeat(60));
@@ -370,6 +589,60 @@ pub fn cmd_doctor(
     Ok(())
 }
 
+/// Print recorded `doctor` scores as a sparkline per project (oldest → newest).
+pub fn cmd_doctor_trend(config: &Config, project: Option<&str>, last: usize) -> Result<()> {
+    let projects = match project {
+        Some(p) => vec![p.to_string()],
+        None => health::health_history_projects(&config.memory_dir)?,
+    };
+
+    println!("{}", "📈 Memory Health Trend".green().bold());
+    println!("{}", "=".repeat(60));
+    println!();
+
+    let mut shown = 0;
+    for proj in &projects {
+        let history = health::load_health_history(&config.memory_dir, proj)?;
+        let recent = &history[history.len().saturating_sub(last)..];
+        let (Some(first), Some(latest)) = (recent.first(), recent.last()) else {
+            continue;
+        };
+        shown += 1;
+
+        let scores: Vec<u8> = recent.iter().map(|s| s.score).collect();
+        let delta = latest.score as i16 - first.score as i16;
+        let delta_str = match delta {
+            d if d > 0 => format!("+{}", d).green(),
+            d if d < 0 => d.to_string().red(),
+            _ => "±0".dimmed(),
+        };
+        println!(
+            "{:<24} {}  {} → {} ({})",
+            proj.cyan().bold(),
+            health::sparkline(&scores),
+            first.score,
+            latest.score,
+            delta_str
+        );
+        println!(
+            "{:<24} {} samples, {} → {}",
+            "",
+            scores.len(),
+            first.timestamp.format("%Y-%m-%d"),
+            latest.timestamp.format("%Y-%m-%d")
+        );
+    }
+
+    if shown == 0 {
+        println!(
+            "{} No health history yet. Run 'engram doctor' to record scores.",
+            "Not found:".yellow()
+        );
+    }
+
+    Ok(())
+}
+
 fn check_pack_health(memory_dir: &Path, auto_fix: bool, verbose: bool) -> Result<()> {
     use hive::PackInstaller;
 
@@ -423,24 +696,41 @@ fn check_pack_health(memory_dir: &Path, auto_fix: bool, verbose: bool) -> Result
             pack_issues.push("Registry removed (orphaned pack)");
         }
 
+        // Check 5: Knowledge files unchanged since install
+        let tampered = matches!(pack.verify_checksum(), Ok(Some(false)));
+        if tampered {
+            pack_issues.push("Checksum mismatch: knowledge files changed since install");
+        }
+
         if pack_issues.is_empty() {
             println!("{}", "✓ Healthy".green());
         } else {
-            println!("{} {} issue(s)", "⚠".yellow(), pack_issues.len());
+            if tampered {
+                println!(
+                    "{} {} issue(s)",
+                    "✗ Critical:".red().bold(),
+                    pack_issues.len()
+                );
+            } else {
+                println!("{} {} issue(s)", "⚠".yellow(), pack_issues.len());
+            }
             total_issues += pack_issues.len();
 
             if verbose || !pack_issues.is_empty() {
                 for issue in &pack_issues {
-                    println!("       {} {}", "•".yellow(), issue);
+                    if issue.starts_with("Checksum") {
+                        println!("       {} {}", "•".red(), issue.red());
+                    } else {
+                        println!("       {} {}", "•".yellow(), issue);
+                    }
                 }
             }
 
             if auto_fix {
-                // Auto-fix: Re-download corrupted packs
-                if pack_issues
-                    .iter()
-                    .any(|i| i.contains("Missing") || i.contains("Invalid"))
-                {
+                // Auto-fix: Re-download corrupted or modified packs (update re-verifies)
+                if pack_issues.iter().any(|i| {
+                    i.contains("Missing") || i.contains("Invalid") || i.starts_with("Checksum")
+                }) {
                     println!("       \u{1f527} Attempting to repair...");
 
                     if let Err(e) = installer.update(&pack.name) {
@@ -485,3 +775,98 @@ fn check_pack_health(memory_dir: &Path, auto_fix: bool, verbose: bool) -> Result
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::embeddings::{ChunkMetadata, EmbeddedChunk, EmbeddingStore};
+    use tempfile::TempDir;
+
+    fn chunk(category: &str, session_id: Option<&str>) -> EmbeddedChunk {
+        EmbeddedChunk {
+            id: format!("{}-{:?}", category, session_id),
+            text: "Use sqlx for database access".to_string(),
+            embedding: vec![1.0, 0.0],
+            metadata: ChunkMetadata {
+                project: "demo".to_string(),
+                category: category.to_string(),
+                session_id: session_id.map(str::to_string),
+                timestamp: "2024-01-01T00:00:00Z".to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_plan_merges_keeps_important_then_newest() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("decisions.md"),
+            "# Decisions\n\n## Session: old (2024-01-01T00:00:00Z)\nUse sqlx\n\n\
+             ## Session: new (2024-02-01T00:00:00Z)\nUse sqlx\n\n\
+             ## Session: pinned (2023-01-01T00:00:00Z) [importance:0.90]\nUse sqlx\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("patterns.md"),
+            "# Patterns\n\n## Session: a (2024-01-01T00:00:00Z)\nRetry\n\n\
+             ## Session: b (2024-03-01T00:00:00Z)\nRetry\n",
+        )
+        .unwrap();
+
+        let mut store = EmbeddingStore::new(dir.path().join("embeddings.json"));
+        for (cat, id) in [
+            ("decisions", Some("old")),
+            ("decisions", Some("new")),
+            ("decisions", Some("pinned")),
+            ("context", None),
+            ("patterns", Some("a")),
+            ("patterns", Some("b")),
+            ("patterns", Some("gone")),
+        ] {
+            store.add_chunk(chunk(cat, id));
+        }
+        let groups = vec![
+            vec![(1.0, 0), (0.95, 1), (0.95, 2), (0.95, 3)],
+            // Overlaps the first group: "old" is already planned for removal
+            vec![(1.0, 1), (0.95, 0)],
+            vec![(1.0, 4), (0.95, 5), (0.95, 6)],
+        ];
+
+        let plan = plan_merges(&store, &groups, dir.path()).unwrap();
+        let key = |c: &str, id: &str| (c.to_string(), id.to_string());
+        assert_eq!(
+            plan,
+            vec![
+                key("decisions", "new"),
+                key("decisions", "old"),
+                key("patterns", "a"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_plan_merges_keeps_blocks_with_unique_chunks() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("decisions.md"),
+            "# Decisions\n\n## Session: long (2024-01-01T00:00:00Z)\nUse sqlx\n\nAlso: pin Postgres 16\n\n\
+             ## Session: short (2024-02-01T00:00:00Z)\nUse sqlx\n",
+        )
+        .unwrap();
+
+        // "long" is split into two chunks; only the first duplicates "short"
+        let mut store = EmbeddingStore::new(dir.path().join("embeddings.json"));
+        for id in ["long", "long", "short"] {
+            store.add_chunk(chunk("decisions", Some(id)));
+        }
+        let groups = vec![vec![(1.0, 0), (0.95, 2)]];
+        assert!(plan_merges(&store, &groups, dir.path()).unwrap().is_empty());
+
+        // Once every chunk of "long" duplicates "short", it can go
+        let groups = vec![vec![(1.0, 0), (0.95, 2)], vec![(1.0, 1), (0.95, 2)]];
+        assert_eq!(
+            plan_merges(&store, &groups, dir.path()).unwrap(),
+            vec![("decisions".to_string(), "long".to_string())]
+        );
+    }
+}
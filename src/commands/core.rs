@@ -3,12 +3,13 @@ use std::path::Path;
 use colored::Colorize;
 
 use crate::analytics;
-use crate::config::Config;
+use crate::config::{Category, Config};
 use crate::error::{MemoryError, Result};
 use crate::extractor;
 use crate::hive;
 use crate::learning;
 use crate::mcp;
+use crate::output::status;
 use crate::parser;
 use crate::renderer;
 use crate::state;
@@ -24,6 +25,11 @@ pub fn cmd_ingest(
     since: Option<String>,
     skip_knowledge: bool,
     ttl: Option<String>,
+    compact: bool,
+    only_new_projects: bool,
+    secret_scan: bool,
+    summary_style: Option<renderer::markdown::SummaryStyle>,
+    summary_code: Option<bool>,
     verbose: bool,
 ) -> Result<()> {
     use indicatif::{ProgressBar, ProgressStyle};
@@ -39,20 +45,49 @@ pub fn cmd_ingest(
         }
     }
 
-    let since_duration = since
-        .map(|s| crate::extractor::knowledge::parse_duration_strict(&s))
-        .transpose()?;
+    let usage_before = crate::llm::client::total_usage();
 
     // Discover projects
     let projects = parser::discovery::discover_projects(&config.claude_projects_dir)?;
+
+    // --since is a duration, or a session ID meaning "sessions modified after it"
+    let since_cutoff = match since {
+        Some(ref id) if parser::discovery::looks_like_session_id(id) => Some(
+            parser::discovery::find_session(&projects, id)
+                .map(|session| session.modified)
+                .ok_or_else(|| {
+                    MemoryError::Config(format!("--since: session '{}' not found", id))
+                })?,
+        ),
+        Some(ref duration) => {
+            Some(chrono::Utc::now() - crate::extractor::knowledge::parse_duration_strict(duration)?)
+        }
+        None => None,
+    };
+
     let projects: Vec<_> = if let Some(ref filter) = project_filter {
         projects.into_iter().filter(|p| p.name == *filter).collect()
     } else {
         projects
     };
+    // Projects that already have knowledge are established; leave them alone
+    let projects: Vec<_> = if only_new_projects {
+        let knowledge_dir = config.memory_dir.join("knowledge");
+        projects
+            .into_iter()
+            .filter(|p| !knowledge_dir.join(&p.name).exists())
+            .collect()
+    } else {
+        projects
+    };
 
     if projects.is_empty() {
-        println!("{}", "No projects found.".yellow());
+        let message = if only_new_projects {
+            "No new projects found."
+        } else {
+            "No projects found."
+        };
+        println!("{}", message.yellow());
         return Ok(());
     }
 
@@ -63,26 +98,64 @@ pub fn cmd_ingest(
         state::Manifest::load(&config.memory_dir)?
     };
 
-    // Collect all sessions to process
+    // Collect all sessions to process. A session whose content was already
+    // processed (or queued) under a different project is skipped as a duplicate.
     let mut all_sessions: Vec<(String, parser::discovery::SessionFile)> = Vec::new();
+    let mut duplicates: Vec<(String, parser::discovery::SessionFile, String, String)> = Vec::new();
+    let mut seen_hashes = manifest.paths_by_hash();
     for project in &projects {
         for session in &project.sessions {
             // Filter by time if --since provided
-            if let Some(ref dur) = since_duration {
-                let cutoff = chrono::Utc::now() - *dur;
-                if session.modified < cutoff {
-                    continue;
-                }
+            if since_cutoff.is_some_and(|cutoff| session.modified <= cutoff) {
+                continue;
             }
             // Skip if already processed (unless --force)
             if !force && manifest.is_processed(&session.path) {
                 continue;
             }
+            if let Ok(hash) = state::hash_file(&session.path) {
+                match seen_hashes.get(&hash) {
+                    Some(original) if Path::new(original).parent() != session.path.parent() => {
+                        let original = original.clone();
+                        duplicates.push((project.name.clone(), session.clone(), hash, original));
+                        continue;
+                    }
+                    _ => {
+                        seen_hashes.insert(hash, session.path.to_string_lossy().to_string());
+                    }
+                }
+            }
             all_sessions.push((project.name.clone(), session.clone()));
         }
     }
 
+    let duplicate_count = duplicates.len();
+    for (project, session, _, original) in &duplicates {
+        if verbose || dry_run {
+            println!(
+                "  {} {}/{} (same content as {})",
+                "Duplicate:".yellow(),
+                project,
+                session.session_id,
+                original
+            );
+        }
+    }
+    if duplicate_count > 0 && !dry_run {
+        for (_, session, hash, original) in duplicates {
+            manifest.mark_duplicate(&session.path, hash, &original);
+        }
+        manifest.save(&config.memory_dir)?;
+    }
+
     if all_sessions.is_empty() {
+        if duplicate_count > 0 {
+            status!(
+                "{} {} duplicate sessions skipped",
+                "Note:".yellow(),
+                duplicate_count
+            );
+        }
         println!(
             "{}",
             "Everything up to date. Use --force to re-process.".green()
@@ -112,6 +185,18 @@ pub fn cmd_ingest(
     // Ensure output directories exist
     std::fs::create_dir_all(&config.memory_dir)?;
 
+    let mut render_options = if compact {
+        renderer::markdown::RenderOptions::compact()
+    } else {
+        renderer::markdown::RenderOptions::default()
+    };
+    // Flags win over config.json; an unreadable config falls back to defaults
+    let config_file = crate::config::ConfigFile::load().unwrap_or_default();
+    render_options.summary_style = summary_style
+        .or(config_file.summary_style)
+        .unwrap_or_default();
+    render_options.summary_code = summary_code.or(config_file.summary_code).unwrap_or(false);
+
     let pb = ProgressBar::new(all_sessions.len() as u64);
     pb.set_style(
         ProgressStyle::default_bar()
@@ -128,18 +213,34 @@ pub fn cmd_ingest(
         all_sessions
             .par_iter()
             .map(|(project_name, session)| {
-                let result = process_session(config, project_name, session, true, ttl.as_deref());
+                let result = process_session(
+                    config,
+                    project_name,
+                    session,
+                    true,
+                    ttl.as_deref(),
+                    secret_scan,
+                    &render_options,
+                );
                 pb.inc(1);
-                (session.path.clone(), result)
+                (project_name, session, result)
             })
             .collect()
     } else {
         all_sessions
             .iter()
             .map(|(project_name, session)| {
-                let result = process_session(config, project_name, session, false, ttl.as_deref());
+                let result = process_session(
+                    config,
+                    project_name,
+                    session,
+                    false,
+                    ttl.as_deref(),
+                    secret_scan,
+                    &render_options,
+                );
                 pb.inc(1);
-                (session.path.clone(), result)
+                (project_name, session, result)
             })
             .collect()
     };
@@ -150,12 +251,25 @@ pub fn cmd_ingest(
     let mut all_analytics = Vec::new();
     let mut success_count = 0;
     let mut error_count = 0;
+    let mut conversation_index = state::ConversationIndex::load_or_rebuild(&config.memory_dir)?;
 
-    for (path, result) in results {
+    for (project_name, session, result) in results {
+        let path = &session.path;
         match result {
             Ok(analytics) => {
-                manifest.mark_processed(&path)?;
+                manifest.mark_processed(path)?;
                 if let Some(a) = analytics {
+                    if let Err(e) = conversation_index.upsert(
+                        &config.memory_dir,
+                        project_name,
+                        &session.session_id,
+                    ) {
+                        tracing::warn!(
+                            session = %session.session_id,
+                            error = %e,
+                            "failed to index conversation"
+                        );
+                    }
                     if verbose {
                         let tool_uses: usize = a.tool_usage.values().sum();
                         println!(
@@ -173,7 +287,7 @@ pub fn cmd_ingest(
                 success_count += 1;
             }
             Err(e) => {
-                eprintln!("{} {}: {}", "Error processing".red(), path.display(), e);
+                tracing::error!(session = %path.display(), error = %e, "failed to process session");
                 error_count += 1;
             }
         }
@@ -186,6 +300,9 @@ pub fn cmd_ingest(
 
     // Save manifest
     manifest.save(&config.memory_dir)?;
+    if let Err(e) = conversation_index.save(&config.memory_dir) {
+        tracing::warn!(error = %e, "failed to save conversation index");
+    }
 
     // Trigger learning for each processed project
     let processed_projects: std::collections::HashSet<String> = all_sessions
@@ -195,33 +312,93 @@ pub fn cmd_ingest(
 
     for project_name in &processed_projects {
         if let Err(e) = learning::post_ingest_hook(config, project_name) {
-            eprintln!("Learning hook failed for {}: {}", project_name, e);
+            tracing::warn!(project = %project_name, error = %e, "post-ingest learning hook failed");
         }
     }
 
-    println!(
-        "\n{} {} sessions processed, {} errors",
+    status!(
+        "\n{} {} sessions processed, {} errors{}",
         "Done!".green().bold(),
         success_count,
-        error_count
+        error_count,
+        if duplicate_count > 0 {
+            format!(", {} duplicates skipped", duplicate_count)
+        } else {
+            String::new()
+        }
+    );
+
+    let usage = crate::llm::client::total_usage().since(&usage_before);
+    report_llm_usage(
+        config,
+        project_filter.as_deref().unwrap_or("all"),
+        "ingest",
+        &usage,
+        &format!("{} sessions", success_count),
     );
 
     Ok(())
 }
 
+/// Print "~X tokens across <scope>" for the LLM work of one run and record the
+/// total as an `LlmUsage` analytics event. Does nothing when no LLM calls were made.
+pub(crate) fn report_llm_usage(
+    config: &Config,
+    project: &str,
+    operation: &str,
+    usage: &crate::llm::client::TokenUsage,
+    scope: &str,
+) {
+    use crate::analytics::{EventTracker, EventType, UsageEvent};
+
+    if usage.calls == 0 {
+        return;
+    }
+
+    status!(
+        "{} ~{} tokens across {} ({} prompt, {} completion{})",
+        "LLM usage:".cyan(),
+        usage.total(),
+        scope,
+        usage.prompt_tokens,
+        usage.completion_tokens,
+        if usage.is_estimated() {
+            ", estimated"
+        } else {
+            ""
+        }
+    );
+
+    let tracker = EventTracker::new(&config.memory_dir);
+    let _ = tracker.track(UsageEvent {
+        timestamp: chrono::Utc::now(),
+        event_type: EventType::LlmUsage,
+        project: project.to_string(),
+        query: Some(operation.to_string()),
+        category: None,
+        results_count: Some(usage.calls as usize),
+        session_id: None,
+        tokens_consumed: Some(usage.total()),
+        knowledge_ids: Vec::new(),
+    });
+}
+
 fn process_session(
     config: &Config,
     project_name: &str,
     session: &parser::discovery::SessionFile,
     skip_knowledge: bool,
     ttl: Option<&str>,
+    secret_scan: bool,
+    render_options: &renderer::markdown::RenderOptions,
 ) -> Result<Option<extractor::analytics::SessionAnalytics>> {
-    // Parse JSONL
-    let entries = parser::jsonl::parse_jsonl(&session.path)?;
-
-    // Build conversation model
-    let conversation =
-        parser::conversation::build_conversation(&entries, &session.session_id, project_name);
+    // Stream JSONL straight into the conversation model
+    let entries = parser::jsonl::stream_jsonl(&session.path)?;
+    let conversation = parser::conversation::build_conversation_streaming(
+        entries,
+        &session.session_id,
+        project_name,
+    )?;
 
     if conversation.turns.is_empty() {
         return Ok(None);
@@ -235,7 +412,7 @@ fn process_session(
         .join(&session.session_id);
     std::fs::create_dir_all(&conv_dir)?;
 
-    let markdown = renderer::markdown::render_conversation(&conversation);
+    let markdown = renderer::markdown::render_conversation(&conversation, render_options);
     std::fs::write(conv_dir.join("conversation.md"), &markdown)?;
 
     let meta = renderer::markdown::render_meta(&conversation);
@@ -247,7 +424,7 @@ fn process_session(
     // Write summary
     let summary_dir = config.memory_dir.join("summaries").join(project_name);
     std::fs::create_dir_all(&summary_dir)?;
-    let summary = renderer::markdown::render_summary(&conversation);
+    let summary = renderer::markdown::render_summary(&conversation, render_options);
     std::fs::write(
         summary_dir.join(format!("{}.md", session.session_id)),
         &summary,
@@ -266,15 +443,15 @@ fn process_session(
                 project_name,
                 &conversation,
                 ttl,
+                secret_scan,
             )
             .await
             {
-                eprintln!(
-                    "  {} knowledge extraction for {}/{}: {}",
-                    "Warning:".yellow(),
-                    project_name,
-                    session.session_id,
-                    e
+                tracing::warn!(
+                    project = %project_name,
+                    session = %session.session_id,
+                    error = %e,
+                    "knowledge extraction failed"
                 );
             }
         });
@@ -298,18 +475,23 @@ fn process_session(
             } else {
                 None
             },
+            knowledge_ids: Vec::new(),
         });
     }
 
     Ok(Some(analytics))
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn cmd_search(
     config: &Config,
     query: &str,
     project: Option<String>,
     knowledge_only: bool,
     context_lines: usize,
+    files_only: bool,
+    max_results: Option<usize>,
+    turns: bool,
 ) -> Result<()> {
     let search_dir = if knowledge_only {
         config.memory_dir.join("knowledge")
@@ -328,7 +510,7 @@ pub fn cmd_search(
     let pattern = regex::Regex::new(query)
         .map_err(|e| crate::error::MemoryError::Config(format!("Invalid regex: {}", e)))?;
 
-    let mut found = false;
+    let mut matched_files = 0usize;
     for entry in walkdir::WalkDir::new(&search_dir)
         .into_iter()
         .filter_map(|e| e.ok())
@@ -353,37 +535,51 @@ pub fn cmd_search(
             Err(_) => continue,
         };
 
-        let lines: Vec<&str> = content.lines().collect();
-        let mut matched_in_file = false;
+        if !content.lines().any(|line| pattern.is_match(line)) {
+            continue;
+        }
+        matched_files += 1;
+        if max_results.is_some_and(|max| matched_files > max) {
+            continue;
+        }
+
+        let rel = path.strip_prefix(&config.memory_dir).unwrap_or(path);
 
+        // Paths only, uncolored, so the output can be piped into other tools
+        if files_only {
+            println!("{}", rel.display());
+            continue;
+        }
+
+        println!("\n{}", rel.display().to_string().cyan().bold());
+        let lines: Vec<&str> = content.lines().collect();
+        if turns && path.file_name().is_some_and(|n| n == "conversation.md") {
+            print_turn_matches(&content, &lines, &pattern, context_lines);
+            continue;
+        }
         for (i, line) in lines.iter().enumerate() {
             if pattern.is_match(line) {
-                if !matched_in_file {
-                    let rel = path.strip_prefix(&config.memory_dir).unwrap_or(path);
-                    println!("\n{}", rel.display().to_string().cyan().bold());
-                    matched_in_file = true;
-                    found = true;
-                }
-
-                let start = i.saturating_sub(context_lines);
-                let end = (i + context_lines + 1).min(lines.len());
-                for (j, line) in lines.iter().enumerate().take(end).skip(start) {
-                    let prefix = if j == i {
-                        format!("{:>4} > ", j + 1).green().to_string()
-                    } else {
-                        format!("{:>4}   ", j + 1).dimmed().to_string()
-                    };
-                    println!("{}{}", prefix, line);
-                }
-                if end < lines.len() {
-                    println!("{}", "  ---".dimmed());
-                }
+                print_line_window(&lines, i, context_lines);
             }
         }
     }
 
+    let found = matched_files > 0;
+    if let Some(max) = max_results.filter(|max| matched_files > *max) {
+        let note = format!("Showing {} of {} matching files.", max, matched_files);
+        if files_only {
+            eprintln!("{}", note.dimmed());
+        } else {
+            status!("\n{}", note.dimmed());
+        }
+    }
+
     if !found {
-        println!("{} No matches for '{}'", "Not found:".yellow(), query);
+        if files_only {
+            eprintln!("{} No matches for '{}'", "Not found:".yellow(), query);
+        } else {
+            println!("{} No matches for '{}'", "Not found:".yellow(), query);
+        }
     }
 
     // Track usage
@@ -394,15 +590,114 @@ pub fn cmd_search(
         project: project.clone().unwrap_or_else(|| "all".to_string()),
         query: Some(query.to_string()),
         category: None,
-        results_count: if found { Some(1) } else { Some(0) },
+        results_count: Some(matched_files),
         session_id: None,
         tokens_consumed: None,
+        knowledge_ids: Vec::new(),
     });
 
     Ok(())
 }
 
-pub fn cmd_recall(config: &Config, project: &str, verbose: bool) -> Result<()> {
+/// Print ±`context_lines` around the matching line `i`.
+fn print_line_window(lines: &[&str], i: usize, context_lines: usize) {
+    let start = i.saturating_sub(context_lines);
+    let end = (i + context_lines + 1).min(lines.len());
+    for (j, line) in lines.iter().enumerate().take(end).skip(start) {
+        let prefix = if j == i {
+            format!("{:>4} > ", j + 1).green().to_string()
+        } else {
+            format!("{:>4}   ", j + 1).dimmed().to_string()
+        };
+        println!("{}{}", prefix, line);
+    }
+    if end < lines.len() {
+        println!("{}", "  ---".dimmed());
+    }
+}
+
+/// Longest message excerpt shown per matching turn section
+const TURN_EXCERPT_LINES: usize = 30;
+
+/// Print each conversation.md match as the turn message that contains it,
+/// prefixed by the user's prompt when the match is in the response.
+/// Matches outside any turn (the session header) fall back to a line window.
+fn print_turn_matches(content: &str, lines: &[&str], pattern: &regex::Regex, context_lines: usize) {
+    use crate::parser::conversation::{locate_turn_sections, TurnRole};
+
+    let sections = locate_turn_sections(content);
+    let mut shown = std::collections::HashSet::new();
+
+    for (i, line) in lines.iter().enumerate() {
+        if !pattern.is_match(line) {
+            continue;
+        }
+        let Some(idx) = sections.iter().position(|s| (s.start..s.end).contains(&i)) else {
+            print_line_window(lines, i, context_lines);
+            continue;
+        };
+        if !shown.insert(idx) {
+            continue;
+        }
+        let section = &sections[idx];
+
+        println!(
+            "  {} {}",
+            format!("Turn {}", section.turn).bold(),
+            format!("· {}", section.role.as_str()).dimmed()
+        );
+        if section.role != TurnRole::User {
+            let prompt = sections
+                .iter()
+                .find(|s| s.turn == section.turn && s.role == TurnRole::User)
+                .and_then(|s| lines[s.start..s.end].iter().find(|l| !l.trim().is_empty()));
+            if let Some(prompt) = prompt {
+                let prompt = prompt.trim();
+                let prompt = match prompt.char_indices().nth(100) {
+                    Some((cut, _)) => format!("{}…", &prompt[..cut]),
+                    None => prompt.to_string(),
+                };
+                println!("  {} {}", "user:".dimmed(), prompt.dimmed());
+            }
+        }
+
+        // Long messages: start a little before the first match
+        let start = if section.end - section.start > TURN_EXCERPT_LINES {
+            i.saturating_sub(TURN_EXCERPT_LINES / 3).max(section.start)
+        } else {
+            section.start
+        };
+        let end = (start + TURN_EXCERPT_LINES).min(section.end);
+        if start > section.start {
+            println!(
+                "{}",
+                format!("       … {} earlier lines", start - section.start).dimmed()
+            );
+        }
+        for (j, line) in lines.iter().enumerate().take(end).skip(start) {
+            let prefix = if pattern.is_match(line) {
+                format!("{:>4} > ", j + 1).green().to_string()
+            } else {
+                format!("{:>4}   ", j + 1).dimmed().to_string()
+            };
+            println!("{}{}", prefix, line);
+        }
+        if end < section.end {
+            println!(
+                "{}",
+                format!("       … {} more lines", section.end - end).dimmed()
+            );
+        }
+        println!("{}", "  ---".dimmed());
+    }
+}
+
+pub fn cmd_recall(
+    config: &Config,
+    project: &str,
+    chronological: bool,
+    verbose: bool,
+) -> Result<()> {
     let knowledge_dir = config.memory_dir.join("knowledge").join(project);
     let context_path = knowledge_dir.join("context.md");
 
@@ -419,11 +714,16 @@ pub fn cmd_recall(config: &Config, project: &str, verbose: bool) -> Result<()> {
                 "Source:".cyan()
             );
         }
-        crate::inject::build_raw_context(project, &knowledge_dir)
+        let order = if chronological {
+            crate::inject::BlockOrder::Chronological
+        } else {
+            crate::inject::BlockOrder::Fresh
+        };
+        crate::inject::build_raw_context_ordered(project, &knowledge_dir, order)
     };
 
     // Get knowledge from installed packs
-    let pack_content = hive::get_installed_pack_knowledge(&config.memory_dir)?;
+    let pack_content = hive::get_installed_pack_knowledge(&config.memory_dir, project)?;
 
     // Combine local and pack knowledge
     let content = if let Some(local) = local_content {
@@ -448,7 +748,8 @@ pub fn cmd_recall(config: &Config, project: &str, verbose: bool) -> Result<()> {
 
     println!("{}", content);
 
-    // Track usage
+    // Track usage — record which blocks were recalled so `learn feedback` can target them
+    let recalled_ids = collect_active_session_ids(&knowledge_dir);
     let tracker = analytics::EventTracker::new(&config.memory_dir);
     let _ = tracker.track(analytics::UsageEvent {
         timestamp: chrono::Utc::now(),
@@ -456,16 +757,16 @@ pub fn cmd_recall(config: &Config, project: &str, verbose: bool) -> Result<()> {
         project: project.to_string(),
         query: None,
         category: None,
-        results_count: None,
+        results_count: Some(recalled_ids.len()),
         session_id: None,
         tokens_consumed: None,
+        knowledge_ids: recalled_ids.clone(),
     });
 
     // Track learning signals from recall — pass actual session IDs so importance boosts apply
-    let recalled_ids = collect_active_session_ids(&knowledge_dir);
     let id_refs: Vec<String> = recalled_ids;
     if let Err(e) = learning::post_recall_hook(config, project, &id_refs) {
-        eprintln!("Learning hook failed (non-fatal): {}", e);
+        tracing::warn!(project, error = %e, "post-recall learning hook failed");
     }
 
     Ok(())
@@ -538,6 +839,7 @@ pub fn cmd_context(config: &Config, project: &str, verbose: bool) -> Result<()>
         results_count: None,
         session_id: None,
         tokens_consumed: None,
+        knowledge_ids: Vec::new(),
     });
 
     Ok(())
@@ -553,14 +855,28 @@ pub fn cmd_status(config: &Config) -> Result<()> {
     }
 
     let manifest = state::Manifest::load(&config.memory_dir)?;
+    let conversation_index = state::ConversationIndex::load_or_rebuild(&config.memory_dir)?;
 
-    // Count files and sizes
+    // Count files and sizes; archived sessions come from the conversation index
     let mut total_size: u64 = 0;
     let mut md_count = 0u64;
     let mut json_count = 0u64;
 
+    for session in &conversation_index.sessions {
+        total_size += session.size;
+        for file in &session.files {
+            match Path::new(file).extension().and_then(|e| e.to_str()) {
+                Some("md") => md_count += 1,
+                Some("json") => json_count += 1,
+                _ => {}
+            }
+        }
+    }
+
+    let conv_dir = config.memory_dir.join("conversations");
     for entry in walkdir::WalkDir::new(&config.memory_dir)
         .into_iter()
+        .filter_entry(|e| e.depth() != 3 || !e.path().starts_with(&conv_dir))
         .filter_map(|e| e.ok())
         .filter(|e| e.file_type().is_file())
     {
@@ -572,16 +888,7 @@ pub fn cmd_status(config: &Config) -> Result<()> {
         }
     }
 
-    // Count projects with conversations
-    let conv_dir = config.memory_dir.join("conversations");
-    let project_count = if conv_dir.exists() {
-        std::fs::read_dir(&conv_dir)?
-            .filter_map(|e| e.ok())
-            .filter(|e| e.file_type().map(|t| t.is_dir()).unwrap_or(false))
-            .count()
-    } else {
-        0
-    };
+    let project_count = conversation_index.project_count();
 
     // Count knowledge projects
     let knowledge_dir = config.memory_dir.join("knowledge");
@@ -629,6 +936,7 @@ pub fn cmd_status(config: &Config) -> Result<()> {
     println!("  Markdown files:    {}", md_count);
     println!("  JSON files:        {}", json_count);
     println!("  Projects archived: {}", project_count);
+    println!("  Sessions archived: {}", conversation_index.sessions.len());
     println!("  Knowledge bases:   {}", knowledge_count);
     println!("  Sessions processed:{}", manifest.processed_count());
     println!(
@@ -658,7 +966,7 @@ pub fn cmd_status(config: &Config) -> Result<()> {
         let mut needs_attention: Vec<&(String, u8)> =
             quality_scores.iter().filter(|(_, s)| *s < 75).collect();
         if !needs_attention.is_empty() {
-            needs_attention.sort_by(|a, b| a.1.cmp(&b.1));
+            needs_attention.sort_by_key(|a| a.1);
             print!("  Needs attention:   ");
             let flagged: Vec<String> = needs_attention
                 .iter()
@@ -672,34 +980,56 @@ pub fn cmd_status(config: &Config) -> Result<()> {
     Ok(())
 }
 
-pub fn cmd_projects(config: &Config) -> Result<()> {
-    let projects = parser::discovery::discover_projects(&config.claude_projects_dir)?;
+pub fn cmd_projects(
+    config: &Config,
+    sort: Option<&str>,
+    filter: Option<&str>,
+    with_knowledge: bool,
+) -> Result<()> {
+    let mut projects = parser::discovery::discover_projects(&config.claude_projects_dir)?;
 
     if projects.is_empty() {
         println!("{}", "No Claude projects found.".yellow());
         return Ok(());
     }
 
+    let has_knowledge = |name: &str| {
+        config
+            .memory_dir
+            .join("knowledge")
+            .join(name)
+            .join("context.md")
+            .exists()
+    };
+
+    if let Some(needle) = filter {
+        let needle = needle.to_lowercase();
+        projects.retain(|p| p.name.to_lowercase().contains(&needle));
+    }
+    if with_knowledge {
+        projects.retain(|p| has_knowledge(&p.name));
+    }
+    if let Some(key) = sort {
+        parser::discovery::sort_projects(&mut projects, key);
+    }
+
+    if projects.is_empty() {
+        println!("{}", "No projects match the given filters.".yellow());
+        return Ok(());
+    }
+
     println!("{}", "Claude Projects".green().bold());
     println!("{}", "=".repeat(60));
 
     for project in &projects {
-        let total_size: u64 = project.sessions.iter().map(|s| s.size).sum();
+        let total_size = project.total_size();
         let latest = project
-            .sessions
-            .iter()
-            .map(|s| s.modified)
-            .max()
+            .last_modified()
             .map(|t| t.format("%Y-%m-%d %H:%M").to_string())
             .unwrap_or_else(|| "unknown".into());
 
         // Check if we have knowledge for this project
-        let has_knowledge = config
-            .memory_dir
-            .join("knowledge")
-            .join(&project.name)
-            .join("context.md")
-            .exists();
+        let has_knowledge = has_knowledge(&project.name);
 
         let knowledge_indicator = if has_knowledge {
             " *".green().to_string()
@@ -725,14 +1055,26 @@ pub fn cmd_mcp(config: &Config) -> Result<()> {
     server.run()
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn cmd_export(
     config: &Config,
     project: &str,
     format: &str,
     output: Option<&str>,
     include_conversations: bool,
+    categories: &[String],
+    since: Option<&str>,
+    anonymize: bool,
 ) -> Result<()> {
-    use extractor::knowledge::{parse_session_blocks, partition_by_expiry, reconstruct_blocks};
+    use extractor::knowledge::{
+        filter_since, parse_duration_strict, parse_session_blocks, partition_by_expiry,
+        reconstruct_blocks,
+    };
+
+    let cutoff = since
+        .map(parse_duration_strict)
+        .transpose()?
+        .map(|dur| chrono::Utc::now() - dur);
 
     let knowledge_dir = config.memory_dir.join("knowledge").join(project);
 
@@ -745,21 +1087,55 @@ pub fn cmd_export(
         return Ok(());
     }
 
-    // Read and filter knowledge files
-    let read_and_filter = |path: &Path| -> String {
+    if format == "jsonl" {
+        if include_conversations {
+            return Err(MemoryError::Config(
+                "--include-conversations is not supported for jsonl exports".into(),
+            ));
+        }
+        let mut exported =
+            super::backup::export_jsonl(&knowledge_dir, project, categories, cutoff)?;
+        if anonymize {
+            exported = anonymize_export(format, &exported)?;
+        }
+        if let Some(output_path) = crate::output::output_file(output) {
+            std::fs::write(output_path, &exported)?;
+            status!(
+                "{} Exported {} knowledge to {}",
+                "Done!".green().bold(),
+                project,
+                output_path
+            );
+        } else {
+            print!("{}", exported);
+        }
+        return Ok(());
+    }
+
+    // Read and filter knowledge files; unselected sections export as empty
+    let read_and_filter = |file_name: &str| -> String {
+        let section = file_name.trim_end_matches(".md");
+        if !categories.is_empty() && !categories.iter().any(|c| c == section) {
+            return String::new();
+        }
+        let path = knowledge_dir.join(file_name);
         if !path.exists() {
             return String::new();
         }
         let raw = std::fs::read_to_string(path).unwrap_or_default();
         let (preamble, blocks) = parse_session_blocks(&raw);
-        let (active, _) = partition_by_expiry(blocks);
+        let (mut active, _) = partition_by_expiry(blocks);
+        if let Some(cutoff) = cutoff {
+            active = filter_since(active, cutoff);
+        }
         reconstruct_blocks(&preamble, &active)
     };
 
-    let decisions = read_and_filter(&knowledge_dir.join("decisions.md"));
-    let solutions = read_and_filter(&knowledge_dir.join("solutions.md"));
-    let patterns = read_and_filter(&knowledge_dir.join("patterns.md"));
-    let context = read_and_filter(&knowledge_dir.join("context.md"));
+    let decisions = read_and_filter(Category::Decisions.file_name());
+    let solutions = read_and_filter(Category::Solutions.file_name());
+    let patterns = read_and_filter(Category::Patterns.file_name());
+    let workflows = read_and_filter(Category::Workflows.file_name());
+    let context = read_and_filter("context.md");
 
     let exported_content = match format {
         "markdown" => export_markdown(
@@ -768,6 +1144,7 @@ pub fn cmd_export(
             &decisions,
             &solutions,
             &patterns,
+            &workflows,
             include_conversations,
             config,
         )?,
@@ -777,6 +1154,7 @@ pub fn cmd_export(
             &decisions,
             &solutions,
             &patterns,
+            &workflows,
             include_conversations,
             config,
         )?,
@@ -786,15 +1164,21 @@ pub fn cmd_export(
             &decisions,
             &solutions,
             &patterns,
+            &workflows,
             include_conversations,
             config,
         )?,
         _ => return Err(MemoryError::Config(format!("Unknown format: {}", format))),
     };
+    let exported_content = if anonymize {
+        anonymize_export(format, &exported_content)?
+    } else {
+        exported_content
+    };
 
-    if let Some(output_path) = output {
+    if let Some(output_path) = crate::output::output_file(output) {
         std::fs::write(output_path, &exported_content)?;
-        println!(
+        status!(
             "{} Exported {} knowledge to {}",
             "Done!".green().bold(),
             project,
@@ -807,12 +1191,65 @@ pub fn cmd_export(
     Ok(())
 }
 
+/// Redact secrets in an export with [`hive::SecretDetector`], reporting the count on stderr.
+/// JSON formats are redacted value by value so the output stays valid JSON.
+fn anonymize_export(format: &str, content: &str) -> Result<String> {
+    fn redact_value(value: &mut serde_json::Value, detector: &hive::SecretDetector) -> usize {
+        match value {
+            serde_json::Value::String(s) => {
+                let (redacted, count) = detector.redact_secrets_counted(s);
+                *s = redacted;
+                count
+            }
+            serde_json::Value::Array(items) => {
+                items.iter_mut().map(|v| redact_value(v, detector)).sum()
+            }
+            serde_json::Value::Object(map) => {
+                map.values_mut().map(|v| redact_value(v, detector)).sum()
+            }
+            _ => 0,
+        }
+    }
+
+    let detector = hive::SecretDetector::new()?;
+    let (redacted, count) = match format {
+        "json" => {
+            let mut value: serde_json::Value = serde_json::from_str(content)?;
+            let count = redact_value(&mut value, &detector);
+            (serde_json::to_string_pretty(&value)?, count)
+        }
+        "jsonl" => {
+            let mut out = String::new();
+            let mut count = 0;
+            for line in content.lines() {
+                let mut value: serde_json::Value = serde_json::from_str(line)?;
+                count += redact_value(&mut value, &detector);
+                out.push_str(&serde_json::to_string(&value)?);
+                out.push('\n');
+            }
+            (out, count)
+        }
+        _ => detector.redact_secrets_counted(content),
+    };
+
+    // stderr, so exports written to stdout stay clean
+    eprintln!(
+        "{} Redacted {} potential secret{}",
+        "Anonymize:".cyan(),
+        count,
+        if count == 1 { "" } else { "s" }
+    );
+    Ok(redacted)
+}
+
+#[allow(clippy::too_many_arguments)]
 fn export_markdown(
     project: &str,
     context: &str,
     decisions: &str,
     solutions: &str,
     patterns: &str,
+    workflows: &str,
     include_conversations: bool,
     config: &Config,
 ) -> Result<String> {
@@ -844,6 +1281,11 @@ fn export_markdown(
 
     if !patterns.trim().is_empty() {
         output.push_str(patterns);
+        output.push_str("\n\n---\n\n");
+    }
+
+    if !workflows.trim().is_empty() {
+        output.push_str(workflows);
         output.push_str("\n\n");
     }
 
@@ -869,12 +1311,14 @@ fn export_markdown(
     Ok(output)
 }
 
+#[allow(clippy::too_many_arguments)]
 fn export_json(
     project: &str,
     context: &str,
     decisions: &str,
     solutions: &str,
     patterns: &str,
+    workflows: &str,
     include_conversations: bool,
     config: &Config,
 ) -> Result<String> {
@@ -892,15 +1336,11 @@ fn export_json(
                     if conv_file.exists() {
                         let session_id = entry.file_name().to_string_lossy().to_string();
                         let content = std::fs::read_to_string(conv_file)?;
-                        let meta = if meta_file.exists() {
-                            std::fs::read_to_string(meta_file).ok()
-                        } else {
-                            None
-                        };
+                        let meta = renderer::markdown::SessionMeta::load(&meta_file);
                         conversations.push(json!({
                             "session_id": session_id,
                             "content": content,
-                            "meta": meta.and_then(|m| serde_json::from_str::<serde_json::Value>(&m).ok()),
+                            "meta": meta,
                         }));
                     }
                 }
@@ -918,6 +1358,7 @@ fn export_json(
             "decisions": decisions,
             "solutions": solutions,
             "patterns": patterns,
+            "workflows": workflows,
         },
         "conversations": conversations,
     });
@@ -925,12 +1366,14 @@ fn export_json(
     Ok(serde_json::to_string_pretty(&export)?)
 }
 
+#[allow(clippy::too_many_arguments)]
 fn export_html(
     project: &str,
     context: &str,
     decisions: &str,
     solutions: &str,
     patterns: &str,
+    workflows: &str,
     include_conversations: bool,
     config: &Config,
 ) -> Result<String> {
@@ -992,6 +1435,7 @@ fn export_html(
         ("Decisions", decisions),
         ("Solutions", solutions),
         ("Patterns", patterns),
+        ("Workflows", workflows),
     ];
 
     for (title, content) in sections {
@@ -4,6 +4,7 @@ use crate::analytics;
 use crate::config::Config;
 use crate::diff;
 use crate::error::*;
+use crate::output::status;
 
 pub fn cmd_diff(
     config: &Config,
@@ -50,8 +51,8 @@ pub fn cmd_diff(
             );
         }
 
-        println!("\nTo compare versions:");
-        println!(
+        status!("\nTo compare versions:");
+        status!(
             "  {}",
             format!(
                 "engram diff {} {} --version <version-id>",
@@ -121,6 +122,152 @@ pub fn cmd_diff(
     Ok(())
 }
 
+/// Summarize, for every project, which knowledge files differ from their latest
+/// tracked version. Read-only: files without a baseline are reported, not tracked.
+pub fn cmd_diff_all(config: &Config, category: Option<&str>) -> Result<()> {
+    let knowledge_dir = config.memory_dir.join("knowledge");
+    let mut projects: Vec<String> = std::fs::read_dir(&knowledge_dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().map(|t| t.is_dir()).unwrap_or(false))
+        .map(|e| e.file_name().to_string_lossy().into_owned())
+        .filter(|name| name != crate::config::GLOBAL_DIR)
+        .collect();
+    projects.sort();
+
+    let categories: Vec<&str> = match category {
+        Some(category) => vec![category],
+        None => crate::config::CATEGORIES.to_vec(),
+    };
+
+    println!("{}", "Knowledge Drift".green().bold());
+    println!("{}", "=".repeat(60));
+
+    let mut checked = 0;
+    let mut changed = 0;
+    let mut untracked = 0;
+
+    for project in &projects {
+        let tracker = diff::VersionTracker::new(&config.memory_dir, project);
+        let mut rows = Vec::new();
+
+        for category in &categories {
+            let current_file = knowledge_dir.join(project).join(format!("{}.md", category));
+            if !current_file.exists() {
+                continue;
+            }
+            let current_content = std::fs::read_to_string(&current_file)?;
+            checked += 1;
+
+            let summary = match tracker.get_latest_version(category)? {
+                Some(v) => {
+                    let old_content = tracker.get_version_content(&v.version_id)?;
+                    let diff_result = diff::compute_diff(&old_content, &current_content, category);
+                    if diff_result.is_empty() {
+                        "unchanged".dimmed().to_string()
+                    } else {
+                        changed += 1;
+                        diff_result.summary().yellow().to_string()
+                    }
+                }
+                None => {
+                    untracked += 1;
+                    "no baseline".cyan().to_string()
+                }
+            };
+            rows.push(format!("  {:<12} {}", category, summary));
+        }
+
+        if !rows.is_empty() {
+            println!("\n{}", project.bold());
+            for row in rows {
+                println!("{}", row);
+            }
+        }
+    }
+
+    if checked == 0 {
+        println!("\n{}", "No knowledge files found".yellow());
+        return Ok(());
+    }
+
+    println!(
+        "\n{} of {} files changed since their last tracked version",
+        changed, checked
+    );
+    if untracked > 0 {
+        status!(
+            "{} {} files have no baseline yet; run {} to create one",
+            "Note:".cyan(),
+            untracked,
+            "engram diff <project> <category>".cyan()
+        );
+    }
+
+    Ok(())
+}
+
+/// Remove old knowledge versions beyond `keep` per category, optionally only
+/// those older than `older_than`, for one project or every project.
+pub fn cmd_prune_versions(
+    config: &Config,
+    project: Option<&str>,
+    keep: usize,
+    older_than: Option<&str>,
+    dry_run: bool,
+) -> Result<()> {
+    let cutoff = older_than
+        .map(crate::extractor::knowledge::parse_duration_strict)
+        .transpose()?
+        .map(|dur| chrono::Utc::now() - dur);
+
+    let versions_dir = config.memory_dir.join("versions");
+    let mut projects: Vec<String> = match project {
+        Some(project) => vec![project.to_string()],
+        None => std::fs::read_dir(&versions_dir)
+            .into_iter()
+            .flatten()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().map(|t| t.is_dir()).unwrap_or(false))
+            .map(|e| e.file_name().to_string_lossy().into_owned())
+            .collect(),
+    };
+    projects.sort();
+
+    let mut removed = 0;
+    let mut bytes = 0;
+    for project in &projects {
+        let tracker = diff::VersionTracker::new(&config.memory_dir, project);
+        let report = tracker.prune_versions(keep, cutoff, dry_run)?;
+        if report.removed > 0 {
+            println!(
+                "  {} {} versions ({})",
+                project.cyan(),
+                report.removed,
+                humansize::format_size(report.bytes, humansize::BINARY)
+            );
+        }
+        removed += report.removed;
+        bytes += report.bytes;
+    }
+
+    let verb = if dry_run { "Would remove" } else { "Removed" };
+    status!(
+        "{} {} {} versions, reclaiming {}",
+        if dry_run {
+            "Dry run:".yellow().bold()
+        } else {
+            "Done!".green().bold()
+        },
+        verb,
+        removed,
+        humansize::format_size(bytes, humansize::BINARY)
+    );
+
+    Ok(())
+}
+
 pub fn cmd_analytics(
     project: Option<&str>,
     days: u32,
@@ -176,6 +323,7 @@ pub fn cmd_analytics(
                 analytics::EventType::Inject => "💉",
                 analytics::EventType::Ingest => "📥",
                 analytics::EventType::Ask => "❓",
+                analytics::EventType::LlmUsage => "🪙",
             };
 
             println!(
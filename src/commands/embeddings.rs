@@ -4,12 +4,64 @@ use crate::embeddings::store::SearchFilter;
 use crate::error::{MemoryError, Result};
 use crate::extractor::knowledge::parse_ttl;
 use crate::llm::client::LlmClient;
+use crate::output::status;
 use colored::Colorize;
+use indicatif::{ProgressBar, ProgressStyle};
+
+/// `--provider` picks the embedding provider outright; otherwise the configured
+/// one is used, with `--model` / `--endpoint` applied on top.
+fn resolve_embed_provider(
+    config: &Config,
+    provider: Option<&str>,
+    model: Option<&str>,
+    endpoint: Option<&str>,
+) -> Result<embeddings::EmbeddingProvider> {
+    match provider {
+        Some(p) => embeddings::EmbeddingProvider::from_override(p, model, endpoint),
+        None => embeddings::EmbeddingProvider::from_config(config).with_overrides(model, endpoint),
+    }
+}
+
+/// Provider for embedding a search query. Explicit flags win; otherwise a
+/// single-project search reuses the provider recorded in that project's index
+/// so the query vector is comparable with the stored ones.
+fn resolve_query_provider(
+    config: &Config,
+    project: Option<&str>,
+    provider: Option<&str>,
+    model: Option<&str>,
+    endpoint: Option<&str>,
+) -> Result<embeddings::EmbeddingProvider> {
+    if provider.is_some() || model.is_some() || endpoint.is_some() {
+        return resolve_embed_provider(config, provider, model, endpoint);
+    }
+    let recorded = project.and_then(|p| {
+        embeddings::EmbeddingStore::read_embedder(
+            &config
+                .memory_dir
+                .join("knowledge")
+                .join(p)
+                .join("embeddings.json"),
+        )
+    });
+    if let Some(info) = recorded {
+        match embeddings::EmbeddingProvider::from_info(&info) {
+            Ok(p) => return Ok(p),
+            Err(e) => {
+                tracing::warn!(error = %e, embedder = %info, "cannot use the index's embedding provider; using the configured one")
+            }
+        }
+    }
+    Ok(embeddings::EmbeddingProvider::from_config(config))
+}
 
 pub fn cmd_embed(
     config: &Config,
     project: &str,
     provider_override: Option<&str>,
+    model: Option<&str>,
+    endpoint: Option<&str>,
+    chunk_strategy: Option<embeddings::ChunkStrategy>,
     verbose: bool,
 ) -> Result<()> {
     let rt = tokio::runtime::Builder::new_current_thread()
@@ -24,80 +76,54 @@ pub fn cmd_embed(
             project
         );
 
-        let provider = if let Some(prov) = provider_override {
-            // Read stored embed_model from auth.json for all explicit-provider paths
-            let stored_embed_model = crate::auth::AuthStore::load()
-                .ok()
-                .and_then(|s| s.embed_model);
-
-            match prov {
-                "openai" => {
-                    let key = std::env::var("OPENAI_API_KEY")
-                        .map_err(|_| MemoryError::Config("OPENAI_API_KEY not set".into()))?;
-                    let model =
-                        stored_embed_model.unwrap_or_else(|| "text-embedding-3-small".to_string());
-                    if verbose {
-                        println!("{} Provider: OpenAI ({})", "Embed:".cyan(), model);
-                    }
-                    embeddings::EmbeddingProvider::OpenAI {
-                        api_key: key,
-                        model,
-                    }
-                }
-                "gemini" => {
-                    let key = std::env::var("GEMINI_API_KEY")
-                        .map_err(|_| MemoryError::Config("GEMINI_API_KEY not set".into()))?;
-                    let model =
-                        stored_embed_model.unwrap_or_else(|| "gemini-embedding-001".to_string());
-                    if verbose {
-                        println!("{} Provider: Gemini ({})", "Embed:".cyan(), model);
-                    }
-                    embeddings::EmbeddingProvider::Gemini {
-                        api_key: key,
-                        model,
-                    }
-                }
-                "ollama" => {
-                    let model =
-                        stored_embed_model.unwrap_or_else(|| "nomic-embed-text".to_string());
-                    if verbose {
-                        println!("{} Provider: Ollama ({})", "Embed:".cyan(), model);
-                    }
-                    embeddings::EmbeddingProvider::OllamaLocal { model }
-                }
-                _ => return Err(MemoryError::Config(format!("Unknown provider: {}", prov))),
-            }
+        let provider = resolve_embed_provider(config, provider_override, model, endpoint)?;
+        if verbose {
+            println!("{} Provider: {}", "Embed:".cyan(), provider.info());
+        }
+
+        let pb = if crate::output::is_quiet() {
+            ProgressBar::hidden()
         } else {
-            let p = embeddings::EmbeddingProvider::from_config(config);
-            if verbose {
-                let name = match &p {
-                    embeddings::EmbeddingProvider::OpenAI { .. } => {
-                        "OpenAI (text-embedding-3-small)"
-                    }
-                    embeddings::EmbeddingProvider::Gemini { .. } => "Gemini (text-embedding-004)",
-                    embeddings::EmbeddingProvider::OllamaLocal { .. } => {
-                        "Ollama (nomic-embed-text)"
-                    }
-                };
-                println!("{} Provider: {}", "Embed:".cyan(), name);
-            }
-            p
+            ProgressBar::new(0)
         };
+        pb.set_style(
+            ProgressStyle::default_bar()
+                .template("{spinner:.green} [{bar:40.cyan/blue}] {pos}/{len} chunks")
+                .unwrap()
+                .progress_chars("=>-"),
+        );
+
+        let strategy = chunk_strategy.unwrap_or_else(|| {
+            embeddings::search::SemanticSearch::stored_strategy(&config.memory_dir, project)
+        });
+        if verbose {
+            println!("{} Chunking: {}", "Embed:".cyan(), strategy);
+        }
 
-        let store =
-            embeddings::search::SemanticSearch::build_index(&config.memory_dir, project, &provider)
-                .await?;
+        let store = embeddings::search::SemanticSearch::build_index_with_progress(
+            &config.memory_dir,
+            project,
+            &provider,
+            strategy,
+            |done, total| {
+                pb.set_length(total as u64);
+                pb.set_position(done as u64);
+            },
+        )
+        .await?;
+        pb.finish_and_clear();
 
         let stats = store.stats();
 
-        println!("{} Embeddings created:", "Done!".green().bold());
+        status!("{} Embeddings created:", "Done!".green().bold());
+        println!("  Chunking: {}", store.chunk_strategy);
         println!("  Total chunks: {}", stats.total_chunks);
         println!("  By category:");
         for (cat, count) in stats.by_category {
             println!("    {}: {}", cat, count);
         }
-        println!("\nSearch with:");
-        println!(
+        status!("\nSearch with:");
+        status!(
             "  {}",
             format!(
                 "engram search-semantic \"your query\" --project {}",
@@ -116,11 +142,16 @@ pub fn cmd_search_semantic(
     query: &str,
     project: Option<&str>,
     top_k: usize,
-    threshold: f32,
+    threshold: &str,
     verbose: bool,
     since: Option<&str>,
     category: Option<&str>,
     file: Option<&str>,
+    explain: bool,
+    rerank: bool,
+    embed_provider: Option<&str>,
+    embed_model: Option<&str>,
+    endpoint: Option<&str>,
 ) -> Result<()> {
     let rt = tokio::runtime::Builder::new_current_thread()
         .enable_all()
@@ -144,17 +175,14 @@ pub fn cmd_search_semantic(
     };
 
     let has_filter = since.is_some() || category.is_some() || file.is_some();
+    let threshold = Threshold::parse(threshold)?;
 
     rt.block_on(async {
-        let provider = embeddings::EmbeddingProvider::from_config(config);
+        let provider =
+            resolve_query_provider(config, project, embed_provider, embed_model, endpoint)?;
 
         if verbose {
-            let name = match &provider {
-                embeddings::EmbeddingProvider::OpenAI { .. } => "OpenAI (text-embedding-3-small)",
-                embeddings::EmbeddingProvider::Gemini { .. } => "Gemini (text-embedding-004)",
-                embeddings::EmbeddingProvider::OllamaLocal { .. } => "Ollama (nomic-embed-text)",
-            };
-            println!("{} Provider: {}", "Search:".cyan(), name);
+            println!("{} Provider: {}", "Search:".cyan(), provider.info());
             if has_filter {
                 println!(
                     "{} Filters: since={:?}  category={:?}  file={:?}",
@@ -169,6 +197,10 @@ pub fn cmd_search_semantic(
         // Build optional LLM client for HyDE
         let llm_client = LlmClient::new(&config.llm);
 
+        // (score, text, chunk metadata, project when searching all projects)
+        let mut all_results: Vec<(f32, String, embeddings::ChunkMetadata, Option<String>)> =
+            Vec::new();
+
         if let Some(proj) = project {
             // Search specific project (filtered)
             let results = search_project_filtered(
@@ -182,26 +214,14 @@ pub fn cmd_search_semantic(
                 verbose,
             )
             .await?;
-
-            println!(
-                "{} Semantic search results for '{}':\n",
-                "Search".green().bold(),
-                query
+            all_results.extend(
+                results
+                    .into_iter()
+                    .map(|(score, text, meta)| (score, text, meta, None)),
             );
-
-            for (score, text, cat) in results {
-                if score >= threshold {
-                    println!("  {} [{}] ({:.1}%)", ">".green(), cat.cyan(), score * 100.0);
-                    if verbose {
-                        println!("    similarity: {:.4}", score);
-                    }
-                    println!("    {}\n", truncate_text(&text, 150));
-                }
-            }
         } else {
             // Search all projects with embeddings
             let knowledge_dir = config.memory_dir.join("knowledge");
-            let mut all_results = Vec::new();
 
             for entry in std::fs::read_dir(&knowledge_dir)? {
                 let entry = entry?;
@@ -231,35 +251,119 @@ pub fn cmd_search_semantic(
                 )
                 .await
                 {
-                    for (score, text, cat) in results {
-                        if score >= threshold {
-                            all_results.push((score, text, cat, project_name.clone()));
-                        }
+                    for (score, text, meta) in results {
+                        all_results.push((score, text, meta, Some(project_name.clone())));
                     }
                 }
             }
+        }
+
+        // Sort by score
+        all_results.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+        all_results.truncate(top_k);
 
-            // Sort by score
-            all_results.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
-            all_results.truncate(top_k);
+        let scores: Vec<f32> = all_results.iter().map(|r| r.0).collect();
+        let cutoff = threshold.resolve(&scores);
+        let hits = all_results.iter().filter(|r| r.0 >= cutoff).count();
+        let results_count = hits;
 
+        println!(
+            "{} Semantic search results for '{}':\n",
+            "Search".green().bold(),
+            query
+        );
+        if verbose || threshold == Threshold::Auto {
             println!(
-                "{} Semantic search results for '{}':\n",
-                "Search".green().bold(),
-                query
+                "{} Threshold: {:.2}{}\n",
+                "Search:".cyan(),
+                cutoff,
+                if threshold == Threshold::Auto {
+                    " (auto: mean + stddev of scores)"
+                } else {
+                    ""
+                }
             );
+        }
 
-            for (score, text, cat, proj) in all_results {
-                println!(
+        // Nothing cleared a fixed threshold: show the best matches anyway so the
+        // user can see what scores their data produces
+        let shown = if hits == 0 && !all_results.is_empty() {
+            let best = all_results[0].0;
+            println!(
+                "  {} No results above threshold {:.2} (best score {:.2}). Showing top {} anyway.",
+                "Note:".yellow(),
+                cutoff,
+                best,
+                all_results.len()
+            );
+            println!(
+                "  Try {} or {}.\n",
+                format!("--threshold {:.2}", suggest_threshold(best)).cyan(),
+                "--threshold auto".cyan()
+            );
+            all_results.len()
+        } else {
+            hits
+        };
+        all_results.truncate(shown);
+
+        if rerank && all_results.len() > 1 {
+            let candidates: Vec<&str> = all_results.iter().map(|r| r.1.as_str()).collect();
+            let prompt = crate::llm::prompts::rerank_prompt(query, &candidates);
+            let order = match llm_client
+                .chat(crate::llm::prompts::SYSTEM_RERANKER, &prompt)
+                .await
+            {
+                Ok(response) => parse_rerank_order(&response, all_results.len()),
+                Err(_) => None,
+            };
+            match order {
+                Some(order) => {
+                    let mut slots: Vec<_> = all_results.into_iter().map(Some).collect();
+                    all_results = order.into_iter().filter_map(|i| slots[i].take()).collect();
+                    if verbose {
+                        println!("{} Re-ranked by LLM\n", "Search:".cyan());
+                    }
+                }
+                None => println!(
+                    "  {} Re-ranking failed; showing vector order.\n",
+                    "Note:".yellow()
+                ),
+            }
+        }
+
+        for (score, text, meta, proj) in all_results {
+            let cat = &meta.category;
+            match proj {
+                Some(proj) => println!(
                     "  {} [{}:{}] ({:.1}%)",
                     ">".green(),
                     proj.dimmed(),
                     cat.cyan(),
                     score * 100.0
+                ),
+                None => println!("  {} [{}] ({:.1}%)", ">".green(), cat.cyan(), score * 100.0),
+            }
+            if verbose {
+                println!("    similarity: {:.4}", score);
+            }
+            if explain {
+                println!(
+                    "    {} knowledge/{}/{}",
+                    "source:".dimmed(),
+                    meta.project,
+                    chunk_source_file(cat)
                 );
-                if verbose {
-                    println!("    similarity: {:.4}", score);
+                println!(
+                    "    {} {}",
+                    "session:".dimmed(),
+                    meta.session_id.as_deref().unwrap_or("-")
+                );
+                for line in text.trim().lines() {
+                    println!("    | {}", line);
                 }
+                println!();
+            } else {
                 println!("    {}\n", truncate_text(&text, 150));
             }
         }
@@ -274,9 +378,10 @@ pub fn cmd_search_semantic(
                 .unwrap_or_else(|| "all".to_string()),
             query: Some(query.to_string()),
             category: category.map(|s| s.to_string()),
-            results_count: None,
+            results_count: Some(results_count),
             session_id: None,
             tokens_consumed: None,
+            knowledge_ids: Vec::new(),
         });
 
         Ok(())
@@ -295,7 +400,7 @@ async fn search_project_filtered(
     filter: &SearchFilter,
     llm_client: Option<&LlmClient>,
     verbose: bool,
-) -> Result<Vec<(f32, String, String)>> {
+) -> Result<Vec<(f32, String, embeddings::ChunkMetadata)>> {
     use embeddings::store::EmbeddingStore;
 
     let index_path = memory_dir
@@ -310,6 +415,19 @@ async fn search_project_filtered(
     }
 
     let store = EmbeddingStore::load(&index_path)?;
+    if let Some(ref recorded) = store.embedder {
+        let current = provider.info();
+        if (&recorded.provider, &recorded.model) != (&current.provider, &current.model) {
+            tracing::warn!(
+                project = %project,
+                indexed_with = %recorded,
+                query_uses = %current,
+                "embedder mismatch; scores are not comparable. Re-run 'engram embed {}' \
+                 or pass a matching --provider/--model",
+                project
+            );
+        }
+    }
 
     // HyDE: generate a hypothetical document that would answer the query, then embed that
     let embed_text = if let Some(client) = llm_client {
@@ -340,10 +458,88 @@ async fn search_project_filtered(
 
     Ok(results
         .into_iter()
-        .map(|(score, chunk)| (score, chunk.text.clone(), chunk.metadata.category.clone()))
+        .map(|(score, chunk)| (score, chunk.text.clone(), chunk.metadata.clone()))
         .collect())
 }
 
+/// Parse an LLM re-ranking response ("3, 1, 2") into 0-based indices over `count`
+/// results. Numbers out of range or repeated are ignored; results the response
+/// left out keep their vector order after the ranked ones. Returns `None` when
+/// the response names no valid result.
+fn parse_rerank_order(response: &str, count: usize) -> Option<Vec<usize>> {
+    let mut order: Vec<usize> = Vec::with_capacity(count);
+    for token in response.split(|c: char| !c.is_ascii_digit()) {
+        if let Ok(n) = token.parse::<usize>() {
+            if (1..=count).contains(&n) && !order.contains(&(n - 1)) {
+                order.push(n - 1);
+            }
+        }
+    }
+    if order.is_empty() {
+        return None;
+    }
+    order.extend(
+        (0..count)
+            .filter(|i| !order.contains(i))
+            .collect::<Vec<_>>(),
+    );
+    Some(order)
+}
+
+/// Knowledge file an embedded chunk of `category` was taken from.
+fn chunk_source_file(category: &str) -> &'static str {
+    crate::config::CATEGORIES
+        .iter()
+        .position(|c| *c == category)
+        .map(|i| crate::config::CATEGORY_FILES[i])
+        .unwrap_or("context.md")
+}
+
+/// Similarity cutoff for `search-semantic`: a fixed score or derived from the results.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Threshold {
+    Fixed(f32),
+    Auto,
+}
+
+impl Threshold {
+    fn parse(s: &str) -> Result<Self> {
+        if s.eq_ignore_ascii_case("auto") {
+            return Ok(Threshold::Auto);
+        }
+        match s.parse::<f32>() {
+            Ok(v) if (0.0..=1.0).contains(&v) => Ok(Threshold::Fixed(v)),
+            _ => Err(MemoryError::Config(format!(
+                "Invalid --threshold '{}'. Use a number between 0.0 and 1.0 or 'auto'",
+                s
+            ))),
+        }
+    }
+
+    /// Cutoff for this set of scores. `Auto` uses mean + one standard deviation,
+    /// capped at the best score so at least one result always clears it.
+    fn resolve(&self, scores: &[f32]) -> f32 {
+        match *self {
+            Threshold::Fixed(v) => v,
+            Threshold::Auto => {
+                if scores.is_empty() {
+                    return 0.0;
+                }
+                let n = scores.len() as f32;
+                let mean = scores.iter().sum::<f32>() / n;
+                let variance = scores.iter().map(|s| (s - mean).powi(2)).sum::<f32>() / n;
+                let best = scores.iter().cloned().fold(f32::MIN, f32::max);
+                (mean + variance.sqrt()).min(best)
+            }
+        }
+    }
+}
+
+/// A threshold a little below the best score, rounded to two decimals.
+fn suggest_threshold(best: f32) -> f32 {
+    ((best - 0.05).max(0.0) * 100.0).round() / 100.0
+}
+
 fn truncate_text(text: &str, max_len: usize) -> String {
     let cleaned = text.replace('\n', " ").trim().to_string();
     if cleaned.len() <= max_len {
@@ -352,3 +548,94 @@ fn truncate_text(text: &str, max_len: usize) -> String {
         format!("{}...", &cleaned[..max_len - 3])
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn make_test_config(dir: &TempDir) -> Config {
+        Config {
+            memory_dir: dir.path().to_path_buf(),
+            claude_projects_dir: dir.path().to_path_buf(),
+            llm: crate::auth::providers::ResolvedProvider {
+                provider: crate::auth::providers::Provider::Ollama,
+                endpoint: "http://127.0.0.1:9".to_string(),
+                model: "test".to_string(),
+                api_key: None,
+                oauth: false,
+            },
+            prompts: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_semantic_search_tracks_usage_event() {
+        let dir = TempDir::new().unwrap();
+        let config = make_test_config(&dir);
+        std::fs::create_dir_all(dir.path().join("knowledge")).unwrap();
+
+        cmd_search_semantic(
+            &config,
+            "auth flow",
+            None,
+            5,
+            "0.5",
+            false,
+            None,
+            None,
+            None,
+            false,
+            false,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let tracker = crate::analytics::EventTracker::new(dir.path());
+        let events = tracker.get_events(Some("all"), 1).unwrap();
+        assert_eq!(events.len(), 1);
+        assert!(matches!(
+            events[0].event_type,
+            crate::analytics::EventType::SemanticSearch
+        ));
+        assert_eq!(events[0].query.as_deref(), Some("auth flow"));
+        assert_eq!(events[0].results_count, Some(0));
+    }
+
+    #[test]
+    fn test_threshold_parse() {
+        assert_eq!(Threshold::parse("auto").unwrap(), Threshold::Auto);
+        assert_eq!(Threshold::parse("0.3").unwrap(), Threshold::Fixed(0.3));
+        assert!(Threshold::parse("1.5").is_err());
+        assert!(Threshold::parse("high").is_err());
+    }
+
+    #[test]
+    fn test_auto_threshold_uses_score_distribution() {
+        let scores = [0.9, 0.5, 0.4, 0.3, 0.3];
+        let cutoff = Threshold::Auto.resolve(&scores);
+        // mean 0.48, stddev ~0.22 → only the standout result clears it
+        assert!(cutoff > 0.6 && cutoff < 0.9, "cutoff was {}", cutoff);
+        assert_eq!(scores.iter().filter(|s| **s >= cutoff).count(), 1);
+
+        // Identical scores: capped at the best so results still show
+        assert_eq!(Threshold::Auto.resolve(&[0.4, 0.4]), 0.4);
+        assert_eq!(Threshold::Auto.resolve(&[]), 0.0);
+    }
+
+    #[test]
+    fn test_suggest_threshold_is_below_best() {
+        assert_eq!(suggest_threshold(0.42), 0.37);
+        assert_eq!(suggest_threshold(0.02), 0.0);
+    }
+
+    #[test]
+    fn test_parse_rerank_order() {
+        assert_eq!(parse_rerank_order("3, 1, 2", 3), Some(vec![2, 0, 1]));
+        // Omitted results keep vector order; out-of-range and repeats are ignored
+        assert_eq!(parse_rerank_order("[2] then 9, 2", 3), Some(vec![1, 0, 2]));
+        assert_eq!(parse_rerank_order("none are relevant", 3), None);
+    }
+}
@@ -5,30 +5,14 @@ use colored::Colorize;
 use crate::config::Config;
 use crate::error::{MemoryError, Result};
 use crate::graph;
+use crate::output::status;
 
-pub fn cmd_graph_build(config: &Config, project: &str) -> Result<()> {
+/// Active knowledge used as graph-builder input (context + decisions/solutions/patterns).
+fn graph_input(knowledge_dir: &Path) -> String {
     use crate::extractor::knowledge::{
         parse_session_blocks, partition_by_expiry, reconstruct_blocks,
     };
 
-    let knowledge_dir = config.memory_dir.join("knowledge").join(project);
-
-    if !knowledge_dir.exists() {
-        eprintln!(
-            "{} No knowledge found for '{}'. Run 'ingest' first.",
-            "Not found:".yellow(),
-            project
-        );
-        return Ok(());
-    }
-
-    println!(
-        "{} Building knowledge graph for '{}'...",
-        "Analyzing".green().bold(),
-        project
-    );
-
-    // Read knowledge files
     let read_and_filter = |path: &Path| -> String {
         if !path.exists() {
             return String::new();
@@ -47,7 +31,43 @@ pub fn cmd_graph_build(config: &Config, project: &str) -> Result<()> {
     knowledge_content.push_str(&read_and_filter(&knowledge_dir.join("solutions.md")));
     knowledge_content.push_str("\n\n");
     knowledge_content.push_str(&read_and_filter(&knowledge_dir.join("patterns.md")));
+    knowledge_content
+}
+
+/// Build and save one project's graph, returning (concepts, relationships).
+async fn build_and_save(
+    config: &Config,
+    project: &str,
+    knowledge_content: &str,
+) -> Result<(usize, usize)> {
+    let graph =
+        graph::builder::build_graph_from_knowledge(config, project, knowledge_content).await?;
+    let graph_path = graph_path(config, project);
+    graph
+        .save(&graph_path)
+        .map_err(|e| MemoryError::Config(format!("Failed to save graph: {}", e)))?;
+    Ok((graph.concepts.len(), graph.relationships.len()))
+}
+
+pub fn cmd_graph_build(config: &Config, project: &str) -> Result<()> {
+    let knowledge_dir = config.memory_dir.join("knowledge").join(project);
+
+    if !knowledge_dir.exists() {
+        eprintln!(
+            "{} No knowledge found for '{}'. Run 'ingest' first.",
+            "Not found:".yellow(),
+            project
+        );
+        return Ok(());
+    }
+
+    println!(
+        "{} Building knowledge graph for '{}'...",
+        "Analyzing".green().bold(),
+        project
+    );
 
+    let knowledge_content = graph_input(&knowledge_dir);
     if knowledge_content.trim().is_empty() {
         eprintln!("{} No knowledge content to analyze", "Error:".red());
         return Ok(());
@@ -59,36 +79,242 @@ pub fn cmd_graph_build(config: &Config, project: &str) -> Result<()> {
         .build()
         .map_err(|e| MemoryError::Config(format!("tokio runtime: {}", e)))?;
 
-    let graph = rt.block_on(async {
-        graph::builder::build_graph_from_knowledge(config, project, &knowledge_content).await
-    })?;
+    let (concepts, relationships) =
+        rt.block_on(build_and_save(config, project, &knowledge_content))?;
 
-    // Save graph
     let graph_path = knowledge_dir.join("graph.json");
-    graph
-        .save(&graph_path)
-        .map_err(|e| MemoryError::Config(format!("Failed to save graph: {}", e)))?;
-
-    println!("{} Knowledge graph created:", "Done!".green().bold());
-    println!("  Concepts: {}", graph.concepts.len());
-    println!("  Relationships: {}", graph.relationships.len());
+    status!("{} Knowledge graph created:", "Done!".green().bold());
+    println!("  Concepts: {}", concepts);
+    println!("  Relationships: {}", relationships);
     println!("  Saved to: {}", graph_path.display().to_string().cyan());
-    println!("\nExplore with:");
-    println!(
+    status!("\nExplore with:");
+    status!(
         "  {}",
         format!("engram graph query {} <concept>", project).cyan()
     );
-    println!("  {}", format!("engram graph viz {} ascii", project).cyan());
+    status!("  {}", format!("engram graph viz {} ascii", project).cyan());
 
     Ok(())
 }
 
-pub fn cmd_graph_query(config: &Config, project: &str, concept: &str, depth: usize) -> Result<()> {
-    let graph_path = config
+/// A project name and its graph-builder input
+type GraphInput = (String, String);
+
+/// Split the projects under `knowledge_root` into inputs to build and the
+/// names of projects with no active knowledge.
+fn graph_projects(knowledge_root: &Path) -> Result<(Vec<GraphInput>, Vec<String>)> {
+    let mut projects: Vec<String> = std::fs::read_dir(knowledge_root)?
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().map(|t| t.is_dir()).unwrap_or(false))
+        .map(|e| e.file_name().to_string_lossy().to_string())
+        .filter(|name| !name.starts_with('_')) // skip _global, _packs, etc.
+        .collect();
+    projects.sort();
+
+    let mut pending = Vec::new();
+    let mut skipped = Vec::new();
+    for project in projects {
+        let content = graph_input(&knowledge_root.join(&project));
+        if content.trim().is_empty() {
+            skipped.push(project);
+        } else {
+            pending.push((project, content));
+        }
+    }
+    Ok((pending, skipped))
+}
+
+/// Build graphs for every project in the knowledge dir, running at most
+/// `concurrency` LLM builds at a time. Projects without knowledge are skipped.
+pub fn cmd_graph_build_all(config: &Config, concurrency: usize) -> Result<()> {
+    let knowledge_root = config.memory_dir.join("knowledge");
+    if !knowledge_root.exists() {
+        eprintln!(
+            "{} No memory directory found. Run 'engram ingest' first.",
+            "Not found:".yellow()
+        );
+        return Ok(());
+    }
+
+    let (pending, skipped) = graph_projects(&knowledge_root)?;
+    if pending.is_empty() {
+        println!("{} No projects with knowledge found.", "Empty:".yellow());
+        return Ok(());
+    }
+
+    println!(
+        "{} Building knowledge graphs for {} project(s) ({} at a time)...",
+        "Analyzing".green().bold(),
+        pending.len(),
+        concurrency
+    );
+
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| MemoryError::Config(format!("tokio runtime: {}", e)))?;
+
+    let mut built = 0;
+    let mut failed = 0;
+    rt.block_on(async {
+        let mut tasks = tokio::task::JoinSet::new();
+        let mut queue = pending.into_iter();
+
+        loop {
+            while tasks.len() < concurrency.max(1) {
+                let Some((project, content)) = queue.next() else {
+                    break;
+                };
+                let config = config.clone();
+                tasks.spawn(async move {
+                    let result = build_and_save(&config, &project, &content).await;
+                    (project, result)
+                });
+            }
+
+            let Some(joined) = tasks.join_next().await else {
+                break;
+            };
+            match joined {
+                Ok((project, Ok((concepts, relationships)))) => {
+                    built += 1;
+                    println!(
+                        "  {} {}: {} concepts, {} relationships",
+                        "✓".green(),
+                        project,
+                        concepts,
+                        relationships
+                    );
+                }
+                Ok((project, Err(e))) => {
+                    failed += 1;
+                    println!("  {} {}: {}", "✗".red(), project, e);
+                }
+                Err(e) => {
+                    failed += 1;
+                    println!("  {} build task failed: {}", "✗".red(), e);
+                }
+            }
+        }
+    });
+
+    for project in &skipped {
+        println!("  {} {}: no knowledge, skipped", "-".dimmed(), project);
+    }
+
+    status!(
+        "\n{} Built {} graph(s), {} failed, {} skipped.",
+        "Done!".green().bold(),
+        built,
+        failed,
+        skipped.len()
+    );
+
+    Ok(())
+}
+
+/// Path of a project's graph. The special project "global" maps to the
+/// merged cross-project graph in `_global/`.
+fn graph_path(config: &Config, project: &str) -> std::path::PathBuf {
+    let dir = if project == crate::config::GLOBAL_PROJECT {
+        crate::config::GLOBAL_DIR
+    } else {
+        project
+    };
+    config
         .memory_dir
         .join("knowledge")
-        .join(project)
-        .join("graph.json");
+        .join(dir)
+        .join("graph.json")
+}
+
+/// Merge project graphs into `_global/graph.json`. With no projects given,
+/// every project that has a `graph.json` is included.
+pub fn cmd_graph_merge(config: &Config, projects: &[String]) -> Result<()> {
+    let knowledge_root = config.memory_dir.join("knowledge");
+
+    let mut names: Vec<String> = if projects.is_empty() {
+        std::fs::read_dir(&knowledge_root)
+            .map(|rd| {
+                rd.filter_map(|e| e.ok())
+                    .filter(|e| e.path().join("graph.json").exists())
+                    .map(|e| e.file_name().to_string_lossy().to_string())
+                    .filter(|name| !name.starts_with('_'))
+                    .collect()
+            })
+            .unwrap_or_default()
+    } else {
+        projects.to_vec()
+    };
+    names.sort();
+
+    let mut graphs = Vec::new();
+    for name in &names {
+        let path = knowledge_root.join(name).join("graph.json");
+        if !path.exists() {
+            eprintln!(
+                "{} No graph for '{}', skipping. Run 'graph build {}' first.",
+                "Warning:".yellow(),
+                name,
+                name
+            );
+            continue;
+        }
+        let graph = graph::KnowledgeGraph::load(&path).map_err(|e| {
+            MemoryError::Config(format!("Failed to load graph for {}: {}", name, e))
+        })?;
+        graphs.push(graph);
+    }
+
+    if graphs.len() < 2 {
+        eprintln!(
+            "{} Need at least two project graphs to merge (found {}).",
+            "Not enough:".yellow(),
+            graphs.len()
+        );
+        return Ok(());
+    }
+
+    let merged = graph::merge::merge_graphs(crate::config::GLOBAL_DIR, &graphs);
+    let out_dir = knowledge_root.join(crate::config::GLOBAL_DIR);
+    std::fs::create_dir_all(&out_dir)?;
+    let out_path = out_dir.join("graph.json");
+    merged
+        .save(&out_path)
+        .map_err(|e| MemoryError::Config(format!("Failed to save graph: {}", e)))?;
+
+    let mut shared: Vec<&graph::Concept> = merged
+        .concepts
+        .values()
+        .filter(|c| c.projects.len() > 1)
+        .collect();
+    shared.sort_by(|a, b| {
+        b.projects
+            .len()
+            .cmp(&a.projects.len())
+            .then_with(|| a.name.cmp(&b.name))
+    });
+
+    status!("{} Merged {} graphs:", "Done!".green().bold(), graphs.len());
+    println!("  Concepts: {}", merged.concepts.len());
+    println!("  Relationships: {}", merged.relationships.len());
+    println!("  Shared across projects: {}", shared.len());
+    for concept in shared.iter().take(10) {
+        println!(
+            "    {} ({})",
+            concept.name.cyan(),
+            concept.projects.join(", ")
+        );
+    }
+    println!("  Saved to: {}", out_path.display().to_string().cyan());
+    status!("\nExplore with:");
+    status!("  {}", "engram graph query global <concept>".cyan());
+
+    Ok(())
+}
+
+pub fn cmd_graph_query(config: &Config, project: &str, concept: &str, depth: usize) -> Result<()> {
+    let graph_path = graph_path(config, project);
 
     if !graph_path.exists() {
         eprintln!(
@@ -104,6 +330,20 @@ pub fn cmd_graph_query(config: &Config, project: &str, concept: &str, depth: usi
 
     let related = graph::query::find_related(&graph, concept, depth);
 
+    // Track usage
+    let tracker = crate::analytics::EventTracker::new(&config.memory_dir);
+    let _ = tracker.track(crate::analytics::UsageEvent {
+        timestamp: chrono::Utc::now(),
+        event_type: crate::analytics::EventType::GraphQuery,
+        project: project.to_string(),
+        query: Some(concept.to_string()),
+        category: None,
+        results_count: Some(related.len()),
+        session_id: None,
+        tokens_consumed: None,
+        knowledge_ids: Vec::new(),
+    });
+
     if related.is_empty() {
         println!(
             "{} No concepts found related to '{}'",
@@ -123,12 +363,18 @@ pub fn cmd_graph_query(config: &Config, project: &str, concept: &str, depth: usi
     for (concept_id, dist) in related {
         if let Some(c) = graph.concepts.get(&concept_id) {
             let indent = "  ".repeat(dist);
+            let projects = if c.projects.is_empty() {
+                String::new()
+            } else {
+                format!(" [{}]", c.projects.join(", ")).dimmed().to_string()
+            };
             println!(
-                "{}[{}] {} (importance: {:.1})",
+                "{}[{}] {} (importance: {:.1}){}",
                 indent,
                 dist,
                 c.name.cyan(),
-                c.importance
+                c.importance,
+                projects
             );
         }
     }
@@ -136,6 +382,25 @@ pub fn cmd_graph_query(config: &Config, project: &str, concept: &str, depth: usi
     Ok(())
 }
 
+/// Render DOT source to an SVG at `out_path` by piping it into graphviz's
+/// `dot`, so no intermediate file is needed.
+fn dot_to_svg(dot: &str, out_path: &str) -> std::io::Result<std::process::Output> {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    let mut child = Command::new("dot")
+        .args(["-Tsvg", "-o", out_path])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        // A write error means dot exited early; its status and stderr say why
+        let _ = stdin.write_all(dot.as_bytes());
+    }
+    child.wait_with_output()
+}
+
 pub fn cmd_graph_viz(
     config: &Config,
     project: &str,
@@ -143,11 +408,7 @@ pub fn cmd_graph_viz(
     output: Option<&str>,
     root: Option<&str>,
 ) -> Result<()> {
-    let graph_path = config
-        .memory_dir
-        .join("knowledge")
-        .join(project)
-        .join("graph.json");
+    let graph_path = graph_path(config, project);
 
     if !graph_path.exists() {
         eprintln!(
@@ -158,6 +419,8 @@ pub fn cmd_graph_viz(
         return Ok(());
     }
 
+    let output = crate::output::output_file(output);
+
     let graph = graph::KnowledgeGraph::load(&graph_path)
         .map_err(|e| MemoryError::Config(format!("Failed to load graph: {}", e)))?;
 
@@ -165,46 +428,47 @@ pub fn cmd_graph_viz(
         "dot" => graph::viz::to_dot(&graph),
         "ascii" => graph::viz::to_ascii(&graph, root),
         "svg" => {
-            // Generate DOT and convert to SVG using graphviz
+            // Prefer graphviz; without it, lay the graph out ourselves
+            let out_path = crate::output::require_output_file(output, format)?;
             let dot = graph::viz::to_dot(&graph);
-            if let Some(out_path) = output {
-                // Write DOT to temp file
-                let temp_dot = "/tmp/graph.dot";
-                std::fs::write(temp_dot, &dot)?;
-
-                // Convert to SVG using dot command
-                let status = std::process::Command::new("dot")
-                    .args(["-Tsvg", temp_dot, "-o", out_path])
-                    .status();
-
-                match status {
-                    Ok(s) if s.success() => {
-                        println!("{} SVG created: {}", "Done!".green().bold(), out_path);
-                        return Ok(());
-                    }
-                    _ => {
-                        eprintln!(
-                            "{} graphviz not installed. Install with: brew install graphviz",
-                            "Error:".red()
-                        );
-                        eprintln!("Outputting DOT format instead...");
-                        dot
-                    }
+
+            match dot_to_svg(&dot, out_path) {
+                Ok(out) if out.status.success() => {
+                    status!("{} SVG created: {}", "Done!".green().bold(), out_path);
+                }
+                Ok(out) => {
+                    return Err(MemoryError::Config(format!(
+                        "graphviz failed to write {}: {}",
+                        out_path,
+                        String::from_utf8_lossy(&out.stderr).trim()
+                    )));
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                    // No graphviz: fall back to the built-in layout
+                    std::fs::write(out_path, graph::viz::to_svg(&graph)).map_err(|e| {
+                        MemoryError::Config(format!("Cannot write {}: {}", out_path, e))
+                    })?;
+                    status!("{} SVG created: {}", "Done!".green().bold(), out_path);
+                    status!(
+                        "{} graphviz not installed, used the built-in layout (install graphviz for a better one, e.g. brew install graphviz)",
+                        "Note:".yellow()
+                    );
+                }
+                Err(e) => {
+                    return Err(MemoryError::Config(format!(
+                        "Could not run graphviz for {}: {}",
+                        out_path, e
+                    )));
                 }
-            } else {
-                eprintln!(
-                    "{} SVG requires --output. Showing DOT instead.",
-                    "Note:".yellow()
-                );
-                dot
             }
+            return Ok(());
         }
         _ => return Err(MemoryError::Config(format!("Unknown format: {}", format))),
     };
 
     if let Some(out_path) = output {
         std::fs::write(out_path, &viz_content)?;
-        println!(
+        status!(
             "{} Visualization saved to {}",
             "Done!".green().bold(),
             out_path
@@ -216,12 +480,14 @@ pub fn cmd_graph_viz(
     Ok(())
 }
 
-pub fn cmd_graph_path(config: &Config, project: &str, from: &str, to: &str) -> Result<()> {
-    let graph_path = config
-        .memory_dir
-        .join("knowledge")
-        .join(project)
-        .join("graph.json");
+pub fn cmd_graph_path(
+    config: &Config,
+    project: &str,
+    from: &str,
+    to: &str,
+    weighted: bool,
+) -> Result<()> {
+    let graph_path = graph_path(config, project);
 
     if !graph_path.exists() {
         eprintln!(
@@ -235,7 +501,7 @@ pub fn cmd_graph_path(config: &Config, project: &str, from: &str, to: &str) -> R
     let graph = graph::KnowledgeGraph::load(&graph_path)
         .map_err(|e| MemoryError::Config(format!("Failed to load graph: {}", e)))?;
 
-    match graph::query::shortest_path(&graph, from, to) {
+    match graph::query::shortest_path(&graph, from, to, weighted) {
         Some(path) => {
             println!(
                 "{} Path from '{}' to '{}':\n",
@@ -264,11 +530,7 @@ pub fn cmd_graph_path(config: &Config, project: &str, from: &str, to: &str) -> R
 }
 
 pub fn cmd_graph_hubs(config: &Config, project: &str, top_n: usize) -> Result<()> {
-    let graph_path = config
-        .memory_dir
-        .join("knowledge")
-        .join(project)
-        .join("graph.json");
+    let graph_path = graph_path(config, project);
 
     if !graph_path.exists() {
         eprintln!(
@@ -305,3 +567,36 @@ pub fn cmd_graph_hubs(config: &Config, project: &str, top_n: usize) -> Result<()
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_graph_projects_skips_empty_and_internal_dirs() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        for name in ["beta", "alpha", "_global", "expired"] {
+            std::fs::create_dir_all(root.join(name)).unwrap();
+        }
+        std::fs::write(
+            root.join("alpha/decisions.md"),
+            "# Decisions\n\n## Session: s1 (2020-01-01T00:00:00Z)\nUse Postgres\n",
+        )
+        .unwrap();
+        std::fs::write(root.join("beta/context.md"), "# beta - Project Context\n").unwrap();
+        std::fs::write(root.join("_global/patterns.md"), "shared pattern\n").unwrap();
+        std::fs::write(
+            root.join("expired/patterns.md"),
+            "## Session: s2 (2020-01-01T00:00:00Z) [ttl:1d]\nOld pattern\n",
+        )
+        .unwrap();
+
+        let (pending, skipped) = graph_projects(root).unwrap();
+        let names: Vec<&str> = pending.iter().map(|(p, _)| p.as_str()).collect();
+        assert_eq!(names, ["alpha", "beta"]);
+        assert!(pending[0].1.contains("Use Postgres"));
+        assert_eq!(skipped, ["expired"]);
+    }
+}
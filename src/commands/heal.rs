@@ -1,6 +1,6 @@
 use colored::Colorize;
 
-use crate::commands::hooks::cmd_hooks_install;
+use crate::commands::hooks::{cmd_hooks_install, events_to_repair};
 use crate::config::Config;
 use crate::error::{MemoryError, Result};
 use crate::health;
@@ -25,7 +25,7 @@ pub fn cmd_heal(config: &Config, check_only: bool) -> Result<()> {
         }
         if !check_only {
             print!("   Reinstalling hooks... ");
-            match cmd_hooks_install() {
+            match cmd_hooks_install(&events_to_repair(&home)) {
                 Ok(()) => println!("{}", "ok".green()),
                 Err(e) => println!("{}: {}", "error".red(), e),
             }
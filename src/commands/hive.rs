@@ -5,6 +5,7 @@ use colored::Colorize;
 use crate::cli::{HiveCommand, PackCommand, RegistryCommand};
 use crate::error::{MemoryError, Result};
 use crate::hive;
+use crate::output::status;
 
 // ── Hive commands ───────────────────────────────────────────────────────
 
@@ -20,14 +21,35 @@ pub fn cmd_hive(command: HiveCommand) -> Result<()> {
             pack,
             registry,
             scope,
-        } => cmd_hive_install(&pack, registry.as_deref(), &scope, &memory_dir),
+            project,
+        } => {
+            // Clap enforces --project for project scope
+            let project = match scope {
+                hive::PackScope::Global => None,
+                hive::PackScope::Project => project.as_deref(),
+            };
+            cmd_hive_install(&pack, registry.as_deref(), project, &memory_dir)
+        }
         HiveCommand::Uninstall { pack } => cmd_hive_uninstall(&pack, &memory_dir),
         HiveCommand::List => cmd_hive_list(&memory_dir),
-        HiveCommand::Update { pack } => cmd_hive_update(pack.as_deref(), &memory_dir),
-        HiveCommand::Browse { category, keyword } => {
-            cmd_hive_browse(category.as_deref(), keyword.as_deref(), &memory_dir)
-        }
-        HiveCommand::Search { query } => cmd_hive_search(&query, &memory_dir),
+        HiveCommand::Update { pack, check } => cmd_hive_update(pack.as_deref(), check, &memory_dir),
+        HiveCommand::Browse {
+            category,
+            keyword,
+            json,
+            limit,
+            offset,
+            refresh,
+        } => cmd_hive_browse(
+            category.as_deref(),
+            keyword.as_deref(),
+            json,
+            limit,
+            offset,
+            refresh,
+            &memory_dir,
+        ),
+        HiveCommand::Search { query, refresh } => cmd_hive_search(&query, refresh, &memory_dir),
     }
 }
 
@@ -37,15 +59,19 @@ fn cmd_hive_registry(command: RegistryCommand, memory_dir: &Path) -> Result<()>
     let manager = RegistryManager::new(memory_dir);
 
     match command {
-        RegistryCommand::Add { url } => {
+        RegistryCommand::Add { url, public_key } => {
             println!("{} Adding registry: {}", "→".blue(), url);
-            let registry = manager.add(&url)?;
+            let registry = manager.add(&url, public_key.as_deref())?;
             println!(
                 "{} Registry '{}' added successfully",
                 "✓".green(),
                 registry.name
             );
             println!("  URL: {}", registry.url);
+            println!("  Type: {}", registry.kind.as_str());
+            if registry.public_key.is_some() {
+                println!("  Signatures: required");
+            }
         }
         RegistryCommand::Remove { name } => {
             println!("{} Removing registry: {}", "→".blue(), name);
@@ -56,8 +82,8 @@ fn cmd_hive_registry(command: RegistryCommand, memory_dir: &Path) -> Result<()>
             let registries = manager.list()?;
             if registries.is_empty() {
                 println!("No registries configured.");
-                println!("\nAdd a registry with:");
-                println!("  engram hive registry add owner/repo");
+                status!("\nAdd a registry with:");
+                status!("  engram hive registry add owner/repo");
                 return Ok(());
             }
 
@@ -65,6 +91,7 @@ fn cmd_hive_registry(command: RegistryCommand, memory_dir: &Path) -> Result<()>
             for reg in registries {
                 println!("  {} {}", "●".blue(), reg.name.bold());
                 println!("    URL: {}", reg.url);
+                println!("    Type: {}", reg.kind.as_str());
                 if let Some(updated) = reg.last_updated {
                     println!("    Last updated: {}", updated.format("%Y-%m-%d %H:%M:%S"));
                 }
@@ -95,7 +122,7 @@ fn cmd_hive_registry(command: RegistryCommand, memory_dir: &Path) -> Result<()>
 fn cmd_hive_install(
     pack: &str,
     registry: Option<&str>,
-    _scope: &str,
+    project: Option<&str>,
     memory_dir: &Path,
 ) -> Result<()> {
     use hive::PackInstaller;
@@ -106,8 +133,24 @@ fn cmd_hive_install(
     if let Some(reg) = registry {
         println!("  Registry: {}", reg);
     }
+    if let Some(project) = project {
+        println!("  Scope: project '{}'", project);
+    }
 
-    let installed = installer.install(pack, registry)?;
+    // Dependencies come first; the requested pack is last
+    let mut dependencies = installer.install_with_dependencies(pack, registry, project)?;
+    let installed = dependencies
+        .pop()
+        .ok_or_else(|| MemoryError::Config(format!("Pack '{}' was not installed", pack)))?;
+
+    for dep in &dependencies {
+        println!(
+            "  {} Installed dependency '{}' v{}",
+            "✓".green(),
+            dep.name,
+            dep.version
+        );
+    }
 
     println!(
         "{} Pack '{}' installed successfully",
@@ -148,18 +191,30 @@ fn cmd_hive_list(memory_dir: &Path) -> Result<()> {
 
     if packs.is_empty() {
         println!("No packs installed.");
-        println!("\nBrowse available packs with:");
-        println!("  engram hive browse");
-        println!("\nInstall a pack with:");
-        println!("  engram hive install <pack-name>");
+        status!("\nBrowse available packs with:");
+        status!("  engram hive browse");
+        status!("\nInstall a pack with:");
+        status!("  engram hive install <pack-name>");
         return Ok(());
     }
 
     println!("Installed Knowledge Packs:\n");
     for pack in packs {
-        println!("  {} {}", "●".green(), pack.name.bold());
+        match &pack.required_by {
+            Some(parent) => println!(
+                "  {} {} {}",
+                "●".green(),
+                pack.name.bold(),
+                format!("(dependency of {})", parent).dimmed()
+            ),
+            None => println!("  {} {}", "●".green(), pack.name.bold()),
+        }
         println!("    Version: {}", pack.version);
         println!("    Registry: {}", pack.registry);
+        match &pack.project {
+            Some(project) => println!("    Scope: project '{}'", project),
+            None => println!("    Scope: global"),
+        }
         println!(
             "    Installed: {}",
             pack.installed_at.format("%Y-%m-%d %H:%M:%S")
@@ -171,11 +226,15 @@ fn cmd_hive_list(memory_dir: &Path) -> Result<()> {
     Ok(())
 }
 
-fn cmd_hive_update(pack: Option<&str>, memory_dir: &Path) -> Result<()> {
+fn cmd_hive_update(pack: Option<&str>, check: bool, memory_dir: &Path) -> Result<()> {
     use hive::PackInstaller;
 
     let installer = PackInstaller::new(memory_dir);
 
+    if check {
+        return cmd_hive_update_check(&installer, pack);
+    }
+
     if let Some(pack_name) = pack {
         println!("{} Updating pack: {}", "→".blue(), pack_name.bold());
         installer.update(pack_name)?;
@@ -203,7 +262,67 @@ fn cmd_hive_update(pack: Option<&str>, memory_dir: &Path) -> Result<()> {
     Ok(())
 }
 
-fn cmd_hive_browse(category: Option<&str>, keyword: Option<&str>, memory_dir: &Path) -> Result<()> {
+fn cmd_hive_update_check(installer: &hive::PackInstaller, pack: Option<&str>) -> Result<()> {
+    let checks = installer.check_updates(pack)?;
+    if checks.is_empty() {
+        println!("No packs installed.");
+        return Ok(());
+    }
+
+    println!(
+        "{} Checked installed packs against refreshed registries",
+        "→".blue()
+    );
+    let mut pending = 0;
+    for check in &checks {
+        match &check.available {
+            Some(version) if check.has_update() => {
+                pending += 1;
+                println!(
+                    "  {} {} {} → {}",
+                    "↑".yellow(),
+                    check.name.bold(),
+                    check.installed,
+                    version.green()
+                );
+            }
+            Some(_) => println!(
+                "  {} {} {} {}",
+                "✓".green(),
+                check.name,
+                check.installed,
+                "(up to date)".dimmed()
+            ),
+            None => println!(
+                "  {} {} {} {}",
+                "?".dimmed(),
+                check.name,
+                check.installed,
+                format!("(not in registry '{}')", check.registry).dimmed()
+            ),
+        }
+    }
+
+    println!();
+    if pending == 0 {
+        println!("{} All packs are up to date", "✓".green());
+    } else {
+        println!("{} pack(s) would be updated", pending);
+        status!("  Run 'engram hive update' to apply (it fetches registries first, so newer versions may appear)");
+    }
+
+    Ok(())
+}
+
+fn cmd_hive_browse(
+    category: Option<&str>,
+    keyword: Option<&str>,
+    json: bool,
+    limit: Option<usize>,
+    offset: usize,
+    refresh: bool,
+    memory_dir: &Path,
+) -> Result<()> {
     use hive::{PackCategory, PackInstaller, RegistryManager};
     use std::str::FromStr;
 
@@ -211,19 +330,23 @@ fn cmd_hive_browse(category: Option<&str>, keyword: Option<&str>, memory_dir: &P
     let installer = PackInstaller::new(memory_dir);
 
     let registries = registry_manager.list()?;
-    if registries.is_empty() {
+    if registries.is_empty() && !json {
         println!("No registries configured.");
-        println!("\nAdd a registry with:");
-        println!("  engram hive registry add owner/repo");
+        status!("\nAdd a registry with:");
+        status!("  engram hive registry add owner/repo");
         return Ok(());
     }
 
     // Collect all packs from all registries
     let mut all_packs = Vec::new();
+    let mut cached_registries = std::collections::HashSet::new();
     for registry in registries {
-        match registry_manager.discover_packs(&registry.name) {
-            Ok(packs) => {
-                for pack in packs {
+        match registry_manager.list_packs(&registry.name, refresh) {
+            Ok(listing) => {
+                if listing.cached {
+                    cached_registries.insert(registry.name.clone());
+                }
+                for pack in listing.packs {
                     all_packs.push((registry.name.clone(), pack));
                 }
             }
@@ -247,18 +370,58 @@ fn cmd_hive_browse(category: Option<&str>, keyword: Option<&str>, memory_dir: &P
         all_packs.retain(|(_, pack)| pack.matches_keyword(kw));
     }
 
-    if all_packs.is_empty() {
+    if all_packs.is_empty() && !json {
         println!("No packs found matching criteria.");
         return Ok(());
     }
 
+    // Stable order so --offset pages don't shift between runs
+    all_packs.sort_by(|(ra, a), (rb, b)| (&a.name, ra).cmp(&(&b.name, rb)));
+    let total = all_packs.len();
+    let page: Vec<_> = all_packs
+        .into_iter()
+        .skip(offset)
+        .take(limit.unwrap_or(usize::MAX))
+        .collect();
+
     // Get installed packs for status display
     let installed_packs = installer.list()?;
     let installed_names: std::collections::HashSet<_> =
         installed_packs.iter().map(|p| p.name.as_str()).collect();
 
+    if json {
+        let packs: Vec<_> = page
+            .iter()
+            .map(|(registry_name, pack)| {
+                serde_json::json!({
+                    "name": pack.name,
+                    "version": pack.version,
+                    "description": pack.description,
+                    "categories": pack.categories.iter().map(|c| c.to_string()).collect::<Vec<_>>(),
+                    "keywords": pack.keywords,
+                    "registry": registry_name,
+                    "cached": cached_registries.contains(registry_name),
+                    "installed": installed_names.contains(pack.name.as_str()),
+                })
+            })
+            .collect();
+        let output = serde_json::json!({
+            "total": total,
+            "offset": offset,
+            "limit": limit,
+            "packs": packs,
+        });
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    if page.is_empty() {
+        println!("No packs at offset {} ({} total).", offset, total);
+        return Ok(());
+    }
+
     println!("Available Knowledge Packs:\n");
-    for (registry_name, pack) in all_packs {
+    for (registry_name, pack) in &page {
         let status = if installed_names.contains(pack.name.as_str()) {
             format!("[{}]", "INSTALLED".green())
         } else {
@@ -275,7 +438,11 @@ fn cmd_hive_browse(category: Option<&str>, keyword: Option<&str>, memory_dir: &P
                 .collect::<Vec<_>>()
                 .join(", ")
         );
-        println!("    Registry: {}", registry_name);
+        if cached_registries.contains(registry_name) {
+            println!("    Registry: {} {}", registry_name, "(cached)".dimmed());
+        } else {
+            println!("    Registry: {}", registry_name);
+        }
         println!("    Version: {}", pack.version);
         if !pack.keywords.is_empty() {
             println!("    Keywords: {}", pack.keywords.join(", "));
@@ -283,13 +450,23 @@ fn cmd_hive_browse(category: Option<&str>, keyword: Option<&str>, memory_dir: &P
         println!();
     }
 
-    println!("\n💡 Install a pack with:");
-    println!("  engram hive install <pack-name>");
+    if offset + page.len() < total {
+        status!(
+            "Showing {}-{} of {} packs (next page: --offset {})",
+            offset + 1,
+            offset + page.len(),
+            total,
+            offset + page.len()
+        );
+    }
+
+    status!("\n💡 Install a pack with:");
+    status!("  engram hive install <pack-name>");
 
     Ok(())
 }
 
-fn cmd_hive_search(query: &str, memory_dir: &Path) -> Result<()> {
+fn cmd_hive_search(query: &str, refresh: bool, memory_dir: &Path) -> Result<()> {
     use hive::{PackInstaller, RegistryManager};
 
     let registry_manager = RegistryManager::new(memory_dir);
@@ -297,7 +474,7 @@ fn cmd_hive_search(query: &str, memory_dir: &Path) -> Result<()> {
 
     println!("{} Searching for: {}", "→".blue(), query.bold());
 
-    let results = registry_manager.search_packs(query)?;
+    let results = registry_manager.search_packs(query, refresh)?;
 
     if results.is_empty() {
         println!("\nNo packs found matching '{}'", query);
@@ -310,9 +487,17 @@ fn cmd_hive_search(query: &str, memory_dir: &Path) -> Result<()> {
         installed_packs.iter().map(|p| p.name.as_str()).collect();
 
     println!("\nSearch Results:\n");
-    for (registry_name, packs) in results {
-        println!("From registry '{}':", registry_name.bold());
-        for pack in packs {
+    for (registry_name, listing) in results {
+        if listing.cached {
+            println!(
+                "From registry '{}' {}:",
+                registry_name.bold(),
+                format!("(cached {})", listing.fetched_at.format("%Y-%m-%d %H:%M")).dimmed()
+            );
+        } else {
+            println!("From registry '{}':", registry_name.bold());
+        }
+        for pack in listing.packs {
             let status = if installed_names.contains(pack.name.as_str()) {
                 format!("[{}]", "INSTALLED".green())
             } else {
@@ -328,8 +513,8 @@ fn cmd_hive_search(query: &str, memory_dir: &Path) -> Result<()> {
         }
     }
 
-    println!("💡 Install a pack with:");
-    println!("  engram hive install <pack-name>");
+    status!("💡 Install a pack with:");
+    status!("  engram hive install <pack-name>");
 
     Ok(())
 }
@@ -369,6 +554,12 @@ fn cmd_hive_pack(command: PackCommand, memory_dir: &Path) -> Result<()> {
             skip_security,
         ),
         PackCommand::Validate { path } => cmd_hive_pack_validate(&path),
+        PackCommand::Diff { name } => cmd_hive_pack_diff(&name, memory_dir),
+        PackCommand::Preview {
+            name,
+            registry,
+            entries,
+        } => cmd_hive_pack_preview(&name, registry.as_deref(), entries, memory_dir),
     }
 }
 
@@ -525,11 +716,11 @@ fn cmd_hive_pack_create(
             .collect::<Vec<_>>()
             .join(", ")
     );
-    println!("\n💡 Next steps:");
-    println!("  1. Review content: cd {}", pack_dir.display());
-    println!("  2. Initialize git: git init && git add . && git commit -m 'Initial pack'");
-    println!("  3. Push to GitHub: git remote add origin <url> && git push");
-    println!("  4. Share: engram hive registry add <owner>/<repo>");
+    status!("\n💡 Next steps:");
+    status!("  1. Review content: cd {}", pack_dir.display());
+    status!("  2. Initialize git: git init && git add . && git commit -m 'Initial pack'");
+    status!("  3. Push to GitHub: git remote add origin <url> && git push");
+    status!("  4. Share: engram hive registry add <owner>/<repo>");
 
     Ok(())
 }
@@ -616,6 +807,149 @@ fn cmd_hive_pack_stats(name: &str, memory_dir: &Path) -> Result<()> {
     Ok(())
 }
 
+fn cmd_hive_pack_diff(name: &str, memory_dir: &Path) -> Result<()> {
+    use crate::extractor::knowledge::parse_session_blocks;
+    use std::collections::{BTreeSet, HashSet};
+
+    let installer = hive::PackInstaller::new(memory_dir);
+    println!("{} Fetching upstream for {}...", "→".blue(), name.bold());
+    let (installed, upstream) = installer.fetch_upstream(name)?;
+    let upstream_dir = upstream
+        .source_path
+        .clone()
+        .ok_or_else(|| MemoryError::Config(format!("Pack '{}' has no source path", name)))?;
+
+    println!(
+        "  Installed: v{}  Upstream: v{}\n",
+        installed.version, upstream.version
+    );
+
+    let local_knowledge = installed.path.join("knowledge");
+    let upstream_knowledge = upstream_dir.join("knowledge");
+    let list_md = |dir: &Path| -> Vec<String> {
+        std::fs::read_dir(dir)
+            .map(|entries| {
+                entries
+                    .flatten()
+                    .map(|e| e.file_name().to_string_lossy().to_string())
+                    .filter(|n| n.ends_with(".md"))
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    let files: BTreeSet<String> = list_md(&local_knowledge)
+        .into_iter()
+        .chain(list_md(&upstream_knowledge))
+        .collect();
+
+    let mut changed = 0;
+    for file in &files {
+        let old = std::fs::read_to_string(local_knowledge.join(file)).unwrap_or_default();
+        let new = std::fs::read_to_string(upstream_knowledge.join(file)).unwrap_or_default();
+        let diff_result = crate::diff::compute_diff(&old, &new, file.trim_end_matches(".md"));
+        if diff_result.is_empty() {
+            continue;
+        }
+        changed += 1;
+
+        let session_ids = |content: &str| -> HashSet<String> {
+            parse_session_blocks(content)
+                .1
+                .into_iter()
+                .map(|b| b.session_id)
+                .collect()
+        };
+        let (old_ids, new_ids) = (session_ids(&old), session_ids(&new));
+        let mut added: Vec<_> = new_ids.difference(&old_ids).collect();
+        let mut removed: Vec<_> = old_ids.difference(&new_ids).collect();
+        added.sort();
+        removed.sort();
+
+        println!("{}", diff_result);
+        for id in added {
+            println!("  {} session {}", "+".green(), id.green());
+        }
+        for id in removed {
+            println!("  {} session {}", "-".red(), id.red());
+        }
+        println!();
+    }
+
+    if changed == 0 {
+        println!("{} '{}' is up to date with upstream", "✓".green(), name);
+    } else {
+        println!("{} {} file(s) differ from upstream", "ℹ".cyan(), changed);
+        status!("\n💡 Apply with: engram hive update {}", name);
+    }
+
+    Ok(())
+}
+
+fn cmd_hive_pack_preview(
+    name: &str,
+    registry: Option<&str>,
+    entries: usize,
+    memory_dir: &Path,
+) -> Result<()> {
+    let installer = hive::PackInstaller::new(memory_dir);
+    let (pack, found_registry) = installer.find_available(name, registry)?;
+    let pack_dir = pack
+        .source_path
+        .clone()
+        .ok_or_else(|| MemoryError::Config(format!("Pack '{}' has no source path", name)))?;
+
+    println!("{} Pack Preview: {}", "→".blue(), pack.name.bold());
+    println!();
+    println!("  {} {}", "Version:".bold(), pack.version);
+    println!("  {} {}", "Registry:".bold(), found_registry.name);
+    println!("  {} {}", "Description:".bold(), pack.description);
+    if !pack.keywords.is_empty() {
+        println!("  {} {}", "Keywords:".bold(), pack.keywords.join(", "));
+    }
+    if !pack.dependencies.is_empty() {
+        let deps: Vec<String> = pack
+            .dependencies
+            .iter()
+            .map(|d| format!("{} {}", d.name, d.version))
+            .collect();
+        println!("  {} {}", "Depends on:".bold(), deps.join(", "));
+    }
+    println!();
+
+    let previews = hive::pack::preview_knowledge(&pack_dir, entries);
+    if previews.is_empty() {
+        println!("  No knowledge entries.");
+        return Ok(());
+    }
+
+    let total: usize = previews.iter().map(|p| p.entries).sum();
+    println!("  {} {} entries", "Knowledge:".bold(), total);
+    for preview in &previews {
+        println!();
+        println!(
+            "    {} ({} entries)",
+            preview.category.cyan(),
+            preview.entries
+        );
+        for (session_id, title) in &preview.samples {
+            println!("      • {} {}", title, format!("[{}]", session_id).dimmed());
+        }
+        if preview.entries > preview.samples.len() {
+            println!(
+                "      {}",
+                format!("… {} more", preview.entries - preview.samples.len()).dimmed()
+            );
+        }
+    }
+
+    let installed = installer.list()?.iter().any(|p| p.name == pack.name);
+    if !installed {
+        status!("\n💡 Install with: engram hive install {}", pack.name);
+    }
+
+    Ok(())
+}
+
 fn cmd_hive_pack_publish(
     pack_path: &str,
     repo_url: Option<&str>,
@@ -649,7 +983,24 @@ fn cmd_hive_pack_publish(
         pack.version
     );
 
-    // Step 3: Security scan (unless skipped)
+    // Step 3: Record the knowledge checksum so installs can detect tampering
+    let mut pack = pack;
+    let checksum = hive::integrity::compute_checksum(pack_dir)?;
+    if pack.checksum.as_deref() != Some(checksum.as_str()) {
+        pack.checksum = Some(checksum.clone());
+        // A signature over the old checksum no longer applies
+        pack.signature = None;
+        pack.save(pack_dir)?;
+    }
+    println!("  {} Checksum: {}", "✓".green(), checksum.dimmed());
+    if pack.signature.is_none() {
+        status!(
+            "  {} Unsigned. To sign, add an Ed25519 signature of the checksum (hex) as \"signature\" in .pack/manifest.json",
+            "ℹ".cyan()
+        );
+    }
+
+    // Step 4: Security scan (unless skipped)
     if !skip_security {
         println!("\n{} Scanning for secrets...", "→".blue());
         let detector = hive::SecretDetector::new()?;
@@ -693,7 +1044,7 @@ fn cmd_hive_pack_publish(
         println!("\n{} Skipping security scan", "⚠".yellow().bold());
     }
 
-    // Step 4: Initialize or verify git repo
+    // Step 5: Initialize or verify git repo
     println!("\n{} Checking git repository...", "→".blue());
 
     let is_git_repo = pack_dir.join(".git").exists();
@@ -720,7 +1071,7 @@ fn cmd_hive_pack_publish(
         println!("  {} Git repository exists", "✓".green());
     }
 
-    // Step 5: Commit changes
+    // Step 6: Commit changes
     println!("\n{} Committing changes...", "→".blue());
 
     std::process::Command::new("git")
@@ -742,7 +1093,7 @@ fn cmd_hive_pack_publish(
         println!("  {} No changes to commit", "ℹ".cyan());
     }
 
-    // Step 6: Set up remote if provided
+    // Step 7: Set up remote if provided
     if let Some(url) = repo_url {
         println!("\n{} Setting up remote repository...", "→".blue());
 
@@ -771,7 +1122,7 @@ fn cmd_hive_pack_publish(
         }
     }
 
-    // Step 7: Push if requested
+    // Step 8: Push if requested
     if do_push {
         println!("\n{} Pushing to remote...", "→".blue());
 
@@ -789,7 +1140,7 @@ fn cmd_hive_pack_publish(
         println!("  {} Pushed successfully", "✓".green());
     }
 
-    // Step 8: Tag version
+    // Step 9: Tag version
     println!("\n{} Creating version tag...", "→".blue());
 
     let tag = format!("v{}", pack.version);
@@ -810,15 +1161,15 @@ fn cmd_hive_pack_publish(
         }
     }
 
-    println!("\n{} Pack published successfully!", "✓".green().bold());
-    println!("\n💡 Share your pack:");
+    status!("\n{} Pack published successfully!", "✓".green().bold());
+    status!("\n💡 Share your pack:");
     if let Some(url) = repo_url {
-        println!("  Users can install with:");
-        println!("  {}", format!("engram hive registry add {}", url).cyan());
+        status!("  Users can install with:");
+        status!("  {}", format!("engram hive registry add {}", url).cyan());
     } else {
-        println!("  1. Push to GitHub: git push -u origin main");
-        println!("  2. Share the repository URL");
-        println!("  3. Users can add: engram hive registry add <owner>/<repo>");
+        status!("  1. Push to GitHub: git push -u origin main");
+        status!("  2. Share the repository URL");
+        status!("  3. Users can add: engram hive registry add <owner>/<repo>");
     }
 
     Ok(())
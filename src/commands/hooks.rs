@@ -4,18 +4,36 @@ use colored::Colorize;
 
 use crate::error::{self, Result};
 use crate::extractor;
+use crate::output::status;
 use crate::parser;
 
 // ── Helpers (also used by cmd_inject in core.rs) ────────────────────────
 
-pub(crate) fn find_claude_project_dir(
+/// Find the Claude Code project directories for `project_name`.
+///
+/// When the project is `cwd` (the usual case for inject), its directory name
+/// is computed directly with [`parser::discovery::encode_project_name`] and
+/// is the only match. Otherwise, or if that directory doesn't exist, every
+/// entry is scanned and matched by its decoded name; repos sharing a basename
+/// in different parent paths all match.
+pub(crate) fn find_claude_project_dirs(
     claude_projects_dir: &Path,
     project_name: &str,
-) -> Result<Option<PathBuf>> {
+    cwd: Option<&Path>,
+) -> Result<Vec<PathBuf>> {
     if !claude_projects_dir.exists() {
-        return Ok(None);
+        return Ok(Vec::new());
     }
 
+    if let Some(cwd) = cwd.filter(|p| p.file_name().is_some_and(|n| n == project_name)) {
+        let encoded = parser::discovery::encode_project_name(&cwd.to_string_lossy());
+        let candidate = claude_projects_dir.join(encoded);
+        if candidate.is_dir() {
+            return Ok(vec![candidate]);
+        }
+    }
+
+    let mut matches = Vec::new();
     for entry in std::fs::read_dir(claude_projects_dir)? {
         let entry = entry?;
         if !entry.file_type()?.is_dir() {
@@ -24,11 +42,12 @@ pub(crate) fn find_claude_project_dir(
         let dir_name = entry.file_name().to_string_lossy().to_string();
         let decoded = parser::discovery::decode_project_name(&dir_name);
         if decoded == project_name {
-            return Ok(Some(entry.path()));
+            matches.push(entry.path());
         }
     }
+    matches.sort();
 
-    Ok(None)
+    Ok(matches)
 }
 
 /// Build a lightweight context string from raw knowledge files (no LLM).
@@ -75,27 +94,70 @@ pub(crate) fn build_raw_context(project: &str, project_knowledge_dir: &Path) ->
 const HOOK_SCRIPT: &str = include_str!("../../hooks/engram-hook.sh");
 const INJECT_SCRIPT: &str = include_str!("../../hooks/inject-context.sh");
 const SESSION_END_SCRIPT: &str = include_str!("../../hooks/session-end-hook.sh");
+/// Line in each script naming the binary to run, rewritten on install.
+const ENGRAM_BIN_LINE: &str = "ENGRAM=\"engram\"";
+
+/// A Claude Code hook event engram can register for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum HookEvent {
+    /// Inject project context when a session starts
+    SessionStart,
+    /// Ingest incrementally after each tool use
+    PostToolUse,
+    /// Extract knowledge when a session ends
+    Stop,
+}
+
+impl HookEvent {
+    pub const ALL: &'static [HookEvent] = &[
+        HookEvent::SessionStart,
+        HookEvent::PostToolUse,
+        HookEvent::Stop,
+    ];
+
+    /// Event name used as the key under `hooks` in settings.json
+    pub fn settings_key(self) -> &'static str {
+        match self {
+            HookEvent::SessionStart => "SessionStart",
+            HookEvent::PostToolUse => "PostToolUse",
+            HookEvent::Stop => "Stop",
+        }
+    }
+
+    /// Script file installed into ~/.claude/hooks for this event
+    pub fn script_name(self) -> &'static str {
+        match self {
+            HookEvent::SessionStart => "inject-context.sh",
+            HookEvent::PostToolUse => "engram-hook.sh",
+            HookEvent::Stop => "session-end-hook.sh",
+        }
+    }
+
+    /// Script contents with the binary set to `exe`.
+    fn render_script(self, exe: &Path) -> String {
+        let quoted = exe.to_string_lossy().replace('\'', r"'\''");
+        self.script()
+            .replacen(ENGRAM_BIN_LINE, &format!("ENGRAM='{}'", quoted), 1)
+    }
+
+    fn script(self) -> &'static str {
+        match self {
+            HookEvent::SessionStart => INJECT_SCRIPT,
+            HookEvent::PostToolUse => HOOK_SCRIPT,
+            HookEvent::Stop => SESSION_END_SCRIPT,
+        }
+    }
+}
 
-pub fn cmd_hooks_install() -> Result<()> {
+/// Install hook scripts for `events` and register them in settings.json.
+/// Events left out are unregistered and their scripts removed.
+pub fn cmd_hooks_install(events: &[HookEvent]) -> Result<()> {
     let home = dirs::home_dir()
         .ok_or_else(|| error::MemoryError::Config("Could not determine home directory".into()))?;
 
     let hooks_dir = home.join(".claude").join("hooks");
     std::fs::create_dir_all(&hooks_dir)?;
 
-    // Write hook scripts
-    let hook_path = hooks_dir.join("engram-hook.sh");
-    std::fs::write(&hook_path, HOOK_SCRIPT)?;
-    set_executable(&hook_path)?;
-
-    let inject_path = hooks_dir.join("inject-context.sh");
-    std::fs::write(&inject_path, INJECT_SCRIPT)?;
-    set_executable(&inject_path)?;
-
-    let session_end_path = hooks_dir.join("session-end-hook.sh");
-    std::fs::write(&session_end_path, SESSION_END_SCRIPT)?;
-    set_executable(&session_end_path)?;
-
     // Update settings.json
     let settings_path = home.join(".claude").join("settings.json");
     let mut settings: serde_json::Value = if settings_path.exists() {
@@ -111,21 +173,29 @@ pub fn cmd_hooks_install() -> Result<()> {
         .entry("hooks")
         .or_insert_with(|| serde_json::json!({}));
 
-    // Add SessionStart hook for inject
-    add_hook_entry(hooks, "SessionStart", &inject_path.to_string_lossy())?;
-
-    // Add PostToolUse hook for auto-ingest
-    add_hook_entry(hooks, "PostToolUse", &hook_path.to_string_lossy())?;
-
-    // Add SessionEnd hook for full knowledge extraction
-    add_hook_entry(hooks, "Stop", &session_end_path.to_string_lossy())?;
+    let exe = crate::config::engram_exe();
+    status!("{} Hooks installed:", "Done!".green().bold());
+    for &event in HookEvent::ALL {
+        let script_path = hooks_dir.join(event.script_name());
+        if events.contains(&event) {
+            std::fs::write(&script_path, event.render_script(&exe))?;
+            set_executable(&script_path)?;
+            add_hook_entry(hooks, event.settings_key(), &script_path.to_string_lossy())?;
+            println!(
+                "  {} -> {}",
+                event.settings_key().cyan(),
+                script_path.display()
+            );
+        } else {
+            remove_hook_entries(hooks, event.settings_key());
+            if script_path.exists() {
+                std::fs::remove_file(&script_path)?;
+            }
+        }
+    }
 
     std::fs::write(&settings_path, serde_json::to_string_pretty(&settings)?)?;
 
-    println!("{} Hooks installed:", "Done!".green().bold());
-    println!("  {} -> {}", "SessionStart".cyan(), inject_path.display());
-    println!("  {} -> {}", "PostToolUse".cyan(), hook_path.display());
-    println!("  {} -> {}", "Stop".cyan(), session_end_path.display());
     println!(
         "\n  Settings updated: {}",
         settings_path.display().to_string().dimmed()
@@ -153,12 +223,12 @@ pub fn cmd_hooks_install() -> Result<()> {
         .unwrap_or(false);
 
     if !already_running {
-        match std::process::Command::new("engram")
+        match std::process::Command::new(crate::config::engram_exe())
             .args(["daemon", "start"])
             .spawn()
         {
             Ok(_) => println!("  {} Daemon started", "✓".green()),
-            Err(e) => eprintln!("  Warning: could not start daemon: {}", e),
+            Err(e) => tracing::warn!(error = %e, "could not start daemon"),
         }
     } else {
         println!("  {} Daemon already running", "✓".green());
@@ -198,16 +268,8 @@ pub fn cmd_hooks_uninstall() -> Result<()> {
         let content = std::fs::read_to_string(&settings_path)?;
         let mut settings: serde_json::Value = serde_json::from_str(&content)?;
 
-        if let Some(hooks) = settings.get_mut("hooks").and_then(|h| h.as_object_mut()) {
-            for (_key, entries) in hooks.iter_mut() {
-                if let Some(arr) = entries.as_array_mut() {
-                    arr.retain(|entry| {
-                        // Check nested hooks array for engram commands
-                        let entry_str = serde_json::to_string(entry).unwrap_or_default();
-                        !entry_str.contains("engram")
-                    });
-                }
-            }
+        if let Some(hooks) = settings.get_mut("hooks") {
+            remove_all_hook_entries(hooks);
         }
 
         std::fs::write(&settings_path, serde_json::to_string_pretty(&settings)?)?;
@@ -216,7 +278,7 @@ pub fn cmd_hooks_uninstall() -> Result<()> {
     if removed.is_empty() {
         println!("{} No hooks were installed.", "Note:".yellow());
     } else {
-        println!("{} Hooks uninstalled:", "Done!".green().bold());
+        status!("{} Hooks uninstalled:", "Done!".green().bold());
         for name in &removed {
             println!("  Removed {}", name);
         }
@@ -226,53 +288,52 @@ pub fn cmd_hooks_uninstall() -> Result<()> {
     Ok(())
 }
 
-pub fn cmd_hooks_status() -> Result<()> {
+/// Show which hook events are active; `verbose` also lists every command
+/// registered in settings.json and flags stale or duplicate entries.
+pub fn cmd_hooks_status(verbose: bool) -> Result<()> {
     let home = dirs::home_dir()
         .ok_or_else(|| error::MemoryError::Config("Could not determine home directory".into()))?;
 
     let hooks_dir = home.join(".claude").join("hooks");
-    let hook_path = hooks_dir.join("engram-hook.sh");
-    let inject_path = hooks_dir.join("inject-context.sh");
-    let session_end_path = hooks_dir.join("session-end-hook.sh");
+    let settings_path = home.join(".claude").join("settings.json");
+    let settings: Option<serde_json::Value> = if settings_path.exists() {
+        let content = std::fs::read_to_string(&settings_path)?;
+        Some(serde_json::from_str(&content)?)
+    } else {
+        None
+    };
+    let hooks = settings.as_ref().and_then(|s| s.get("hooks"));
 
     println!("{}", "Engram Hooks Status".green().bold());
     println!("{}", "=".repeat(50));
 
-    let check = |path: &Path, name: &str, event: &str| {
-        if path.exists() {
-            println!("  {} {} ({})", "installed".green(), name, event.cyan());
-        } else {
-            println!(
-                "  {} {} ({})",
-                "not installed".yellow(),
-                name,
-                event.dimmed()
-            );
-        }
-    };
+    for &event in HookEvent::ALL {
+        let script = event.script_name();
+        let installed = hooks_dir.join(script).exists();
+        let registered = hooks.is_some_and(|h| has_hook_entry(h, event.settings_key()));
+        let state = match (installed, registered) {
+            (true, true) => "active".green(),
+            (true, false) => "not registered".yellow(),
+            (false, true) => "script missing".red(),
+            (false, false) => "not installed".dimmed(),
+        };
+        println!(
+            "  {:<15} {} ({})",
+            state,
+            event.settings_key().cyan(),
+            script
+        );
+    }
 
-    check(&inject_path, "inject-context.sh", "SessionStart");
-    check(&hook_path, "engram-hook.sh", "PostToolUse");
-    check(&session_end_path, "session-end-hook.sh", "Stop");
+    if verbose {
+        print_hook_entries(hooks, &hooks_dir);
+    }
 
-    // Check settings.json
-    let settings_path = home.join(".claude").join("settings.json");
-    if settings_path.exists() {
-        let content = std::fs::read_to_string(&settings_path)?;
-        let has_hooks = content.contains("engram");
-        if has_hooks {
-            println!(
-                "\n  Settings: {} entries found in {}",
-                "engram".cyan(),
-                settings_path.display().to_string().dimmed()
-            );
-        } else {
-            println!(
-                "\n  Settings: {} in {}",
-                "no engram entries".yellow(),
-                settings_path.display().to_string().dimmed()
-            );
-        }
+    if settings.is_some() {
+        println!(
+            "\n  Settings: {}",
+            settings_path.display().to_string().dimmed()
+        );
     } else {
         println!(
             "\n  Settings: {}",
@@ -283,7 +344,157 @@ pub fn cmd_hooks_status() -> Result<()> {
     Ok(())
 }
 
-/// Add a hook entry to a hook event array in settings.json, idempotently.
+/// Print each event's registered commands from settings.json `hooks`.
+fn print_hook_entries(hooks: Option<&serde_json::Value>, hooks_dir: &Path) {
+    println!("\n{}", "Registered commands".bold());
+    let Some(events) = hooks.and_then(|h| h.as_object()).filter(|e| !e.is_empty()) else {
+        println!("  {}", "(none)".dimmed());
+        return;
+    };
+
+    for (event, entries) in events {
+        println!("  {}", event.cyan());
+        let commands: Vec<(&str, bool)> = entries
+            .as_array()
+            .into_iter()
+            .flatten()
+            .flat_map(|entry| {
+                let engram = is_engram_entry(entry);
+                entry_commands(entry).map(move |command| (command, engram))
+            })
+            .collect();
+        if commands.is_empty() {
+            println!("    {}", "(none)".dimmed());
+        }
+        for (i, &(command, engram)) in commands.iter().enumerate() {
+            let seen: Vec<&str> = commands[..i].iter().map(|&(c, _)| c).collect();
+            let tag = if engram { "[engram] " } else { "" };
+            match command_problem(event, command, &seen, hooks_dir) {
+                Some(problem) => {
+                    println!("    {}{} {}", tag, command, format!("({})", problem).red())
+                }
+                None => println!("    {}{}", tag, command),
+            }
+        }
+    }
+}
+
+/// Why a registered command under `event` looks wrong, if it does: repeated
+/// within the event, or an engram script that isn't the one installed for it.
+fn command_problem(
+    event: &str,
+    command: &str,
+    seen: &[&str],
+    hooks_dir: &Path,
+) -> Option<&'static str> {
+    if seen.contains(&command) {
+        return Some("duplicate");
+    }
+    if !is_engram_script(command) {
+        return None;
+    }
+    let expected = HookEvent::ALL
+        .iter()
+        .find(|e| e.settings_key() == event)
+        .map(|e| hooks_dir.join(e.script_name()));
+    match expected {
+        Some(path) if Path::new(command) == path => {
+            (!path.exists()).then_some("stale: script missing")
+        }
+        _ => Some("stale: not the installed script"),
+    }
+}
+
+/// Events with an engram entry in `~/.claude/settings.json` under `home`.
+pub fn registered_hook_events(home: &Path) -> Vec<HookEvent> {
+    let settings: Option<serde_json::Value> =
+        std::fs::read_to_string(home.join(".claude").join("settings.json"))
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok());
+    let Some(hooks) = settings.as_ref().and_then(|s| s.get("hooks")) else {
+        return Vec::new();
+    };
+    HookEvent::ALL
+        .iter()
+        .copied()
+        .filter(|event| has_hook_entry(hooks, event.settings_key()))
+        .collect()
+}
+
+/// Events to reinstall when repairing hooks: the registered ones, or all of
+/// them if none are registered yet.
+pub fn events_to_repair(home: &Path) -> Vec<HookEvent> {
+    let registered = registered_hook_events(home);
+    if registered.is_empty() {
+        HookEvent::ALL.to_vec()
+    } else {
+        registered
+    }
+}
+
+/// Whether settings.json `hooks` registers an engram command for `event`.
+fn has_hook_entry(hooks: &serde_json::Value, event: &str) -> bool {
+    hooks
+        .get(event)
+        .and_then(|entries| entries.as_array())
+        .is_some_and(|arr| arr.iter().any(is_engram_entry))
+}
+
+/// Drop engram's entries for `event` from settings.json `hooks`, leaving others.
+fn remove_hook_entries(hooks: &mut serde_json::Value, event: &str) {
+    if let Some(arr) = hooks.get_mut(event).and_then(|e| e.as_array_mut()) {
+        arr.retain(|entry| !is_engram_entry(entry));
+    }
+}
+
+/// Drop engram's entries for every event, leaving others.
+fn remove_all_hook_entries(hooks: &mut serde_json::Value) {
+    if let Some(events) = hooks.as_object_mut() {
+        for entries in events.values_mut() {
+            if let Some(arr) = entries.as_array_mut() {
+                arr.retain(|entry| !is_engram_entry(entry));
+            }
+        }
+    }
+}
+
+/// Key added to every settings.json hook entry engram writes, so uninstall and
+/// re-install touch exactly those entries and never a user's own hooks.
+const ENTRY_MARKER_KEY: &str = "_managed_by";
+const ENTRY_MARKER_VALUE: &str = "engram";
+
+/// Commands run by a settings.json hook entry.
+fn entry_commands(entry: &serde_json::Value) -> impl Iterator<Item = &str> {
+    entry
+        .get("hooks")
+        .and_then(|h| h.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|hook| hook.get("command").and_then(|c| c.as_str()))
+}
+
+/// Whether `command` is one of engram's scripts in a `.claude/hooks` directory.
+fn is_engram_script(command: &str) -> bool {
+    let path = Path::new(command);
+    path.parent()
+        .is_some_and(|dir| dir.ends_with(".claude/hooks"))
+        && path.file_name().is_some_and(|name| {
+            HookEvent::ALL
+                .iter()
+                .any(|event| name == event.script_name())
+        })
+}
+
+/// Whether a settings.json hook entry was added by engram: it carries the
+/// marker, or (for installs that predate the marker) runs one of its scripts.
+fn is_engram_entry(entry: &serde_json::Value) -> bool {
+    entry.get(ENTRY_MARKER_KEY).and_then(|m| m.as_str()) == Some(ENTRY_MARKER_VALUE)
+        || entry_commands(entry).any(is_engram_script)
+}
+
+/// Add a hook entry running `command` to a hook event array in settings.json.
+/// Idempotent: an entry with the exact command is kept, and engram entries
+/// pointing anywhere else (e.g. an old install location) are replaced.
 fn add_hook_entry(hooks: &mut serde_json::Value, event: &str, command: &str) -> Result<()> {
     let event_hooks = hooks
         .as_object_mut()
@@ -295,14 +506,12 @@ fn add_hook_entry(hooks: &mut serde_json::Value, event: &str, command: &str) ->
         .as_array_mut()
         .ok_or_else(|| error::MemoryError::Config(format!("hooks.{} is not an array", event)))?;
 
-    // Check if already installed (look for "engram" in any entry's command)
-    let already_installed = arr.iter().any(|entry| {
-        let entry_str = serde_json::to_string(entry).unwrap_or_default();
-        entry_str.contains("engram")
-    });
+    let runs_command = |entry: &serde_json::Value| entry_commands(entry).any(|c| c == command);
+    arr.retain(|entry| !is_engram_entry(entry) || runs_command(entry));
 
-    if !already_installed {
+    if !arr.iter().any(runs_command) {
         arr.push(serde_json::json!({
+            ENTRY_MARKER_KEY: ENTRY_MARKER_VALUE,
             "matcher": "",
             "hooks": [{
                 "type": "command",
@@ -327,3 +536,165 @@ fn set_executable(path: &Path) -> Result<()> {
 fn set_executable(_path: &Path) -> Result<()> {
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_claude_project_dirs() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let projects = temp.path();
+        for dir in [
+            "-Users-me-Projects-site-io",
+            "-Users-me-Projects-api",
+            "-Users-me-Personal-api",
+        ] {
+            std::fs::create_dir(projects.join(dir)).unwrap();
+        }
+
+        // Direct lookup from the current directory, even when decoding is lossy
+        let cwd = Path::new("/Users/me/Projects/site.io");
+        assert_eq!(
+            find_claude_project_dirs(projects, "site.io", Some(cwd)).unwrap(),
+            vec![projects.join("-Users-me-Projects-site-io")]
+        );
+        assert!(find_claude_project_dirs(projects, "site.io", None)
+            .unwrap()
+            .is_empty());
+
+        // Fallback scan returns every directory sharing the name
+        assert_eq!(
+            find_claude_project_dirs(projects, "api", Some(cwd)).unwrap(),
+            vec![
+                projects.join("-Users-me-Personal-api"),
+                projects.join("-Users-me-Projects-api"),
+            ]
+        );
+        // ...unless the current directory pins one down
+        assert_eq!(
+            find_claude_project_dirs(projects, "api", Some(Path::new("/Users/me/Projects/api")))
+                .unwrap(),
+            vec![projects.join("-Users-me-Projects-api")]
+        );
+    }
+
+    #[test]
+    fn test_hook_entries_per_event() {
+        let mut hooks = serde_json::json!({
+            "Stop": [{"matcher": "", "hooks": [{"type": "command", "command": "other.sh"}]}]
+        });
+
+        add_hook_entry(
+            &mut hooks,
+            "Stop",
+            "/home/u/.claude/hooks/session-end-hook.sh",
+        )
+        .unwrap();
+        assert!(has_hook_entry(&hooks, "Stop"));
+        assert!(!has_hook_entry(&hooks, "PostToolUse"));
+
+        // Idempotent, and removal keeps non-engram entries
+        add_hook_entry(
+            &mut hooks,
+            "Stop",
+            "/home/u/.claude/hooks/session-end-hook.sh",
+        )
+        .unwrap();
+        assert_eq!(hooks["Stop"].as_array().unwrap().len(), 2);
+        remove_hook_entries(&mut hooks, "Stop");
+        assert!(!has_hook_entry(&hooks, "Stop"));
+        assert_eq!(hooks["Stop"].as_array().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_install_uninstall_leaves_unrelated_hooks() {
+        let original = serde_json::json!({
+            "Stop": [
+                {"matcher": "", "hooks": [{"type": "command", "command": "/opt/engram-notes/sync.sh"}]},
+                {"matcher": "", "hooks": [{"type": "command", "command": "/home/u/bin/session-end-hook.sh"}]}
+            ],
+            "PreToolUse": [
+                {"matcher": "Bash", "hooks": [{"type": "command", "command": "echo engram"}]}
+            ]
+        });
+        let mut hooks = original.clone();
+        assert!(!has_hook_entry(&hooks, "Stop"));
+
+        for _ in 0..2 {
+            for &event in HookEvent::ALL {
+                let script = format!("/home/u/.claude/hooks/{}", event.script_name());
+                add_hook_entry(&mut hooks, event.settings_key(), &script).unwrap();
+            }
+        }
+        assert_eq!(hooks["Stop"].as_array().unwrap().len(), 3);
+        assert_eq!(hooks["SessionStart"].as_array().unwrap().len(), 1);
+        assert_eq!(hooks["PreToolUse"].as_array().unwrap().len(), 1);
+
+        // Moving the install location replaces the old entry
+        add_hook_entry(&mut hooks, "Stop", "/new/.claude/hooks/session-end-hook.sh").unwrap();
+        assert_eq!(hooks["Stop"].as_array().unwrap().len(), 3);
+
+        remove_all_hook_entries(&mut hooks);
+        assert_eq!(hooks["Stop"], original["Stop"]);
+        assert_eq!(hooks["PreToolUse"], original["PreToolUse"]);
+        assert_eq!(hooks["SessionStart"], serde_json::json!([]));
+
+        remove_all_hook_entries(&mut hooks);
+        assert_eq!(hooks["Stop"], original["Stop"]);
+    }
+
+    #[test]
+    fn test_render_script_uses_binary_path() {
+        for &event in HookEvent::ALL {
+            assert!(event.script().contains(ENGRAM_BIN_LINE));
+            let script = event.render_script(Path::new("/opt/my tools/mem"));
+            assert!(script.contains("ENGRAM='/opt/my tools/mem'"));
+            assert!(!script.contains(ENGRAM_BIN_LINE));
+        }
+        let script = HookEvent::Stop.render_script(Path::new("/it's/engram"));
+        assert!(script.contains(r"ENGRAM='/it'\''s/engram'"));
+    }
+
+    #[test]
+    fn test_command_problem() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let hooks_dir = temp.path().join(".claude").join("hooks");
+        std::fs::create_dir_all(&hooks_dir).unwrap();
+        let installed = hooks_dir.join("session-end-hook.sh");
+        let installed = installed.to_str().unwrap();
+
+        assert_eq!(
+            command_problem("Stop", installed, &[], &hooks_dir),
+            Some("stale: script missing")
+        );
+        std::fs::write(installed, "").unwrap();
+        assert_eq!(command_problem("Stop", installed, &[], &hooks_dir), None);
+        assert_eq!(
+            command_problem("Stop", installed, &[installed], &hooks_dir),
+            Some("duplicate")
+        );
+        // Right script, wrong event or location
+        assert!(command_problem("PostToolUse", installed, &[], &hooks_dir).is_some());
+        assert!(command_problem(
+            "Stop",
+            "/old/.claude/hooks/session-end-hook.sh",
+            &[],
+            &hooks_dir
+        )
+        .is_some());
+        assert_eq!(command_problem("Stop", "other.sh", &[], &hooks_dir), None);
+    }
+
+    #[test]
+    fn test_legacy_entries_without_marker_are_recognized() {
+        let legacy = serde_json::json!({
+            "matcher": "",
+            "hooks": [{"type": "command", "command": "/home/u/.claude/hooks/engram-hook.sh"}]
+        });
+        assert!(is_engram_entry(&legacy));
+        assert!(!is_engram_entry(&serde_json::json!({
+            "hooks": [{"type": "command", "command": "/home/u/engram-hook.sh"}]
+        })));
+    }
+}
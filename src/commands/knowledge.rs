@@ -3,8 +3,10 @@ use crate::error;
 use crate::error::Result;
 use crate::extractor;
 use crate::llm;
+use crate::output::status;
+use crate::trash::TrashBatch;
 use colored::Colorize;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 // ── Regen command ───────────────────────────────────────────────────────
 
@@ -12,12 +14,12 @@ pub fn cmd_regen(
     config: &Config,
     project: &str,
     persist_cleanup: bool,
+    dry_run: bool,
     verbose: bool,
 ) -> Result<()> {
     use extractor::knowledge::{parse_session_blocks, partition_by_expiry, reconstruct_blocks};
 
     let knowledge_dir = config.memory_dir.join("knowledge").join(project);
-    let summary_dir = config.memory_dir.join("summaries").join(project);
 
     if !knowledge_dir.exists() {
         eprintln!(
@@ -103,11 +105,6 @@ pub fn cmd_regen(
 
         (d, s, p)
     };
-    let bugs = read_or_empty(&knowledge_dir.join("bugs.md"));
-    let insights = read_or_empty(&knowledge_dir.join("insights.md"));
-    let questions = read_or_empty(&knowledge_dir.join("questions.md"));
-    let summaries = collect_summary_dir(&summary_dir)?;
-
     if decisions.is_empty() && solutions.is_empty() && patterns.is_empty() {
         eprintln!(
             "{} Knowledge files are empty for '{}'.",
@@ -117,39 +114,101 @@ pub fn cmd_regen(
         return Ok(());
     }
 
+    if dry_run {
+        let prompt = context_prompt(config, project, &decisions, &solutions, &patterns)?;
+        println!("{}", "── System prompt ──".cyan().bold());
+        println!("{}\n", config.prompts.extractor);
+        println!("{}", "── Context prompt ──".cyan().bold());
+        println!("{}", prompt);
+        return Ok(());
+    }
+
     println!(
         "{} Regenerating context for '{}'...",
         "Regen".green().bold(),
         project
     );
 
-    let client = llm::client::LlmClient::new(&config.llm);
     let rt = tokio::runtime::Builder::new_current_thread()
         .enable_all()
         .build()
         .map_err(|e| error::MemoryError::Config(format!("tokio runtime: {}", e)))?;
 
-    let context = rt.block_on(async {
-        client
-            .chat(
-                llm::prompts::SYSTEM_KNOWLEDGE_EXTRACTOR,
-                &llm::prompts::context_prompt(
-                    project, &decisions, &solutions, &patterns, &bugs, &insights, &questions,
-                    &summaries,
-                ),
-            )
-            .await
-    })?;
-
-    let context_with_header = format!("# {} - Project Context\n\n{}\n", project, context);
-    std::fs::write(knowledge_dir.join("context.md"), &context_with_header)?;
+    let usage_before = llm::client::total_usage();
+    rt.block_on(write_context(
+        config, project, &decisions, &solutions, &patterns,
+    ))?;
 
-    println!(
+    status!(
         "{} Context regenerated for '{}'.",
         "Done!".green().bold(),
         project
     );
 
+    let usage = llm::client::total_usage().since(&usage_before);
+    super::core::report_llm_usage(config, project, "regen", &usage, "1 project");
+
+    Ok(())
+}
+
+/// Regenerate context.md from active (non-expired) knowledge without touching the files.
+///
+/// Shared by `regen` and `doctor --fix`. Returns `false` when there is nothing to synthesize.
+pub async fn regenerate_context(config: &Config, project: &str) -> Result<bool> {
+    let knowledge_dir = config.memory_dir.join("knowledge").join(project);
+    let decisions = read_active(&knowledge_dir.join("decisions.md"));
+    let solutions = read_active(&knowledge_dir.join("solutions.md"));
+    let patterns = read_active(&knowledge_dir.join("patterns.md"));
+
+    if decisions.trim().is_empty() && solutions.trim().is_empty() && patterns.trim().is_empty() {
+        return Ok(false);
+    }
+
+    write_context(config, project, &decisions, &solutions, &patterns).await?;
+    Ok(true)
+}
+
+/// Build the user prompt for context synthesis, pulling in active bugs, insights,
+/// questions and session summaries alongside the given (already filtered) knowledge.
+fn context_prompt(
+    config: &Config,
+    project: &str,
+    decisions: &str,
+    solutions: &str,
+    patterns: &str,
+) -> Result<String> {
+    let knowledge_dir = config.memory_dir.join("knowledge").join(project);
+    let summary_dir = config.memory_dir.join("summaries").join(project);
+
+    let bugs = read_active(&knowledge_dir.join("bugs.md"));
+    let insights = read_active(&knowledge_dir.join("insights.md"));
+    let questions = read_active(&knowledge_dir.join("questions.md"));
+    let summaries = collect_summary_dir(&summary_dir)?;
+
+    Ok(config.prompts.context_prompt(
+        project, decisions, solutions, patterns, &bugs, &insights, &questions, "", &summaries,
+    ))
+}
+
+/// Ask the LLM to synthesize context.md from the given knowledge and write it to disk.
+async fn write_context(
+    config: &Config,
+    project: &str,
+    decisions: &str,
+    solutions: &str,
+    patterns: &str,
+) -> Result<()> {
+    let knowledge_dir = config.memory_dir.join("knowledge").join(project);
+    let prompt = context_prompt(config, project, decisions, solutions, patterns)?;
+
+    let client = llm::client::LlmClient::new(&config.llm);
+    let context = client.chat(&config.prompts.extractor, &prompt).await?;
+
+    let context_with_header = format!("# {} - Project Context\n\n{}\n", project, context);
+    let context_path = knowledge_dir.join("context.md");
+    std::fs::write(&context_path, &context_with_header)?;
+    crate::diff::VersionTracker::new(&config.memory_dir, project).track_file(&context_path)?;
+
     Ok(())
 }
 
@@ -157,6 +216,15 @@ fn read_or_empty(path: &Path) -> String {
     std::fs::read_to_string(path).unwrap_or_default()
 }
 
+/// Read a knowledge file with its expired entries dropped
+fn read_active(path: &Path) -> String {
+    use extractor::knowledge::{parse_session_blocks, partition_by_expiry, reconstruct_blocks};
+
+    let (preamble, blocks) = parse_session_blocks(&read_or_empty(path));
+    let (active, _) = partition_by_expiry(blocks);
+    reconstruct_blocks(&preamble, &active)
+}
+
 fn collect_summary_dir(dir: &Path) -> Result<String> {
     let mut summaries = String::new();
     if !dir.exists() {
@@ -178,23 +246,180 @@ fn collect_summary_dir(dir: &Path) -> Result<String> {
     Ok(summaries)
 }
 
+// ── Restore command ─────────────────────────────────────────────────────
+
+pub fn cmd_restore(project: &str, id: Option<&str>) -> Result<()> {
+    let home = dirs::home_dir()
+        .ok_or_else(|| error::MemoryError::Config("Could not determine home directory".into()))?;
+    let memory_dir = home.join("memory");
+
+    let Some(id) = id else {
+        if let Err(e) = crate::trash::purge_expired(&memory_dir, crate::trash::retention()) {
+            tracing::warn!(error = %e, "failed to purge old trash entries");
+        }
+        let entries = crate::trash::list(&memory_dir, project)?;
+        if entries.is_empty() {
+            println!(
+                "{} Trash is empty for '{}'.",
+                "Not found:".yellow(),
+                project
+            );
+            return Ok(());
+        }
+        println!("Trash for '{}':", project);
+        for entry in &entries {
+            println!(
+                "  {}  {} entr{}  {}",
+                entry.id.cyan(),
+                entry.entries,
+                if entry.entries == 1 { "y" } else { "ies" },
+                entry.reason.dimmed()
+            );
+        }
+        status!(
+            "\nRestore with '{}'.",
+            format!("engram restore {} <id>", project).cyan()
+        );
+        return Ok(());
+    };
+
+    let restored = crate::trash::restore(&memory_dir, project, id)?;
+    let context_path = memory_dir
+        .join("knowledge")
+        .join(project)
+        .join("context.md");
+    if restored > 0 && context_path.exists() {
+        std::fs::remove_file(&context_path)?;
+    }
+    status!(
+        "{} Restored {} entr{} to '{}'.",
+        "Done!".green().bold(),
+        restored,
+        if restored == 1 { "y" } else { "ies" },
+        project
+    );
+    if restored > 0 {
+        status!(
+            "  Run '{}' to regenerate context.",
+            format!("engram regen {}", project).cyan()
+        );
+    }
+    Ok(())
+}
+
 // ── Forget command ──────────────────────────────────────────────────────
 
+/// Write the trash entry for removed knowledge, apply the queued rewrites and
+/// tell the user how to undo.
+fn report_trash(project: &str, trash: TrashBatch) -> Result<()> {
+    let id = trash.commit()?;
+    print_trash_hint(project, id);
+    Ok(())
+}
+
+fn print_trash_hint(project: &str, trash_id: Option<String>) {
+    if let Some(id) = trash_id {
+        status!(
+            "  Moved to trash; undo with '{}'.",
+            format!("engram restore {} {}", project, id).cyan()
+        );
+    }
+}
+
+/// Files in a project's knowledge dir derived from the entries, deleted
+/// without trashing by `forget --all` (rebuilt by regen, `graph build`, `embed`)
+const DERIVED_FILES: &[&str] = &["context.md", "graph.json", "embeddings.json"];
+
+/// Move every session entry of `project` to the trash, along with its global
+/// preferences, then delete the emptied files and derived indexes. Files
+/// whose content the trash can't hold are left in place and returned.
+fn forget_all(memory_dir: &Path, project: &str) -> Result<(Option<String>, Vec<PathBuf>)> {
+    use extractor::knowledge::parse_session_blocks;
+
+    let knowledge_dir = memory_dir.join("knowledge").join(project);
+    let mut trash = TrashBatch::new(memory_dir, project, "forget --all");
+    let mut session_ids = std::collections::BTreeSet::new();
+    let mut remove = Vec::new();
+    let mut kept = Vec::new();
+
+    let mut entries: Vec<PathBuf> = match std::fs::read_dir(&knowledge_dir) {
+        Ok(dir) => dir.filter_map(|e| e.ok()).map(|e| e.path()).collect(),
+        Err(_) => Vec::new(),
+    };
+    entries.sort();
+    for path in entries {
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        if DERIVED_FILES.contains(&name.as_str()) {
+            remove.push(path);
+            continue;
+        }
+        if !path.is_file() || !name.ends_with(".md") {
+            kept.push(path);
+            continue;
+        }
+
+        let content = std::fs::read_to_string(&path)?;
+        let (_preamble, blocks) = parse_session_blocks(&content);
+        if blocks.is_empty() {
+            // Only a title (or nothing) is lost by deleting it
+            if content
+                .lines()
+                .all(|l| l.trim().is_empty() || l.starts_with("# "))
+            {
+                remove.push(path);
+            } else {
+                kept.push(path);
+            }
+            continue;
+        }
+        session_ids.extend(blocks.iter().map(|b| b.session_id.clone()));
+        trash.record(&path, blocks);
+        remove.push(path);
+    }
+
+    // Remove those sessions from global preferences
+    if !session_ids.is_empty() {
+        let global_prefs = memory_dir
+            .join("knowledge")
+            .join(crate::config::GLOBAL_DIR)
+            .join("preferences.md");
+        let ids_ref: Vec<&str> = session_ids.iter().map(|s| s.as_str()).collect();
+        trash.remove_sessions(&global_prefs, &ids_ref)?;
+    }
+
+    // The trash entry is written before anything is deleted
+    let trash_id = trash.commit()?;
+    for path in &remove {
+        std::fs::remove_file(path)?;
+    }
+    if kept.is_empty() && knowledge_dir.exists() {
+        std::fs::remove_dir(&knowledge_dir)?;
+    }
+    Ok((trash_id, kept))
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn cmd_forget(
     project: &str,
     session_id: Option<String>,
     topic: Option<String>,
+    label: Option<String>,
     all: bool,
     purge: bool,
     expired: bool,
     stale: Option<String>,
     auto_approve: bool,
     summarize: bool,
+    older_than: Option<String>,
+    dry_run: bool,
 ) -> Result<()> {
     use extractor::knowledge::{
-        find_sessions_by_topic, parse_session_blocks, parse_ttl, partition_by_expiry,
-        reconstruct_blocks, remove_session_blocks,
+        find_sessions_by_label, find_sessions_by_topic, parse_duration_strict,
+        parse_session_blocks, parse_ttl, partition_by_expiry, partition_older_than,
+        reconstruct_blocks,
     };
     use std::collections::BTreeSet;
 
@@ -207,18 +432,9 @@ pub fn cmd_forget(
         .join("_global")
         .join("preferences.md");
 
-    let knowledge_files = [
-        "decisions.md",
-        "solutions.md",
-        "patterns.md",
-        "bugs.md",
-        "insights.md",
-        "questions.md",
-    ];
-
     // Helper: collect all project knowledge files that exist
     let existing_files = || -> Vec<std::path::PathBuf> {
-        knowledge_files
+        crate::config::CATEGORY_FILES
             .iter()
             .map(|f| knowledge_dir.join(f))
             .filter(|p| p.exists())
@@ -358,7 +574,7 @@ pub fn cmd_forget(
                 let summary_content = rt.block_on(async {
                     client
                         .chat(
-                            prompts::SYSTEM_KNOWLEDGE_EXTRACTOR,
+                            &config.prompts.extractor,
                             &prompts::summarize_stale_prompt(cat, &entries_text),
                         )
                         .await
@@ -391,11 +607,13 @@ pub fn cmd_forget(
             .map(|e| e.session_id.as_str())
             .collect();
 
+        let mut trash = TrashBatch::new(
+            &memory_dir,
+            project,
+            format!("forget --stale {}", stale_str),
+        );
         for path in existing_files() {
-            let content = std::fs::read_to_string(&path)?;
-            if let Some(cleaned) = remove_session_blocks(&content, &ids_to_remove) {
-                std::fs::write(&path, cleaned)?;
-            }
+            trash.remove_sessions(&path, &ids_to_remove)?;
         }
 
         // Invalidate context.md
@@ -404,18 +622,88 @@ pub fn cmd_forget(
             std::fs::remove_file(&context_path)?;
         }
 
-        println!(
+        status!(
             "{} Removed {} stale entries from {}.",
             "Done!".green().bold(),
             stale_entries.len(),
             project
         );
-        return Ok(());
+        return report_trash(project, trash);
+    }
+
+    // ── Older-than mode ─────────────────────────────────────────────
+    if let Some(ref older_str) = older_than {
+        let cutoff = chrono::Utc::now() - parse_duration_strict(older_str)?;
+        let mut removed_ids = BTreeSet::new();
+        let mut trash = TrashBatch::new(
+            &memory_dir,
+            project,
+            format!("forget --older-than {}", older_str),
+        );
+
+        for path in existing_files()
+            .iter()
+            .chain(std::iter::once(&global_prefs))
+        {
+            if !path.exists() {
+                continue;
+            }
+            let content = std::fs::read_to_string(path)?;
+            let (preamble, blocks) = parse_session_blocks(&content);
+            let (kept, old) = partition_older_than(blocks, cutoff);
+            if old.is_empty() {
+                continue;
+            }
+            removed_ids.extend(old.iter().map(|b| b.session_id.clone()));
+            if !dry_run {
+                trash.replace(path, reconstruct_blocks(&preamble, &kept), old);
+            }
+        }
+
+        if removed_ids.is_empty() {
+            println!(
+                "{} No entries older than {} found for '{}'.",
+                "Not found:".yellow(),
+                older_str,
+                project
+            );
+            return Ok(());
+        }
+
+        if dry_run {
+            println!(
+                "{} Would remove {} session(s) older than {} from '{}':",
+                "Dry run:".cyan().bold(),
+                removed_ids.len(),
+                older_str,
+                project
+            );
+        } else {
+            let context_path = knowledge_dir.join("context.md");
+            if context_path.exists() {
+                std::fs::remove_file(&context_path)?;
+            }
+            status!(
+                "{} Removed {} session(s) older than {} from '{}':",
+                "Done!".green().bold(),
+                removed_ids.len(),
+                older_str,
+                project
+            );
+        }
+        for id in &removed_ids {
+            println!("  - {}", id);
+        }
+        if dry_run {
+            return Ok(());
+        }
+        return report_trash(project, trash);
     }
 
     // ── Expired mode ────────────────────────────────────────────────
     if expired {
         let mut removed_ids = BTreeSet::new();
+        let mut trash = TrashBatch::new(&memory_dir, project, "forget --expired");
 
         let mut process_file = |path: &std::path::Path| -> Result<()> {
             if !path.exists() {
@@ -430,8 +718,7 @@ pub fn cmd_forget(
             for block in &expired_blocks {
                 removed_ids.insert(block.session_id.clone());
             }
-            let rebuilt = reconstruct_blocks(&preamble, &active);
-            std::fs::write(path, rebuilt)?;
+            trash.replace(path, reconstruct_blocks(&preamble, &active), expired_blocks);
             Ok(())
         };
 
@@ -455,7 +742,7 @@ pub fn cmd_forget(
                 project
             );
         } else {
-            println!(
+            status!(
                 "{} Removed {} expired session(s) from '{}':",
                 "Done!".green().bold(),
                 removed_ids.len(),
@@ -464,52 +751,31 @@ pub fn cmd_forget(
             for id in &removed_ids {
                 println!("  - {}", id);
             }
-            println!(
+            status!(
                 "  Run '{}' to regenerate context.",
                 format!("engram regen {}", project).cyan()
             );
         }
-        return Ok(());
+        return report_trash(project, trash);
     }
 
     // ── All mode ──────────────────────────────────────────────────
     if all {
-        // Collect session IDs before deletion so we can clean global preferences
-        let mut session_ids = BTreeSet::new();
-        for path in existing_files() {
-            let content = std::fs::read_to_string(&path)?;
-            let (_preamble, blocks) = parse_session_blocks(&content);
-            for block in blocks {
-                session_ids.insert(block.session_id);
-            }
-        }
-
-        // Remove those sessions from global preferences
-        if global_prefs.exists() && !session_ids.is_empty() {
-            let prefs_content = std::fs::read_to_string(&global_prefs)?;
-            let ids_ref: Vec<&str> = session_ids.iter().map(|s| s.as_str()).collect();
-            if let Some(cleaned) = remove_session_blocks(&prefs_content, &ids_ref) {
-                std::fs::write(&global_prefs, cleaned)?;
-            }
-        }
-
-        // Delete all knowledge files for project
-        if knowledge_dir.exists() {
-            std::fs::remove_dir_all(&knowledge_dir)?;
-        }
+        let (trash_id, kept) = forget_all(&memory_dir, project)?;
 
         if purge {
             let conv_dir = memory_dir.join("conversations").join(project);
             if conv_dir.exists() {
                 std::fs::remove_dir_all(&conv_dir)?;
             }
+            crate::state::ConversationIndex::forget(&memory_dir, project, None)?;
             let summ_dir = memory_dir.join("summaries").join(project);
             if summ_dir.exists() {
                 std::fs::remove_dir_all(&summ_dir)?;
             }
         }
 
-        println!(
+        status!(
             "{} Removed all knowledge for '{}'{}.",
             "Done!".green().bold(),
             project,
@@ -519,10 +785,21 @@ pub fn cmd_forget(
                 ""
             }
         );
-        println!(
+        status!(
             "  Run '{}' to regenerate context.",
             format!("engram ingest --project {}", project).cyan()
         );
+        print_trash_hint(project, trash_id);
+        if !kept.is_empty() {
+            println!(
+                "  {} Kept {} file(s) that can't be moved to trash:",
+                "Note:".yellow(),
+                kept.len()
+            );
+            for path in &kept {
+                println!("    {}", path.display());
+            }
+        }
         return Ok(());
     }
 
@@ -555,20 +832,12 @@ pub fn cmd_forget(
 
         let ids_ref: Vec<&str> = matched_ids.iter().map(|s| s.as_str()).collect();
 
-        // Remove from all knowledge files
+        // Remove from all knowledge files and global preferences
+        let mut trash = TrashBatch::new(&memory_dir, project, format!("forget --topic {}", query));
         for path in existing_files() {
-            let content = std::fs::read_to_string(&path)?;
-            if let Some(cleaned) = remove_session_blocks(&content, &ids_ref) {
-                std::fs::write(&path, cleaned)?;
-            }
-        }
-        // Remove from global preferences
-        if global_prefs.exists() {
-            let content = std::fs::read_to_string(&global_prefs)?;
-            if let Some(cleaned) = remove_session_blocks(&content, &ids_ref) {
-                std::fs::write(&global_prefs, cleaned)?;
-            }
+            trash.remove_sessions(&path, &ids_ref)?;
         }
+        trash.remove_sessions(&global_prefs, &ids_ref)?;
 
         // Delete stale context.md
         let context_path = knowledge_dir.join("context.md");
@@ -576,7 +845,7 @@ pub fn cmd_forget(
             std::fs::remove_file(&context_path)?;
         }
 
-        println!(
+        status!(
             "{} Removed {} session(s) matching '{}':",
             "Done!".green().bold(),
             matched_ids.len(),
@@ -585,35 +854,80 @@ pub fn cmd_forget(
         for id in &matched_ids {
             println!("  - {}", id);
         }
-        println!(
+        status!(
             "  Run '{}' to regenerate context.",
             format!("engram ingest --project {}", project).cyan()
         );
-        return Ok(());
+        return report_trash(project, trash);
+    }
+
+    // ── Label mode ────────────────────────────────────────────────
+    if let Some(ref raw_label) = label {
+        let label = super::manual::sanitize_session_id(raw_label);
+        if label.is_empty() {
+            return Err(error::MemoryError::Config(format!(
+                "Invalid --label '{}'",
+                raw_label
+            )));
+        }
+
+        let mut matched_ids = BTreeSet::new();
+        let mut files = existing_files();
+        files.push(global_prefs.clone());
+        for path in files.iter().filter(|p| p.exists()) {
+            let content = std::fs::read_to_string(path)?;
+            matched_ids.extend(find_sessions_by_label(&content, &label));
+        }
+
+        if matched_ids.is_empty() {
+            println!(
+                "{} No sessions with label '{}' in project '{}'.",
+                "Not found:".yellow(),
+                label,
+                project
+            );
+            return Ok(());
+        }
+
+        let ids_ref: Vec<&str> = matched_ids.iter().map(|s| s.as_str()).collect();
+        let mut trash = TrashBatch::new(&memory_dir, project, format!("forget --label {}", label));
+        for path in &files {
+            trash.remove_sessions(path, &ids_ref)?;
+        }
+
+        let context_path = knowledge_dir.join("context.md");
+        if context_path.exists() {
+            std::fs::remove_file(&context_path)?;
+        }
+
+        status!(
+            "{} Removed {} session(s) with label '{}':",
+            "Done!".green().bold(),
+            matched_ids.len(),
+            label
+        );
+        for id in &matched_ids {
+            println!("  - {}", id);
+        }
+        status!(
+            "  Run '{}' to regenerate context.",
+            format!("engram regen {}", project).cyan()
+        );
+        return report_trash(project, trash);
     }
 
     // ── Session mode ──────────────────────────────────────────────
     if let Some(ref sid) = session_id {
         let ids = [sid.as_str()];
 
-        let mut removed_any = false;
+        // Remove from all knowledge files and global preferences
+        let mut trash = TrashBatch::new(&memory_dir, project, format!("forget {}", sid));
         for path in existing_files() {
-            let content = std::fs::read_to_string(&path)?;
-            if let Some(cleaned) = remove_session_blocks(&content, &ids) {
-                std::fs::write(&path, cleaned)?;
-                removed_any = true;
-            }
-        }
-        // Remove from global preferences
-        if global_prefs.exists() {
-            let content = std::fs::read_to_string(&global_prefs)?;
-            if let Some(cleaned) = remove_session_blocks(&content, &ids) {
-                std::fs::write(&global_prefs, cleaned)?;
-                removed_any = true;
-            }
+            trash.remove_sessions(&path, &ids)?;
         }
+        trash.remove_sessions(&global_prefs, &ids)?;
 
-        if !removed_any {
+        if trash.is_empty() {
             println!(
                 "{} Session '{}' not found in knowledge for '{}'.",
                 "Not found:".yellow(),
@@ -622,6 +936,7 @@ pub fn cmd_forget(
             );
             return Ok(());
         }
+        let trash_id = trash.commit()?;
 
         // Delete stale context.md
         let context_path = knowledge_dir.join("context.md");
@@ -634,6 +949,7 @@ pub fn cmd_forget(
             if conv_session.exists() {
                 std::fs::remove_dir_all(&conv_session)?;
             }
+            crate::state::ConversationIndex::forget(&memory_dir, project, Some(sid))?;
             let summ_file = memory_dir
                 .join("summaries")
                 .join(project)
@@ -643,7 +959,7 @@ pub fn cmd_forget(
             }
         }
 
-        println!(
+        status!(
             "{} Removed session '{}' from '{}'{}.",
             "Done!".green().bold(),
             sid,
@@ -654,10 +970,11 @@ pub fn cmd_forget(
                 ""
             }
         );
-        println!(
+        status!(
             "  Run '{}' to regenerate context.",
             format!("engram ingest --project {}", project).cyan()
         );
+        print_trash_hint(project, trash_id);
         return Ok(());
     }
 
@@ -718,10 +1035,94 @@ pub fn cmd_forget(
             preview_display
         );
     }
-    println!(
+    status!(
         "\nTo remove a session: {}",
         format!("engram forget {} <session-id>", project).cyan()
     );
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_context_prompt_skips_expired_entries() {
+        let dir = TempDir::new().unwrap();
+        let config = Config {
+            memory_dir: dir.path().to_path_buf(),
+            claude_projects_dir: dir.path().to_path_buf(),
+            llm: crate::auth::providers::ResolvedProvider {
+                provider: crate::auth::providers::Provider::Anthropic,
+                endpoint: "https://api.anthropic.com".to_string(),
+                model: "claude-haiku-4-5-20251001".to_string(),
+                api_key: None,
+                oauth: false,
+            },
+            prompts: Default::default(),
+        };
+        let knowledge_dir = dir.path().join("knowledge").join("proj");
+        std::fs::create_dir_all(&knowledge_dir).unwrap();
+        for (file, title) in [
+            ("bugs.md", "Bugs"),
+            ("insights.md", "Insights"),
+            ("questions.md", "Questions"),
+        ] {
+            std::fs::write(
+                knowledge_dir.join(file),
+                format!(
+                    "# {title}\n\n\
+                     ## Session: old (2020-01-01T00:00:00Z) [ttl:1d]\n\
+                     stale {title}\n\n\
+                     ## Session: new (2020-01-01T00:00:00Z)\n\
+                     fresh {title}\n"
+                ),
+            )
+            .unwrap();
+        }
+
+        let prompt = context_prompt(&config, "proj", "", "", "").unwrap();
+        for title in ["Bugs", "Insights", "Questions"] {
+            assert!(prompt.contains(&format!("fresh {title}")), "{}", prompt);
+            assert!(!prompt.contains(&format!("stale {title}")), "{}", prompt);
+        }
+    }
+
+    #[test]
+    fn test_forget_all_then_restore_round_trips() {
+        const PROCEDURES: &str = "# Procedures\n\n\
+                                  ## Session: p1 (2024-01-01T00:00:00Z)\n\n1. Run migrations\n2. Deploy\n\n\
+                                  ## Session: p2 (2024-01-02T00:00:00Z) [ttl:30d]\n\nRoll back with v1\n";
+        const NOTES: &str = "# Notes\n\nHand-written, not session entries\n";
+
+        let dir = TempDir::new().unwrap();
+        let knowledge_dir = dir.path().join("knowledge").join("proj");
+        std::fs::create_dir_all(&knowledge_dir).unwrap();
+        std::fs::write(knowledge_dir.join("procedures.md"), PROCEDURES).unwrap();
+        std::fs::write(knowledge_dir.join("bugs.md"), "# Bugs\n").unwrap();
+        std::fs::write(
+            knowledge_dir.join("context.md"),
+            "# proj - Project Context\n",
+        )
+        .unwrap();
+        std::fs::write(knowledge_dir.join("notes.md"), NOTES).unwrap();
+
+        let (id, kept) = forget_all(dir.path(), "proj").unwrap();
+        assert_eq!(kept, [knowledge_dir.join("notes.md")]);
+        assert!(!knowledge_dir.join("procedures.md").exists());
+        assert!(!knowledge_dir.join("bugs.md").exists());
+        assert!(!knowledge_dir.join("context.md").exists());
+        assert_eq!(
+            std::fs::read_to_string(knowledge_dir.join("notes.md")).unwrap(),
+            NOTES
+        );
+
+        crate::trash::restore(dir.path(), "proj", &id.unwrap()).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(knowledge_dir.join("procedures.md")).unwrap(),
+            PROCEDURES
+        );
+    }
+}
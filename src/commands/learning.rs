@@ -1,6 +1,7 @@
 use crate::config::Config;
 use crate::error::{MemoryError, Result};
 use crate::learning;
+use crate::output::status;
 use colored::Colorize;
 
 pub fn cmd_learn_dashboard(config: &Config, project: Option<&str>) -> Result<()> {
@@ -39,7 +40,13 @@ pub fn cmd_learn_dashboard(config: &Config, project: Option<&str>) -> Result<()>
     Ok(())
 }
 
-pub fn cmd_learn_optimize(config: &Config, project: &str, dry_run: bool, auto: bool) -> Result<()> {
+pub fn cmd_learn_optimize(
+    config: &Config,
+    project: &str,
+    dry_run: bool,
+    auto: bool,
+    min_samples: u32,
+) -> Result<()> {
     use learning::{adaptation, progress};
 
     println!(
@@ -66,7 +73,7 @@ pub fn cmd_learn_optimize(config: &Config, project: &str, dry_run: bool, auto: b
     }
 
     // Preview changes
-    let preview = adaptation::preview_changes(config, project, &state)?;
+    let preview = adaptation::preview_changes(config, project, &state, min_samples)?;
     learning::dashboard::display_preview(&preview);
 
     if dry_run {
@@ -93,7 +100,7 @@ pub fn cmd_learn_optimize(config: &Config, project: &str, dry_run: bool, auto: b
     }
 
     // Apply learned parameters
-    let result = adaptation::apply_learned_parameters(config, project, &state)?;
+    let result = adaptation::apply_learned_parameters(config, project, &state, min_samples)?;
 
     println!(
         "\n{}",
@@ -140,6 +147,50 @@ pub fn cmd_learn_reset(config: &Config, project: &str) -> Result<()> {
     Ok(())
 }
 
+pub fn cmd_learn_export(config: &Config, project: &str, output: Option<&str>) -> Result<()> {
+    let state = learning::progress::load_state(&config.memory_dir, project)?;
+    let json = serde_json::to_string_pretty(&state.learned_parameters)?;
+
+    if let Some(out_path) = crate::output::output_file(output) {
+        std::fs::write(out_path, format!("{}\n", json))?;
+        status!(
+            "{} Learned parameters for '{}' exported to {}",
+            "Done!".green().bold(),
+            project,
+            out_path
+        );
+    } else {
+        println!("{}", json);
+    }
+
+    Ok(())
+}
+
+pub fn cmd_learn_import(config: &Config, project: &str, input: &str) -> Result<()> {
+    let content = std::fs::read_to_string(input)?;
+    let params: learning::adaptation::LearnedParameters = serde_json::from_str(&content)
+        .map_err(|e| MemoryError::Config(format!("Invalid learned parameters file: {}", e)))?;
+
+    let boosts = params.importance_boosts.len();
+    let ttls = params.ttl_adjustments.len();
+    let has_strategy = params.consolidation_strategy.is_some();
+
+    learning::progress::import_parameters(&config.memory_dir, project, params)?;
+
+    status!(
+        "{} Imported learned parameters into '{}'",
+        "Done!".green().bold(),
+        project
+    );
+    println!("  {} importance boosts", boosts);
+    println!("  {} TTL adjustments", ttls);
+    if has_strategy {
+        println!("  {} consolidation strategy", "✓".green());
+    }
+
+    Ok(())
+}
+
 pub fn cmd_learn_simulate(
     config: &Config,
     project: &str,
@@ -191,13 +242,13 @@ pub fn cmd_learn_simulate(
     learning::post_ingest_hook(config, project)?;
 
     println!("\n{} Simulation complete", "✓".green().bold());
-    println!("\nNext steps:");
-    println!(
+    status!("\nNext steps:");
+    status!(
         "  {} {}",
         "1.".dimmed(),
         format!("engram learn dashboard {}", project).cyan()
     );
-    println!(
+    status!(
         "  {} {}",
         "2.".dimmed(),
         format!("engram learn optimize {} --dry-run", project).cyan()
@@ -222,6 +273,9 @@ fn parse_duration(s: &str) -> Result<chrono::Duration> {
     }
 }
 
+/// How far back `learn feedback` looks for the recall/inject it applies to.
+const FEEDBACK_LOOKBACK_DAYS: u32 = 7;
+
 pub fn cmd_learn_feedback(
     config: &Config,
     project: &str,
@@ -247,12 +301,18 @@ pub fn cmd_learn_feedback(
 
     let session_id = session.unwrap_or("current").to_string();
 
+    // Attach feedback to the blocks most recently recalled/injected for this project
+    let tracker = crate::analytics::EventTracker::new(&config.memory_dir);
+    let lookup_session = session.filter(|s| *s != "current");
+    let knowledge_ids =
+        tracker.latest_knowledge_ids(project, lookup_session, FEEDBACK_LOOKBACK_DAYS)?;
+
     // Create feedback signal
     let feedback = ExplicitFeedback {
         timestamp: chrono::Utc::now(),
         session_id: session_id.clone(),
         project: project.to_string(),
-        knowledge_ids: vec![], // Would be populated from actual usage
+        knowledge_ids: knowledge_ids.clone(),
         sentiment,
         comment: comment.map(String::from),
     };
@@ -269,6 +329,14 @@ pub fn cmd_learn_feedback(
     println!("  Project: {}", project);
     println!("  Session: {}", session_id);
     println!("  Sentiment: {:?}", sentiment);
+    if knowledge_ids.is_empty() {
+        println!(
+            "  {} no recent recall/inject found; feedback applies project-wide",
+            "Note:".yellow()
+        );
+    } else {
+        println!("  Entries: {}", knowledge_ids.len());
+    }
     if let Some(comment) = comment {
         println!("  Comment: {}", comment);
     }
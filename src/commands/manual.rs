@@ -3,9 +3,11 @@ use std::path::Path;
 use chrono;
 use colored::Colorize;
 
+use crate::config::Category;
 use crate::error::{self, Result};
 use crate::extractor;
 use crate::hive;
+use crate::output::status;
 
 // ── Review command ──────────────────────────────────────────────────────
 
@@ -92,7 +94,7 @@ pub fn cmd_review(project: &str, show_all: bool) -> Result<()> {
         println!();
     }
 
-    println!(
+    status!(
         "  Promote with: {}",
         format!(
             "engram promote {} <session_id> <category> [--global]",
@@ -106,14 +108,90 @@ pub fn cmd_review(project: &str, show_all: bool) -> Result<()> {
 
 // ── Promote command ─────────────────────────────────────────────────────
 
+/// Promote categories offered by `engram promote` and the TUI.
+pub const PROMOTE_CATEGORIES: &[Category] = Category::ALL;
+
+/// Result of moving one inbox entry into a knowledge file.
+#[derive(Debug, PartialEq)]
+pub enum PromoteOutcome {
+    /// Entry was appended to `target_file` and removed from the inbox.
+    Promoted {
+        target_file: &'static str,
+    },
+    NoInbox,
+    NotFound,
+}
+
 pub fn cmd_promote(
     project: &str,
     session_id: &str,
-    category: &str,
+    category: Category,
     global: bool,
     label: &str,
     ttl: Option<&str>,
 ) -> Result<()> {
+    let home = dirs::home_dir()
+        .ok_or_else(|| error::MemoryError::Config("Could not determine home directory".into()))?;
+    let memory_dir = home.join("memory");
+
+    match promote_inbox_entry(
+        &memory_dir,
+        project,
+        session_id,
+        category,
+        global,
+        label,
+        ttl,
+    )? {
+        PromoteOutcome::NoInbox => {
+            println!(
+                "{} No inbox found for '{}'.",
+                "Not found:".yellow(),
+                project
+            );
+        }
+        PromoteOutcome::NotFound => {
+            println!(
+                "{} Session '{}' not found in inbox for '{}'.",
+                "Not found:".yellow(),
+                session_id,
+                project
+            );
+        }
+        PromoteOutcome::Promoted { target_file } => {
+            status!(
+                "{} Promoted '{}' to {}/{}.",
+                "Done!".green().bold(),
+                session_id,
+                if global { "_global" } else { project },
+                target_file
+            );
+
+            if !global {
+                status!(
+                    "  Run '{}' to regenerate context.",
+                    format!("engram regen {}", project).cyan()
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Move an inbox entry into its category file (or `_global` when `global`).
+///
+/// Preferences are only accepted globally. Shared by `engram promote` and the
+/// TUI browser, so it never prints.
+pub fn promote_inbox_entry(
+    memory_dir: &Path,
+    project: &str,
+    session_id: &str,
+    category: Category,
+    global: bool,
+    label: &str,
+    ttl: Option<&str>,
+) -> Result<PromoteOutcome> {
     use extractor::knowledge::{parse_session_blocks, parse_ttl, reconstruct_blocks};
 
     if let Some(ttl_val) = ttl {
@@ -125,39 +203,24 @@ pub fn cmd_promote(
         }
     }
 
-    if !global && category == "preferences" {
+    if !global && category == Category::Preferences {
         return Err(error::MemoryError::Config(
             "preferences can only be promoted with --global".into(),
         ));
     }
 
-    let home = dirs::home_dir()
-        .ok_or_else(|| error::MemoryError::Config("Could not determine home directory".into()))?;
-    let memory_dir = home.join("memory");
     let project_dir = memory_dir.join("knowledge").join(project);
     let inbox_path = project_dir.join("inbox.md");
 
     if !inbox_path.exists() {
-        println!(
-            "{} No inbox found for '{}'.",
-            "Not found:".yellow(),
-            project
-        );
-        return Ok(());
+        return Ok(PromoteOutcome::NoInbox);
     }
 
     let inbox_content = std::fs::read_to_string(&inbox_path)?;
     let (preamble, blocks) = parse_session_blocks(&inbox_content);
 
-    let selected = blocks.iter().find(|b| b.session_id == session_id);
-    let Some(selected) = selected else {
-        println!(
-            "{} Session '{}' not found in inbox for '{}'.",
-            "Not found:".yellow(),
-            session_id,
-            project
-        );
-        return Ok(());
+    let Some(selected) = blocks.iter().find(|b| b.session_id == session_id) else {
+        return Ok(PromoteOutcome::NotFound);
     };
 
     let now = chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string();
@@ -171,41 +234,19 @@ pub fn cmd_promote(
     let (target_dir, target_file, target_title) = if global {
         (
             memory_dir.join("knowledge").join("_global"),
-            if category == "preferences" {
+            if category == Category::Preferences {
                 "preferences.md"
             } else {
                 "shared.md"
             },
-            if category == "preferences" {
+            if category == Category::Preferences {
                 "Preferences"
             } else {
                 "Shared"
             },
         )
     } else {
-        (
-            project_dir.clone(),
-            match category {
-                "decisions" => "decisions.md",
-                "solutions" => "solutions.md",
-                "patterns" => "patterns.md",
-                "bugs" => "bugs.md",
-                "insights" => "insights.md",
-                "questions" => "questions.md",
-                "procedures" => "procedures.md",
-                _ => "decisions.md",
-            },
-            match category {
-                "decisions" => "Decisions",
-                "solutions" => "Solutions",
-                "patterns" => "Patterns",
-                "bugs" => "Bugs",
-                "insights" => "Insights",
-                "questions" => "Questions",
-                "procedures" => "Procedures",
-                _ => "Knowledge",
-            },
-        )
+        (project_dir.clone(), category.file_name(), category.title())
     };
 
     std::fs::create_dir_all(&target_dir)?;
@@ -213,6 +254,12 @@ pub fn cmd_promote(
     init_knowledge_file(&target_path, target_title)?;
 
     append_session_entry(&target_path, &header, selected.content.trim())?;
+    let target_project = if global {
+        crate::config::GLOBAL_DIR
+    } else {
+        project
+    };
+    crate::diff::VersionTracker::new(memory_dir, target_project).track_file(&target_path)?;
 
     // Remove promoted entry from inbox
     let remaining: Vec<_> = blocks
@@ -229,25 +276,10 @@ pub fn cmd_promote(
         }
     }
 
-    println!(
-        "{} Promoted '{}' to {}/{}.",
-        "Done!".green().bold(),
-        session_id,
-        if global { "_global" } else { project },
-        target_file
-    );
-
-    if !global {
-        println!(
-            "  Run '{}' to regenerate context.",
-            format!("engram regen {}", project).cyan()
-        );
-    }
-
-    Ok(())
+    Ok(PromoteOutcome::Promoted { target_file })
 }
 
-fn sanitize_session_id(s: &str) -> String {
+pub(crate) fn sanitize_session_id(s: &str) -> String {
     let out: String = s
         .chars()
         .map(|c| {
@@ -262,14 +294,10 @@ fn sanitize_session_id(s: &str) -> String {
 }
 
 fn session_header(session_id: &str, timestamp: &str, ttl: Option<&str>) -> String {
-    if let Some(ttl_val) = ttl {
-        format!(
-            "\n\n## Session: {} ({}) [ttl:{}]\n\n",
-            session_id, timestamp, ttl_val
-        )
-    } else {
-        format!("\n\n## Session: {} ({})\n\n", session_id, timestamp)
-    }
+    let tags = ttl
+        .map(|t| crate::extractor::knowledge::ttl_tags(timestamp, t))
+        .unwrap_or_default();
+    format!("\n\n## Session: {} ({}){}\n\n", session_id, timestamp, tags)
 }
 
 fn init_knowledge_file(path: &Path, title: &str) -> Result<()> {
@@ -291,7 +319,7 @@ fn append_session_entry(path: &Path, header: &str, content: &str) -> Result<()>
 /// Bulk-promote all inbox entries to their respective knowledge category files.
 /// Category is inferred from the session_id suffix (e.g., `abc123:decisions`) or
 /// the `- category: X` metadata line in the block content.
-pub fn cmd_drain(project: &str, dry_run: bool, category_filter: Option<&str>) -> Result<()> {
+pub fn cmd_drain(project: &str, dry_run: bool, category_filter: Option<Category>) -> Result<()> {
     use extractor::knowledge::{parse_session_blocks, reconstruct_blocks};
 
     let home = dirs::home_dir()
@@ -318,45 +346,22 @@ pub fn cmd_drain(project: &str, dry_run: bool, category_filter: Option<&str>) ->
     }
 
     // Infer category for a block: session_id suffix, then content metadata line
-    let infer_category = |block: &extractor::knowledge::SessionBlock| -> Option<String> {
+    let infer_category = |block: &extractor::knowledge::SessionBlock| -> Option<Category> {
         // 1. Try session_id suffix (e.g., "abc123:decisions")
-        if let Some(suffix) = block.session_id.rsplit(':').next() {
-            let suffix = suffix.trim().to_lowercase();
-            if matches!(
-                suffix.as_str(),
-                "decisions"
-                    | "solutions"
-                    | "patterns"
-                    | "bugs"
-                    | "insights"
-                    | "questions"
-                    | "procedures"
-                    | "preferences"
-            ) {
-                return Some(suffix);
-            }
+        if let Some(cat) = block
+            .session_id
+            .rsplit(':')
+            .next()
+            .and_then(Category::parse)
+        {
+            return Some(cat);
         }
         // 2. Try `- category: X` line in content
-        for line in block.content.lines() {
-            let trimmed = line.trim();
-            if let Some(rest) = trimmed.strip_prefix("- category:") {
-                let cat = rest.trim().to_lowercase();
-                if matches!(
-                    cat.as_str(),
-                    "decisions"
-                        | "solutions"
-                        | "patterns"
-                        | "bugs"
-                        | "insights"
-                        | "questions"
-                        | "procedures"
-                        | "preferences"
-                ) {
-                    return Some(cat);
-                }
-            }
-        }
-        None
+        block
+            .content
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix("- category:"))
+            .find_map(Category::parse)
     };
 
     let now = chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string();
@@ -388,7 +393,7 @@ pub fn cmd_drain(project: &str, dry_run: bool, category_filter: Option<&str>) ->
         }
 
         // Determine target file
-        let (target_file, target_title, is_global) = if cat == "preferences" {
+        let (target_file, target_title, is_global) = if cat == Category::Preferences {
             (
                 memory_dir
                     .join("knowledge")
@@ -398,27 +403,7 @@ pub fn cmd_drain(project: &str, dry_run: bool, category_filter: Option<&str>) ->
                 true,
             )
         } else {
-            let filename = match cat.as_str() {
-                "decisions" => "decisions.md",
-                "solutions" => "solutions.md",
-                "patterns" => "patterns.md",
-                "bugs" => "bugs.md",
-                "insights" => "insights.md",
-                "questions" => "questions.md",
-                "procedures" => "procedures.md",
-                _ => "decisions.md",
-            };
-            let title = match cat.as_str() {
-                "decisions" => "Decisions",
-                "solutions" => "Solutions",
-                "patterns" => "Patterns",
-                "bugs" => "Bugs",
-                "insights" => "Insights",
-                "questions" => "Questions",
-                "procedures" => "Procedures",
-                _ => "Knowledge",
-            };
-            (project_dir.join(filename), title, false)
+            (project_dir.join(cat.file_name()), cat.title(), false)
         };
 
         let preview = block.content.lines().next().unwrap_or("").trim();
@@ -442,7 +427,7 @@ pub fn cmd_drain(project: &str, dry_run: bool, category_filter: Option<&str>) ->
                             .to_string_lossy()
                     )
                 } else {
-                    cat.clone()
+                    cat.to_string()
                 },
                 preview
             );
@@ -462,7 +447,7 @@ pub fn cmd_drain(project: &str, dry_run: bool, category_filter: Option<&str>) ->
                 block.session_id, cat, now
             )];
             if let Some(ref ttl) = block.ttl {
-                parts.push(format!(" [ttl:{}]", ttl));
+                parts.push(crate::extractor::knowledge::ttl_tags(&now, ttl));
             }
             if let Some(ref conf) = block.confidence {
                 parts.push(format!(" [confidence:{}]", conf));
@@ -503,7 +488,7 @@ pub fn cmd_drain(project: &str, dry_run: bool, category_filter: Option<&str>) ->
         );
         println!("  Re-run without --dry-run to apply.");
     } else {
-        println!(
+        status!(
             "{} Promoted {} entries from inbox → knowledge files ({} unknown kept, {} skipped).",
             "Done!".green().bold(),
             promoted,
@@ -511,7 +496,7 @@ pub fn cmd_drain(project: &str, dry_run: bool, category_filter: Option<&str>) ->
             skipped
         );
         if promoted > 0 {
-            println!(
+            status!(
                 "  Run '{}' to regenerate context.",
                 format!("engram regen {}", project).cyan()
             );
@@ -523,8 +508,14 @@ pub fn cmd_drain(project: &str, dry_run: bool, category_filter: Option<&str>) ->
 
 // ── Lookup command ──────────────────────────────────────────────────────
 
-pub fn cmd_lookup(project: &str, query: &str, include_all: bool) -> Result<()> {
-    use extractor::knowledge::{is_expired, parse_session_blocks};
+pub fn cmd_lookup(
+    project: &str,
+    query: Option<&str>,
+    label: Option<&str>,
+    include_all: bool,
+    max_results: Option<usize>,
+) -> Result<()> {
+    use extractor::knowledge::{is_expired, parse_session_blocks, SessionBlock};
 
     let home = dirs::home_dir()
         .ok_or_else(|| error::MemoryError::Config("Could not determine home directory".into()))?;
@@ -548,16 +539,44 @@ pub fn cmd_lookup(project: &str, query: &str, include_all: bool) -> Result<()> {
         return Ok(());
     }
 
-    let query_lower = query.to_lowercase();
-    let mut found = false;
-
-    let files: Vec<(&str, std::path::PathBuf)> = vec![
-        ("decisions", knowledge_dir.join("decisions.md")),
-        ("solutions", knowledge_dir.join("solutions.md")),
-        ("patterns", knowledge_dir.join("patterns.md")),
-        ("preferences", global_prefs),
-        ("shared", global_shared),
-    ];
+    let query_lower = query.map(str::to_lowercase);
+    let label = label.map(sanitize_session_id);
+    let description = match (query, &label) {
+        (Some(q), Some(l)) => format!("'{}' with label '{}'", q, l),
+        (Some(q), None) => format!("'{}'", q),
+        (None, Some(l)) => format!("label '{}'", l),
+        (None, None) => "everything".to_string(),
+    };
+    let matches = |block: &SessionBlock| {
+        label
+            .as_ref()
+            .is_none_or(|l| block.session_id.starts_with(l.as_str()))
+            && query_lower.as_ref().is_none_or(|q| {
+                block.content.to_lowercase().contains(q) || block.header.to_lowercase().contains(q)
+            })
+    };
+    // Lines worth showing for a match: those containing the query, or the
+    // leading lines when filtering by label only
+    let shown_line = |line: &str| {
+        !line.trim().is_empty()
+            && query_lower
+                .as_ref()
+                .is_none_or(|q| line.to_lowercase().contains(q))
+    };
+    let mut matched = 0usize;
+    let over_limit = |matched: usize| max_results.is_some_and(|max| matched > max);
+
+    let mut files: Vec<(&str, std::path::PathBuf)> = [
+        Category::Decisions,
+        Category::Solutions,
+        Category::Patterns,
+        Category::Workflows,
+    ]
+    .iter()
+    .map(|c| (c.as_str(), knowledge_dir.join(c.file_name())))
+    .collect();
+    files.push(("preferences", global_prefs));
+    files.push(("shared", global_shared));
 
     for (category, path) in &files {
         if !path.exists() {
@@ -574,18 +593,19 @@ pub fn cmd_lookup(project: &str, query: &str, include_all: bool) -> Result<()> {
                 continue;
             }
 
-            if block.content.to_lowercase().contains(&query_lower)
-                || block.header.to_lowercase().contains(&query_lower)
-            {
-                if !found {
+            if matches(block) {
+                matched += 1;
+                if over_limit(matched) {
+                    continue;
+                }
+                if matched == 1 {
                     println!(
-                        "{} Results for '{}' in '{}':\n",
+                        "{} Results for {} in '{}':\n",
                         "Lookup".green().bold(),
-                        query,
+                        description,
                         project
                     );
                 }
-                found = true;
 
                 let expired_tag = if expired {
                     " [EXPIRED]".red().to_string()
@@ -603,7 +623,7 @@ pub fn cmd_lookup(project: &str, query: &str, include_all: bool) -> Result<()> {
                 // Print matching lines from content (up to 5)
                 let mut match_count = 0;
                 for line in block.content.lines() {
-                    if line.to_lowercase().contains(&query_lower) && !line.trim().is_empty() {
+                    if shown_line(line) {
                         println!("    {}", line.trim());
                         match_count += 1;
                         if match_count >= 5 {
@@ -619,11 +639,12 @@ pub fn cmd_lookup(project: &str, query: &str, include_all: bool) -> Result<()> {
 
     // Also search installed packs
     let installer = hive::PackInstaller::new(&memory_dir);
-    if let Ok(knowledge_dirs) = installer.get_installed_knowledge_dirs() {
+    if let Ok(knowledge_dirs) = installer.get_installed_knowledge_dirs(Some(project)) {
         for (pack_name, knowledge_dir) in knowledge_dirs {
             for category in &[
                 "patterns.md",
                 "solutions.md",
+                "workflows.md",
                 "decisions.md",
                 "preferences.md",
             ] {
@@ -638,18 +659,19 @@ pub fn cmd_lookup(project: &str, query: &str, include_all: bool) -> Result<()> {
 
                 let (_preamble, blocks) = parse_session_blocks(&content);
                 for block in &blocks {
-                    if block.content.to_lowercase().contains(&query_lower)
-                        || block.header.to_lowercase().contains(&query_lower)
-                    {
-                        if !found {
+                    if matches(block) {
+                        matched += 1;
+                        if over_limit(matched) {
+                            continue;
+                        }
+                        if matched == 1 {
                             println!(
-                                "{} Results for '{}' in '{}':\n",
+                                "{} Results for {} in '{}':\n",
                                 "Lookup".green().bold(),
-                                query,
+                                description,
                                 project
                             );
                         }
-                        found = true;
                         println!(
                             "  {} [{}] {} (pack: {})",
                             ">".green(),
@@ -659,8 +681,7 @@ pub fn cmd_lookup(project: &str, query: &str, include_all: bool) -> Result<()> {
                         );
                         let mut match_count = 0;
                         for line in block.content.lines() {
-                            if line.to_lowercase().contains(&query_lower) && !line.trim().is_empty()
-                            {
+                            if shown_line(line) {
                                 println!("    {}", line.trim());
                                 match_count += 1;
                                 if match_count >= 5 {
@@ -676,11 +697,18 @@ pub fn cmd_lookup(project: &str, query: &str, include_all: bool) -> Result<()> {
         }
     }
 
-    if !found {
+    if let Some(max) = max_results.filter(|max| matched > *max) {
+        status!(
+            "{}",
+            format!("Showing {} of {} matching entries.", max, matched).dimmed()
+        );
+    }
+
+    if matched == 0 {
         println!(
-            "{} No knowledge matching '{}' in '{}'.",
+            "{} No knowledge matching {} in '{}'.",
             "Not found:".yellow(),
-            query,
+            description,
             project
         );
     }
@@ -692,19 +720,13 @@ pub fn cmd_lookup(project: &str, query: &str, include_all: bool) -> Result<()> {
 
 pub fn cmd_add(
     project: &str,
-    category: &str,
+    category: Category,
     content: &str,
     label: &str,
     ttl: Option<&str>,
 ) -> Result<()> {
     use extractor::knowledge::parse_ttl;
 
-    if category.is_empty() {
-        return Err(error::MemoryError::Config(
-            "Category cannot be empty".into(),
-        ));
-    }
-
     // Validate TTL format early
     if let Some(ttl_val) = ttl {
         if parse_ttl(ttl_val).is_none() {
@@ -719,66 +741,22 @@ pub fn cmd_add(
         .ok_or_else(|| error::MemoryError::Config("Could not determine home directory".into()))?;
     let memory_dir = home.join("memory");
 
-    let (dir, filename) = if project == crate::config::GLOBAL_PROJECT || category == "preferences" {
-        let filename = match category {
-            "preferences" => "preferences.md",
-            "decisions" => "decisions.md",
-            "solutions" => "solutions.md",
-            "patterns" => "patterns.md",
-            "bugs" => "bugs.md",
-            "insights" => "insights.md",
-            "questions" => "questions.md",
-            "procedures" => "procedures.md",
-            _ => return Err(error::MemoryError::Config(format!(
-                "Unknown category: '{}'. Use: decisions, solutions, patterns, bugs, insights, questions, procedures, preferences",
-                category
-            ))),
-        };
-        (
-            memory_dir.join("knowledge").join(crate::config::GLOBAL_DIR),
-            filename,
-        )
+    let dir = if project == crate::config::GLOBAL_PROJECT || category == Category::Preferences {
+        memory_dir.join("knowledge").join(crate::config::GLOBAL_DIR)
     } else {
-        (
-            memory_dir.join("knowledge").join(project),
-            match category {
-                "decisions" => "decisions.md",
-                "solutions" => "solutions.md",
-                "patterns" => "patterns.md",
-                "bugs" => "bugs.md",
-                "insights" => "insights.md",
-                "questions" => "questions.md",
-                "procedures" => "procedures.md",
-                _ => return Err(error::MemoryError::Config(format!(
-                    "Unknown category: '{}'. Use: decisions, solutions, patterns, bugs, insights, questions, procedures, preferences",
-                    category
-                ))),
-            },
-        )
+        memory_dir.join("knowledge").join(project)
     };
+    let filename = category.file_name();
 
     std::fs::create_dir_all(&dir)?;
     let path = dir.join(filename);
 
     // Initialize file if needed
-    if !path.exists() {
-        let title = if category.is_empty() {
-            String::new()
-        } else {
-            let mut t = category.chars().next().unwrap().to_uppercase().to_string();
-            t.push_str(&category[1..]);
-            t
-        };
-        std::fs::write(&path, format!("# {}\n", title))?;
-    }
+    init_knowledge_file(&path, category.title())?;
 
     // Build header with timestamp and label
-    let now = chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ");
-    let header = if let Some(ttl_val) = ttl {
-        format!("\n\n## Session: {} ({}) [ttl:{}]\n\n", label, now, ttl_val)
-    } else {
-        format!("\n\n## Session: {} ({})\n\n", label, now)
-    };
+    let now = chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string();
+    let header = session_header(label, &now, ttl);
 
     // Dedup: replace existing session if same label already present
     use crate::extractor::knowledge::replace_session_block;
@@ -790,6 +768,11 @@ pub fn cmd_add(
         let mut file = std::fs::OpenOptions::new().append(true).open(&path)?;
         writeln!(file, "{}{}", header, content)?;
     }
+    let version_project = dir
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| project.to_string());
+    crate::diff::VersionTracker::new(&memory_dir, &version_project).track_file(&path)?;
 
     // Delete stale context.md (manual entries change the knowledge base)
     let context_path = memory_dir
@@ -800,20 +783,21 @@ pub fn cmd_add(
         std::fs::remove_file(&context_path)?;
     }
 
-    let display_project = if project == crate::config::GLOBAL_PROJECT || category == "preferences" {
-        crate::config::GLOBAL_DIR
-    } else {
-        project
-    };
-    println!(
+    let display_project =
+        if project == crate::config::GLOBAL_PROJECT || category == Category::Preferences {
+            crate::config::GLOBAL_DIR
+        } else {
+            project
+        };
+    status!(
         "{} Added to {}/{} for '{}'.",
         "Done!".green().bold(),
         category,
         filename,
         display_project
     );
-    if project != crate::config::GLOBAL_PROJECT && category != "preferences" {
-        println!(
+    if project != crate::config::GLOBAL_PROJECT && category != Category::Preferences {
+        status!(
             "  Run '{}' to update context.",
             format!("engram regen {}", project).cyan()
         );
@@ -821,3 +805,433 @@ pub fn cmd_add(
 
     Ok(())
 }
+
+// ── Stats command ───────────────────────────────────────────────────────
+
+/// Entry counts for one knowledge category file.
+#[derive(Debug, serde::Serialize)]
+pub struct CategoryStats {
+    pub category: &'static str,
+    pub active: usize,
+    pub expired: usize,
+    pub bytes: u64,
+}
+
+/// Inventory of a project's knowledge, as printed by `engram stats`.
+#[derive(Debug, serde::Serialize)]
+pub struct ProjectStats {
+    pub project: String,
+    pub categories: Vec<CategoryStats>,
+    pub total_entries: usize,
+    pub expired_entries: usize,
+    pub total_bytes: u64,
+    pub oldest: Option<String>,
+    pub newest: Option<String>,
+    pub has_graph: bool,
+    pub has_embeddings: bool,
+}
+
+/// Collect [`ProjectStats`] from the knowledge files in `knowledge_dir`.
+pub fn project_stats(knowledge_dir: &Path, project: &str) -> Result<ProjectStats> {
+    use extractor::knowledge::{parse_session_blocks, partition_by_expiry};
+
+    let mut categories = Vec::new();
+    let mut timestamps = Vec::new();
+    for category in Category::ALL {
+        let path = knowledge_dir.join(category.file_name());
+        if !path.exists() {
+            continue;
+        }
+        let content = std::fs::read_to_string(&path)?;
+        let (_preamble, blocks) = parse_session_blocks(&content);
+        timestamps.extend(
+            blocks
+                .iter()
+                .filter_map(|b| chrono::DateTime::parse_from_rfc3339(&b.timestamp).ok()),
+        );
+        let (active, expired) = partition_by_expiry(blocks);
+        categories.push(CategoryStats {
+            category: category.as_str(),
+            active: active.len(),
+            expired: expired.len(),
+            bytes: content.len() as u64,
+        });
+    }
+
+    let total_entries = categories.iter().map(|c| c.active + c.expired).sum();
+    let expired_entries = categories.iter().map(|c| c.expired).sum();
+    let total_bytes = categories.iter().map(|c| c.bytes).sum();
+    let to_string = |ts: &chrono::DateTime<chrono::FixedOffset>| {
+        ts.with_timezone(&chrono::Utc)
+            .format("%Y-%m-%dT%H:%M:%SZ")
+            .to_string()
+    };
+    Ok(ProjectStats {
+        project: project.to_string(),
+        categories,
+        total_entries,
+        expired_entries,
+        total_bytes,
+        oldest: timestamps.iter().min().map(to_string),
+        newest: timestamps.iter().max().map(to_string),
+        has_graph: knowledge_dir.join("graph.json").exists(),
+        has_embeddings: knowledge_dir.join("embeddings.json").exists(),
+    })
+}
+
+pub fn cmd_stats(project: &str, json: bool) -> Result<()> {
+    let home = dirs::home_dir()
+        .ok_or_else(|| error::MemoryError::Config("Could not determine home directory".into()))?;
+    let knowledge_dir = home.join("memory").join("knowledge").join(project);
+
+    if !knowledge_dir.exists() {
+        return Err(error::MemoryError::Config(format!(
+            "No knowledge found for '{}'. Run 'ingest' first.",
+            project
+        )));
+    }
+
+    let stats = project_stats(&knowledge_dir, project)?;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&stats)?);
+        return Ok(());
+    }
+
+    println!("{} Knowledge Statistics: {}", "→".blue(), project.bold());
+    println!();
+    for c in &stats.categories {
+        let expired = if c.expired > 0 {
+            format!(" (+{} expired)", c.expired).red().to_string()
+        } else {
+            String::new()
+        };
+        println!(
+            "  {:<12} {:>4} entries{}  {}",
+            c.category.cyan(),
+            c.active,
+            expired,
+            humansize::format_size(c.bytes, humansize::BINARY).dimmed()
+        );
+    }
+    println!();
+    println!(
+        "  {} {} entries ({} active, {} expired)",
+        "Total:".bold(),
+        stats.total_entries,
+        stats.total_entries - stats.expired_entries,
+        stats.expired_entries
+    );
+    println!(
+        "  {} {}",
+        "Size:".bold(),
+        humansize::format_size(stats.total_bytes, humansize::BINARY)
+    );
+    if let (Some(oldest), Some(newest)) = (&stats.oldest, &stats.newest) {
+        println!("  {} {} .. {}", "Span:".bold(), oldest, newest);
+    }
+    let yes_no = |present: bool| {
+        if present {
+            "yes".green()
+        } else {
+            "no".dimmed()
+        }
+    };
+    println!("  {} {}", "Graph:".bold(), yes_no(stats.has_graph));
+    println!(
+        "  {} {}",
+        "Embeddings:".bold(),
+        yes_no(stats.has_embeddings)
+    );
+
+    Ok(())
+}
+
+// ── Timeline command ────────────────────────────────────────────────────
+
+/// Knowledge entries from the last `days` days (all history when 0),
+/// newest first — the same data as the TUI Timeline screen
+pub fn timeline_entries(
+    memory_dir: &Path,
+    project: Option<&str>,
+    days: u32,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Vec<crate::tui::data::TimelineEntry> {
+    let cutoff = now - chrono::Duration::days(days as i64);
+    crate::tui::data::load_timeline(memory_dir)
+        .into_iter()
+        .filter(|e| project.is_none_or(|p| e.project == p))
+        .filter(|e| {
+            days == 0
+                || chrono::DateTime::parse_from_rfc3339(&e.timestamp)
+                    .is_ok_and(|ts| ts.with_timezone(&chrono::Utc) >= cutoff)
+        })
+        .collect()
+}
+
+pub fn cmd_timeline(project: Option<&str>, days: u32, json: bool) -> Result<()> {
+    use crate::tui::data::date_from_ts;
+
+    let home = dirs::home_dir()
+        .ok_or_else(|| error::MemoryError::Config("Could not determine home directory".into()))?;
+    let memory_dir = home.join("memory");
+    let entries = timeline_entries(&memory_dir, project, days, chrono::Utc::now());
+
+    if json {
+        let items: Vec<_> = entries
+            .iter()
+            .map(|e| {
+                serde_json::json!({
+                    "timestamp": e.timestamp,
+                    "project": e.project,
+                    "category": e.category,
+                    "session_id": e.session_id,
+                    "preview": e.preview,
+                })
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&items)?);
+        return Ok(());
+    }
+
+    let span = if days == 0 {
+        "all time".to_string()
+    } else {
+        format!("last {} day{}", days, if days == 1 { "" } else { "s" })
+    };
+    let scope = project.unwrap_or("all projects");
+    if entries.is_empty() {
+        println!("No knowledge added in {} ({}).", scope, span);
+        return Ok(());
+    }
+
+    println!("{} Timeline: {} ({})", "→".blue(), scope.bold(), span);
+
+    let mut start = 0;
+    while start < entries.len() {
+        let date = date_from_ts(&entries[start].timestamp);
+        let end = entries[start..]
+            .iter()
+            .position(|e| date_from_ts(&e.timestamp) != date)
+            .map_or(entries.len(), |n| start + n);
+        let day = &entries[start..end];
+
+        let sessions: std::collections::HashSet<_> =
+            day.iter().map(|e| (&e.project, &e.session_id)).collect();
+        println!();
+        println!(
+            "{}  {}",
+            if date.is_empty() { "undated" } else { &date }.bold(),
+            format!(
+                "({} entr{}, {} session{})",
+                day.len(),
+                if day.len() == 1 { "y" } else { "ies" },
+                sessions.len(),
+                if sessions.len() == 1 { "" } else { "s" }
+            )
+            .dimmed()
+        );
+        for e in day {
+            let time = e.timestamp.get(11..16).unwrap_or("     ");
+            let project_col = if project.is_some() {
+                String::new()
+            } else {
+                format!("{}  ", e.project)
+            };
+            println!(
+                "  {}  {:<11} {}{}  {}",
+                time.dimmed(),
+                e.category.cyan(),
+                project_col,
+                e.preview,
+                format!("[{}]", e.session_id).dimmed()
+            );
+        }
+        start = end;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_inbox(dir: &TempDir) -> std::path::PathBuf {
+        let project_dir = dir.path().join("knowledge").join("demo");
+        std::fs::create_dir_all(&project_dir).unwrap();
+        let inbox = project_dir.join("inbox.md");
+        std::fs::write(
+            &inbox,
+            "# Inbox\n\n## Session: a1 (2024-01-01T00:00:00Z)\nUse sqlx\n\n## Session: b2 (2024-01-02T00:00:00Z)\nTabs over spaces\n",
+        )
+        .unwrap();
+        inbox
+    }
+
+    #[test]
+    fn test_project_stats_counts_entries() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("decisions.md"),
+            "# Decisions\n\n## Session: a1 (2024-01-01T00:00:00Z)\nUse sqlx\n\n\
+             ## Session: b2 (2024-03-01T00:00:00Z) [ttl:1d]\nShort-lived\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("patterns.md"),
+            "# Patterns\n\n## Session: c3 (2024-02-01T00:00:00Z)\nBuilder\n",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("graph.json"), "{}").unwrap();
+
+        let stats = project_stats(dir.path(), "demo").unwrap();
+        assert_eq!(stats.total_entries, 3);
+        assert_eq!(stats.expired_entries, 1);
+        assert_eq!(stats.categories.len(), 2);
+        assert_eq!(stats.categories[0].category, "decisions");
+        assert_eq!(stats.categories[0].active, 1);
+        assert_eq!(stats.oldest.as_deref(), Some("2024-01-01T00:00:00Z"));
+        assert_eq!(stats.newest.as_deref(), Some("2024-03-01T00:00:00Z"));
+        assert!(stats.has_graph);
+        assert!(!stats.has_embeddings);
+    }
+
+    #[test]
+    fn test_timeline_entries_filters_project_and_days() {
+        let dir = TempDir::new().unwrap();
+        for (project, body) in [
+            (
+                "api",
+                "# Decisions\n\n## Session: new (2024-03-10T09:00:00Z)\nUse sqlx\n\n\
+                 ## Session: old (2024-01-01T00:00:00Z)\nUse diesel\n\n\
+                 ## Session: manual\nHand-added\n",
+            ),
+            (
+                "web",
+                "# Decisions\n\n## Session: w1 (2024-03-09T12:00:00Z)\nUse htmx\n",
+            ),
+        ] {
+            let project_dir = dir.path().join("knowledge").join(project);
+            std::fs::create_dir_all(&project_dir).unwrap();
+            std::fs::write(project_dir.join("decisions.md"), body).unwrap();
+        }
+        let now = chrono::DateTime::parse_from_rfc3339("2024-03-11T00:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+
+        let recent = timeline_entries(dir.path(), None, 7, now);
+        let ids: Vec<&str> = recent.iter().map(|e| e.session_id.as_str()).collect();
+        assert_eq!(ids, ["new", "w1"]);
+
+        let api = timeline_entries(dir.path(), Some("api"), 0, now);
+        assert_eq!(api.len(), 3);
+        assert!(api.iter().all(|e| e.project == "api"));
+    }
+
+    #[test]
+    fn test_promote_inbox_entry_moves_block() {
+        let dir = TempDir::new().unwrap();
+        let inbox = write_inbox(&dir);
+
+        let outcome = promote_inbox_entry(
+            dir.path(),
+            "demo",
+            "a1",
+            Category::Decisions,
+            false,
+            "promoted",
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            outcome,
+            PromoteOutcome::Promoted {
+                target_file: "decisions.md"
+            }
+        );
+
+        let decisions =
+            std::fs::read_to_string(dir.path().join("knowledge/demo/decisions.md")).unwrap();
+        assert!(decisions.contains("promoted:a1"));
+        assert!(decisions.contains("Use sqlx"));
+        let remaining = std::fs::read_to_string(inbox).unwrap();
+        assert!(!remaining.contains("Use sqlx"));
+        assert!(remaining.contains("Tabs over spaces"));
+
+        let missing = promote_inbox_entry(
+            dir.path(),
+            "demo",
+            "a1",
+            Category::Decisions,
+            false,
+            "promoted",
+            None,
+        )
+        .unwrap();
+        assert_eq!(missing, PromoteOutcome::NotFound);
+    }
+
+    #[test]
+    fn test_promote_inbox_entry_to_workflows() {
+        let dir = TempDir::new().unwrap();
+        write_inbox(&dir);
+
+        let outcome = promote_inbox_entry(
+            dir.path(),
+            "demo",
+            "a1",
+            Category::Workflows,
+            false,
+            "promoted",
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            outcome,
+            PromoteOutcome::Promoted {
+                target_file: "workflows.md"
+            }
+        );
+
+        let workflows =
+            std::fs::read_to_string(dir.path().join("knowledge/demo/workflows.md")).unwrap();
+        assert!(workflows.starts_with("# Workflows"));
+        assert!(workflows.contains("Use sqlx"));
+    }
+
+    #[test]
+    fn test_promote_preferences_requires_global() {
+        let dir = TempDir::new().unwrap();
+        write_inbox(&dir);
+
+        assert!(promote_inbox_entry(
+            dir.path(),
+            "demo",
+            "b2",
+            Category::Preferences,
+            false,
+            "promoted",
+            None
+        )
+        .is_err());
+
+        let outcome = promote_inbox_entry(
+            dir.path(),
+            "demo",
+            "b2",
+            Category::Preferences,
+            true,
+            "promoted",
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            outcome,
+            PromoteOutcome::Promoted {
+                target_file: "preferences.md"
+            }
+        );
+        assert!(dir.path().join("knowledge/_global/preferences.md").exists());
+    }
+}
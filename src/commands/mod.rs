@@ -1,5 +1,6 @@
 pub mod ask;
 pub mod auth;
+pub mod backup;
 pub mod consolidate;
 pub mod core;
 pub mod diff;
@@ -1,6 +1,11 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::time::Instant;
 
-use crate::auth::{providers::Provider, resolve_provider};
+use chrono::{DateTime, Utc};
+
+use crate::auth::providers::{gemini_auth, Provider};
+use crate::auth::resolve_provider;
 use crate::error::{MemoryError, Result};
 use crate::llm::client::LlmClient;
 
@@ -75,9 +80,14 @@ pub async fn fetch_models_async(provider: Provider) -> Result<Vec<String>> {
     // Gemini uses its own listing API
     if provider == Provider::Gemini {
         let store = crate::auth::AuthStore::load().unwrap_or_default();
-        let api_key = std::env::var("GEMINI_API_KEY")
+        let (api_key, oauth) = std::env::var("GEMINI_API_KEY")
             .ok()
-            .or_else(|| store.get(Provider::Gemini).map(|c| c.key.clone()))
+            .map(|k| (k, false))
+            .or_else(|| {
+                store
+                    .get(Provider::Gemini)
+                    .map(|c| (c.key.clone(), c.is_oauth()))
+            })
             .ok_or_else(|| MemoryError::Config("GEMINI_API_KEY not set".into()))?;
 
         let client = reqwest::Client::builder()
@@ -85,13 +95,8 @@ pub async fn fetch_models_async(provider: Provider) -> Result<Vec<String>> {
             .build()
             .map_err(|e| MemoryError::Config(e.to_string()))?;
 
-        let resp = client
-            .get(format!(
-                "https://generativelanguage.googleapis.com/v1beta/models?key={}",
-                api_key
-            ))
-            .send()
-            .await?;
+        let request = client.get("https://generativelanguage.googleapis.com/v1beta/models");
+        let resp = gemini_auth(request, &api_key, oauth).send().await?;
 
         let json: serde_json::Value = resp.json().await?;
         let mut models: Vec<String> = json
@@ -171,6 +176,123 @@ pub fn fetch_models_sync(provider: Provider) -> Result<Vec<String>> {
         .block_on(fetch_models_async(provider))
 }
 
+/// How long a fetched model list is reused before `/models` is queried again.
+pub const MODEL_CACHE_TTL_MINUTES: i64 = 60;
+
+/// Model lists from previous fetches, keyed by provider and endpoint, stored
+/// in `~/.config/engram/models.json`.
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+struct ModelCache {
+    #[serde(default)]
+    providers: HashMap<String, CachedModels>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct CachedModels {
+    fetched_at: DateTime<Utc>,
+    models: Vec<String>,
+}
+
+/// A provider's model list and whether it came from the on-disk cache.
+#[derive(Debug)]
+pub struct ModelListing {
+    pub models: Vec<String>,
+    pub fetched_at: DateTime<Utc>,
+    pub cached: bool,
+}
+
+fn model_cache_path() -> Result<PathBuf> {
+    let config_dir = dirs::config_dir()
+        .ok_or_else(|| MemoryError::Config("Could not determine config directory".into()))?;
+    Ok(config_dir.join("engram").join("models.json"))
+}
+
+fn load_model_cache(path: &Path) -> ModelCache {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default()
+}
+
+/// Cache key for a provider's model list: the same provider pointed at another
+/// endpoint (e.g. a second Ollama server) lists different models.
+fn model_cache_key(provider: Provider, endpoint: &str) -> String {
+    format!("{} {}", provider, endpoint.trim_end_matches('/'))
+}
+
+/// Cached models for `provider` at `endpoint`, if fetched within the TTL.
+fn cached_models(
+    path: &Path,
+    provider: Provider,
+    endpoint: &str,
+    now: DateTime<Utc>,
+) -> Option<ModelListing> {
+    let entry = load_model_cache(path)
+        .providers
+        .remove(&model_cache_key(provider, endpoint))?;
+    if now - entry.fetched_at > chrono::Duration::minutes(MODEL_CACHE_TTL_MINUTES) {
+        return None;
+    }
+    Some(ModelListing {
+        models: entry.models,
+        fetched_at: entry.fetched_at,
+        cached: true,
+    })
+}
+
+fn store_models(
+    path: &Path,
+    provider: Provider,
+    endpoint: &str,
+    models: &[String],
+    now: DateTime<Utc>,
+) -> Result<()> {
+    let mut cache = load_model_cache(path);
+    cache.providers.insert(
+        model_cache_key(provider, endpoint),
+        CachedModels {
+            fetched_at: now,
+            models: models.to_vec(),
+        },
+    );
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let json = serde_json::to_string_pretty(&cache)
+        .map_err(|e| MemoryError::Config(format!("Cannot serialize model cache: {}", e)))?;
+    std::fs::write(path, json)?;
+    Ok(())
+}
+
+/// [`fetch_models_sync`] backed by a cache keyed by provider and endpoint. A
+/// fresh cached list is returned without a network call unless `refresh` is
+/// set; successful non-empty fetches are written back to the cache.
+pub fn fetch_models_cached(provider: Provider, refresh: bool) -> Result<ModelListing> {
+    let path = model_cache_path()?;
+    // The fetch below reports resolution errors itself
+    let endpoint = resolve_provider(Some(&provider.to_string()), None, None)
+        .map(|resolved| resolved.endpoint)
+        .unwrap_or_default();
+    let now = Utc::now();
+    if !refresh {
+        if let Some(listing) = cached_models(&path, provider, &endpoint, now) {
+            return Ok(listing);
+        }
+    }
+
+    let models = fetch_models_sync(provider)?;
+    if !models.is_empty() {
+        if let Err(e) = store_models(&path, provider, &endpoint, &models, now) {
+            tracing::warn!(error = %e, "failed to write model cache");
+        }
+    }
+    Ok(ModelListing {
+        models,
+        fetched_at: now,
+        cached: false,
+    })
+}
+
 /// Fetch embedding-capable models for a given provider.
 /// For OpenAI: filters /v1/models for ids containing "embedding".
 /// For Ollama: filters /v1/models for ids containing "embed".
@@ -195,12 +317,13 @@ pub async fn fetch_embed_models_async(provider_name: &str) -> Result<Vec<String>
         "gemini" => {
             // Gemini: query v1beta/models and filter by embedContent support
             let store = crate::auth::AuthStore::load().unwrap_or_default();
-            let api_key = std::env::var("GEMINI_API_KEY")
+            let (api_key, oauth) = std::env::var("GEMINI_API_KEY")
                 .ok()
+                .map(|k| (k, false))
                 .or_else(|| {
                     store
                         .get(crate::auth::providers::Provider::Gemini)
-                        .map(|c| c.key.clone())
+                        .map(|c| (c.key.clone(), c.is_oauth()))
                 })
                 .ok_or_else(|| MemoryError::Config("GEMINI_API_KEY not set".into()))?;
 
@@ -209,13 +332,8 @@ pub async fn fetch_embed_models_async(provider_name: &str) -> Result<Vec<String>
                 .build()
                 .map_err(|e| MemoryError::Config(e.to_string()))?;
 
-            let resp = client
-                .get(format!(
-                    "https://generativelanguage.googleapis.com/v1beta/models?key={}",
-                    api_key
-                ))
-                .send()
-                .await?;
+            let request = client.get("https://generativelanguage.googleapis.com/v1beta/models");
+            let resp = gemini_auth(request, &api_key, oauth).send().await?;
 
             let json: serde_json::Value = resp.json().await?;
             let models: Vec<String> = json
@@ -264,3 +382,37 @@ pub fn test_all_providers_sync() -> Vec<TestResult> {
         .map(|&p| test_provider_sync(p))
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_model_cache_round_trip_and_ttl() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("engram").join("models.json");
+        let now = Utc::now();
+        let openai = "https://api.openai.com/v1";
+        let (local, remote) = ("http://localhost:11434/v1", "http://gpu-box:11434/v1");
+        assert!(cached_models(&path, Provider::OpenAI, openai, now).is_none());
+
+        let models = vec!["gpt-4o".to_string(), "gpt-4o-mini".to_string()];
+        store_models(&path, Provider::OpenAI, openai, &models, now).unwrap();
+        store_models(&path, Provider::Ollama, local, &["llama3".to_string()], now).unwrap();
+
+        let listing = cached_models(&path, Provider::OpenAI, openai, now).unwrap();
+        assert!(listing.cached);
+        assert_eq!(listing.models, models);
+        assert_eq!(
+            cached_models(&path, Provider::Ollama, local, now)
+                .unwrap()
+                .models,
+            ["llama3"]
+        );
+        // Another server for the same provider is a separate entry
+        assert!(cached_models(&path, Provider::Ollama, remote, now).is_none());
+
+        let later = now + chrono::Duration::minutes(MODEL_CACHE_TTL_MINUTES + 1);
+        assert!(cached_models(&path, Provider::OpenAI, openai, later).is_none());
+    }
+}
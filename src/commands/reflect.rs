@@ -478,7 +478,7 @@ pub fn cmd_reflect_all() -> Result<()> {
     }
 
     // Sort: worst score first so problems are visible at the top
-    projects.sort_by(|a, b| a.quality_score.cmp(&b.quality_score));
+    projects.sort_by_key(|a| a.quality_score);
 
     let score_color = |s: u8| match s {
         90..=100 => colored::Color::Green,
@@ -2,6 +2,7 @@ use colored::Colorize;
 
 use crate::config::Config;
 use crate::error::{MemoryError, Result};
+use crate::output::status;
 use crate::sync;
 
 pub fn cmd_sync_push(
@@ -36,15 +37,15 @@ pub fn cmd_sync_push(
             client.create_gist(description, files).await?
         };
 
-        println!(
+        status!(
             "{} Pushed {} knowledge to gist",
             "Done!".green().bold(),
             project
         );
         println!("  Gist ID:  {}", gist.id.cyan());
         println!("  URL:      {}", gist.html_url.cyan());
-        println!("\nTo pull on another machine:");
-        println!(
+        status!("\nTo pull on another machine:");
+        status!(
             "  {}",
             format!("engram sync pull {} {}", project, gist.id).cyan()
         );
@@ -77,14 +78,14 @@ pub fn cmd_sync_pull(config: &Config, project: &str, gist_id: &str, force: bool)
 
         sync::write_knowledge_files(&config.memory_dir, project, &gist.files)?;
 
-        println!(
+        status!(
             "{} Pulled {} knowledge from gist",
             "Done!".green().bold(),
             project
         );
         println!("  {} files synced", gist.files.len());
-        println!("\nView with:");
-        println!("  {}", format!("engram recall {}", project).cyan());
+        status!("\nView with:");
+        status!("  {}", format!("engram recall {}", project).cyan());
 
         Ok(())
     })
@@ -205,13 +206,13 @@ pub fn cmd_sync_history(gist_id: &str, version: Option<&str>) -> Result<()> {
                 println!();
             }
 
-            println!("\nTo view a specific version:");
-            println!(
+            status!("\nTo view a specific version:");
+            status!(
                 "  {}",
                 format!("engram sync history {} --version <version>", gist_id).cyan()
             );
-            println!("\nTo restore a version:");
-            println!(
+            status!("\nTo restore a version:");
+            status!(
                 "  {}",
                 format!(
                     "engram sync pull <project> {}",
@@ -228,6 +229,95 @@ pub fn cmd_sync_history(gist_id: &str, version: Option<&str>) -> Result<()> {
     })
 }
 
+/// Report which sessions differ between local knowledge and a gist or git repo.
+/// Read-only: nothing is written on either side.
+pub fn cmd_sync_status(
+    config: &Config,
+    project: &str,
+    gist_id: Option<&str>,
+    repo: Option<&str>,
+) -> Result<()> {
+    let local = sync::local_sync_files(&config.memory_dir, project)?;
+
+    let (remote, remote_label) = if let Some(id) = gist_id {
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| MemoryError::Config(format!("tokio runtime: {}", e)))?;
+        let gist = rt.block_on(async { sync::GistClient::from_env()?.get_gist(id).await })?;
+        let files = gist
+            .files
+            .into_iter()
+            .filter_map(|(name, file)| file.content.map(|c| (name, c)))
+            .collect();
+        (files, format!("gist {}", id))
+    } else if let Some(repo) = repo {
+        let expanded = shellexpand::tilde(repo);
+        let repo_path = std::path::PathBuf::from(expanded.as_ref());
+        (
+            sync::read_repo_knowledge_files(&repo_path, project)?,
+            format!("repo {}", repo_path.display()),
+        )
+    } else {
+        return Err(MemoryError::Config(
+            "Specify --gist-id or --repo to compare against".into(),
+        ));
+    };
+
+    let statuses = sync::compare_knowledge(&local, &remote);
+
+    println!(
+        "{} {} vs {}\n",
+        "Sync status:".green().bold(),
+        project.cyan(),
+        remote_label
+    );
+
+    if statuses.is_empty() {
+        println!("  No session-based knowledge on either side.");
+        return Ok(());
+    }
+
+    println!(
+        "  {:<16} {:>10} {:>11} {:>9} {:>8}",
+        "File", "local-only", "remote-only", "diverged", "in sync"
+    );
+    for s in &statuses {
+        println!(
+            "  {:<16} {:>10} {:>11} {:>9} {:>8}",
+            s.file, s.local_only, s.remote_only, s.diverged, s.in_sync
+        );
+    }
+
+    let local_only: usize = statuses.iter().map(|s| s.local_only).sum();
+    let remote_only: usize = statuses.iter().map(|s| s.remote_only).sum();
+    let diverged: usize = statuses.iter().map(|s| s.diverged).sum();
+
+    println!();
+    match (local_only > 0, remote_only > 0 || diverged > 0) {
+        (false, false) => println!("{} Local and remote are in sync", "✓".green()),
+        (true, false) => println!(
+            "{} Local is ahead by {} session(s) — push to share them",
+            "→".cyan(),
+            local_only
+        ),
+        (false, true) if diverged == 0 => println!(
+            "{} Remote is ahead by {} session(s) — pull to get them",
+            "←".cyan(),
+            remote_only
+        ),
+        _ => println!(
+            "{} Both sides changed ({} local-only, {} remote-only, {} diverged) — review before pushing or pulling",
+            "!".yellow(),
+            local_only,
+            remote_only,
+            diverged
+        ),
+    }
+
+    Ok(())
+}
+
 pub fn cmd_sync_push_repo(
     config: &Config,
     project: &str,
@@ -245,7 +335,7 @@ pub fn cmd_sync_push_repo(
         repo_path.display()
     );
 
-    sync::push_to_git_repo(
+    let committed = sync::push_to_git_repo(
         &config.memory_dir,
         project,
         &repo_path,
@@ -253,7 +343,12 @@ pub fn cmd_sync_push_repo(
         push_remote,
     )?;
 
-    println!(
+    if !committed {
+        println!("  No knowledge changes to commit");
+        return Ok(());
+    }
+
+    status!(
         "{} Pushed {} knowledge to {}",
         "Done!".green().bold(),
         project,
@@ -273,6 +368,8 @@ pub fn cmd_sync_pull_repo(
     repo: &str,
     fetch_remote: bool,
     branch: &str,
+    merge: bool,
+    force: bool,
 ) -> Result<()> {
     let expanded = shellexpand::tilde(repo);
     let repo_path = std::path::PathBuf::from(expanded.as_ref());
@@ -284,23 +381,74 @@ pub fn cmd_sync_pull_repo(
         repo_path.display()
     );
 
-    sync::pull_from_git_repo(
+    let mode = if force {
+        sync::PullMode::Force
+    } else if merge {
+        sync::PullMode::Merge
+    } else {
+        sync::PullMode::Safe
+    };
+    let report = sync::pull_from_git_repo(
         &config.memory_dir,
         project,
         &repo_path,
         fetch_remote,
         branch,
+        mode,
     )?;
 
-    println!(
+    let mut skipped = 0;
+    for (file, action) in &report {
+        match action {
+            sync::PullAction::Unchanged => {}
+            sync::PullAction::Copied => println!("  {} {}", "updated".green(), file),
+            sync::PullAction::Merged { added, conflicts } => {
+                println!(
+                    "  {} {} (+{} sessions{})",
+                    "merged".cyan(),
+                    file,
+                    added,
+                    if *conflicts > 0 {
+                        format!(", {} conflicting kept local", conflicts)
+                    } else {
+                        String::new()
+                    }
+                );
+            }
+            sync::PullAction::Skipped {
+                local_only,
+                diverged,
+            } => {
+                skipped += 1;
+                println!(
+                    "  {} {} ({} local-only, {} diverged session(s) would be lost)",
+                    "conflict".yellow(),
+                    file,
+                    local_only,
+                    diverged
+                );
+            }
+        }
+    }
+
+    if skipped > 0 {
+        println!(
+            "\n{} {} file(s) left untouched. Re-run with --merge to combine sessions or --force to overwrite.",
+            "Warning:".yellow(),
+            skipped
+        );
+        return Ok(());
+    }
+
+    status!(
         "{} Pulled {} knowledge from {}",
         "Done!".green().bold(),
         project,
         repo_path.display()
     );
 
-    println!("\nView with:");
-    println!("  {}", format!("engram recall {}", project).cyan());
+    status!("\nView with:");
+    status!("  {}", format!("engram recall {}", project).cyan());
 
     Ok(())
 }
@@ -317,11 +465,11 @@ pub fn cmd_sync_init_repo(repo: &str) -> Result<()> {
 
     sync::init_git_repo(&repo_path)?;
 
-    println!("{} Git repository initialized", "Done!".green().bold());
+    status!("{} Git repository initialized", "Done!".green().bold());
     println!("  Path: {}", repo_path.display().to_string().cyan());
-    println!("\nNext steps:");
-    println!("  1. {} (optional)", "git remote add origin <url>".dimmed());
-    println!(
+    status!("\nNext steps:");
+    status!("  1. {} (optional)", "git remote add origin <url>".dimmed());
+    status!(
         "  2. {}",
         format!("engram sync push-repo <project> {}", repo).cyan()
     );
@@ -1,6 +1,7 @@
 use colored::Colorize;
 
 use crate::error::Result;
+use crate::output::status;
 use crate::vcs::{MemoryVcs, CATEGORIES};
 
 fn memory_dir() -> Result<std::path::PathBuf> {
@@ -41,7 +42,7 @@ pub fn cmd_mem_init(project: Option<&str>) -> Result<()> {
     }
 
     vcs.init()?;
-    println!(
+    status!(
         "{} Initialized VCS for '{}' at {}",
         "Done!".green().bold(),
         project,
@@ -143,7 +144,7 @@ pub fn cmd_mem_stage(project: &str, sessions: &[String], all: bool) -> Result<()
         if n == 0 {
             println!("{} Nothing new to stage.", "Info:".cyan());
         } else {
-            println!("{} Staged {} new session(s).", "Done!".green().bold(), n);
+            status!("{} Staged {} new session(s).", "Done!".green().bold(), n);
         }
         return Ok(());
     }
@@ -158,7 +159,7 @@ pub fn cmd_mem_stage(project: &str, sessions: &[String], all: bool) -> Result<()
 
     let ids: Vec<&str> = sessions.iter().map(|s| s.as_str()).collect();
     let n = vcs.stage_sessions(&ids)?;
-    println!("{} Staged {} session(s).", "Done!".green().bold(), n);
+    status!("{} Staged {} session(s).", "Done!".green().bold(), n);
     Ok(())
 }
 
@@ -282,7 +283,7 @@ pub fn cmd_mem_branch(project: &str, create: Option<&str>, delete: Option<&str>)
 
     if let Some(name) = create {
         vcs.create_branch(name, None)?;
-        println!(
+        status!(
             "{} Created branch '{}'.",
             "Done!".green().bold(),
             name.cyan()
@@ -292,7 +293,7 @@ pub fn cmd_mem_branch(project: &str, create: Option<&str>, delete: Option<&str>)
 
     if let Some(name) = delete {
         vcs.delete_branch(name)?;
-        println!(
+        status!(
             "{} Deleted branch '{}'.",
             "Done!".green().bold(),
             name.cyan()
@@ -327,6 +328,48 @@ pub fn cmd_mem_branch(project: &str, create: Option<&str>, delete: Option<&str>)
     Ok(())
 }
 
+// ── Tag ───────────────────────────────────────────────────────────────────
+
+pub fn cmd_mem_tag(
+    project: &str,
+    name: Option<&str>,
+    at: Option<&str>,
+    delete: Option<&str>,
+) -> Result<()> {
+    let mem_dir = memory_dir()?;
+    let vcs = MemoryVcs::new(&mem_dir, project);
+
+    if let Some(name) = name {
+        let hash = vcs.create_tag(name, at)?;
+        status!(
+            "{} Tagged {} as '{}'.",
+            "Done!".green().bold(),
+            hash[..hash.len().min(8)].yellow(),
+            name.cyan()
+        );
+        return Ok(());
+    }
+
+    if let Some(name) = delete {
+        vcs.delete_tag(name)?;
+        status!("{} Deleted tag '{}'.", "Done!".green().bold(), name.cyan());
+        return Ok(());
+    }
+
+    let tags = vcs.list_tags()?;
+    if tags.is_empty() {
+        println!("{}", "(no tags yet)".dimmed());
+    }
+    for t in &tags {
+        println!(
+            "{} {}",
+            t.name,
+            format!("({})", &t.hash[..t.hash.len().min(8)]).dimmed()
+        );
+    }
+    Ok(())
+}
+
 // ── Checkout ──────────────────────────────────────────────────────────────
 
 pub fn cmd_mem_checkout(project: &str, target: &str, dry_run: bool, force: bool) -> Result<()> {
@@ -353,7 +396,7 @@ pub fn cmd_mem_checkout(project: &str, target: &str, dry_run: bool, force: bool)
             );
         }
     } else {
-        println!(
+        status!(
             "{} Switched from {} → {}",
             "Done!".green().bold(),
             result.previous_branch.yellow(),
@@ -437,13 +480,18 @@ pub fn cmd_mem_diff(
     project: &str,
     from: Option<&str>,
     to: Option<&str>,
+    staged: bool,
     category: Option<&str>,
 ) -> Result<()> {
     let mem_dir = memory_dir()?;
     let vcs = MemoryVcs::new(&mem_dir, project);
 
     let from_label = from.unwrap_or("HEAD");
-    let to_label = to.unwrap_or("working");
+    let to_label = if staged {
+        "staged"
+    } else {
+        to.unwrap_or("working")
+    };
     println!(
         "diff {} {} → {}",
         project.cyan(),
@@ -457,7 +505,11 @@ pub fn cmd_mem_diff(
     }
     println!();
 
-    let output = vcs.diff(from, to, category)?;
+    let output = if staged {
+        vcs.diff_staged(category)?
+    } else {
+        vcs.diff(from, to, category)?
+    };
     print!("{}", output);
     Ok(())
 }
@@ -1,8 +1,9 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::auth;
 use crate::auth::providers::ResolvedProvider;
 use crate::error::{MemoryError, Result};
+use crate::llm::prompts::PromptSet;
 
 /// Reserved pseudo-project name for cross-project global knowledge
 pub const GLOBAL_PROJECT: &str = "global";
@@ -18,6 +19,7 @@ pub const CATEGORIES: &[&str] = &[
     "insights",
     "questions",
     "procedures",
+    "workflows",
 ];
 
 /// Canonical knowledge category filenames (with .md extension)
@@ -29,8 +31,99 @@ pub const CATEGORY_FILES: &[&str] = &[
     "insights.md",
     "questions.md",
     "procedures.md",
+    "workflows.md",
 ];
 
+/// A knowledge category accepted on the command line (`add`, `promote`, `drain`, `diff`).
+///
+/// Parsed by clap, so an unknown name is a usage error rather than a runtime failure.
+/// `Preferences` always lives in the global knowledge dir.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Category {
+    Decisions,
+    Solutions,
+    Patterns,
+    Bugs,
+    Insights,
+    Questions,
+    Procedures,
+    Workflows,
+    Preferences,
+}
+
+impl Category {
+    pub const ALL: &'static [Category] = &[
+        Category::Decisions,
+        Category::Solutions,
+        Category::Patterns,
+        Category::Bugs,
+        Category::Insights,
+        Category::Questions,
+        Category::Procedures,
+        Category::Workflows,
+        Category::Preferences,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Category::Decisions => "decisions",
+            Category::Solutions => "solutions",
+            Category::Patterns => "patterns",
+            Category::Bugs => "bugs",
+            Category::Insights => "insights",
+            Category::Questions => "questions",
+            Category::Procedures => "procedures",
+            Category::Workflows => "workflows",
+            Category::Preferences => "preferences",
+        }
+    }
+
+    /// Knowledge file holding this category (e.g. `decisions.md`).
+    pub fn file_name(self) -> &'static str {
+        match self {
+            Category::Decisions => "decisions.md",
+            Category::Solutions => "solutions.md",
+            Category::Patterns => "patterns.md",
+            Category::Bugs => "bugs.md",
+            Category::Insights => "insights.md",
+            Category::Questions => "questions.md",
+            Category::Procedures => "procedures.md",
+            Category::Workflows => "workflows.md",
+            Category::Preferences => "preferences.md",
+        }
+    }
+
+    /// `# Title` written at the top of a new knowledge file.
+    pub fn title(self) -> &'static str {
+        match self {
+            Category::Decisions => "Decisions",
+            Category::Solutions => "Solutions",
+            Category::Patterns => "Patterns",
+            Category::Bugs => "Bugs",
+            Category::Insights => "Insights",
+            Category::Questions => "Questions",
+            Category::Procedures => "Procedures",
+            Category::Workflows => "Workflows",
+            Category::Preferences => "Preferences",
+        }
+    }
+
+    /// Case-insensitive lookup by name, for categories read from knowledge files.
+    pub fn parse(name: &str) -> Option<Self> {
+        let name = name.trim();
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|c| c.as_str().eq_ignore_ascii_case(name))
+    }
+}
+
+impl std::fmt::Display for Category {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Minimum active block count before daemon triggers distillation
 pub const DISTILL_THRESHOLD: usize = 30;
 /// Age cutoff for daemon distillation (days)
@@ -45,6 +138,116 @@ pub const STRENGTH_RECALL_BOOST: f32 = 0.3;
 /// Maximum FadeMem strength (clamp ceiling)
 pub const STRENGTH_MAX: f32 = 5.0;
 
+/// Environment variables overriding the Claude projects directory, in order
+pub const PROJECTS_DIR_ENV_VARS: &[&str] = &["ENGRAM_PROJECTS_DIR", "CLAUDE_MEMORY_PROJECTS_DIR"];
+
+/// Optional settings read from `~/.config/engram/config.json`
+#[derive(Debug, Default, serde::Deserialize)]
+pub struct ConfigFile {
+    /// Where Claude Code stores project data (default `~/.claude/projects`)
+    #[serde(default, deserialize_with = "or_warn")]
+    pub claude_projects_dir: Option<PathBuf>,
+    /// Days removed knowledge stays in the trash before it is purged (default 30)
+    #[serde(default)]
+    pub trash_retention_days: Option<u32>,
+    /// Default `ingest --summary-style` (brief or detailed)
+    #[serde(default)]
+    pub summary_style: Option<crate::renderer::markdown::SummaryStyle>,
+    /// Default for `ingest --summary-code`
+    #[serde(default)]
+    pub summary_code: Option<bool>,
+}
+
+/// Deserialize an optional setting, reporting an invalid value and leaving
+/// the setting unset instead of rejecting the whole file.
+fn or_warn<'de, D, T>(deserializer: D) -> std::result::Result<Option<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::de::DeserializeOwned,
+{
+    let value = <serde_json::Value as serde::Deserialize>::deserialize(deserializer)?;
+    match serde_json::from_value(value) {
+        Ok(parsed) => Ok(parsed),
+        Err(e) => {
+            tracing::warn!(error = %e, "ignoring invalid value in config.json");
+            Ok(None)
+        }
+    }
+}
+
+impl ConfigFile {
+    /// Path to config.json, next to auth.json
+    pub fn path() -> Result<PathBuf> {
+        let config_dir = dirs::config_dir()
+            .ok_or_else(|| MemoryError::Config("Could not determine config directory".into()))?;
+        Ok(config_dir.join("engram").join("config.json"))
+    }
+
+    /// Load from disk, returning default if file doesn't exist
+    pub fn load() -> Result<Self> {
+        let path = Self::path()?;
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let data = std::fs::read_to_string(&path)?;
+        serde_json::from_str(&data)
+            .map_err(|e| MemoryError::Config(format!("Invalid {}: {}", path.display(), e)))
+    }
+}
+
+/// Directory Claude Code stores project data in: an environment override,
+/// then `claude_projects_dir` from config.json, then `~/.claude/projects`.
+pub fn claude_projects_dir(home: &Path) -> Result<PathBuf> {
+    let env = PROJECTS_DIR_ENV_VARS.iter().find_map(|&var| {
+        std::env::var(var)
+            .ok()
+            .filter(|v| !v.trim().is_empty())
+            .map(|v| (var, v))
+    });
+    let file = match ConfigFile::load() {
+        Ok(config) => config.claude_projects_dir,
+        Err(e) => {
+            tracing::warn!(error = %e, "ignoring claude_projects_dir from config.json");
+            None
+        }
+    };
+    Ok(select_projects_dir(home, env, file))
+}
+
+/// Pick the projects directory from the overrides. An override that isn't an
+/// existing directory is reported and the default is used instead.
+fn select_projects_dir(home: &Path, env: Option<(&str, String)>, file: Option<PathBuf>) -> PathBuf {
+    let default = home.join(".claude").join("projects");
+    let (source, dir) = match (env, file) {
+        (Some((var, value)), _) => (var.to_string(), PathBuf::from(value)),
+        (None, Some(dir)) => ("claude_projects_dir in config.json".to_string(), dir),
+        (None, None) => return default,
+    };
+    let dir = match dir.strip_prefix("~") {
+        Ok(rest) => home.join(rest),
+        Err(_) => dir,
+    };
+
+    if dir.is_dir() {
+        dir
+    } else {
+        tracing::warn!(
+            source = %source,
+            "{} is not a directory; using {}",
+            dir.display(),
+            default.display()
+        );
+        default
+    }
+}
+
+/// The running engram binary, for spawning subcommands and writing hook
+/// scripts, so they work whatever name it was installed under. Falls back to
+/// `engram` on PATH when the executable path can't be determined.
+pub fn engram_exe() -> PathBuf {
+    std::env::current_exe().unwrap_or_else(|_| PathBuf::from("engram"))
+}
+
 #[derive(Debug, Clone)]
 pub struct Config {
     /// Where Claude stores project data
@@ -53,6 +256,8 @@ pub struct Config {
     pub memory_dir: PathBuf,
     /// Resolved LLM provider configuration
     pub llm: ResolvedProvider,
+    /// Extraction/context prompts, with overrides from `<memory_dir>/prompts/`
+    pub prompts: PromptSet,
 }
 
 impl Config {
@@ -60,7 +265,7 @@ impl Config {
         let home = dirs::home_dir()
             .ok_or_else(|| MemoryError::Config("Could not determine home directory".into()))?;
 
-        let claude_projects_dir = home.join(".claude").join("projects");
+        let claude_projects_dir = claude_projects_dir(&home)?;
         let memory_dir = home.join("memory");
 
         // Legacy env var overrides still work
@@ -68,11 +273,81 @@ impl Config {
         let env_model = std::env::var("ENGRAM_LLM_MODEL").ok();
 
         let llm = auth::resolve_provider(provider_override, env_endpoint, env_model)?;
+        let prompts = PromptSet::load(&memory_dir)?;
 
         Ok(Config {
             claude_projects_dir,
             memory_dir,
             llm,
+            prompts,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::ValueEnum;
+
+    #[test]
+    fn test_category_names_and_files_agree() {
+        for category in Category::ALL {
+            assert_eq!(category.file_name(), format!("{}.md", category.as_str()));
+            assert_eq!(Category::parse(category.as_str()), Some(*category));
+            let value = category.to_possible_value().unwrap();
+            assert_eq!(value.get_name(), category.as_str());
+        }
+        assert_eq!(Category::parse(" Workflows "), Some(Category::Workflows));
+        assert_eq!(Category::parse("context"), None);
+    }
+
+    #[test]
+    fn test_invalid_projects_dir_setting_is_ignored() {
+        let config: ConfigFile =
+            serde_json::from_str(r#"{"claude_projects_dir": 42, "trash_retention_days": 7}"#)
+                .unwrap();
+        assert_eq!(config.claude_projects_dir, None);
+        assert_eq!(config.trash_retention_days, Some(7));
+
+        let config: ConfigFile =
+            serde_json::from_str(r#"{"claude_projects_dir": "/data/projects"}"#).unwrap();
+        assert_eq!(
+            config.claude_projects_dir,
+            Some(PathBuf::from("/data/projects"))
+        );
+    }
+
+    #[test]
+    fn test_select_projects_dir() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let home = temp.path();
+        let default = home.join(".claude").join("projects");
+        let backup = home.join("backup");
+        std::fs::create_dir_all(&backup).unwrap();
+        let env = |value: &str| Some(("ENGRAM_PROJECTS_DIR", value.to_string()));
+
+        assert_eq!(select_projects_dir(home, None, None), default);
+        assert_eq!(
+            select_projects_dir(home, None, Some(backup.clone())),
+            backup
+        );
+        assert_eq!(
+            select_projects_dir(home, None, Some(PathBuf::from("~/backup"))),
+            backup
+        );
+        // Environment wins over the config file
+        assert_eq!(
+            select_projects_dir(home, env(backup.to_str().unwrap()), Some(home.join("x"))),
+            backup
+        );
+        // Missing directories fall back to the default
+        assert_eq!(
+            select_projects_dir(home, env("/does/not/exist"), Some(backup.clone())),
+            default
+        );
+        assert_eq!(
+            select_projects_dir(home, None, Some(home.join("missing"))),
+            default
+        );
+    }
+}
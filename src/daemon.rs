@@ -1,10 +1,11 @@
 use std::fs;
 use std::io::{BufRead, BufReader};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 use std::thread;
 use std::time::Duration;
 
+use chrono::{DateTime, Utc};
 use colored::Colorize;
 use serde::{Deserialize, Serialize};
 
@@ -23,6 +24,28 @@ fn cfg_file(config: &Config) -> PathBuf {
     config.memory_dir.join("daemon.cfg")
 }
 
+fn heartbeat_file(config: &Config) -> PathBuf {
+    config.memory_dir.join("daemon.status")
+}
+
+/// Accepted ingest interval range, in minutes.
+const MIN_INTERVAL_MINS: u64 = 1;
+const MAX_INTERVAL_MINS: u64 = 1440;
+
+/// Grace period before a sleeping daemon that missed its next run is reported as overdue.
+const OVERDUE_GRACE_MINS: i64 = 5;
+
+fn validate_interval(interval: u64) -> Result<u64> {
+    if (MIN_INTERVAL_MINS..=MAX_INTERVAL_MINS).contains(&interval) {
+        Ok(interval)
+    } else {
+        Err(MemoryError::Config(format!(
+            "Invalid daemon interval: {} minutes (must be {}-{})",
+            interval, MIN_INTERVAL_MINS, MAX_INTERVAL_MINS
+        )))
+    }
+}
+
 fn read_pid(config: &Config) -> Option<u32> {
     let path = pid_file(config);
     let contents = fs::read_to_string(&path).ok()?;
@@ -61,6 +84,51 @@ fn read_daemon_cfg(config: &Config) -> Option<DaemonCfg> {
     serde_json::from_str(&contents).ok()
 }
 
+/// Heartbeat written by the run loop after every state change, read back by
+/// `daemon status` and the TUI daemon screen.
+#[derive(Serialize, Deserialize)]
+pub struct DaemonHeartbeat {
+    pub pid: u32,
+    pub interval: u64,
+    /// "ingesting" or "sleeping"
+    pub state: String,
+    pub last_ingest: Option<DateTime<Utc>>,
+    pub last_result: Option<String>,
+    pub next_run: Option<DateTime<Utc>>,
+}
+
+fn write_heartbeat(config: &Config, heartbeat: &DaemonHeartbeat) {
+    if let Ok(json) = serde_json::to_string(heartbeat) {
+        let _ = fs::write(heartbeat_file(config), json);
+    }
+}
+
+fn read_heartbeat(config: &Config) -> Option<DaemonHeartbeat> {
+    load_heartbeat(&config.memory_dir)
+}
+
+/// Read the heartbeat from a memory directory, if the daemon has written one
+pub fn load_heartbeat(memory_dir: &Path) -> Option<DaemonHeartbeat> {
+    let contents = fs::read_to_string(memory_dir.join("daemon.status")).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+/// Interval for the next sleep: the persisted value if it is valid, so
+/// `daemon set-interval` takes effect without a restart.
+fn effective_interval(config: &Config, fallback: u64) -> u64 {
+    read_daemon_cfg(config)
+        .and_then(|c| validate_interval(c.interval).ok())
+        .unwrap_or(fallback)
+}
+
+/// True when a sleeping daemon's scheduled run is more than the grace period in the past.
+pub fn is_overdue(heartbeat: &DaemonHeartbeat, now: DateTime<Utc>) -> bool {
+    heartbeat.state == "sleeping"
+        && heartbeat
+            .next_run
+            .is_some_and(|next| now > next + chrono::Duration::minutes(OVERDUE_GRACE_MINS))
+}
+
 /// Rotate log file: if > max_lines, keep only the last keep_lines lines.
 fn rotate_log_if_needed(log_path: &PathBuf, max_lines: usize, keep_lines: usize) {
     let contents = match fs::read_to_string(log_path) {
@@ -90,6 +158,8 @@ fn count_active_blocks(knowledge_dir: &std::path::Path) -> usize {
 }
 
 pub fn cmd_daemon_start(config: &Config, interval: u64, provider: Option<&str>) -> Result<()> {
+    validate_interval(interval)?;
+
     // Check if already running
     if let Some(pid) = read_pid(config) {
         if is_running(pid) {
@@ -104,7 +174,7 @@ pub fn cmd_daemon_start(config: &Config, interval: u64, provider: Option<&str>)
 
     let log_path = log_file(config);
 
-    let mut cmd = Command::new("engram");
+    let mut cmd = Command::new(crate::config::engram_exe());
     cmd.arg("daemon").arg("run");
     cmd.arg("--interval").arg(interval.to_string());
     if let Some(p) = provider {
@@ -222,6 +292,9 @@ pub fn cmd_daemon_status(config: &Config) -> Result<()> {
                     println!("  Provider: {}", p);
                 }
             }
+            if let Some(hb) = read_heartbeat(config) {
+                print_heartbeat(&hb);
+            }
             println!("  Logs: {}", log_file(config).display());
         }
         Some(pid) => {
@@ -245,6 +318,50 @@ pub fn cmd_daemon_status(config: &Config) -> Result<()> {
     Ok(())
 }
 
+fn print_heartbeat(hb: &DaemonHeartbeat) {
+    let fmt = |t: DateTime<Utc>| {
+        t.with_timezone(&chrono::Local)
+            .format("%Y-%m-%d %H:%M")
+            .to_string()
+    };
+    println!("  State: {}", hb.state);
+    if let Some(last) = hb.last_ingest {
+        println!(
+            "  Last ingest: {} ({})",
+            fmt(last),
+            hb.last_result.as_deref().unwrap_or("unknown")
+        );
+    }
+    if let Some(next) = hb.next_run {
+        if is_overdue(hb, Utc::now()) {
+            println!(
+                "  Next run: {} {}",
+                fmt(next),
+                "(overdue — check the logs)".yellow()
+            );
+        } else {
+            println!("  Next run: {}", fmt(next));
+        }
+    }
+}
+
+/// Persist a new ingest interval. A running daemon picks it up after its current cycle.
+pub fn cmd_daemon_set_interval(config: &Config, interval: u64) -> Result<()> {
+    validate_interval(interval)?;
+    let provider = read_daemon_cfg(config).and_then(|c| c.provider);
+    write_daemon_cfg(config, interval, provider.as_deref())?;
+
+    println!(
+        "{} Daemon interval set to every {} minutes",
+        "engram:".cyan().bold(),
+        interval
+    );
+    if read_pid(config).is_some_and(is_running) {
+        println!("  The running daemon applies it after the current cycle.");
+    }
+    Ok(())
+}
+
 pub fn cmd_daemon_logs(config: &Config, lines: usize, follow: bool) -> Result<()> {
     let log_path = log_file(config);
 
@@ -304,17 +421,11 @@ fn heal_hooks_if_needed(log: &dyn Fn(&str)) {
     let Some(home) = dirs::home_dir() else {
         return;
     };
-    let settings_path = home.join(".claude").join("settings.json");
-    let registered = settings_path
-        .exists()
-        .then(|| std::fs::read_to_string(&settings_path).ok())
-        .flatten()
-        .map(|c| c.contains("engram"))
-        .unwrap_or(false);
-
-    if !registered {
+    if crate::commands::hooks::registered_hook_events(&home).is_empty() {
         log("hooks drift detected — reinstalling engram hooks");
-        let status = Command::new("engram").args(["hooks", "install"]).status();
+        let status = Command::new(crate::config::engram_exe())
+            .args(["hooks", "install"])
+            .status();
         match status {
             Ok(s) if s.success() => log("  hooks install — ok"),
             Ok(_) => log("  hooks install — exited with error"),
@@ -327,6 +438,8 @@ fn heal_hooks_if_needed(log: &dyn Fn(&str)) {
 pub fn cmd_daemon_run(config: &Config, interval_mins: u64, provider: Option<&str>) -> Result<()> {
     use chrono::Local;
 
+    let mut interval_mins = validate_interval(interval_mins)?;
+
     let log = |msg: &str| {
         println!("[{}] {}", Local::now().format("%Y-%m-%d %H:%M:%S"), msg);
     };
@@ -341,20 +454,33 @@ pub fn cmd_daemon_run(config: &Config, interval_mins: u64, provider: Option<&str
     log(&format!("  Interval: {} minutes", interval_mins));
     log(&format!("  Memory dir: {}", config.memory_dir.display()));
 
-    let interval = Duration::from_secs(interval_mins * 60);
     let timeout = Duration::from_secs(7200); // 2 hours
 
-    // Write our own PID (in case start didn't, e.g. direct invocation)
+    // Write our own PID and config (in case start didn't, e.g. direct invocation)
     let pid = std::process::id();
     let _ = fs::write(pid_file(config), pid.to_string());
+    write_daemon_cfg(config, interval_mins, provider)?;
+
+    let mut heartbeat = DaemonHeartbeat {
+        pid,
+        interval: interval_mins,
+        state: "ingesting".to_string(),
+        last_ingest: None,
+        last_result: None,
+        next_run: None,
+    };
 
     let log_path = log_file(config);
 
     loop {
+        heartbeat.state = "ingesting".to_string();
+        heartbeat.next_run = None;
+        write_heartbeat(config, &heartbeat);
+
         heal_hooks_if_needed(&log);
         log("Running ingest...");
 
-        let mut cmd = Command::new("engram");
+        let mut cmd = Command::new(crate::config::engram_exe());
         cmd.arg("ingest");
         if let Some(p) = provider {
             cmd.arg("--provider").arg(p);
@@ -382,11 +508,22 @@ pub fn cmd_daemon_run(config: &Config, interval_mins: u64, provider: Option<&str
                 };
 
                 let ingest_ok = matches!(exit_status, Some(ref s) if s.success());
-                match exit_status {
-                    Some(s) if s.success() => log("Ingest complete"),
-                    Some(_) => log("Ingest exited with error"),
-                    None => log("Ingest killed (timeout or wait error)"),
-                }
+                let result = match exit_status {
+                    Some(s) if s.success() => {
+                        log("Ingest complete");
+                        "ok"
+                    }
+                    Some(_) => {
+                        log("Ingest exited with error");
+                        "error"
+                    }
+                    None => {
+                        log("Ingest killed (timeout or wait error)");
+                        "killed"
+                    }
+                };
+                heartbeat.last_ingest = Some(Utc::now());
+                heartbeat.last_result = Some(result.to_string());
 
                 // After a successful ingest, refresh MEMORY.md for every known project
                 if ingest_ok {
@@ -405,7 +542,7 @@ pub fn cmd_daemon_run(config: &Config, interval_mins: u64, provider: Option<&str
                     } else {
                         log(&format!("Injecting {} project(s)...", projects.len()));
                         for project in &projects {
-                            let mut inject_cmd = Command::new("engram");
+                            let mut inject_cmd = Command::new(crate::config::engram_exe());
                             inject_cmd.arg("inject").arg(project);
                             match inject_cmd.output() {
                                 Ok(out) if out.status.success() => {
@@ -434,7 +571,7 @@ pub fn cmd_daemon_run(config: &Config, interval_mins: u64, provider: Option<&str
                                 "  distill {} ({} blocks > {} threshold)",
                                 project, active_count, distill_threshold
                             ));
-                            let mut forget_cmd = Command::new("engram");
+                            let mut forget_cmd = Command::new(crate::config::engram_exe());
                             forget_cmd.args([
                                 "forget",
                                 project,
@@ -453,7 +590,7 @@ pub fn cmd_daemon_run(config: &Config, interval_mins: u64, provider: Option<&str
                                 }
                                 Err(e) => log(&format!("    forget --stale — spawn failed: {}", e)),
                             }
-                            let mut regen_cmd = Command::new("engram");
+                            let mut regen_cmd = Command::new(crate::config::engram_exe());
                             regen_cmd.args(["regen", project]);
                             match regen_cmd.output() {
                                 Ok(out) if out.status.success() => {
@@ -473,7 +610,7 @@ pub fn cmd_daemon_run(config: &Config, interval_mins: u64, provider: Option<&str
 
                     // Doctor pass: fix stale context / missing embeddings per project
                     for project in &projects {
-                        let mut doctor_cmd = Command::new("engram");
+                        let mut doctor_cmd = Command::new(crate::config::engram_exe());
                         doctor_cmd.args(["doctor", project, "--fix"]);
                         match doctor_cmd.output() {
                             Ok(out) if out.status.success() => {
@@ -514,25 +651,39 @@ pub fn cmd_daemon_run(config: &Config, interval_mins: u64, provider: Option<&str
                             scored += 1;
                         }
                     }
-                    if scored > 0 {
+                    if let Some(avg) = total_score.checked_div(scored) {
                         log(&format!(
                             "  avg quality {}/100 across {} project(s)",
-                            total_score / scored,
-                            scored
+                            avg, scored
                         ));
                     }
                 }
             }
             Err(e) => {
                 log(&format!("Failed to run ingest: {}", e));
+                heartbeat.last_ingest = Some(Utc::now());
+                heartbeat.last_result = Some("spawn failed".to_string());
             }
         }
 
         // Rotate log if needed (> 5000 lines -> keep last 2500)
         rotate_log_if_needed(&log_path, 5000, 2500);
 
+        let next_interval = effective_interval(config, interval_mins);
+        if next_interval != interval_mins {
+            log(&format!(
+                "Interval changed: {} -> {} minutes",
+                interval_mins, next_interval
+            ));
+            interval_mins = next_interval;
+        }
+        heartbeat.interval = interval_mins;
+        heartbeat.state = "sleeping".to_string();
+        heartbeat.next_run = Some(Utc::now() + chrono::Duration::minutes(interval_mins as i64));
+        write_heartbeat(config, &heartbeat);
+
         log(&format!("Sleeping {} minutes...", interval_mins));
-        thread::sleep(interval);
+        thread::sleep(Duration::from_secs(interval_mins * 60));
     }
 }
 
@@ -551,7 +702,9 @@ mod tests {
                 endpoint: "https://api.anthropic.com".to_string(),
                 model: "claude-haiku-4-5-20251001".to_string(),
                 api_key: None,
+                oauth: false,
             },
+            prompts: Default::default(),
         }
     }
 
@@ -665,4 +818,58 @@ mod tests {
         let config = make_test_config(&dir);
         assert!(read_daemon_cfg(&config).is_none());
     }
+
+    #[test]
+    fn test_validate_interval_bounds() {
+        assert!(validate_interval(0).is_err());
+        assert_eq!(validate_interval(1).unwrap(), 1);
+        assert_eq!(validate_interval(1440).unwrap(), 1440);
+        assert!(validate_interval(1441).is_err());
+    }
+
+    #[test]
+    fn test_set_interval_keeps_provider_and_feeds_run_loop() {
+        let dir = TempDir::new().unwrap();
+        let config = make_test_config(&dir);
+
+        write_daemon_cfg(&config, 15, Some("ollama")).unwrap();
+        cmd_daemon_set_interval(&config, 45).unwrap();
+
+        let cfg = read_daemon_cfg(&config).unwrap();
+        assert_eq!(cfg.interval, 45);
+        assert_eq!(cfg.provider.as_deref(), Some("ollama"));
+        assert_eq!(effective_interval(&config, 15), 45);
+
+        assert!(cmd_daemon_set_interval(&config, 0).is_err());
+        // An invalid hand-edited value falls back to the running interval
+        fs::write(cfg_file(&config), r#"{"interval":0,"provider":null}"#).unwrap();
+        assert_eq!(effective_interval(&config, 15), 15);
+    }
+
+    #[test]
+    fn test_heartbeat_overdue_only_when_sleeping_past_grace() {
+        let dir = TempDir::new().unwrap();
+        let config = make_test_config(&dir);
+        let now = Utc::now();
+
+        let mut hb = DaemonHeartbeat {
+            pid: 1,
+            interval: 15,
+            state: "sleeping".to_string(),
+            last_ingest: Some(now),
+            last_result: Some("ok".to_string()),
+            next_run: Some(now - chrono::Duration::minutes(OVERDUE_GRACE_MINS + 1)),
+        };
+        write_heartbeat(&config, &hb);
+        let loaded = read_heartbeat(&config).unwrap();
+        assert_eq!(loaded.last_result.as_deref(), Some("ok"));
+        assert!(is_overdue(&loaded, now));
+
+        hb.next_run = Some(now + chrono::Duration::minutes(10));
+        assert!(!is_overdue(&hb, now));
+
+        hb.state = "ingesting".to_string();
+        hb.next_run = Some(now - chrono::Duration::hours(1));
+        assert!(!is_overdue(&hb, now));
+    }
 }
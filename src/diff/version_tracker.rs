@@ -17,6 +17,15 @@ pub struct KnowledgeVersion {
     pub size_bytes: usize,
 }
 
+/// Result of [`VersionTracker::prune_versions`]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PruneReport {
+    /// Number of versions removed
+    pub removed: usize,
+    /// Bytes reclaimed (metadata and content snapshots)
+    pub bytes: u64,
+}
+
 pub struct VersionTracker {
     versions_dir: PathBuf,
 }
@@ -27,13 +36,27 @@ impl VersionTracker {
         Self { versions_dir }
     }
 
+    /// Snapshot `content` as a new version of `category`. When it is identical to
+    /// the latest version, that version is returned and nothing is written.
     pub fn track_version(&self, category: &str, content: &str) -> Result<KnowledgeVersion> {
+        let content_hash = Self::hash_content(content);
+        if let Some(latest) = self.get_latest_version(category)? {
+            if latest.content_hash == content_hash {
+                return Ok(latest);
+            }
+        }
+
         fs::create_dir_all(&self.versions_dir)?;
 
         let timestamp = Utc::now();
-        let content_hash = Self::hash_content(content);
-        let counter = VERSION_COUNTER.fetch_add(1, Ordering::Relaxed);
-        let version_id = format!("{}-{}-{}", category, timestamp.timestamp(), counter);
+        // The counter is per process; skip ids another run already wrote this second
+        let version_id = loop {
+            let counter = VERSION_COUNTER.fetch_add(1, Ordering::Relaxed);
+            let id = format!("{}-{}-{}", category, timestamp.timestamp(), counter);
+            if !self.versions_dir.join(format!("{}.json", id)).exists() {
+                break id;
+            }
+        };
 
         let version = KnowledgeVersion {
             version_id: version_id.clone(),
@@ -55,6 +78,19 @@ impl VersionTracker {
         Ok(version)
     }
 
+    /// Snapshot a knowledge file after it was written; the category is the file
+    /// stem (`decisions.md` -> `decisions`). Does nothing when the file is missing.
+    pub fn track_file(&self, path: &Path) -> Result<Option<KnowledgeVersion>> {
+        let Some(category) = path.file_stem().and_then(|s| s.to_str()) else {
+            return Ok(None);
+        };
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(path)?;
+        self.track_version(category, &content).map(Some)
+    }
+
     pub fn get_versions(&self, category: &str) -> Result<Vec<KnowledgeVersion>> {
         if !self.versions_dir.exists() {
             return Ok(Vec::new());
@@ -78,7 +114,7 @@ impl VersionTracker {
             }
         }
 
-        versions.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+        versions.sort_by_key(|v| std::cmp::Reverse(v.timestamp));
         Ok(versions)
     }
 
@@ -93,8 +129,21 @@ impl VersionTracker {
     }
 
     pub fn cleanup_old_versions(&self, keep_count: usize) -> Result<usize> {
+        Ok(self.prune_versions(keep_count, None, false)?.removed)
+    }
+
+    /// Remove stored versions, per category, that are not among the `keep_count`
+    /// most recent and (when `cutoff` is set) are older than `cutoff`.
+    /// With `dry_run`, only reports what would be removed.
+    pub fn prune_versions(
+        &self,
+        keep_count: usize,
+        cutoff: Option<DateTime<Utc>>,
+        dry_run: bool,
+    ) -> Result<PruneReport> {
+        let mut report = PruneReport::default();
         if !self.versions_dir.exists() {
-            return Ok(0);
+            return Ok(report);
         }
 
         let mut category_versions: HashMap<String, Vec<KnowledgeVersion>> = HashMap::new();
@@ -117,30 +166,34 @@ impl VersionTracker {
             }
         }
 
-        let mut removed = 0;
-
         // Remove old versions, keeping only keep_count most recent per category
         for (_, mut versions) in category_versions {
-            versions.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+            versions.sort_by_key(|v| std::cmp::Reverse(v.timestamp));
 
             for version in versions.iter().skip(keep_count) {
+                if cutoff.is_some_and(|cutoff| version.timestamp >= cutoff) {
+                    continue;
+                }
+
                 let meta_file = self
                     .versions_dir
                     .join(format!("{}.json", version.version_id));
                 let content_file = self.versions_dir.join(format!("{}.md", version.version_id));
 
-                if meta_file.exists() {
-                    fs::remove_file(&meta_file)?;
-                }
-                if content_file.exists() {
-                    fs::remove_file(&content_file)?;
+                for file in [meta_file, content_file] {
+                    if let Ok(metadata) = fs::metadata(&file) {
+                        report.bytes += metadata.len();
+                        if !dry_run {
+                            fs::remove_file(&file)?;
+                        }
+                    }
                 }
 
-                removed += 1;
+                report.removed += 1;
             }
         }
 
-        Ok(removed)
+        Ok(report)
     }
 
     fn hash_content(content: &str) -> String {
@@ -183,6 +236,32 @@ mod tests {
         assert_eq!(versions.len(), 2);
     }
 
+    #[test]
+    fn test_track_version_skips_identical_content() {
+        let temp = TempDir::new().unwrap();
+        let tracker = VersionTracker::new(temp.path(), "test-project");
+
+        let first = tracker.track_version("decisions", "Version 1").unwrap();
+        let again = tracker.track_version("decisions", "Version 1").unwrap();
+        assert_eq!(first.version_id, again.version_id);
+        assert_eq!(tracker.get_versions("decisions").unwrap().len(), 1);
+
+        tracker.track_version("decisions", "Version 2").unwrap();
+        assert_eq!(tracker.get_versions("decisions").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_track_file_uses_file_stem() {
+        let temp = TempDir::new().unwrap();
+        let tracker = VersionTracker::new(temp.path(), "test-project");
+        let path = temp.path().join("patterns.md");
+
+        assert!(tracker.track_file(&path).unwrap().is_none());
+        fs::write(&path, "# Patterns\n").unwrap();
+        let version = tracker.track_file(&path).unwrap().unwrap();
+        assert_eq!(version.category, "patterns");
+    }
+
     #[test]
     fn test_cleanup_old_versions() {
         let temp = TempDir::new().unwrap();
@@ -200,4 +279,29 @@ mod tests {
         let versions = tracker.get_versions("decisions").unwrap();
         assert_eq!(versions.len(), 2);
     }
+
+    #[test]
+    fn test_prune_versions_respects_cutoff_and_dry_run() {
+        let temp = TempDir::new().unwrap();
+        let tracker = VersionTracker::new(temp.path(), "test-project");
+
+        for i in 0..4 {
+            tracker
+                .track_version("decisions", &format!("Version {}", i))
+                .unwrap();
+        }
+
+        // Everything is newer than an hour ago, so nothing qualifies
+        let hour_ago = Utc::now() - chrono::Duration::hours(1);
+        let report = tracker.prune_versions(1, Some(hour_ago), false).unwrap();
+        assert_eq!(report.removed, 0);
+
+        let report = tracker.prune_versions(1, None, true).unwrap();
+        assert_eq!(report.removed, 3);
+        assert!(report.bytes > 0);
+        assert_eq!(tracker.get_versions("decisions").unwrap().len(), 4);
+
+        assert_eq!(tracker.prune_versions(1, None, false).unwrap(), report);
+        assert_eq!(tracker.get_versions("decisions").unwrap().len(), 1);
+    }
 }
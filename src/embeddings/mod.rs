@@ -2,7 +2,7 @@ pub mod provider;
 pub mod search;
 pub mod store;
 
-pub use provider::EmbeddingProvider;
+pub use provider::{EmbedderInfo, EmbeddingProvider};
 pub use store::EmbeddingStore;
 
 /// Standard embedding dimension (OpenAI ada-002, all-MiniLM-L6-v2, etc.)
@@ -25,6 +25,108 @@ pub struct ChunkMetadata {
     pub timestamp: String,
 }
 
+/// Maximum chars for a single session block before sub-chunking.
+pub const MAX_SESSION_CHUNK: usize = 1200;
+
+/// How knowledge text is split into chunks before embedding.
+///
+/// Stored in the index so rebuilds without an explicit strategy stay consistent.
+#[derive(Debug, Clone, Copy, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum ChunkStrategy {
+    /// One chunk per session block; oversized blocks are sub-chunked
+    #[default]
+    Block,
+    /// One chunk per paragraph (blank-line separated)
+    Paragraph,
+    /// Fixed windows of `size` words, each sharing `overlap` words with the previous one
+    Window { size: usize, overlap: usize },
+}
+
+/// Default window size (words) for `ChunkStrategy::Window`
+pub const DEFAULT_WINDOW_SIZE: usize = 200;
+/// Default overlap (words) for `ChunkStrategy::Window`
+pub const DEFAULT_WINDOW_OVERLAP: usize = 40;
+
+impl ChunkStrategy {
+    /// Build a strategy from CLI values (`block`, `paragraph` or `window`).
+    pub fn parse(
+        name: &str,
+        window_size: Option<usize>,
+        overlap: Option<usize>,
+    ) -> crate::error::Result<Self> {
+        match name {
+            "block" => Ok(ChunkStrategy::Block),
+            "paragraph" => Ok(ChunkStrategy::Paragraph),
+            "window" => {
+                let size = window_size.unwrap_or(DEFAULT_WINDOW_SIZE);
+                let overlap = overlap.unwrap_or(DEFAULT_WINDOW_OVERLAP);
+                if size == 0 || overlap >= size {
+                    return Err(crate::error::MemoryError::Config(format!(
+                        "Invalid chunk window: size {} must be > 0 and larger than overlap {}",
+                        size, overlap
+                    )));
+                }
+                Ok(ChunkStrategy::Window { size, overlap })
+            }
+            other => Err(crate::error::MemoryError::Config(format!(
+                "Unknown chunk strategy: {} (use block, paragraph or window)",
+                other
+            ))),
+        }
+    }
+
+    /// Split one session block (or context.md) into chunks.
+    pub fn chunk(&self, text: &str) -> Vec<String> {
+        let text = text.trim();
+        if text.is_empty() {
+            return Vec::new();
+        }
+        match *self {
+            ChunkStrategy::Block => {
+                if text.len() <= MAX_SESSION_CHUNK {
+                    vec![text.to_string()]
+                } else {
+                    chunk_text(text, MAX_SESSION_CHUNK)
+                }
+            }
+            ChunkStrategy::Paragraph => text
+                .split("\n\n")
+                .map(str::trim)
+                .filter(|p| !p.is_empty())
+                .map(str::to_string)
+                .collect(),
+            ChunkStrategy::Window { size, overlap } => {
+                let words: Vec<&str> = text.split_whitespace().collect();
+                let step = size.saturating_sub(overlap).max(1);
+                let mut chunks = Vec::new();
+                let mut start = 0;
+                loop {
+                    let end = (start + size).min(words.len());
+                    chunks.push(words[start..end].join(" "));
+                    if end == words.len() {
+                        break;
+                    }
+                    start += step;
+                }
+                chunks
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for ChunkStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChunkStrategy::Block => write!(f, "block"),
+            ChunkStrategy::Paragraph => write!(f, "paragraph"),
+            ChunkStrategy::Window { size, overlap } => {
+                write!(f, "window ({} words, {} overlap)", size, overlap)
+            }
+        }
+    }
+}
+
 /// Cosine similarity between two vectors
 pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
     if a.len() != b.len() {
@@ -67,3 +169,32 @@ pub fn chunk_text(text: &str, max_chunk_size: usize) -> Vec<String> {
 
     chunks
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paragraph_strategy_splits_on_blank_lines() {
+        let chunks = ChunkStrategy::Paragraph.chunk("first\n\n\n\nsecond\nline\n\n  ");
+        assert_eq!(chunks, ["first", "second\nline"]);
+    }
+
+    #[test]
+    fn test_window_strategy_overlaps() {
+        let text = "a b c d e f g";
+        let strategy = ChunkStrategy::parse("window", Some(3), Some(1)).unwrap();
+        assert_eq!(strategy.chunk(text), ["a b c", "c d e", "e f g"]);
+        assert_eq!(strategy.chunk("a b"), ["a b"]);
+    }
+
+    #[test]
+    fn test_chunk_strategy_parse_rejects_bad_window() {
+        assert!(ChunkStrategy::parse("window", Some(10), Some(10)).is_err());
+        assert!(ChunkStrategy::parse("sentence", None, None).is_err());
+        assert_eq!(
+            ChunkStrategy::parse("block", None, None).unwrap(),
+            ChunkStrategy::Block
+        );
+    }
+}
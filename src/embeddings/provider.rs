@@ -1,10 +1,70 @@
+use crate::auth::providers::gemini_auth;
 use crate::error::{MemoryError, Result};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+
+/// Ollama server used for embeddings when no endpoint is given.
+pub const DEFAULT_OLLAMA_ENDPOINT: &str = "http://localhost:11434";
+
+/// Embedding providers accepted by `--provider`.
+pub const EMBED_PROVIDERS: &[&str] = &["openai", "gemini", "ollama"];
+
+/// Inputs per request for APIs with native batching (Gemini caps at 100).
+const REMOTE_MAX_BATCH: usize = 64;
+
+/// Character budget per request (~4 chars/token), well under the OpenAI
+/// 300k-token request limit even for dense text.
+const MAX_BATCH_CHARS: usize = 200_000;
 
 pub enum EmbeddingProvider {
-    OpenAI { api_key: String, model: String },
-    Gemini { api_key: String, model: String },
-    OllamaLocal { model: String },
+    OpenAI {
+        api_key: String,
+        model: String,
+    },
+    Gemini {
+        api_key: String,
+        model: String,
+        /// `api_key` is an OAuth access token
+        oauth: bool,
+    },
+    OllamaLocal {
+        model: String,
+        endpoint: String,
+    },
+}
+
+/// Which provider and model produced an index's vectors. Stored in the index
+/// so queries can be embedded the same way.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmbedderInfo {
+    pub provider: String,
+    pub model: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub endpoint: Option<String>,
+}
+
+impl std::fmt::Display for EmbedderInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.provider, self.model)?;
+        if let Some(ref endpoint) = self.endpoint {
+            write!(f, " @ {}", endpoint)?;
+        }
+        Ok(())
+    }
+}
+
+fn default_model(provider: &str) -> &'static str {
+    match provider {
+        "openai" => "text-embedding-3-small",
+        "gemini" => "gemini-embedding-001",
+        _ => "nomic-embed-text",
+    }
+}
+
+/// Accept an Ollama base URL with or without the OpenAI-compatible `/v1` suffix.
+fn normalize_ollama_endpoint(endpoint: &str) -> String {
+    let trimmed = endpoint.trim().trim_end_matches('/');
+    trimmed.strip_suffix("/v1").unwrap_or(trimmed).to_string()
 }
 
 impl EmbeddingProvider {
@@ -23,10 +83,12 @@ impl EmbeddingProvider {
             return Ok(Self::Gemini {
                 api_key: key,
                 model: "gemini-embedding-001".to_string(),
+                oauth: false,
             });
         }
         Ok(Self::OllamaLocal {
             model: "nomic-embed-text".to_string(),
+            endpoint: DEFAULT_OLLAMA_ENDPOINT.to_string(),
         })
     }
 
@@ -45,6 +107,7 @@ impl EmbeddingProvider {
             return Self::Gemini {
                 api_key: key,
                 model: "gemini-embedding-001".to_string(),
+                oauth: false,
             };
         }
 
@@ -74,11 +137,17 @@ impl EmbeddingProvider {
                             .llm
                             .api_key
                             .clone()
-                            .or_else(|| store.get(Provider::Gemini).map(|c| c.key.clone()))
-                            .filter(|k| !k.is_empty());
-                        if let Some(k) = key {
+                            .map(|k| (k, config.llm.oauth))
+                            .or_else(|| {
+                                store
+                                    .get(Provider::Gemini)
+                                    .map(|c| (c.key.clone(), c.is_oauth()))
+                            })
+                            .filter(|(k, _)| !k.is_empty());
+                        if let Some((k, oauth)) = key {
                             return Self::Gemini {
                                 api_key: k,
+                                oauth,
                                 model: stored_model
                                     .unwrap_or_else(|| "gemini-embedding-001".to_string()),
                             };
@@ -87,6 +156,7 @@ impl EmbeddingProvider {
                     "ollama" => {
                         return Self::OllamaLocal {
                             model: stored_model.unwrap_or_else(|| "nomic-embed-text".to_string()),
+                            endpoint: DEFAULT_OLLAMA_ENDPOINT.to_string(),
                         }
                     }
                     _ => {}
@@ -109,6 +179,7 @@ impl EmbeddingProvider {
                     return Self::Gemini {
                         api_key: key.clone(),
                         model: "gemini-embedding-001".to_string(),
+                        oauth: config.llm.oauth,
                     };
                 }
             }
@@ -118,15 +189,170 @@ impl EmbeddingProvider {
 
         Self::OllamaLocal {
             model: "nomic-embed-text".to_string(),
+            endpoint: DEFAULT_OLLAMA_ENDPOINT.to_string(),
+        }
+    }
+
+    /// Build a specific provider, e.g. from `--provider`. `model` defaults to the
+    /// stored `embed_model`, then the provider's default; `endpoint` only
+    /// applies to Ollama. API keys come from the environment, then auth.json.
+    pub fn from_override(
+        provider: &str,
+        model: Option<&str>,
+        endpoint: Option<&str>,
+    ) -> Result<Self> {
+        use crate::auth::providers::Provider;
+
+        let store = crate::auth::AuthStore::load().unwrap_or_default();
+        let model = model
+            .map(str::to_string)
+            .or_else(|| store.embed_model.clone())
+            .unwrap_or_else(|| default_model(provider).to_string());
+        // (key, whether it is an OAuth token)
+        let api_key = |env: &str, p: Provider| {
+            std::env::var(env)
+                .ok()
+                .map(|k| (k, false))
+                .or_else(|| store.get(p).map(|c| (c.key.clone(), c.is_oauth())))
+                .filter(|(k, _)| !k.is_empty())
+                .ok_or_else(|| MemoryError::Config(format!("{} not set", env)))
+        };
+        if endpoint.is_some() && provider != "ollama" {
+            return Err(MemoryError::Config(format!(
+                "--endpoint is only supported for the ollama embedding provider, not {}",
+                provider
+            )));
+        }
+
+        match provider {
+            "openai" => Ok(Self::OpenAI {
+                api_key: api_key("OPENAI_API_KEY", Provider::OpenAI)?.0,
+                model,
+            }),
+            "gemini" => {
+                let (api_key, oauth) = api_key("GEMINI_API_KEY", Provider::Gemini)?;
+                Ok(Self::Gemini {
+                    api_key,
+                    model,
+                    oauth,
+                })
+            }
+            "ollama" => Ok(Self::OllamaLocal {
+                model,
+                endpoint: normalize_ollama_endpoint(endpoint.unwrap_or(DEFAULT_OLLAMA_ENDPOINT)),
+            }),
+            _ => Err(MemoryError::Config(format!(
+                "Unknown embedding provider: {}. Use: {}",
+                provider,
+                EMBED_PROVIDERS.join(", ")
+            ))),
+        }
+    }
+
+    /// Rebuild the provider recorded in an index.
+    pub fn from_info(info: &EmbedderInfo) -> Result<Self> {
+        Self::from_override(&info.provider, Some(&info.model), info.endpoint.as_deref())
+    }
+
+    /// Replace the model and, for Ollama, the endpoint of an already chosen provider.
+    pub fn with_overrides(mut self, model: Option<&str>, endpoint: Option<&str>) -> Result<Self> {
+        match &mut self {
+            Self::OpenAI { model: m, .. } | Self::Gemini { model: m, .. } => {
+                if endpoint.is_some() {
+                    return Err(MemoryError::Config(format!(
+                        "--endpoint is only supported for the ollama embedding provider, not {}",
+                        self.info().provider
+                    )));
+                }
+                if let Some(model) = model {
+                    *m = model.to_string();
+                }
+            }
+            Self::OllamaLocal {
+                model: m,
+                endpoint: e,
+            } => {
+                if let Some(model) = model {
+                    *m = model.to_string();
+                }
+                if let Some(endpoint) = endpoint {
+                    *e = normalize_ollama_endpoint(endpoint);
+                }
+            }
+        }
+        Ok(self)
+    }
+
+    /// The provider name, model and (for Ollama) endpoint of this provider.
+    pub fn info(&self) -> EmbedderInfo {
+        match self {
+            Self::OpenAI { model, .. } => EmbedderInfo {
+                provider: "openai".to_string(),
+                model: model.clone(),
+                endpoint: None,
+            },
+            Self::Gemini { model, .. } => EmbedderInfo {
+                provider: "gemini".to_string(),
+                model: model.clone(),
+                endpoint: None,
+            },
+            Self::OllamaLocal { model, endpoint } => EmbedderInfo {
+                provider: "ollama".to_string(),
+                model: model.clone(),
+                endpoint: Some(endpoint.clone()),
+            },
+        }
+    }
+
+    /// Most texts sent in a single request. Ollama has no batch endpoint.
+    fn max_batch_size(&self) -> usize {
+        match self {
+            Self::OpenAI { .. } | Self::Gemini { .. } => REMOTE_MAX_BATCH,
+            Self::OllamaLocal { .. } => 1,
         }
     }
 
     /// Generate embeddings for a batch of texts
     pub async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+        self.embed_batch_with_progress(texts, |_| {}).await
+    }
+
+    /// Embed `texts` in provider-sized requests, calling `on_progress` with the
+    /// number of texts finished after each request.
+    pub async fn embed_batch_with_progress(
+        &self,
+        texts: &[String],
+        mut on_progress: impl FnMut(usize),
+    ) -> Result<Vec<Vec<f32>>> {
+        let mut embeddings = Vec::with_capacity(texts.len());
+        for range in plan_batches(texts, self.max_batch_size(), MAX_BATCH_CHARS) {
+            let batch = &texts[range];
+            let result = self.embed_request(batch).await?;
+            if result.len() != batch.len() {
+                return Err(MemoryError::Config(format!(
+                    "Embedding API returned {} vectors for {} inputs",
+                    result.len(),
+                    batch.len()
+                )));
+            }
+            embeddings.extend(result);
+            on_progress(batch.len());
+        }
+        Ok(embeddings)
+    }
+
+    /// Send one embedding request for `texts`.
+    async fn embed_request(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
         match self {
             Self::OpenAI { api_key, model } => self.embed_openai(texts, api_key, model).await,
-            Self::Gemini { api_key, model } => self.embed_gemini(texts, api_key, model).await,
-            Self::OllamaLocal { model } => self.embed_ollama(texts, model).await,
+            Self::Gemini {
+                api_key,
+                model,
+                oauth,
+            } => self.embed_gemini(texts, api_key, model, *oauth).await,
+            Self::OllamaLocal { model, endpoint } => {
+                self.embed_ollama(texts, model, endpoint).await
+            }
         }
     }
 
@@ -180,6 +406,7 @@ impl EmbeddingProvider {
         texts: &[String],
         api_key: &str,
         model: &str,
+        oauth: bool,
     ) -> Result<Vec<Vec<f32>>> {
         if texts.is_empty() {
             return Ok(vec![]);
@@ -200,14 +427,13 @@ impl EmbeddingProvider {
             })
             .collect();
 
-        let response = client
+        let request = client
             .post(format!(
-                "https://generativelanguage.googleapis.com/v1beta/models/{}:batchEmbedContents?key={}",
-                model, api_key
+                "https://generativelanguage.googleapis.com/v1beta/models/{}:batchEmbedContents",
+                model
             ))
-            .json(&serde_json::json!({ "requests": requests }))
-            .send()
-            .await?;
+            .json(&serde_json::json!({ "requests": requests }));
+        let response = gemini_auth(request, api_key, oauth).send().await?;
 
         if response.status().is_success() {
             let result: serde_json::Value = response.json().await?;
@@ -234,18 +460,17 @@ impl EmbeddingProvider {
         // Fallback: individual requests per text
         let mut embeddings = Vec::new();
         for text in texts {
-            let response = client
+            let request = client
                 .post(format!(
-                    "https://generativelanguage.googleapis.com/v1beta/models/{}:embedContent?key={}",
-                    model, api_key
+                    "https://generativelanguage.googleapis.com/v1beta/models/{}:embedContent",
+                    model
                 ))
                 .json(&serde_json::json!({
                     "content": {
                         "parts": [{ "text": text }]
                     }
-                }))
-                .send()
-                .await?;
+                }));
+            let response = gemini_auth(request, api_key, oauth).send().await?;
 
             if !response.status().is_success() {
                 let body = response.text().await.unwrap_or_default();
@@ -268,13 +493,18 @@ impl EmbeddingProvider {
         Ok(embeddings)
     }
 
-    async fn embed_ollama(&self, texts: &[String], model: &str) -> Result<Vec<Vec<f32>>> {
+    async fn embed_ollama(
+        &self,
+        texts: &[String],
+        model: &str,
+        endpoint: &str,
+    ) -> Result<Vec<Vec<f32>>> {
         let client = reqwest::Client::new();
         let mut embeddings = Vec::new();
 
         for text in texts {
             let response = client
-                .post("http://localhost:11434/api/embeddings")
+                .post(format!("{}/api/embeddings", endpoint))
                 .json(&serde_json::json!({
                     "model": model,
                     "prompt": text,
@@ -302,3 +532,102 @@ impl EmbeddingProvider {
         Ok(embeddings)
     }
 }
+
+/// Split `texts` into consecutive batches of at most `max_items` entries and
+/// roughly `max_chars` characters. A single oversized text gets its own batch.
+fn plan_batches(texts: &[String], max_items: usize, max_chars: usize) -> Vec<Range<usize>> {
+    let mut batches = Vec::new();
+    let mut start = 0;
+    let mut chars = 0;
+
+    for (i, text) in texts.iter().enumerate() {
+        let full = i - start >= max_items.max(1);
+        let over_budget = i > start && chars + text.len() > max_chars;
+        if full || over_budget {
+            batches.push(start..i);
+            start = i;
+            chars = 0;
+        }
+        chars += text.len();
+    }
+    if start < texts.len() {
+        batches.push(start..texts.len());
+    }
+    batches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(lens: &[usize]) -> Vec<String> {
+        lens.iter().map(|&n| "x".repeat(n)).collect()
+    }
+
+    #[test]
+    fn test_plan_batches_respects_item_limit() {
+        let batches = plan_batches(&texts(&[10; 150]), 64, MAX_BATCH_CHARS);
+        assert_eq!(batches, vec![0..64, 64..128, 128..150]);
+    }
+
+    #[test]
+    fn test_plan_batches_respects_char_budget() {
+        let batches = plan_batches(&texts(&[40, 40, 40, 500, 10]), 64, 100);
+        // The 500-char text exceeds the budget on its own and is sent alone
+        assert_eq!(batches, vec![0..2, 2..3, 3..4, 4..5]);
+    }
+
+    #[test]
+    fn test_plan_batches_single_item_for_ollama() {
+        let provider = EmbeddingProvider::OllamaLocal {
+            model: "nomic-embed-text".to_string(),
+            endpoint: DEFAULT_OLLAMA_ENDPOINT.to_string(),
+        };
+        let batches = plan_batches(&texts(&[5, 5, 5]), provider.max_batch_size(), 1000);
+        assert_eq!(batches, vec![0..1, 1..2, 2..3]);
+        assert!(plan_batches(&[], 64, 1000).is_empty());
+    }
+
+    #[test]
+    fn test_ollama_overrides_and_info() {
+        let provider = EmbeddingProvider::from_override(
+            "ollama",
+            Some("mxbai-embed-large"),
+            Some("http://gpu-box:11434/v1/"),
+        )
+        .unwrap();
+        let info = provider.info();
+        assert_eq!(
+            info,
+            EmbedderInfo {
+                provider: "ollama".to_string(),
+                model: "mxbai-embed-large".to_string(),
+                endpoint: Some("http://gpu-box:11434".to_string()),
+            }
+        );
+        assert_eq!(
+            EmbeddingProvider::from_info(&info).unwrap().info(),
+            info,
+            "recorded info rebuilds the same provider"
+        );
+
+        let overridden = EmbeddingProvider::OllamaLocal {
+            model: "nomic-embed-text".to_string(),
+            endpoint: DEFAULT_OLLAMA_ENDPOINT.to_string(),
+        }
+        .with_overrides(None, Some("http://127.0.0.1:9999"))
+        .unwrap();
+        assert_eq!(
+            overridden.info().endpoint.as_deref(),
+            Some("http://127.0.0.1:9999")
+        );
+        assert_eq!(overridden.info().model, "nomic-embed-text");
+
+        let openai = EmbeddingProvider::OpenAI {
+            api_key: "k".to_string(),
+            model: "text-embedding-3-small".to_string(),
+        };
+        assert!(openai.with_overrides(None, Some("http://x")).is_err());
+        assert!(EmbeddingProvider::from_override("cohere", None, None).is_err());
+    }
+}
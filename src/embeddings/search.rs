@@ -1,53 +1,68 @@
-use super::{chunk_text, ChunkMetadata, EmbeddedChunk, EmbeddingProvider, EmbeddingStore};
+use super::{
+    chunk_text, ChunkMetadata, ChunkStrategy, EmbeddedChunk, EmbeddingProvider, EmbeddingStore,
+};
 use crate::error::Result;
 use crate::extractor::knowledge::{parse_session_blocks, partition_by_expiry};
 use sha2::{Digest, Sha256};
 use std::path::Path;
 
-/// Maximum chars for a single session block before sub-chunking.
-const MAX_SESSION_CHUNK: usize = 1200;
-
 pub struct SemanticSearch;
 
 impl SemanticSearch {
     /// Build embedding index for a project.
     ///
-    /// Uses session-aware chunking: each session block in a knowledge file gets
-    /// its own embedding with its `session_id` set. Blocks larger than
-    /// `MAX_SESSION_CHUNK` are sub-chunked (preserving the same session_id).
-    /// context.md is still chunked with the legacy char-based chunker since it
-    /// is a synthesised narrative without session blocks.
+    /// Uses session-aware chunking: each session block in a knowledge file is
+    /// chunked on its own so every chunk keeps its `session_id`. The split
+    /// within a block follows the existing index's [`ChunkStrategy`] (block
+    /// level by default), so rebuilds keep the granularity the user chose.
     pub async fn build_index(
         memory_dir: &Path,
         project: &str,
         provider: &EmbeddingProvider,
+    ) -> Result<EmbeddingStore> {
+        let strategy = Self::stored_strategy(memory_dir, project);
+        Self::build_index_with_progress(memory_dir, project, provider, strategy, |_, _| {}).await
+    }
+
+    /// Chunk strategy recorded in the project's current index, if any.
+    pub fn stored_strategy(memory_dir: &Path, project: &str) -> ChunkStrategy {
+        let index_path = memory_dir
+            .join("knowledge")
+            .join(project)
+            .join("embeddings.json");
+        EmbeddingStore::load_or_create(index_path).chunk_strategy
+    }
+
+    /// Like [`build_index`](Self::build_index) with an explicit strategy,
+    /// reporting `(embedded, total)` chunk counts after every embedding request.
+    pub async fn build_index_with_progress(
+        memory_dir: &Path,
+        project: &str,
+        provider: &EmbeddingProvider,
+        strategy: ChunkStrategy,
+        mut on_progress: impl FnMut(usize, usize),
     ) -> Result<EmbeddingStore> {
         let knowledge_dir = memory_dir.join("knowledge").join(project);
         let index_path = knowledge_dir.join("embeddings.json");
 
         let mut store = EmbeddingStore::new(index_path);
+        store.chunk_strategy = strategy;
+        store.embedder = Some(provider.info());
+
+        // Collect every chunk first so all files share the same request batches:
+        // (text, category, session_id)
+        let mut pending: Vec<(String, String, Option<String>)> = Vec::new();
 
-        // ── context.md: char-based chunking (synthesised narrative, no sessions) ──
+        // ── context.md: synthesised narrative without sessions ──
         let context_path = knowledge_dir.join("context.md");
         if context_path.exists() {
             let content = std::fs::read_to_string(&context_path)?;
-            if !content.trim().is_empty() {
-                let chunks = chunk_text(&content, 1000);
-                let embeddings = provider.embed_batch(&chunks).await?;
-                for (text, embedding) in chunks.into_iter().zip(embeddings) {
-                    let chunk_id = generate_chunk_id(&text);
-                    store.add_chunk(EmbeddedChunk {
-                        id: chunk_id,
-                        text,
-                        embedding,
-                        metadata: ChunkMetadata {
-                            project: project.to_string(),
-                            category: "context".to_string(),
-                            session_id: None,
-                            timestamp: chrono::Utc::now().to_rfc3339(),
-                        },
-                    });
-                }
+            let chunks = match strategy {
+                ChunkStrategy::Block => chunk_text(content.trim(), 1000),
+                _ => strategy.chunk(&content),
+            };
+            for text in chunks.into_iter().filter(|t| !t.trim().is_empty()) {
+                pending.push((text, "context".to_string(), None));
             }
         }
 
@@ -69,48 +84,36 @@ impl SemanticSearch {
             let (_preamble, blocks) = parse_session_blocks(&content);
             let (active, _expired) = partition_by_expiry(blocks);
 
-            if active.is_empty() {
-                continue;
-            }
-
-            // Build (session_id, text) pairs — sub-chunk long blocks
-            let mut pairs: Vec<(String, String)> = Vec::new();
             for block in active {
-                let text = block.content.trim().to_string();
-                if text.is_empty() {
-                    continue;
+                for text in strategy.chunk(&block.content) {
+                    pending.push((text, cat.to_string(), Some(block.session_id.clone())));
                 }
-                if text.len() <= MAX_SESSION_CHUNK {
-                    pairs.push((block.session_id.clone(), text));
-                } else {
-                    // Sub-chunk large blocks — all sub-chunks share the session_id
-                    for sub in chunk_text(&text, MAX_SESSION_CHUNK) {
-                        pairs.push((block.session_id.clone(), sub));
-                    }
-                }
-            }
-
-            if pairs.is_empty() {
-                continue;
             }
+        }
 
-            let texts: Vec<String> = pairs.iter().map(|(_, t)| t.clone()).collect();
-            let embeddings = provider.embed_batch(&texts).await?;
-
-            for ((session_id, text), embedding) in pairs.into_iter().zip(embeddings) {
-                let chunk_id = generate_chunk_id(&text);
-                store.add_chunk(EmbeddedChunk {
-                    id: chunk_id,
-                    text,
-                    embedding,
-                    metadata: ChunkMetadata {
-                        project: project.to_string(),
-                        category: cat.to_string(),
-                        session_id: Some(session_id),
-                        timestamp: chrono::Utc::now().to_rfc3339(),
-                    },
-                });
-            }
+        let total = pending.len();
+        let texts: Vec<String> = pending.iter().map(|(t, _, _)| t.clone()).collect();
+        let mut done = 0;
+        let embeddings = provider
+            .embed_batch_with_progress(&texts, |n| {
+                done += n;
+                on_progress(done, total);
+            })
+            .await?;
+
+        for ((text, category, session_id), embedding) in pending.into_iter().zip(embeddings) {
+            let chunk_id = generate_chunk_id(&text);
+            store.add_chunk(EmbeddedChunk {
+                id: chunk_id,
+                text,
+                embedding,
+                metadata: ChunkMetadata {
+                    project: project.to_string(),
+                    category,
+                    session_id,
+                    timestamp: chrono::Utc::now().to_rfc3339(),
+                },
+            });
         }
 
         store.save()?;
@@ -155,8 +158,7 @@ fn generate_chunk_id(text: &str) -> String {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-    use crate::embeddings::chunk_text;
+    use crate::embeddings::{chunk_text, MAX_SESSION_CHUNK};
 
     // ── chunk_text (legacy char-based) ─────────────────────────────────────
 
@@ -189,8 +191,8 @@ mod tests {
     #[test]
     fn test_max_session_chunk_constant() {
         // Ensure sub-chunking threshold is larger than typical session content
-        assert!(MAX_SESSION_CHUNK >= 800);
-        assert!(MAX_SESSION_CHUNK <= 2000);
+        const { assert!(MAX_SESSION_CHUNK >= 800) };
+        const { assert!(MAX_SESSION_CHUNK <= 2000) };
     }
 
     // ── session block sub-chunking ─────────────────────────────────────────
@@ -1,4 +1,4 @@
-use super::{cosine_similarity, EmbeddedChunk};
+use super::{cosine_similarity, ChunkStrategy, EmbeddedChunk, EmbedderInfo};
 use crate::error::Result;
 use std::path::Path;
 
@@ -57,6 +57,23 @@ impl SearchFilter {
 pub struct EmbeddingStore {
     pub chunks: Vec<EmbeddedChunk>,
     pub index_path: std::path::PathBuf,
+    /// Chunking used to build this index
+    pub chunk_strategy: ChunkStrategy,
+    /// Provider and model that embedded the chunks (unknown for older indexes)
+    pub embedder: Option<EmbedderInfo>,
+}
+
+/// On-disk index layout. Older indexes are a bare chunk array (block chunking).
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum IndexFile {
+    Current {
+        chunk_strategy: ChunkStrategy,
+        #[serde(default)]
+        embedder: Option<EmbedderInfo>,
+        chunks: Vec<EmbeddedChunk>,
+    },
+    Legacy(Vec<EmbeddedChunk>),
 }
 
 impl EmbeddingStore {
@@ -65,6 +82,8 @@ impl EmbeddingStore {
         Self {
             chunks: Vec::new(),
             index_path,
+            chunk_strategy: ChunkStrategy::default(),
+            embedder: None,
         }
     }
 
@@ -80,19 +99,43 @@ impl EmbeddingStore {
     /// Load from JSON file
     pub fn load(path: &Path) -> Result<Self> {
         let content = std::fs::read_to_string(path)?;
-        let chunks: Vec<EmbeddedChunk> = serde_json::from_str(&content)?;
+        let (chunk_strategy, embedder, chunks) = match serde_json::from_str(&content)? {
+            IndexFile::Current {
+                chunk_strategy,
+                embedder,
+                chunks,
+            } => (chunk_strategy, embedder, chunks),
+            IndexFile::Legacy(chunks) => (ChunkStrategy::default(), None, chunks),
+        };
         Ok(Self {
             chunks,
             index_path: path.to_path_buf(),
+            chunk_strategy,
+            embedder,
         })
     }
 
+    /// Read only the recorded embedder of an index, without loading its chunks.
+    pub fn read_embedder(path: &Path) -> Option<EmbedderInfo> {
+        #[derive(serde::Deserialize)]
+        struct Header {
+            #[serde(default)]
+            embedder: Option<EmbedderInfo>,
+        }
+        let content = std::fs::read_to_string(path).ok()?;
+        serde_json::from_str::<Header>(&content).ok()?.embedder
+    }
+
     /// Save to JSON file
     pub fn save(&self) -> Result<()> {
         if let Some(parent) = self.index_path.parent() {
             std::fs::create_dir_all(parent)?;
         }
-        let json = serde_json::to_string_pretty(&self.chunks)?;
+        let json = serde_json::to_string_pretty(&serde_json::json!({
+            "chunk_strategy": self.chunk_strategy,
+            "embedder": self.embedder,
+            "chunks": self.chunks,
+        }))?;
         std::fs::write(&self.index_path, json)?;
         Ok(())
     }
@@ -293,3 +336,40 @@ pub struct StoreStats {
     pub by_category: std::collections::HashMap<String, usize>,
     pub by_project: std::collections::HashMap<String, usize>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_index_keeps_chunk_strategy_and_reads_legacy_arrays() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("embeddings.json");
+
+        std::fs::write(&path, "[]").unwrap();
+        let legacy = EmbeddingStore::load(&path).unwrap();
+        assert_eq!(legacy.chunk_strategy, ChunkStrategy::Block);
+        assert!(legacy.embedder.is_none());
+
+        let mut store = EmbeddingStore::new(path.clone());
+        store.chunk_strategy = ChunkStrategy::Window {
+            size: 50,
+            overlap: 10,
+        };
+        store.embedder = Some(EmbedderInfo {
+            provider: "ollama".to_string(),
+            model: "nomic-embed-text".to_string(),
+            endpoint: Some("http://gpu-box:11434".to_string()),
+        });
+        store.save().unwrap();
+        let loaded = EmbeddingStore::load(&path).unwrap();
+        assert_eq!(loaded.chunk_strategy, store.chunk_strategy);
+        assert_eq!(loaded.embedder, store.embedder);
+        assert_eq!(EmbeddingStore::read_embedder(&path), store.embedder);
+        assert!(loaded.chunks.is_empty());
+
+        // Indexes written before the embedder was recorded still load
+        std::fs::write(&path, r#"{"chunk_strategy":{"kind":"block"},"chunks":[]}"#).unwrap();
+        assert!(EmbeddingStore::load(&path).unwrap().embedder.is_none());
+    }
+}
@@ -68,7 +68,7 @@ pub async fn build_graph_from_knowledge(
 
     // Parse LLM response as JSON
     let parsed: serde_json::Value = serde_json::from_str(json_str).map_err(|e| {
-        eprintln!("LLM response:\n{}", &response[..response.len().min(500)]);
+        tracing::debug!(response = %response, "unparseable graph response");
         crate::error::MemoryError::Config(format!(
             "Failed to parse graph JSON: {}. Try a better model (--provider anthropic)",
             e
@@ -107,6 +107,7 @@ pub async fn build_graph_from_knowledge(
                     description,
                     source_sessions: Vec::new(),
                     importance,
+                    projects: Vec::new(),
                 });
             }
         }
@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+
+use super::{Concept, KnowledgeGraph, RelationType, Relationship};
+
+/// Node key used to match concepts across projects: lowercase name with runs of
+/// non-alphanumeric characters collapsed to `-` ("Tokio Runtime" == "tokio-runtime").
+pub fn normalize_concept_name(name: &str) -> String {
+    let mut key = String::new();
+    for c in name.trim().chars() {
+        if c.is_alphanumeric() {
+            key.extend(c.to_lowercase());
+        } else if !key.ends_with('-') {
+            key.push('-');
+        }
+    }
+    key.trim_matches('-').to_string()
+}
+
+/// Combine several project graphs into one.
+///
+/// Concepts with the same normalized name become a single shared node whose
+/// `projects` lists every source project; importance keeps the maximum.
+/// Relationships are remapped onto the merged nodes and deduplicated by
+/// (from, to, type), keeping the strongest.
+pub fn merge_graphs(name: &str, graphs: &[KnowledgeGraph]) -> KnowledgeGraph {
+    let mut merged = KnowledgeGraph::new(name.to_string());
+    let mut concepts: HashMap<String, Concept> = HashMap::new();
+    let mut edges: HashMap<(String, String, RelationType), Relationship> = HashMap::new();
+
+    for graph in graphs {
+        // Per-graph concept id → merged key
+        let mut id_map: HashMap<&str, String> = HashMap::new();
+
+        for (id, concept) in &graph.concepts {
+            let key = normalize_concept_name(&concept.name);
+            let key = if key.is_empty() {
+                normalize_concept_name(id)
+            } else {
+                key
+            };
+            id_map.insert(id.as_str(), key.clone());
+
+            let entry = concepts.entry(key.clone()).or_insert_with(|| Concept {
+                id: key.clone(),
+                name: concept.name.clone(),
+                category: concept.category.clone(),
+                description: None,
+                source_sessions: Vec::new(),
+                importance: 0.0,
+                projects: Vec::new(),
+            });
+            if entry.description.is_none() {
+                entry.description = concept.description.clone();
+            }
+            entry.importance = entry.importance.max(concept.importance);
+            for session in &concept.source_sessions {
+                if !entry.source_sessions.contains(session) {
+                    entry.source_sessions.push(session.clone());
+                }
+            }
+            if !entry.projects.contains(&graph.project) {
+                entry.projects.push(graph.project.clone());
+            }
+        }
+
+        for rel in &graph.relationships {
+            let (Some(from), Some(to)) =
+                (id_map.get(rel.from.as_str()), id_map.get(rel.to.as_str()))
+            else {
+                continue;
+            };
+            let key = (from.clone(), to.clone(), rel.rel_type);
+            let edge = edges.entry(key).or_insert_with(|| Relationship {
+                from: from.clone(),
+                to: to.clone(),
+                rel_type: rel.rel_type,
+                strength: 0.0,
+                source_sessions: Vec::new(),
+            });
+            edge.strength = edge.strength.max(rel.strength);
+            for session in &rel.source_sessions {
+                if !edge.source_sessions.contains(session) {
+                    edge.source_sessions.push(session.clone());
+                }
+            }
+        }
+    }
+
+    for mut concept in concepts.into_values() {
+        concept.projects.sort();
+        merged.add_concept(concept);
+    }
+    let mut relationships: Vec<Relationship> = edges.into_values().collect();
+    relationships.sort_by(|a, b| (&a.from, &a.to).cmp(&(&b.from, &b.to)));
+    for rel in relationships {
+        merged.add_relationship(rel);
+    }
+
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::ConceptCategory;
+
+    fn graph(project: &str, names: &[&str], rels: &[(&str, &str, f32)]) -> KnowledgeGraph {
+        let mut g = KnowledgeGraph::new(project.to_string());
+        for name in names {
+            g.add_concept(Concept {
+                id: name.to_lowercase(),
+                name: name.to_string(),
+                category: ConceptCategory::Technology,
+                description: None,
+                source_sessions: vec![format!("{}-s1", project)],
+                importance: 0.5,
+                projects: Vec::new(),
+            });
+        }
+        for (from, to, strength) in rels {
+            g.add_relationship(Relationship {
+                from: from.to_string(),
+                to: to.to_string(),
+                rel_type: RelationType::Uses,
+                strength: *strength,
+                source_sessions: Vec::new(),
+            });
+        }
+        g
+    }
+
+    #[test]
+    fn test_normalize_concept_name() {
+        assert_eq!(normalize_concept_name("  Tokio Runtime "), "tokio-runtime");
+        assert_eq!(normalize_concept_name("tokio_runtime"), "tokio-runtime");
+        assert_eq!(normalize_concept_name("C++"), "c");
+    }
+
+    #[test]
+    fn test_merge_shares_matching_concepts() {
+        let a = graph("api", &["Axum", "Tokio"], &[("axum", "tokio", 0.4)]);
+        let b = graph("worker", &["tokio", "Redis"], &[]);
+        let c = graph("cli", &["Clap", "Tokio"], &[("clap", "tokio", 0.3)]);
+
+        let merged = merge_graphs("_global", &[a, b, c]);
+        assert_eq!(merged.project, "_global");
+        assert_eq!(merged.concepts.len(), 4);
+
+        let tokio = &merged.concepts["tokio"];
+        assert_eq!(tokio.projects, ["api", "cli", "worker"]);
+        assert_eq!(tokio.source_sessions.len(), 3);
+        assert_eq!(merged.concepts["redis"].projects, ["worker"]);
+        assert_eq!(merged.relationships.len(), 2);
+    }
+
+    #[test]
+    fn test_merge_dedupes_relationships_keeping_strongest() {
+        let a = graph("a", &["Axum", "Tokio"], &[("axum", "tokio", 0.4)]);
+        let b = graph("b", &["Axum", "Tokio"], &[("axum", "tokio", 0.9)]);
+
+        let merged = merge_graphs("_global", &[a, b]);
+        assert_eq!(merged.relationships.len(), 1);
+        assert_eq!(merged.relationships[0].strength, 0.9);
+    }
+}
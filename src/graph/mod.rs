@@ -1,4 +1,5 @@
 pub mod builder;
+pub mod merge;
 pub mod query;
 pub mod viz;
 
@@ -65,6 +66,9 @@ pub struct Concept {
     pub description: Option<String>,
     pub source_sessions: Vec<String>,
     pub importance: f32, // 0.0 - 1.0
+    /// Source projects, set on merged cross-project graphs
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub projects: Vec<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -1,5 +1,8 @@
 use super::{KnowledgeGraph, RelationType, Relationship};
+use petgraph::graph::NodeIndex;
+use petgraph::visit::EdgeRef;
 use petgraph::Direction;
+use std::collections::HashMap;
 
 /// Find concepts related to a given concept within N hops
 pub fn find_related(
@@ -48,19 +51,61 @@ pub fn find_related(
     results
 }
 
-/// Find shortest path between two concepts
-pub fn shortest_path(graph: &KnowledgeGraph, from: &str, to: &str) -> Option<Vec<String>> {
-    let (pg, node_map) = graph.to_petgraph();
+/// Floor applied to relationship strength before converting it to an edge cost,
+/// so near-zero strengths don't produce unbounded costs.
+const MIN_EDGE_STRENGTH: f32 = 0.05;
 
-    let start_idx = node_map.get(from)?;
-    let end_idx = node_map.get(to)?;
+/// Find shortest path between two concepts.
+///
+/// When `weighted` is false every edge costs one hop (plain BFS semantics).
+/// When `weighted` is true each edge costs `1 / strength`, so Dijkstra favors
+/// paths through strong connections even if they take more hops.
+pub fn shortest_path(
+    graph: &KnowledgeGraph,
+    from: &str,
+    to: &str,
+    weighted: bool,
+) -> Option<Vec<String>> {
+    let (pg, node_map) = graph.to_petgraph();
 
-    petgraph::algo::astar(&pg, *start_idx, |n| n == *end_idx, |_| 1, |_| 0).map(|(_, path)| {
+    let start_idx = *node_map.get(from)?;
+    let end_idx = *node_map.get(to)?;
+
+    let path = if weighted {
+        // Cheapest cost per (from, to) pair — duplicate edges keep their strongest link
+        let mut edge_costs: HashMap<(NodeIndex, NodeIndex), f32> = HashMap::new();
+        for rel in &graph.relationships {
+            if let (Some(&a), Some(&b)) = (node_map.get(&rel.from), node_map.get(&rel.to)) {
+                let cost = 1.0 / rel.strength.clamp(MIN_EDGE_STRENGTH, 1.0);
+                edge_costs
+                    .entry((a, b))
+                    .and_modify(|c| *c = c.min(cost))
+                    .or_insert(cost);
+            }
+        }
+        petgraph::algo::astar(
+            &pg,
+            start_idx,
+            |n| n == end_idx,
+            |e| {
+                edge_costs
+                    .get(&(e.source(), e.target()))
+                    .copied()
+                    .unwrap_or(1.0)
+            },
+            |_| 0.0,
+        )
+        .map(|(_, path)| path)
+    } else {
+        petgraph::algo::astar(&pg, start_idx, |n| n == end_idx, |_| 1, |_| 0).map(|(_, path)| path)
+    }?;
+
+    Some(
         path.iter()
             .filter_map(|&idx| pg.node_weight(idx))
             .map(|c| c.name.clone())
-            .collect()
-    })
+            .collect(),
+    )
 }
 
 /// Find most connected concepts (hubs)
@@ -78,7 +123,7 @@ pub fn find_hubs(graph: &KnowledgeGraph, top_n: usize) -> Vec<(String, usize, us
         })
         .collect();
 
-    degree_counts.sort_by(|a, b| (b.1 + b.2).cmp(&(a.1 + a.2)));
+    degree_counts.sort_by_key(|d| std::cmp::Reverse(d.1 + d.2));
     degree_counts.truncate(top_n);
 
     degree_counts
@@ -124,3 +169,64 @@ pub fn find_clusters(graph: &KnowledgeGraph) -> Vec<Vec<String>> {
         .filter(|cluster: &Vec<String>| cluster.len() > 1)
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{Concept, ConceptCategory};
+
+    fn concept(id: &str) -> Concept {
+        Concept {
+            id: id.to_string(),
+            name: id.to_string(),
+            category: ConceptCategory::Technology,
+            description: None,
+            source_sessions: Vec::new(),
+            importance: 0.5,
+            projects: Vec::new(),
+        }
+    }
+
+    fn relationship(from: &str, to: &str, strength: f32) -> Relationship {
+        Relationship {
+            from: from.to_string(),
+            to: to.to_string(),
+            rel_type: RelationType::Uses,
+            strength,
+            source_sessions: Vec::new(),
+        }
+    }
+
+    /// a → d directly via a weak edge, or a → b → c → d via strong edges.
+    fn detour_graph() -> KnowledgeGraph {
+        let mut graph = KnowledgeGraph::new("test".to_string());
+        for id in ["a", "b", "c", "d"] {
+            graph.add_concept(concept(id));
+        }
+        graph.add_relationship(relationship("a", "d", 0.1));
+        graph.add_relationship(relationship("a", "b", 0.9));
+        graph.add_relationship(relationship("b", "c", 0.9));
+        graph.add_relationship(relationship("c", "d", 0.9));
+        graph
+    }
+
+    #[test]
+    fn test_unweighted_path_minimizes_hops() {
+        let graph = detour_graph();
+        let path = shortest_path(&graph, "a", "d", false).unwrap();
+        assert_eq!(path, vec!["a", "d"]);
+    }
+
+    #[test]
+    fn test_weighted_path_prefers_strong_edges() {
+        let graph = detour_graph();
+        let path = shortest_path(&graph, "a", "d", true).unwrap();
+        assert_eq!(path, vec!["a", "b", "c", "d"]);
+    }
+
+    #[test]
+    fn test_no_path_for_unknown_concept() {
+        let graph = detour_graph();
+        assert!(shortest_path(&graph, "a", "missing", true).is_none());
+    }
+}
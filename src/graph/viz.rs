@@ -1,4 +1,5 @@
 use super::{ConceptCategory, KnowledgeGraph, RelationType};
+use std::collections::HashMap;
 
 /// Generate DOT format for graphviz visualization
 pub fn to_dot(graph: &KnowledgeGraph) -> String {
@@ -148,6 +149,224 @@ pub fn to_ascii(graph: &KnowledgeGraph, root_concept: Option<&str>) -> String {
     output
 }
 
+/// Character width and line height used to size SVG node boxes
+const SVG_CHAR_WIDTH: f64 = 7.0;
+const SVG_NODE_HEIGHT: f64 = 28.0;
+const SVG_MARGIN: f64 = 40.0;
+const LAYOUT_ITERATIONS: usize = 300;
+
+/// Render the graph as standalone SVG using a built-in force-directed layout.
+///
+/// Used when graphviz isn't installed. The layout is deterministic: nodes
+/// start on a circle in id order, so the same graph always renders the same way.
+pub fn to_svg(graph: &KnowledgeGraph) -> String {
+    let mut ids: Vec<&String> = graph.concepts.keys().collect();
+    ids.sort();
+    let index: HashMap<&str, usize> = ids
+        .iter()
+        .enumerate()
+        .map(|(i, id)| (id.as_str(), i))
+        .collect();
+    let edges: Vec<(usize, usize)> = graph
+        .relationships
+        .iter()
+        .filter_map(|r| Some((*index.get(r.from.as_str())?, *index.get(r.to.as_str())?)))
+        .collect();
+
+    let positions = force_layout(ids.len(), &edges);
+    let widths: Vec<f64> = ids
+        .iter()
+        .map(|id| graph.concepts[*id].name.chars().count() as f64 * SVG_CHAR_WIDTH + 20.0)
+        .collect();
+
+    // Shift the layout so every node box sits inside the margin
+    let half_width = widths.iter().cloned().fold(0.0, f64::max) / 2.0;
+    let min_x = positions.iter().map(|p| p.0).fold(f64::INFINITY, f64::min);
+    let min_y = positions.iter().map(|p| p.1).fold(f64::INFINITY, f64::min);
+    let max_x = positions
+        .iter()
+        .map(|p| p.0)
+        .fold(f64::NEG_INFINITY, f64::max);
+    let max_y = positions
+        .iter()
+        .map(|p| p.1)
+        .fold(f64::NEG_INFINITY, f64::max);
+    let (min_x, min_y, max_x, max_y) = if positions.is_empty() {
+        (0.0, 0.0, 0.0, 0.0)
+    } else {
+        (min_x, min_y, max_x, max_y)
+    };
+    let offset_x = SVG_MARGIN + half_width - min_x;
+    let offset_y = SVG_MARGIN + SVG_NODE_HEIGHT - min_y;
+    let width = (max_x - min_x) + 2.0 * (SVG_MARGIN + half_width);
+    let height = (max_y - min_y) + 2.0 * (SVG_MARGIN + SVG_NODE_HEIGHT);
+    let centers: Vec<(f64, f64)> = positions
+        .iter()
+        .map(|(x, y)| (x + offset_x, y + offset_y))
+        .collect();
+
+    let mut svg = String::new();
+    svg.push_str(&format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w:.0}\" height=\"{h:.0}\" viewBox=\"0 0 {w:.0} {h:.0}\" font-family=\"sans-serif\" font-size=\"12\">\n",
+        w = width,
+        h = height
+    ));
+    svg.push_str(&format!(
+        "  <title>{}</title>\n",
+        xml_escape(&graph.project)
+    ));
+    svg.push_str("  <defs>\n");
+    svg.push_str("    <marker id=\"arrow\" viewBox=\"0 0 10 10\" refX=\"10\" refY=\"5\" markerWidth=\"8\" markerHeight=\"8\" orient=\"auto-start-reverse\">\n");
+    svg.push_str("      <path d=\"M 0 0 L 10 5 L 0 10 z\" fill=\"dimgray\"/>\n");
+    svg.push_str("    </marker>\n");
+    svg.push_str("  </defs>\n");
+    svg.push_str("  <rect width=\"100%\" height=\"100%\" fill=\"white\"/>\n");
+
+    // Edges first so node boxes are drawn on top of them
+    for rel in &graph.relationships {
+        let (Some(&from), Some(&to)) = (index.get(rel.from.as_str()), index.get(rel.to.as_str()))
+        else {
+            continue;
+        };
+        if from == to {
+            continue;
+        }
+        let (x1, y1) = box_edge_point(centers[from], centers[to], widths[from]);
+        let (x2, y2) = box_edge_point(centers[to], centers[from], widths[to]);
+        let color = relation_color(&rel.rel_type);
+        let (stroke_width, dash) = if rel.strength > 0.7 {
+            (2.5, "")
+        } else if rel.strength > 0.4 {
+            (1.5, "")
+        } else {
+            (1.0, " stroke-dasharray=\"4 3\"")
+        };
+        svg.push_str(&format!(
+            "  <line x1=\"{:.1}\" y1=\"{:.1}\" x2=\"{:.1}\" y2=\"{:.1}\" stroke=\"{}\" stroke-width=\"{}\"{} marker-end=\"url(#arrow)\"/>\n",
+            x1, y1, x2, y2, color, stroke_width, dash
+        ));
+        svg.push_str(&format!(
+            "  <text x=\"{:.1}\" y=\"{:.1}\" fill=\"{}\" font-size=\"10\" text-anchor=\"middle\">{}</text>\n",
+            (x1 + x2) / 2.0,
+            (y1 + y2) / 2.0 - 3.0,
+            color,
+            rel.rel_type.as_str()
+        ));
+    }
+
+    for (i, id) in ids.iter().enumerate() {
+        let concept = &graph.concepts[*id];
+        let (cx, cy) = centers[i];
+        let w = widths[i];
+        svg.push_str(&format!(
+            "  <g>\n    <title>{}</title>\n",
+            xml_escape(concept.description.as_deref().unwrap_or(&concept.name))
+        ));
+        svg.push_str(&format!(
+            "    <rect x=\"{:.1}\" y=\"{:.1}\" width=\"{:.1}\" height=\"{:.1}\" rx=\"6\" fill=\"{}\" stroke=\"black\" stroke-width=\"{:.1}\"/>\n",
+            cx - w / 2.0,
+            cy - SVG_NODE_HEIGHT / 2.0,
+            w,
+            SVG_NODE_HEIGHT,
+            category_color(&concept.category),
+            1.0 + concept.importance * 2.0
+        ));
+        svg.push_str(&format!(
+            "    <text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"middle\" dominant-baseline=\"central\">{}</text>\n  </g>\n",
+            cx,
+            cy,
+            xml_escape(&concept.name)
+        ));
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Fruchterman-Reingold layout: connected nodes attract, all nodes repel,
+/// and movement is capped by a cooling temperature.
+fn force_layout(n: usize, edges: &[(usize, usize)]) -> Vec<(f64, f64)> {
+    if n == 0 {
+        return Vec::new();
+    }
+
+    // Ideal edge length; the label boxes are wide, so favor horizontal room
+    let k = 120.0;
+    let radius = k * (n as f64).sqrt();
+    let mut pos: Vec<(f64, f64)> = (0..n)
+        .map(|i| {
+            let angle = 2.0 * std::f64::consts::PI * i as f64 / n as f64;
+            (radius * angle.cos(), radius * angle.sin())
+        })
+        .collect();
+
+    let mut temperature = radius / 2.0;
+    let cooling = temperature / LAYOUT_ITERATIONS as f64;
+
+    for _ in 0..LAYOUT_ITERATIONS {
+        let mut disp = vec![(0.0f64, 0.0f64); n];
+
+        for i in 0..n {
+            for j in (i + 1)..n {
+                let (dx, dy) = (pos[i].0 - pos[j].0, pos[i].1 - pos[j].1);
+                let dist = (dx * dx + dy * dy).sqrt().max(0.01);
+                let force = k * k / dist;
+                let (fx, fy) = (dx / dist * force, dy / dist * force);
+                disp[i].0 += fx;
+                disp[i].1 += fy;
+                disp[j].0 -= fx;
+                disp[j].1 -= fy;
+            }
+        }
+
+        for &(a, b) in edges {
+            if a == b {
+                continue;
+            }
+            let (dx, dy) = (pos[a].0 - pos[b].0, pos[a].1 - pos[b].1);
+            let dist = (dx * dx + dy * dy).sqrt().max(0.01);
+            let force = dist * dist / k;
+            let (fx, fy) = (dx / dist * force, dy / dist * force);
+            disp[a].0 -= fx;
+            disp[a].1 -= fy;
+            disp[b].0 += fx;
+            disp[b].1 += fy;
+        }
+
+        for (p, d) in pos.iter_mut().zip(&disp) {
+            let len = (d.0 * d.0 + d.1 * d.1).sqrt();
+            if len > 0.0 {
+                let step = len.min(temperature);
+                p.0 += d.0 / len * step;
+                p.1 += d.1 / len * step;
+            }
+        }
+
+        temperature = (temperature - cooling).max(1.0);
+    }
+
+    pos
+}
+
+/// Point where the line from `center` towards `other` leaves the node box
+fn box_edge_point(center: (f64, f64), other: (f64, f64), width: f64) -> (f64, f64) {
+    let (dx, dy) = (other.0 - center.0, other.1 - center.1);
+    if dx == 0.0 && dy == 0.0 {
+        return center;
+    }
+    let half_w = width / 2.0;
+    let half_h = SVG_NODE_HEIGHT / 2.0;
+    let scale = (half_w / dx.abs()).min(half_h / dy.abs());
+    (center.0 + dx * scale, center.1 + dy * scale)
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
 fn category_color(category: &ConceptCategory) -> &'static str {
     match category {
         ConceptCategory::Technology => "lightblue",
@@ -194,3 +413,53 @@ fn truncate(s: &str, max_len: usize) -> String {
         format!("{}...", &s[..max_len - 3])
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{Concept, Relationship};
+
+    fn concept(id: &str, name: &str) -> Concept {
+        Concept {
+            id: id.to_string(),
+            name: name.to_string(),
+            category: ConceptCategory::Technology,
+            description: None,
+            source_sessions: vec![],
+            importance: 0.5,
+            projects: vec![],
+        }
+    }
+
+    #[test]
+    fn test_to_svg_empty_graph() {
+        let svg = to_svg(&KnowledgeGraph::new("empty".into()));
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert!(!svg.contains("NaN"));
+    }
+
+    #[test]
+    fn test_to_svg_draws_nodes_and_edges() {
+        let mut graph = KnowledgeGraph::new("demo".into());
+        graph.add_concept(concept("rust", "Rust"));
+        graph.add_concept(concept("serde", "Serde <JSON>"));
+        graph.add_concept(concept("tokio", "Tokio"));
+        graph.add_relationship(Relationship {
+            from: "rust".into(),
+            to: "serde".into(),
+            rel_type: RelationType::Uses,
+            strength: 0.9,
+            source_sessions: vec![],
+        });
+
+        let svg = to_svg(&graph);
+        assert_eq!(svg.matches("<rect x=").count(), 3);
+        assert_eq!(svg.matches("<line ").count(), 1);
+        assert!(svg.contains(">Rust</text>"));
+        assert!(svg.contains("Serde &lt;JSON&gt;"));
+        assert!(!svg.contains("NaN"));
+        // Same graph, same picture
+        assert_eq!(svg, to_svg(&graph));
+    }
+}
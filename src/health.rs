@@ -1,7 +1,15 @@
 use crate::error::Result;
+use chrono::{DateTime, Utc};
 use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::Path;
 
+/// File under the memory dir holding per-project health score history
+const HEALTH_HISTORY_FILE: &str = "health-history.json";
+/// Samples kept per project; older ones are dropped
+const HEALTH_HISTORY_LIMIT: usize = 200;
+
 #[derive(Debug)]
 pub struct HealthReport {
     pub project: String,
@@ -38,6 +46,7 @@ pub enum IssueCategory {
     LargeFiles,
     ExpiredEntries,
     MissingHooks,
+    UnparsedContent,
 }
 
 impl HealthReport {
@@ -101,9 +110,12 @@ pub fn check_project_health(memory_dir: &Path, project: &str) -> Result<HealthRe
         return Ok(report);
     }
 
-    // Check for context.md
+    // Check for context.md (only meaningful once there is knowledge to synthesize)
     let context_path = knowledge_dir.join("context.md");
-    if !context_path.exists() {
+    let has_knowledge = crate::config::CATEGORY_FILES
+        .iter()
+        .any(|f| knowledge_dir.join(f).exists());
+    if has_knowledge && !context_path.exists() {
         report.add_issue(Issue {
             severity: Severity::Warning,
             category: IssueCategory::StaleContext,
@@ -111,7 +123,7 @@ pub fn check_project_health(memory_dir: &Path, project: &str) -> Result<HealthRe
             auto_fixable: true,
             fix_command: Some(format!("engram regen {}", project)),
         });
-    } else {
+    } else if has_knowledge {
         // Check if context is stale (older than knowledge files)
         if is_stale(&context_path, &knowledge_dir)? {
             report.add_issue(Issue {
@@ -170,6 +182,40 @@ pub fn check_project_health(memory_dir: &Path, project: &str) -> Result<HealthRe
         }
     }
 
+    // Check for content that doesn't parse into session blocks
+    for file_name in crate::config::CATEGORY_FILES {
+        let path = knowledge_dir.join(file_name);
+        let Ok(content) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        let unparsed = crate::extractor::knowledge::find_unparsed_lines(&content);
+        if unparsed.is_empty() {
+            continue;
+        }
+        let mut locations: Vec<String> = unparsed
+            .iter()
+            .take(3)
+            .map(|(line, reason)| format!("line {}: {}", line, reason))
+            .collect();
+        if unparsed.len() > 3 {
+            locations.push(format!("{} more", unparsed.len() - 3));
+        }
+        report.add_issue(Issue {
+            severity: Severity::Warning,
+            category: IssueCategory::UnparsedContent,
+            description: format!(
+                "{} has content recall and inject will miss ({})",
+                file_name,
+                locations.join("; ")
+            ),
+            auto_fixable: false,
+            fix_command: Some(format!(
+                "edit {} so every entry starts with '## Session: <id> (<timestamp>)'",
+                path.display()
+            )),
+        });
+    }
+
     // Check for inbox items
     let inbox_path = knowledge_dir.join("inbox.md");
     if inbox_path.exists() {
@@ -222,7 +268,7 @@ pub fn check_project_health(memory_dir: &Path, project: &str) -> Result<HealthRe
 fn is_stale(context_path: &Path, knowledge_dir: &Path) -> Result<bool> {
     let context_modified = std::fs::metadata(context_path)?.modified()?;
 
-    for file_name in &["decisions.md", "solutions.md", "patterns.md"] {
+    for file_name in crate::config::CATEGORY_FILES {
         let path = knowledge_dir.join(file_name);
         if let Ok(metadata) = std::fs::metadata(&path) {
             if let Ok(modified) = metadata.modified() {
@@ -251,11 +297,11 @@ pub async fn auto_fix_issues(
 
         match issue.category {
             IssueCategory::StaleContext => {
-                // Regenerate context
-                if let Err(e) = regenerate_context(config, project).await {
-                    eprintln!("  {} Failed to regen context: {}", "✗".red(), e);
-                } else {
-                    fixed.push("Regenerated stale context.md".into());
+                // Same regeneration path as `engram regen`
+                match crate::commands::knowledge::regenerate_context(config, project).await {
+                    Ok(true) => fixed.push("Regenerated stale context.md".into()),
+                    Ok(false) => {}
+                    Err(e) => eprintln!("  {} Failed to regen context: {}", "✗".red(), e),
                 }
             }
             IssueCategory::MissingEmbeddings => {
@@ -281,44 +327,6 @@ pub async fn auto_fix_issues(
     Ok(fixed)
 }
 
-async fn regenerate_context(config: &crate::config::Config, project: &str) -> Result<()> {
-    use crate::extractor::knowledge::{
-        parse_session_blocks, partition_by_expiry, reconstruct_blocks,
-    };
-    use crate::llm::client::LlmClient;
-
-    let knowledge_dir = config.memory_dir.join("knowledge").join(project);
-
-    let read_and_filter = |path: &Path| -> String {
-        let raw = std::fs::read_to_string(path).unwrap_or_default();
-        let (preamble, blocks) = parse_session_blocks(&raw);
-        let (active, _) = partition_by_expiry(blocks);
-        reconstruct_blocks(&preamble, &active)
-    };
-
-    let decisions = read_and_filter(&knowledge_dir.join("decisions.md"));
-    let solutions = read_and_filter(&knowledge_dir.join("solutions.md"));
-    let patterns = read_and_filter(&knowledge_dir.join("patterns.md"));
-    let bugs = read_and_filter(&knowledge_dir.join("bugs.md"));
-    let insights = read_and_filter(&knowledge_dir.join("insights.md"));
-    let questions = read_and_filter(&knowledge_dir.join("questions.md"));
-
-    let client = LlmClient::new(&config.llm);
-    let context = client
-        .chat(
-            crate::llm::prompts::SYSTEM_KNOWLEDGE_EXTRACTOR,
-            &crate::llm::prompts::context_prompt(
-                project, &decisions, &solutions, &patterns, &bugs, &insights, &questions, "",
-            ),
-        )
-        .await?;
-
-    let context_with_header = format!("# {} - Project Context\n\n{}\n", project, context);
-    std::fs::write(knowledge_dir.join("context.md"), &context_with_header)?;
-
-    Ok(())
-}
-
 async fn generate_embeddings(config: &crate::config::Config, project: &str) -> Result<()> {
     use crate::embeddings::search::SemanticSearch;
     use crate::embeddings::EmbeddingProvider;
@@ -370,12 +378,13 @@ async fn build_graph(config: &crate::config::Config, project: &str) -> Result<()
 pub fn check_hooks_health(home: &Path) -> Vec<Issue> {
     let mut issues = Vec::new();
 
+    // Only scripts for registered events are required, so a subset
+    // installed with `hooks install --events` is not reported as broken.
     let hooks_dir = home.join(".claude").join("hooks");
-    let hook_scripts = ["engram-hook.sh", "inject-context.sh", "session-end-hook.sh"];
-
-    for script in &hook_scripts {
-        let path = hooks_dir.join(script);
-        if !path.exists() {
+    let registered = crate::commands::hooks::registered_hook_events(home);
+    for &event in &registered {
+        let script = event.script_name();
+        if !hooks_dir.join(script).exists() {
             issues.push(Issue {
                 severity: Severity::Critical,
                 category: IssueCategory::MissingHooks,
@@ -388,17 +397,14 @@ pub fn check_hooks_health(home: &Path) -> Vec<Issue> {
 
     let settings_path = home.join(".claude").join("settings.json");
     if settings_path.exists() {
-        match std::fs::read_to_string(&settings_path) {
-            Ok(content) if !content.contains("engram") => {
-                issues.push(Issue {
-                    severity: Severity::Critical,
-                    category: IssueCategory::MissingHooks,
-                    description: "Hooks not registered in ~/.claude/settings.json".to_string(),
-                    auto_fixable: true,
-                    fix_command: Some("engram hooks install".to_string()),
-                });
-            }
-            _ => {}
+        if registered.is_empty() {
+            issues.push(Issue {
+                severity: Severity::Critical,
+                category: IssueCategory::MissingHooks,
+                description: "Hooks not registered in ~/.claude/settings.json".to_string(),
+                auto_fixable: true,
+                fix_command: Some("engram hooks install".to_string()),
+            });
         }
     } else {
         issues.push(Issue {
@@ -442,3 +448,196 @@ fn count_expired_entries(memory_dir: &Path, project: &str) -> Result<usize> {
 
     Ok(total_expired)
 }
+
+/// One recorded `doctor` score
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HealthSample {
+    pub timestamp: DateTime<Utc>,
+    pub score: u8,
+}
+
+fn load_all_history(memory_dir: &Path) -> Result<BTreeMap<String, Vec<HealthSample>>> {
+    let path = memory_dir.join(HEALTH_HISTORY_FILE);
+    if !path.exists() {
+        return Ok(BTreeMap::new());
+    }
+    let content = std::fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&content)?)
+}
+
+/// Append a score to the project's history, keeping the most recent samples.
+pub fn record_health_score(memory_dir: &Path, project: &str, score: u8) -> Result<()> {
+    let mut history = load_all_history(memory_dir)?;
+    let samples = history.entry(project.to_string()).or_default();
+    samples.push(HealthSample {
+        timestamp: Utc::now(),
+        score,
+    });
+    if samples.len() > HEALTH_HISTORY_LIMIT {
+        samples.drain(..samples.len() - HEALTH_HISTORY_LIMIT);
+    }
+
+    std::fs::create_dir_all(memory_dir)?;
+    std::fs::write(
+        memory_dir.join(HEALTH_HISTORY_FILE),
+        serde_json::to_string_pretty(&history)?,
+    )?;
+    Ok(())
+}
+
+/// Recorded scores for a project, oldest first.
+pub fn load_health_history(memory_dir: &Path, project: &str) -> Result<Vec<HealthSample>> {
+    Ok(load_all_history(memory_dir)?
+        .remove(project)
+        .unwrap_or_default())
+}
+
+/// Projects with recorded health history.
+pub fn health_history_projects(memory_dir: &Path) -> Result<Vec<String>> {
+    Ok(load_all_history(memory_dir)?.into_keys().collect())
+}
+
+/// Render 0-100 scores as a block sparkline (`▁` = 0, `█` = 100).
+pub fn sparkline(scores: &[u8]) -> String {
+    const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    scores
+        .iter()
+        .map(|&score| BARS[(score.min(100) as usize * (BARS.len() - 1) + 50) / 100])
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, SystemTime};
+    use tempfile::TempDir;
+
+    fn set_mtime(path: &Path, time: SystemTime) {
+        let file = std::fs::OpenOptions::new().write(true).open(path).unwrap();
+        file.set_modified(time).unwrap();
+    }
+
+    fn stale_issue(report: &HealthReport) -> Option<&Issue> {
+        report
+            .issues
+            .iter()
+            .find(|i| i.category == IssueCategory::StaleContext)
+    }
+
+    #[test]
+    fn test_stale_context_detected_for_any_category() {
+        let tmp = TempDir::new().unwrap();
+        let kdir = tmp.path().join("knowledge").join("proj");
+        std::fs::create_dir_all(&kdir).unwrap();
+        std::fs::write(kdir.join("context.md"), "# ctx\n").unwrap();
+        std::fs::write(kdir.join("procedures.md"), "# Procedures\n").unwrap();
+
+        let now = SystemTime::now();
+        set_mtime(&kdir.join("context.md"), now - Duration::from_secs(3600));
+        set_mtime(&kdir.join("procedures.md"), now);
+
+        let report = check_project_health(tmp.path(), "proj").unwrap();
+        let issue = stale_issue(&report).expect("stale context should be flagged");
+        assert!(issue.auto_fixable);
+    }
+
+    #[test]
+    fn test_missing_context_flagged_only_with_knowledge() {
+        let tmp = TempDir::new().unwrap();
+        let kdir = tmp.path().join("knowledge").join("proj");
+        std::fs::create_dir_all(&kdir).unwrap();
+
+        let report = check_project_health(tmp.path(), "proj").unwrap();
+        assert!(stale_issue(&report).is_none());
+
+        std::fs::write(kdir.join("decisions.md"), "# Decisions\n").unwrap();
+        let report = check_project_health(tmp.path(), "proj").unwrap();
+        assert_eq!(stale_issue(&report).unwrap().severity, Severity::Warning);
+    }
+
+    #[test]
+    fn test_unparsed_content_reported_with_line() {
+        let tmp = TempDir::new().unwrap();
+        let kdir = tmp.path().join("knowledge").join("proj");
+        std::fs::create_dir_all(&kdir).unwrap();
+        std::fs::write(
+            kdir.join("decisions.md"),
+            "# Decisions\n\n## Session: abc (2024-01-01T00:00:00Z)\nok\n",
+        )
+        .unwrap();
+        let unparsed = |report: &HealthReport| {
+            report
+                .issues
+                .iter()
+                .find(|i| i.category == IssueCategory::UnparsedContent)
+                .map(|i| i.description.clone())
+        };
+
+        let report = check_project_health(tmp.path(), "proj").unwrap();
+        assert!(unparsed(&report).is_none());
+
+        std::fs::write(
+            kdir.join("decisions.md"),
+            "# Decisions\n\n## Session abc (2024-01-01T00:00:00Z)\nlost\n",
+        )
+        .unwrap();
+        let report = check_project_health(tmp.path(), "proj").unwrap();
+        let description = unparsed(&report).expect("malformed header should be flagged");
+        assert!(description.contains("decisions.md"));
+        assert!(description.contains("line 3: malformed session header"));
+    }
+
+    #[test]
+    fn test_hooks_health_accepts_event_subset() {
+        let tmp = TempDir::new().unwrap();
+        let claude = tmp.path().join(".claude");
+        std::fs::create_dir_all(claude.join("hooks")).unwrap();
+        std::fs::write(
+            claude.join("settings.json"),
+            r#"{"hooks":{"Stop":[{"hooks":[{"type":"command","command":"~/.claude/hooks/session-end-hook.sh"}]}]}}"#,
+        )
+        .unwrap();
+
+        // Registered Stop hook without its script is broken
+        assert_eq!(check_hooks_health(tmp.path()).len(), 1);
+
+        // Other events' scripts are not required
+        std::fs::write(claude.join("hooks").join("session-end-hook.sh"), "").unwrap();
+        assert!(check_hooks_health(tmp.path()).is_empty());
+    }
+
+    #[test]
+    fn test_health_history_round_trip_and_limit() {
+        let tmp = TempDir::new().unwrap();
+        assert!(load_health_history(tmp.path(), "proj").unwrap().is_empty());
+
+        for score in [40, 60, 80] {
+            record_health_score(tmp.path(), "proj", score).unwrap();
+        }
+        record_health_score(tmp.path(), "other", 100).unwrap();
+
+        let scores: Vec<u8> = load_health_history(tmp.path(), "proj")
+            .unwrap()
+            .iter()
+            .map(|s| s.score)
+            .collect();
+        assert_eq!(scores, [40, 60, 80]);
+        assert_eq!(
+            health_history_projects(tmp.path()).unwrap(),
+            ["other", "proj"]
+        );
+
+        for _ in 0..HEALTH_HISTORY_LIMIT {
+            record_health_score(tmp.path(), "proj", 90).unwrap();
+        }
+        let samples = load_health_history(tmp.path(), "proj").unwrap();
+        assert_eq!(samples.len(), HEALTH_HISTORY_LIMIT);
+        assert!(samples.iter().all(|s| s.score == 90));
+    }
+
+    #[test]
+    fn test_sparkline_scales_scores() {
+        assert_eq!(sparkline(&[0, 50, 100]), "▁▅█");
+        assert_eq!(sparkline(&[]), "");
+    }
+}
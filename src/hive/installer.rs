@@ -1,11 +1,12 @@
 // Pack Installation - Installing and managing knowledge packs
 
 use crate::error::{MemoryError, Result};
-use crate::hive::pack::KnowledgePack;
+use crate::hive::integrity;
+use crate::hive::pack::{KnowledgePack, PackDependency};
 use crate::hive::registry::RegistryManager;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 
 /// Metadata for an installed pack
@@ -16,6 +17,71 @@ pub struct InstalledPack {
     pub version: String,
     pub installed_at: DateTime<Utc>,
     pub path: PathBuf,
+    /// Checksum of the knowledge files as installed (None for older installs)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<String>,
+    /// Pack that pulled this one in as a dependency (None if installed directly)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required_by: Option<String>,
+    /// Project this pack is scoped to (None = available to every project)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+}
+
+/// Installed version of a pack vs the version in its registry's local copy
+#[derive(Debug, Clone)]
+pub struct UpdateCheck {
+    pub name: String,
+    pub registry: String,
+    pub installed: String,
+    /// None when the pack is no longer in the registry
+    pub available: Option<String>,
+}
+
+impl UpdateCheck {
+    /// Whether `update` would install a different version
+    pub fn has_update(&self) -> bool {
+        self.available
+            .as_ref()
+            .is_some_and(|version| *version != self.installed)
+    }
+}
+
+/// Where an installed pack's knowledge is visible
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum PackScope {
+    /// Every project's recall/inject
+    #[value(alias = "user")]
+    Global,
+    /// A single project's recall/inject
+    Project,
+}
+
+/// State threaded through a recursive dependency install
+struct Resolution<'a> {
+    project: Option<&'a str>,
+    /// Packs currently being resolved, for cycle detection
+    chain: Vec<String>,
+    installed: Vec<InstalledPack>,
+}
+
+impl InstalledPack {
+    /// Whether this pack's knowledge applies to `project` (None = global only)
+    pub fn is_visible_to(&self, project: Option<&str>) -> bool {
+        match &self.project {
+            None => true,
+            Some(scoped) => Some(scoped.as_str()) == project,
+        }
+    }
+
+    /// Compare the installed knowledge files against the checksum recorded at
+    /// install time. Returns `None` when no checksum was recorded.
+    pub fn verify_checksum(&self) -> Result<Option<bool>> {
+        match &self.checksum {
+            Some(expected) => Ok(Some(*expected == integrity::compute_checksum(&self.path)?)),
+            None => Ok(None),
+        }
+    }
 }
 
 /// Storage for installed packs
@@ -28,6 +94,7 @@ pub struct InstalledPackStore {
 pub struct PackInstaller {
     hive_dir: PathBuf,
     packs_dir: PathBuf,
+    project_packs_dir: PathBuf,
 }
 
 impl InstalledPackStore {
@@ -89,6 +156,11 @@ impl InstalledPackStore {
         self.packs.iter().any(|p| p.name == name)
     }
 
+    /// Get a mutable reference to an installed pack by name
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut InstalledPack> {
+        self.packs.iter_mut().find(|p| p.name == name)
+    }
+
     /// List all installed packs
     pub fn list(&self) -> &[InstalledPack] {
         &self.packs
@@ -101,11 +173,30 @@ impl PackInstaller {
         Self {
             hive_dir: memory_dir.join("hive"),
             packs_dir: memory_dir.join("packs/installed"),
+            project_packs_dir: memory_dir.join("packs/projects"),
         }
     }
 
-    /// Install a pack from a registry
+    /// Install a pack from a registry for every project
     pub fn install(&self, pack_name: &str, registry_name: Option<&str>) -> Result<InstalledPack> {
+        let mut installed = self.install_with_dependencies(pack_name, registry_name, None)?;
+        installed
+            .pop()
+            .ok_or_else(|| MemoryError::Config(format!("Pack '{}' was not installed", pack_name)))
+    }
+
+    /// Install a pack and any missing dependencies from the same registry.
+    ///
+    /// With `project` set, the pack and its new dependencies are scoped to
+    /// that project. Returns every newly installed pack, dependencies first
+    /// and the requested pack last. If any pack fails, the ones installed
+    /// before it are uninstalled again.
+    pub fn install_with_dependencies(
+        &self,
+        pack_name: &str,
+        registry_name: Option<&str>,
+        project: Option<&str>,
+    ) -> Result<Vec<InstalledPack>> {
         let registry_manager = RegistryManager::new(
             self.hive_dir
                 .parent()
@@ -116,7 +207,7 @@ impl PackInstaller {
         let (pack, found_registry) = self.find_pack(pack_name, registry_name, &registry_manager)?;
 
         // Check if already installed
-        let mut store = InstalledPackStore::load(&self.hive_dir)?;
+        let store = InstalledPackStore::load(&self.hive_dir)?;
         if store.is_installed(&pack.name) {
             return Err(MemoryError::Config(format!(
                 "Pack '{}' is already installed. Use 'update' to update it.",
@@ -124,17 +215,121 @@ impl PackInstaller {
             )));
         }
 
-        // Create installation directory
-        let pack_dir = self.packs_dir.join(&pack.name);
-        std::fs::create_dir_all(&pack_dir)?;
+        let mut resolution = Resolution {
+            project,
+            chain: Vec::new(),
+            installed: Vec::new(),
+        };
+        if let Err(e) = self.install_resolved(
+            pack,
+            &found_registry,
+            None,
+            &registry_manager,
+            &mut resolution,
+        ) {
+            for installed in resolution.installed.iter().rev() {
+                if let Err(rollback) = self.uninstall(&installed.name) {
+                    tracing::warn!(
+                        pack = %installed.name,
+                        error = %rollback,
+                        "failed to roll back dependency install"
+                    );
+                }
+            }
+            return Err(e);
+        }
+        Ok(resolution.installed)
+    }
 
-        // Copy knowledge files from the pack's source path (set during discovery)
+    /// Install a pack's dependencies (depth-first), then the pack itself.
+    fn install_resolved(
+        &self,
+        pack: KnowledgePack,
+        found_registry: &crate::hive::registry::Registry,
+        required_by: Option<&str>,
+        registry_manager: &RegistryManager,
+        resolution: &mut Resolution,
+    ) -> Result<()> {
+        resolution.chain.push(pack.name.clone());
+
+        for dep in &pack.dependencies {
+            let chain = &resolution.chain;
+            if let Some(pos) = chain.iter().position(|name| *name == dep.name) {
+                let mut cycle = chain[pos..].to_vec();
+                cycle.push(dep.name.clone());
+                return Err(MemoryError::Config(format!(
+                    "Dependency cycle detected: {}",
+                    cycle.join(" -> ")
+                )));
+            }
+
+            let store = InstalledPackStore::load(&self.hive_dir)?;
+            if let Some(existing) = store.get(&dep.name) {
+                if !existing.is_visible_to(resolution.project) {
+                    return Err(MemoryError::Config(format!(
+                        "Dependency '{}' (required by '{}') is installed for project '{}' only",
+                        dep.name,
+                        pack.name,
+                        existing.project.as_deref().unwrap_or_default()
+                    )));
+                }
+                check_dependency_version(dep, &existing.version, &pack.name)?;
+                continue;
+            }
+
+            let (dep_pack, _) = self
+                .find_pack(&dep.name, Some(&found_registry.name), registry_manager)
+                .map_err(|_| {
+                    MemoryError::Config(format!(
+                        "Dependency '{}' (required by '{}') not found in registry '{}'",
+                        dep.name, pack.name, found_registry.name
+                    ))
+                })?;
+            check_dependency_version(dep, &dep_pack.version, &pack.name)?;
+
+            self.install_resolved(
+                dep_pack,
+                found_registry,
+                Some(&pack.name),
+                registry_manager,
+                resolution,
+            )?;
+        }
+
+        resolution.chain.pop();
+
+        let mut store = InstalledPackStore::load(&self.hive_dir)?;
+
+        // Verify the pack before anything lands on disk
         let registry_pack_dir = pack
             .source_path
             .as_ref()
             .cloned()
             .unwrap_or_else(|| found_registry.local_path(&self.hive_dir).join(&pack.name));
-        self.copy_pack_content(&registry_pack_dir, &pack_dir)?;
+        integrity::verify_pack(
+            &pack,
+            &registry_pack_dir,
+            found_registry.public_key.as_deref(),
+        )?;
+
+        // Create installation directory
+        let pack_dir = match resolution.project {
+            Some(project) => self.project_packs_dir.join(project).join(&pack.name),
+            None => self.packs_dir.join(&pack.name),
+        };
+        std::fs::create_dir_all(&pack_dir)?;
+
+        // Copy knowledge files from the pack's source path (set during discovery)
+        let checksum = match self
+            .copy_pack_content(&registry_pack_dir, &pack_dir)
+            .and_then(|_| integrity::compute_checksum(&pack_dir))
+        {
+            Ok(checksum) => checksum,
+            Err(e) => {
+                let _ = std::fs::remove_dir_all(&pack_dir);
+                return Err(e);
+            }
+        };
 
         // Record installation
         let installed_pack = InstalledPack {
@@ -143,12 +338,16 @@ impl PackInstaller {
             version: pack.version.clone(),
             installed_at: Utc::now(),
             path: pack_dir,
+            checksum: Some(checksum),
+            required_by: required_by.map(String::from),
+            project: resolution.project.map(String::from),
         };
 
         store.add(installed_pack.clone())?;
         store.save(&self.hive_dir)?;
 
-        Ok(installed_pack)
+        resolution.installed.push(installed_pack);
+        Ok(())
     }
 
     /// Uninstall a pack
@@ -181,6 +380,94 @@ impl PackInstaller {
 
     /// Update an installed pack
     pub fn update(&self, pack_name: &str) -> Result<()> {
+        let mut store = InstalledPackStore::load(&self.hive_dir)?;
+        let (installed, pack) = self.fetch_upstream(pack_name)?;
+
+        let registry_pack_dir = pack.source_path.clone().ok_or_else(|| {
+            MemoryError::Config(format!("Pack '{}' has no source path", pack_name))
+        })?;
+
+        // Verify the new content before it replaces the installed copy
+        let public_key = crate::hive::registry::RegistryStore::load(&self.hive_dir)?
+            .get(&installed.registry)
+            .and_then(|r| r.public_key.clone());
+        integrity::verify_pack(&pack, &registry_pack_dir, public_key.as_deref())?;
+
+        // Copy updated content
+        self.copy_pack_content(&registry_pack_dir, &installed.path)?;
+
+        let checksum = integrity::compute_checksum(&installed.path)?;
+        if let Some(entry) = store.get_mut(pack_name) {
+            entry.version = pack.version.clone();
+            entry.checksum = Some(checksum);
+        }
+        store.save(&self.hive_dir)?;
+
+        Ok(())
+    }
+
+    /// Refresh the registries of installed packs (or just `pack_name`) and
+    /// report which packs have a newer upstream version. Only registry
+    /// copies are updated; installed packs are left untouched.
+    pub fn check_updates(&self, pack_name: Option<&str>) -> Result<Vec<UpdateCheck>> {
+        let registry_manager = self.registry_manager()?;
+        let mut refreshed = HashSet::new();
+        for pack in self.packs_to_check(pack_name)? {
+            if refreshed.insert(pack.registry.clone()) {
+                registry_manager.update(&pack.registry)?;
+            }
+        }
+        self.compare_with_registries(pack_name)
+    }
+
+    /// Compare installed packs against their registries' local copies
+    fn compare_with_registries(&self, pack_name: Option<&str>) -> Result<Vec<UpdateCheck>> {
+        let registry_manager = self.registry_manager()?;
+        let mut upstream: HashMap<String, Vec<KnowledgePack>> = HashMap::new();
+
+        let mut checks = Vec::new();
+        for pack in self.packs_to_check(pack_name)? {
+            if !upstream.contains_key(&pack.registry) {
+                let packs = registry_manager.discover_packs(&pack.registry)?;
+                upstream.insert(pack.registry.clone(), packs);
+            }
+            let available = upstream[&pack.registry]
+                .iter()
+                .find(|p| p.name == pack.name)
+                .map(|p| p.version.clone());
+            checks.push(UpdateCheck {
+                name: pack.name,
+                registry: pack.registry,
+                installed: pack.version,
+                available,
+            });
+        }
+
+        Ok(checks)
+    }
+
+    fn packs_to_check(&self, pack_name: Option<&str>) -> Result<Vec<InstalledPack>> {
+        let store = InstalledPackStore::load(&self.hive_dir)?;
+        let installed: Vec<InstalledPack> = match pack_name {
+            Some(name) => vec![store
+                .get(name)
+                .ok_or_else(|| MemoryError::Config(format!("Pack '{}' not installed", name)))?
+                .clone()],
+            None => store.list().to_vec(),
+        };
+        Ok(installed)
+    }
+
+    fn registry_manager(&self) -> Result<RegistryManager> {
+        Ok(RegistryManager::new(self.hive_dir.parent().ok_or_else(
+            || MemoryError::Config("hive_dir has no parent".into()),
+        )?))
+    }
+
+    /// Pull the installed pack's registry and return the installed record
+    /// alongside the upstream manifest (whose `source_path` points at the
+    /// registry copy).
+    pub fn fetch_upstream(&self, pack_name: &str) -> Result<(InstalledPack, KnowledgePack)> {
         let store = InstalledPackStore::load(&self.hive_dir)?;
 
         // Get installed pack info
@@ -209,14 +496,31 @@ impl PackInstaller {
                 ))
             })?;
 
-        let registry_pack_dir = pack.source_path.ok_or_else(|| {
-            MemoryError::Config(format!("Pack '{}' has no source path", pack_name))
-        })?;
+        Ok((installed, pack))
+    }
 
-        // Copy updated content
-        self.copy_pack_content(&registry_pack_dir, &installed.path)?;
+    /// Find a pack available for install, fetching any registry that
+    /// hasn't been cloned yet. The returned pack's `source_path` points at
+    /// the registry copy.
+    pub fn find_available(
+        &self,
+        pack_name: &str,
+        registry_name: Option<&str>,
+    ) -> Result<(KnowledgePack, crate::hive::registry::Registry)> {
+        let registry_manager = RegistryManager::new(
+            self.hive_dir
+                .parent()
+                .ok_or_else(|| MemoryError::Config("hive_dir has no parent".into()))?,
+        );
 
-        Ok(())
+        for registry in registry_manager.list()? {
+            let wanted = registry_name.is_none_or(|name| name == registry.name);
+            if wanted && !registry.is_cloned(&self.hive_dir) {
+                registry_manager.update(&registry.name)?;
+            }
+        }
+
+        self.find_pack(pack_name, registry_name, &registry_manager)
     }
 
     /// Find a pack in registries
@@ -316,12 +620,16 @@ impl PackInstaller {
         Ok(())
     }
 
-    /// Get installed pack knowledge directories
-    pub fn get_installed_knowledge_dirs(&self) -> Result<HashMap<String, PathBuf>> {
+    /// Get knowledge directories of installed packs visible to `project`
+    /// (global packs plus that project's scoped packs; None = global only)
+    pub fn get_installed_knowledge_dirs(
+        &self,
+        project: Option<&str>,
+    ) -> Result<HashMap<String, PathBuf>> {
         let store = InstalledPackStore::load(&self.hive_dir)?;
         let mut dirs = HashMap::new();
 
-        for pack in store.list() {
+        for pack in store.list().iter().filter(|p| p.is_visible_to(project)) {
             let knowledge_dir = pack.path.join("knowledge");
             if knowledge_dir.exists() {
                 dirs.insert(pack.name.clone(), knowledge_dir);
@@ -332,6 +640,17 @@ impl PackInstaller {
     }
 }
 
+fn check_dependency_version(dep: &PackDependency, version: &str, required_by: &str) -> Result<()> {
+    if dep.matches(version) {
+        Ok(())
+    } else {
+        Err(MemoryError::Config(format!(
+            "Pack '{}' requires '{}' {}, but version {} is available",
+            required_by, dep.name, dep.version, version
+        )))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -349,6 +668,9 @@ mod tests {
             version: "1.0.0".to_string(),
             installed_at: Utc::now(),
             path: PathBuf::from("/tmp/test"),
+            checksum: None,
+            required_by: None,
+            project: None,
         };
 
         // Add
@@ -374,6 +696,195 @@ mod tests {
         assert_eq!(store.list().len(), 0);
     }
 
+    /// (name, version, [(dependency, requirement)])
+    type FixturePack<'a> = (&'a str, &'a str, &'a [(&'a str, &'a str)]);
+
+    /// Write a registry with the given packs
+    fn registry_fixture(memory_dir: &Path, packs: &[FixturePack]) {
+        use crate::hive::pack::Author;
+        use crate::hive::registry::{Registry, RegistryStore};
+
+        let hive_dir = memory_dir.join("hive");
+        let mut store = RegistryStore::default();
+        let registry = Registry::from_url("file:///tmp/reg").unwrap();
+        let registry_dir = registry.local_path(&hive_dir);
+        store.add(registry).unwrap();
+        store.save(&hive_dir).unwrap();
+
+        for (name, version, deps) in packs {
+            let mut pack = KnowledgePack::new(
+                name.to_string(),
+                "Test".to_string(),
+                Author::new("Tester".to_string()),
+                "https://github.com/test/reg".to_string(),
+            );
+            pack.version = version.to_string();
+            pack.dependencies = deps
+                .iter()
+                .map(|(dep, req)| PackDependency {
+                    name: dep.to_string(),
+                    version: req.to_string(),
+                })
+                .collect();
+            let pack_dir = registry_dir.join(name);
+            pack.save(&pack_dir).unwrap();
+            std::fs::create_dir_all(pack_dir.join("knowledge")).unwrap();
+            std::fs::write(pack_dir.join("knowledge/patterns.md"), *name).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_install_resolves_dependencies() {
+        let temp_dir = TempDir::new().unwrap();
+        registry_fixture(
+            temp_dir.path(),
+            &[
+                ("base", "1.2.0", &[]),
+                ("mid", "0.3.0", &[("base", "^1.0")]),
+                ("top", "1.0.0", &[("mid", "*"), ("base", ">=1.1")]),
+            ],
+        );
+
+        let installer = PackInstaller::new(temp_dir.path());
+        let installed = installer
+            .install_with_dependencies("top", None, None)
+            .unwrap();
+        let names: Vec<_> = installed.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["base", "mid", "top"]);
+
+        let store = InstalledPackStore::load(&temp_dir.path().join("hive")).unwrap();
+        assert_eq!(
+            store.get("base").unwrap().required_by.as_deref(),
+            Some("mid")
+        );
+        assert_eq!(
+            store.get("mid").unwrap().required_by.as_deref(),
+            Some("top")
+        );
+        assert_eq!(store.get("top").unwrap().required_by, None);
+    }
+
+    #[test]
+    fn test_install_dependency_errors() {
+        let temp_dir = TempDir::new().unwrap();
+        registry_fixture(
+            temp_dir.path(),
+            &[
+                ("a", "1.0.0", &[("b", "*")]),
+                ("b", "1.0.0", &[("a", "*")]),
+                ("orphan", "1.0.0", &[("missing", "*")]),
+                ("picky", "1.0.0", &[("base", "^2")]),
+                ("base", "1.0.0", &[]),
+            ],
+        );
+        let installer = PackInstaller::new(temp_dir.path());
+
+        let err = installer.install("a", None).unwrap_err().to_string();
+        assert!(
+            err.contains("cycle") && err.contains("a -> b -> a"),
+            "{}",
+            err
+        );
+
+        let err = installer.install("orphan", None).unwrap_err().to_string();
+        assert!(
+            err.contains("'missing'") && err.contains("not found"),
+            "{}",
+            err
+        );
+
+        let err = installer.install("picky", None).unwrap_err().to_string();
+        assert!(err.contains("^2"), "{}", err);
+    }
+
+    #[test]
+    fn test_failed_install_rolls_back_dependencies() {
+        let temp_dir = TempDir::new().unwrap();
+        registry_fixture(
+            temp_dir.path(),
+            &[
+                ("base", "1.0.0", &[]),
+                ("top", "1.0.0", &[("base", "^1.0"), ("gone", "^1.0")]),
+            ],
+        );
+        let installer = PackInstaller::new(temp_dir.path());
+
+        let err = installer.install("top", None).unwrap_err().to_string();
+        assert!(err.contains("gone"), "{}", err);
+        assert!(installer.list().unwrap().is_empty());
+        assert!(!temp_dir.path().join("packs/installed/base").exists());
+    }
+
+    #[test]
+    fn test_check_updates_is_read_only() {
+        let temp_dir = TempDir::new().unwrap();
+        registry_fixture(
+            temp_dir.path(),
+            &[("stable", "1.0.0", &[]), ("moving", "1.0.0", &[])],
+        );
+        let installer = PackInstaller::new(temp_dir.path());
+        installer.install("stable", None).unwrap();
+        let moving = installer.install("moving", None).unwrap();
+
+        // Publish a new version of one pack in the registry copy
+        registry_fixture(
+            temp_dir.path(),
+            &[("stable", "1.0.0", &[]), ("moving", "1.1.0", &[])],
+        );
+        let patterns = moving.path.join("knowledge/patterns.md");
+        std::fs::write(&patterns, "local").unwrap();
+
+        let mut checks = installer.compare_with_registries(None).unwrap();
+        checks.sort_by(|a, b| a.name.cmp(&b.name));
+        assert_eq!(checks.len(), 2);
+        assert!(checks[0].has_update());
+        assert_eq!(checks[0].installed, "1.0.0");
+        assert_eq!(checks[0].available.as_deref(), Some("1.1.0"));
+        assert!(!checks[1].has_update());
+
+        // Nothing was touched
+        assert_eq!(installer.list().unwrap().len(), 2);
+        let store = InstalledPackStore::load(&installer.hive_dir).unwrap();
+        assert_eq!(store.get("moving").unwrap().version, "1.0.0");
+        assert_eq!(std::fs::read_to_string(&patterns).unwrap(), "local");
+
+        assert!(installer.check_updates(Some("missing")).is_err());
+    }
+
+    #[test]
+    fn test_check_updates_reports_unreadable_registry() {
+        let temp_dir = TempDir::new().unwrap();
+        registry_fixture(temp_dir.path(), &[("stable", "1.0.0", &[])]);
+        let installer = PackInstaller::new(temp_dir.path());
+        installer.install("stable", None).unwrap();
+
+        // A registry copy that can't be read is an error, not "not in registry"
+        std::fs::remove_dir_all(temp_dir.path().join("hive/registries")).unwrap();
+        assert!(installer.compare_with_registries(None).is_err());
+    }
+
+    #[test]
+    fn test_project_scoped_install() {
+        let temp_dir = TempDir::new().unwrap();
+        registry_fixture(
+            temp_dir.path(),
+            &[("shared", "1.0.0", &[]), ("domain", "1.0.0", &[])],
+        );
+        let installer = PackInstaller::new(temp_dir.path());
+
+        installer.install("shared", None).unwrap();
+        let installed = installer
+            .install_with_dependencies("domain", None, Some("api"))
+            .unwrap();
+        assert!(installed[0].path.ends_with("packs/projects/api/domain"));
+
+        let api = installer.get_installed_knowledge_dirs(Some("api")).unwrap();
+        assert!(api.contains_key("shared") && api.contains_key("domain"));
+
+        let other = installer.get_installed_knowledge_dirs(Some("web")).unwrap();
+        assert!(other.contains_key("shared") && !other.contains_key("domain"));
+    }
+
     #[test]
     fn test_pack_installer() {
         let temp_dir = TempDir::new().unwrap();
@@ -0,0 +1,192 @@
+// Pack Integrity - Checksums and signatures for knowledge packs
+//
+// A pack's checksum is a SHA-256 over its knowledge files (relative path +
+// contents, in sorted order), written as "sha256:<hex>". Publishers may add a
+// detached Ed25519 signature over that checksum string; registries configured
+// with a public key reject packs whose signature is missing or invalid.
+
+use crate::error::{MemoryError, Result};
+use crate::hive::pack::KnowledgePack;
+use sha2::{Digest, Sha256};
+use std::path::Path;
+
+const CHECKSUM_PREFIX: &str = "sha256:";
+
+/// Compute the checksum of a pack's `knowledge/` directory.
+pub fn compute_checksum(pack_dir: &Path) -> Result<String> {
+    let knowledge_dir = pack_dir.join("knowledge");
+    let mut hasher = Sha256::new();
+
+    if knowledge_dir.exists() {
+        let mut files: Vec<_> = walkdir::WalkDir::new(&knowledge_dir)
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_file())
+            .map(|e| e.into_path())
+            .collect();
+        files.sort();
+
+        for path in files {
+            let relative = path
+                .strip_prefix(&knowledge_dir)
+                .unwrap_or(&path)
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            hasher.update(relative.as_bytes());
+            hasher.update([0]);
+            hasher.update(std::fs::read(&path)?);
+            hasher.update([0]);
+        }
+    }
+
+    Ok(format!("{}{:x}", CHECKSUM_PREFIX, hasher.finalize()))
+}
+
+/// Check a pack directory against its manifest before installing it.
+///
+/// Fails when the manifest declares a checksum the files don't match, or when
+/// `public_key` is set and the manifest's signature is missing or invalid.
+/// Returns the computed checksum so callers can record it.
+pub fn verify_pack(
+    pack: &KnowledgePack,
+    pack_dir: &Path,
+    public_key: Option<&str>,
+) -> Result<String> {
+    let actual = compute_checksum(pack_dir)?;
+
+    if let Some(expected) = &pack.checksum {
+        if *expected != actual {
+            return Err(MemoryError::Config(format!(
+                "Checksum mismatch for pack '{}': manifest declares {}, files hash to {}",
+                pack.name, expected, actual
+            )));
+        }
+    }
+
+    if let Some(key) = public_key {
+        let (Some(checksum), Some(signature)) = (&pack.checksum, &pack.signature) else {
+            return Err(MemoryError::Config(format!(
+                "Pack '{}' is not signed, but its registry requires signatures",
+                pack.name
+            )));
+        };
+        if !verify_signature(checksum, signature, key)? {
+            return Err(MemoryError::Config(format!(
+                "Invalid signature for pack '{}'",
+                pack.name
+            )));
+        }
+    }
+
+    Ok(actual)
+}
+
+/// Verify a hex-encoded Ed25519 `signature` of `checksum` against a hex-encoded public key.
+pub fn verify_signature(checksum: &str, signature: &str, public_key: &str) -> Result<bool> {
+    let key = parse_public_key(public_key)?;
+    let Some(signature) = decode_hex(signature) else {
+        return Ok(false);
+    };
+
+    let key = ring::signature::UnparsedPublicKey::new(&ring::signature::ED25519, key);
+    Ok(key.verify(checksum.as_bytes(), &signature).is_ok())
+}
+
+/// Decode and validate a hex-encoded Ed25519 public key (32 bytes).
+pub fn parse_public_key(public_key: &str) -> Result<Vec<u8>> {
+    match decode_hex(public_key.trim()) {
+        Some(bytes) if bytes.len() == 32 => Ok(bytes),
+        _ => Err(MemoryError::Config(
+            "Invalid public key: expected 64 hex characters (Ed25519)".into(),
+        )),
+    }
+}
+
+fn decode_hex(s: &str) -> Option<Vec<u8>> {
+    if !s.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(s.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hive::pack::Author;
+    use ring::signature::{Ed25519KeyPair, KeyPair};
+    use tempfile::TempDir;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    fn pack_dir() -> (TempDir, KnowledgePack) {
+        let temp = TempDir::new().unwrap();
+        std::fs::create_dir_all(temp.path().join("knowledge")).unwrap();
+        std::fs::write(temp.path().join("knowledge/patterns.md"), "use tokio").unwrap();
+        let pack = KnowledgePack::new(
+            "test-pack".to_string(),
+            "Test".to_string(),
+            Author::new("Tester".to_string()),
+            "https://github.com/test/test-pack".to_string(),
+        );
+        (temp, pack)
+    }
+
+    #[test]
+    fn test_checksum_tracks_knowledge_changes() {
+        let (temp, _) = pack_dir();
+        let first = compute_checksum(temp.path()).unwrap();
+        assert!(first.starts_with("sha256:"));
+        assert_eq!(first, compute_checksum(temp.path()).unwrap());
+
+        std::fs::write(temp.path().join("knowledge/patterns.md"), "use std").unwrap();
+        assert_ne!(first, compute_checksum(temp.path()).unwrap());
+    }
+
+    #[test]
+    fn test_verify_pack_checksum() {
+        let (temp, mut pack) = pack_dir();
+        // No declared checksum: accepted
+        let actual = verify_pack(&pack, temp.path(), None).unwrap();
+
+        pack.checksum = Some(actual);
+        assert!(verify_pack(&pack, temp.path(), None).is_ok());
+
+        std::fs::write(temp.path().join("knowledge/patterns.md"), "tampered").unwrap();
+        assert!(verify_pack(&pack, temp.path(), None).is_err());
+    }
+
+    #[test]
+    fn test_verify_pack_signature() {
+        let (temp, mut pack) = pack_dir();
+        let rng = ring::rand::SystemRandom::new();
+        let pkcs8 = Ed25519KeyPair::generate_pkcs8(&rng).unwrap();
+        let keypair = Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap();
+        let public_key = hex(keypair.public_key().as_ref());
+
+        let checksum = compute_checksum(temp.path()).unwrap();
+        pack.checksum = Some(checksum.clone());
+
+        // Unsigned pack is rejected when a key is configured
+        assert!(verify_pack(&pack, temp.path(), Some(&public_key)).is_err());
+
+        pack.signature = Some(hex(keypair.sign(checksum.as_bytes()).as_ref()));
+        assert!(verify_pack(&pack, temp.path(), Some(&public_key)).is_ok());
+
+        pack.signature = Some(hex(keypair.sign(b"something else").as_ref()));
+        assert!(verify_pack(&pack, temp.path(), Some(&public_key)).is_err());
+    }
+
+    #[test]
+    fn test_parse_public_key() {
+        assert!(parse_public_key(&"ab".repeat(32)).is_ok());
+        assert!(parse_public_key("abcd").is_err());
+        assert!(parse_public_key(&"zz".repeat(32)).is_err());
+    }
+}
@@ -7,20 +7,24 @@
 // - Integration with recall/search (union of local + installed)
 
 pub mod installer;
+pub mod integrity;
 pub mod pack;
 pub mod registry;
 pub mod security;
 
 // Re-export commonly used types
-pub use installer::PackInstaller;
+pub use installer::{PackInstaller, PackScope};
 pub use pack::{Author, KnowledgePack, PackCategory, PrivacyPolicy};
 pub use registry::RegistryManager;
 pub use security::SecretDetector;
 
-/// Get aggregated knowledge from all installed packs (full content).
-pub fn get_installed_pack_knowledge(memory_dir: &std::path::Path) -> crate::Result<String> {
+/// Get aggregated knowledge from the packs installed for `project` (full content).
+pub fn get_installed_pack_knowledge(
+    memory_dir: &std::path::Path,
+    project: &str,
+) -> crate::Result<String> {
     let installer = PackInstaller::new(memory_dir);
-    let knowledge_dirs = installer.get_installed_knowledge_dirs()?;
+    let knowledge_dirs = installer.get_installed_knowledge_dirs(Some(project))?;
 
     if knowledge_dirs.is_empty() {
         return Ok(String::new());
@@ -34,6 +38,7 @@ pub fn get_installed_pack_knowledge(memory_dir: &std::path::Path) -> crate::Resu
         for category in &[
             "patterns.md",
             "solutions.md",
+            "workflows.md",
             "decisions.md",
             "preferences.md",
         ] {
@@ -26,6 +26,18 @@ pub struct KnowledgePack {
     #[serde(alias = "min_claude_memory_version")]
     pub min_engram_version: String,
 
+    /// Checksum of the knowledge files ("sha256:<hex>"), checked on install
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<String>,
+
+    /// Hex-encoded Ed25519 signature of `checksum`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+
+    /// Other packs this one builds on, installed alongside it
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dependencies: Vec<PackDependency>,
+
     /// Directory where this pack was loaded from (not serialized)
     #[serde(skip)]
     pub source_path: Option<PathBuf>,
@@ -37,6 +49,19 @@ pub struct Author {
     pub email: Option<String>,
 }
 
+/// A dependency on another pack in the same registry
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PackDependency {
+    pub name: String,
+    /// Semver requirement, e.g. "^1.2" or ">=0.3, <0.5" (default: any version)
+    #[serde(default = "any_version")]
+    pub version: String,
+}
+
+fn any_version() -> String {
+    "*".to_string()
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum PackCategory {
     Patterns,
@@ -86,6 +111,9 @@ impl KnowledgePack {
             updated_at: Utc::now(),
             privacy: PrivacyPolicy::default(),
             min_engram_version: env!("CARGO_PKG_VERSION").to_string(),
+            checksum: None,
+            signature: None,
+            dependencies: Vec::new(),
             source_path: None,
         }
     }
@@ -151,6 +179,21 @@ impl KnowledgePack {
             return Err(MemoryError::Config("Author name cannot be empty".into()));
         }
 
+        for dep in &self.dependencies {
+            if dep.name.is_empty() || dep.name == self.name {
+                return Err(MemoryError::Config(format!(
+                    "Invalid dependency name: '{}'",
+                    dep.name
+                )));
+            }
+            semver::VersionReq::parse(&dep.version).map_err(|e| {
+                MemoryError::Config(format!(
+                    "Invalid version requirement '{}' for dependency '{}': {}",
+                    dep.version, dep.name, e
+                ))
+            })?;
+        }
+
         Ok(())
     }
 
@@ -180,6 +223,19 @@ impl KnowledgePack {
     }
 }
 
+impl PackDependency {
+    /// Check whether a pack version satisfies this dependency's requirement
+    pub fn matches(&self, version: &str) -> bool {
+        match (
+            semver::VersionReq::parse(&self.version),
+            semver::Version::parse(version),
+        ) {
+            (Ok(req), Ok(version)) => req.matches(&version),
+            _ => self.version.trim() == "*",
+        }
+    }
+}
+
 impl Author {
     pub fn new(name: String) -> Self {
         Self { name, email: None }
@@ -205,6 +261,52 @@ impl std::fmt::Display for PackCategory {
     }
 }
 
+/// Entry count and the first few entries of one knowledge file in a pack
+#[derive(Debug, Clone)]
+pub struct CategoryPreview {
+    pub category: String,
+    pub entries: usize,
+    /// (session id, first line of the entry)
+    pub samples: Vec<(String, String)>,
+}
+
+/// Summarize a pack's knowledge files without installing it
+pub fn preview_knowledge(pack_dir: &Path, samples: usize) -> Vec<CategoryPreview> {
+    let knowledge_dir = pack_dir.join("knowledge");
+    let mut previews = Vec::new();
+
+    for category in &[
+        "patterns.md",
+        "solutions.md",
+        "workflows.md",
+        "decisions.md",
+        "preferences.md",
+    ] {
+        let Ok(content) = std::fs::read_to_string(knowledge_dir.join(category)) else {
+            continue;
+        };
+        let (_, blocks) = crate::extractor::knowledge::parse_session_blocks(&content);
+        if blocks.is_empty() {
+            continue;
+        }
+
+        previews.push(CategoryPreview {
+            category: category.trim_end_matches(".md").to_string(),
+            entries: blocks.len(),
+            samples: blocks
+                .iter()
+                .take(samples)
+                .map(|b| {
+                    let title = b.preview.trim_start_matches('#').trim().to_string();
+                    (b.session_id.clone(), title)
+                })
+                .collect(),
+        });
+    }
+
+    previews
+}
+
 impl std::str::FromStr for PackCategory {
     type Err = MemoryError;
 
@@ -329,6 +431,55 @@ mod tests {
         assert!(!pack.matches_keyword("javascript"));
     }
 
+    #[test]
+    fn test_dependency_version_matching() {
+        let dep = |version: &str| PackDependency {
+            name: "base".to_string(),
+            version: version.to_string(),
+        };
+        assert!(dep("*").matches("0.1.0"));
+        assert!(dep("^1.2").matches("1.4.0"));
+        assert!(!dep("^1.2").matches("2.0.0"));
+        assert!(dep(">=0.3, <0.5").matches("0.4.1"));
+        assert!(!dep("^1").matches("not-a-version"));
+
+        let mut pack = KnowledgePack::new(
+            "top".to_string(),
+            "Test".to_string(),
+            Author::new("Tester".to_string()),
+            "https://github.com/test/pack".to_string(),
+        );
+        pack.dependencies = vec![dep("not a range")];
+        assert!(pack.validate().is_err());
+    }
+
+    #[test]
+    fn test_preview_knowledge() {
+        let temp_dir = TempDir::new().unwrap();
+        let knowledge_dir = temp_dir.path().join("knowledge");
+        std::fs::create_dir_all(&knowledge_dir).unwrap();
+        std::fs::write(
+            knowledge_dir.join("patterns.md"),
+            "# Patterns\n\n## Session: a (2026-01-01T00:00:00Z)\n\n### Retry with backoff\nbody\n\n\
+             ## Session: b (2026-01-02T00:00:00Z)\nUse builders\n\n\
+             ## Session: c (2026-01-03T00:00:00Z)\nThird\n",
+        )
+        .unwrap();
+        std::fs::write(knowledge_dir.join("solutions.md"), "# Solutions\n").unwrap();
+
+        let previews = preview_knowledge(temp_dir.path(), 2);
+        assert_eq!(previews.len(), 1);
+        assert_eq!(previews[0].category, "patterns");
+        assert_eq!(previews[0].entries, 3);
+        assert_eq!(
+            previews[0].samples,
+            vec![
+                ("a".to_string(), "Retry with backoff".to_string()),
+                ("b".to_string(), "Use builders".to_string()),
+            ]
+        );
+    }
+
     #[test]
     fn test_category_display() {
         assert_eq!(PackCategory::Patterns.to_string(), "patterns");
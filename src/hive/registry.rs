@@ -7,12 +7,88 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
+/// File name of the cached index inside an HTTP registry's local mirror
+const HTTP_INDEX_FILE: &str = "index.json";
+
+/// How long a cached pack listing is served before browse/search refetch it
+pub const LISTING_TTL_HOURS: i64 = 24;
+
+/// How a registry's packs are fetched
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RegistryKind {
+    /// A Git repository (GitHub, GitLab or any other host), cloned locally
+    #[default]
+    Git,
+    /// A JSON index served over HTTP that lists packs and their files
+    Http,
+}
+
+impl RegistryKind {
+    /// Detect the registry kind from a normalized URL: `http(s)` URLs
+    /// pointing at a `.json` file are HTTP indexes, everything else is Git
+    pub fn detect(url: &str) -> Self {
+        let lower = url.to_lowercase();
+        let is_http = lower.starts_with("https://") || lower.starts_with("http://");
+        let path = lower.split(['?', '#']).next().unwrap_or_default();
+        if is_http && path.ends_with(".json") {
+            RegistryKind::Http
+        } else {
+            RegistryKind::Git
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RegistryKind::Git => "git",
+            RegistryKind::Http => "http",
+        }
+    }
+}
+
 /// A registry of knowledge packs (similar to package registry)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Registry {
     pub name: String,
     pub url: String,
+    /// Registries written before HTTP indexes existed are all Git
+    #[serde(default)]
+    pub kind: RegistryKind,
     pub last_updated: Option<DateTime<Utc>>,
+    /// Hex-encoded Ed25519 key; when set, packs must carry a valid signature
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub public_key: Option<String>,
+}
+
+/// Pack index served by an HTTP registry
+#[derive(Debug, Deserialize)]
+struct HttpIndex {
+    packs: Vec<HttpIndexEntry>,
+}
+
+/// One pack in an HTTP index: its manifest plus the files that make it up
+#[derive(Debug, Deserialize)]
+struct HttpIndexEntry {
+    manifest: KnowledgePack,
+    /// Pack-relative path (e.g. `knowledge/patterns.md`) to a URL, which may
+    /// be relative to the index URL
+    #[serde(default)]
+    files: HashMap<String, String>,
+}
+
+/// Last successful pack listing for a registry, kept for offline use
+#[derive(Debug, Serialize, Deserialize)]
+struct ListingCache {
+    fetched_at: DateTime<Utc>,
+    packs: Vec<KnowledgePack>,
+}
+
+/// Packs listed for a registry, and whether they were served from the cache
+#[derive(Debug, Clone)]
+pub struct PackListing {
+    pub packs: Vec<KnowledgePack>,
+    pub fetched_at: DateTime<Utc>,
+    pub cached: bool,
 }
 
 /// Storage for all registries
@@ -28,15 +104,23 @@ pub struct RegistryManager {
 
 impl Registry {
     /// Create a new registry from a URL
-    /// Supports both full Git URLs and GitHub shorthand (owner/repo)
+    /// Supports full Git URLs (GitHub, GitLab, ...), GitHub shorthand
+    /// (owner/repo), host shorthand (gitlab.com/group/repo) and HTTP JSON
+    /// indexes (https://example.com/packs/index.json)
     pub fn from_url(url: &str) -> Result<Self> {
         let normalized_url = Self::normalize_url(url)?;
-        let name = Self::extract_name(&normalized_url)?;
+        let kind = RegistryKind::detect(&normalized_url);
+        let name = match kind {
+            RegistryKind::Git => Self::extract_name(&normalized_url)?,
+            RegistryKind::Http => Self::extract_index_name(&normalized_url)?,
+        };
 
         Ok(Registry {
             name,
             url: normalized_url,
+            kind,
             last_updated: None,
+            public_key: None,
         })
     }
 
@@ -45,6 +129,9 @@ impl Registry {
         if url.contains("://") {
             // Already a full URL
             Ok(url.to_string())
+        } else if url.contains('/') && url.split('/').next().is_some_and(|h| h.contains('.')) {
+            // Host shorthand: gitlab.com/group/repo
+            Ok(format!("https://{}.git", url.trim_end_matches(".git")))
         } else if url.contains('/') {
             // GitHub shorthand: owner/repo
             Ok(format!("https://github.com/{}.git", url))
@@ -77,14 +164,49 @@ impl Registry {
         Ok(name)
     }
 
+    /// Extract registry name from an HTTP index URL: the index file's stem,
+    /// or its directory (or host) when the file is just `index.json`
+    fn extract_index_name(url: &str) -> Result<String> {
+        let path = url.split(['?', '#']).next().unwrap_or_default();
+        let path = path.split_once("://").map_or(path, |(_, rest)| rest);
+        let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+
+        let stem = segments
+            .last()
+            .map(|s| s.trim_end_matches(".json"))
+            .unwrap_or_default();
+        let name = if stem.eq_ignore_ascii_case("index") && segments.len() > 1 {
+            // Drop the port when falling back to the host
+            segments[segments.len() - 2]
+                .split(':')
+                .next()
+                .unwrap_or_default()
+        } else {
+            stem
+        };
+
+        if name.is_empty() {
+            return Err(MemoryError::Config(format!(
+                "Empty registry name from URL: {}",
+                url
+            )));
+        }
+
+        Ok(name.to_string())
+    }
+
     /// Get the local path where this registry is cloned
     pub fn local_path(&self, hive_dir: &Path) -> PathBuf {
         hive_dir.join("registries").join(&self.name)
     }
 
-    /// Check if registry is cloned locally
+    /// Check if registry is cloned (or, for HTTP indexes, mirrored) locally
     pub fn is_cloned(&self, hive_dir: &Path) -> bool {
-        self.local_path(hive_dir).join(".git").exists()
+        let marker = match self.kind {
+            RegistryKind::Git => ".git",
+            RegistryKind::Http => HTTP_INDEX_FILE,
+        };
+        self.local_path(hive_dir).join(marker).exists()
     }
 }
 
@@ -165,15 +287,19 @@ impl RegistryManager {
     }
 
     /// Add a registry
-    pub fn add(&self, url: &str) -> Result<Registry> {
-        let registry = Registry::from_url(url)?;
+    pub fn add(&self, url: &str, public_key: Option<&str>) -> Result<Registry> {
+        let mut registry = Registry::from_url(url)?;
+        if let Some(key) = public_key {
+            crate::hive::integrity::parse_public_key(key)?;
+            registry.public_key = Some(key.trim().to_lowercase());
+        }
 
         let mut store = RegistryStore::load(&self.hive_dir)?;
         store.add(registry.clone())?;
         store.save(&self.hive_dir)?;
 
         // Clone the registry
-        self.clone_registry(&registry)?;
+        self.fetch_registry(&registry)?;
 
         Ok(registry)
     }
@@ -195,6 +321,11 @@ impl RegistryManager {
             std::fs::remove_dir_all(&local_path)?;
         }
 
+        let cache_path = self.listing_cache_path(name);
+        if cache_path.exists() {
+            std::fs::remove_file(&cache_path)?;
+        }
+
         Ok(())
     }
 
@@ -214,9 +345,9 @@ impl RegistryManager {
 
         let local_path = registry.local_path(&self.hive_dir);
 
-        if !registry.is_cloned(&self.hive_dir) {
-            // Clone if not present
-            self.clone_registry(&registry)?;
+        if registry.kind == RegistryKind::Http || !registry.is_cloned(&self.hive_dir) {
+            // Clone if not present; HTTP indexes are always re-mirrored
+            self.fetch_registry(&registry)?;
         } else {
             // Pull updates
             self.pull_registry(&local_path)?;
@@ -238,6 +369,54 @@ impl RegistryManager {
         Some(format!("git@github.com:{}", trimmed))
     }
 
+    /// Fetch a registry's packs to its local path, however it is hosted
+    fn fetch_registry(&self, registry: &Registry) -> Result<()> {
+        match registry.kind {
+            RegistryKind::Git => self.clone_registry(registry),
+            RegistryKind::Http => self.mirror_http_registry(registry),
+        }
+    }
+
+    /// Download an HTTP index and every pack file it lists, replacing the
+    /// previous mirror only once everything has been fetched
+    fn mirror_http_registry(&self, registry: &Registry) -> Result<()> {
+        let local_path = registry.local_path(&self.hive_dir);
+        let staging = self
+            .hive_dir
+            .join("registries")
+            .join(format!(".{}.partial", registry.name));
+        if staging.exists() {
+            std::fs::remove_dir_all(&staging)?;
+        }
+        std::fs::create_dir_all(&staging)?;
+
+        let url = registry.url.clone();
+        let dest = staging.clone();
+        let result = std::thread::scope(|scope| {
+            scope
+                .spawn(|| {
+                    tokio::runtime::Builder::new_current_thread()
+                        .enable_all()
+                        .build()
+                        .map_err(|e| MemoryError::Config(format!("tokio runtime: {}", e)))?
+                        .block_on(download_http_index(&url, &dest))
+                })
+                .join()
+                .unwrap_or_else(|_| Err(MemoryError::Config("Registry download panicked".into())))
+        });
+
+        if let Err(e) = result {
+            let _ = std::fs::remove_dir_all(&staging);
+            return Err(e);
+        }
+
+        if local_path.exists() {
+            std::fs::remove_dir_all(&local_path)?;
+        }
+        std::fs::rename(&staging, &local_path)?;
+        Ok(())
+    }
+
     /// Clone a registry from Git (tries HTTPS first, falls back to SSH)
     fn clone_registry(&self, registry: &Registry) -> Result<()> {
         let local_path = registry.local_path(&self.hive_dir);
@@ -260,7 +439,7 @@ impl RegistryManager {
 
         // If HTTPS failed and URL is GitHub, try SSH fallback
         if let Some(ssh_url) = Self::https_to_ssh(&registry.url) {
-            eprintln!("HTTPS clone failed, trying SSH: {}", ssh_url);
+            tracing::info!(url = %ssh_url, "HTTPS clone failed, trying SSH");
 
             // Clean up any partial clone
             if local_path.exists() {
@@ -285,7 +464,7 @@ impl RegistryManager {
     /// Pull updates from a registry
     fn pull_registry(&self, local_path: &Path) -> Result<()> {
         let status = std::process::Command::new("git")
-            .args(["pull", "--ff-only"])
+            .args(["pull", "--ff-only", "--quiet"])
             .current_dir(local_path)
             .status()?;
 
@@ -342,10 +521,10 @@ impl RegistryManager {
                         match KnowledgePack::load(&path) {
                             Ok(pack) => packs.push(pack),
                             Err(e) => {
-                                eprintln!(
-                                    "Warning: Failed to load pack at {}: {}",
-                                    path.display(),
-                                    e
+                                tracing::warn!(
+                                    path = %path.display(),
+                                    error = %e,
+                                    "failed to load pack"
                                 );
                             }
                         }
@@ -361,20 +540,108 @@ impl RegistryManager {
         Ok(packs)
     }
 
-    /// Search packs across all registries
-    pub fn search_packs(&self, query: &str) -> Result<HashMap<String, Vec<KnowledgePack>>> {
+    fn listing_cache_path(&self, registry_name: &str) -> PathBuf {
+        self.hive_dir
+            .join("cache")
+            .join(format!("{}.json", registry_name))
+    }
+
+    fn load_listing_cache(&self, registry_name: &str) -> Option<ListingCache> {
+        let content = std::fs::read_to_string(self.listing_cache_path(registry_name)).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    fn save_listing_cache(&self, registry_name: &str, cache: &ListingCache) -> Result<()> {
+        let path = self.listing_cache_path(registry_name);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&path, serde_json::to_string_pretty(cache)?)?;
+        Ok(())
+    }
+
+    /// List a registry's packs for browsing.
+    ///
+    /// Serves the cached listing while it is younger than
+    /// [`LISTING_TTL_HOURS`]; otherwise (or with `refresh`) updates the
+    /// registry and re-discovers its packs. If that fails — typically
+    /// because we're offline — the last cached listing is served instead.
+    pub fn list_packs(&self, registry_name: &str, refresh: bool) -> Result<PackListing> {
+        let cache = self.load_listing_cache(registry_name);
+        let now = Utc::now();
+
+        if let Some(cache) = &cache {
+            if !refresh && now - cache.fetched_at < chrono::Duration::hours(LISTING_TTL_HOURS) {
+                return Ok(PackListing {
+                    packs: cache.packs.clone(),
+                    fetched_at: cache.fetched_at,
+                    cached: true,
+                });
+            }
+        }
+
+        let fetched = self
+            .update(registry_name)
+            .and_then(|_| self.discover_packs(registry_name));
+        match fetched {
+            Ok(packs) => {
+                let fresh = ListingCache {
+                    fetched_at: now,
+                    packs,
+                };
+                if let Err(e) = self.save_listing_cache(registry_name, &fresh) {
+                    tracing::warn!(registry = registry_name, error = %e, "failed to cache pack listing");
+                }
+                Ok(PackListing {
+                    packs: fresh.packs,
+                    fetched_at: now,
+                    cached: false,
+                })
+            }
+            Err(e) => {
+                tracing::warn!(
+                    registry = registry_name,
+                    error = %e,
+                    "registry refresh failed, using cached pack listing"
+                );
+                match cache {
+                    Some(cache) => Ok(PackListing {
+                        packs: cache.packs,
+                        fetched_at: cache.fetched_at,
+                        cached: true,
+                    }),
+                    // No listing cached yet, but an earlier clone may still be on disk
+                    None => {
+                        let packs = self.discover_packs(registry_name).map_err(|_| e)?;
+                        let fetched_at = self
+                            .list()?
+                            .into_iter()
+                            .find(|r| r.name == registry_name)
+                            .and_then(|r| r.last_updated)
+                            .unwrap_or(now);
+                        Ok(PackListing {
+                            packs,
+                            fetched_at,
+                            cached: true,
+                        })
+                    }
+                }
+            }
+        }
+    }
+
+    /// Search packs across all registries, using each registry's cached
+    /// listing where it is fresh (see [`RegistryManager::list_packs`])
+    pub fn search_packs(&self, query: &str, refresh: bool) -> Result<HashMap<String, PackListing>> {
         let registries = self.list()?;
         let mut results = HashMap::new();
 
         for registry in registries {
-            let packs = self.discover_packs(&registry.name)?;
-            let matches: Vec<KnowledgePack> = packs
-                .into_iter()
-                .filter(|p| p.matches_keyword(query))
-                .collect();
-
-            if !matches.is_empty() {
-                results.insert(registry.name.clone(), matches);
+            let mut listing = self.list_packs(&registry.name, refresh)?;
+            listing.packs.retain(|p| p.matches_keyword(query));
+
+            if !listing.packs.is_empty() {
+                results.insert(registry.name.clone(), listing);
             }
         }
 
@@ -382,6 +649,96 @@ impl RegistryManager {
     }
 }
 
+/// Parse an HTTP index, checking that every pack and file lands inside the
+/// mirror directory
+fn parse_http_index(content: &str) -> Result<HttpIndex> {
+    let index: HttpIndex = serde_json::from_str(content)
+        .map_err(|e| MemoryError::Config(format!("Invalid registry index: {}", e)))?;
+
+    for entry in &index.packs {
+        entry.manifest.validate()?;
+        let name = &entry.manifest.name;
+        if name.starts_with('.') || name.contains(['/', '\\']) {
+            return Err(MemoryError::Config(format!(
+                "Invalid pack name in registry index: {}",
+                name
+            )));
+        }
+        for file in entry.files.keys() {
+            let path = Path::new(file);
+            let safe = path
+                .components()
+                .all(|c| matches!(c, std::path::Component::Normal(_)));
+            let allowed = path.starts_with("knowledge")
+                || path.starts_with("graph")
+                || path == Path::new("README.md");
+            if !safe || !allowed {
+                return Err(MemoryError::Config(format!(
+                    "Invalid file path '{}' for pack '{}' in registry index",
+                    file, name
+                )));
+            }
+        }
+    }
+
+    Ok(index)
+}
+
+/// Fetch the index at `url` and lay its packs out under `dest` the same way
+/// a Git registry is structured (`<pack>/.pack/manifest.json`, `<pack>/knowledge/...`)
+async fn download_http_index(url: &str, dest: &Path) -> Result<()> {
+    let client = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(30))
+        .build()
+        .map_err(|e| MemoryError::Config(format!("HTTP client: {}", e)))?;
+    let base = reqwest::Url::parse(url)
+        .map_err(|e| MemoryError::Config(format!("Invalid registry URL {}: {}", url, e)))?;
+
+    let fetch = |target: reqwest::Url| {
+        let client = client.clone();
+        async move {
+            let resp =
+                client.get(target.clone()).send().await.map_err(|e| {
+                    MemoryError::Config(format!("Failed to fetch {}: {}", target, e))
+                })?;
+            if !resp.status().is_success() {
+                return Err(MemoryError::Config(format!(
+                    "Failed to fetch {} ({})",
+                    target,
+                    resp.status()
+                )));
+            }
+            resp.bytes()
+                .await
+                .map_err(|e| MemoryError::Config(format!("Failed to read {}: {}", target, e)))
+        }
+    };
+
+    let content = fetch(base.clone()).await?;
+    let content = String::from_utf8_lossy(&content).into_owned();
+    let index = parse_http_index(&content)?;
+
+    for entry in &index.packs {
+        let pack_dir = dest.join(&entry.manifest.name);
+        entry.manifest.save(&pack_dir)?;
+
+        for (file, file_url) in &entry.files {
+            let target = base.join(file_url).map_err(|e| {
+                MemoryError::Config(format!("Invalid file URL {}: {}", file_url, e))
+            })?;
+            let bytes = fetch(target).await?;
+            let path = pack_dir.join(file);
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(&path, &bytes)?;
+        }
+    }
+
+    std::fs::write(dest.join(HTTP_INDEX_FILE), content)?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -403,6 +760,68 @@ mod tests {
         assert!(Registry::from_url("invalid").is_err());
     }
 
+    #[test]
+    fn test_registry_from_url_detects_kind() {
+        // GitLab full URL (nested groups)
+        let reg = Registry::from_url("https://gitlab.com/group/sub/packs.git").unwrap();
+        assert_eq!(reg.name, "packs");
+        assert_eq!(reg.kind, RegistryKind::Git);
+
+        // Host shorthand
+        let reg = Registry::from_url("gitlab.com/group/packs").unwrap();
+        assert_eq!(reg.url, "https://gitlab.com/group/packs.git");
+        assert_eq!(reg.kind, RegistryKind::Git);
+
+        // HTTP index named after its directory
+        let reg = Registry::from_url("https://example.com/engram/index.json").unwrap();
+        assert_eq!(reg.name, "engram");
+        assert_eq!(reg.kind, RegistryKind::Http);
+
+        // HTTP index named after its file, query string ignored
+        let reg = Registry::from_url("https://example.com/team-packs.json?ref=main").unwrap();
+        assert_eq!(reg.name, "team-packs");
+        assert_eq!(reg.kind, RegistryKind::Http);
+
+        // Index at the root is named after the host
+        let reg = Registry::from_url("https://packs.example.com/index.json").unwrap();
+        assert_eq!(reg.name, "packs.example.com");
+
+        // Records saved before `kind` existed are Git
+        let reg: Registry = serde_json::from_str(
+            r#"{"name":"repo","url":"https://github.com/u/repo.git","last_updated":null}"#,
+        )
+        .unwrap();
+        assert_eq!(reg.kind, RegistryKind::Git);
+    }
+
+    #[test]
+    fn test_parse_http_index_rejects_unsafe_paths() {
+        let manifest = serde_json::to_value(KnowledgePack::new(
+            "rust-patterns".to_string(),
+            "Test".to_string(),
+            crate::hive::pack::Author::new("Tester".to_string()),
+            "https://example.com/packs".to_string(),
+        ))
+        .unwrap();
+        let index = |files: serde_json::Value| {
+            serde_json::json!({"packs": [{"manifest": manifest, "files": files}]}).to_string()
+        };
+
+        let parsed = parse_http_index(&index(
+            serde_json::json!({"knowledge/patterns.md": "patterns.md"}),
+        ))
+        .unwrap();
+        assert_eq!(parsed.packs.len(), 1);
+        assert_eq!(parsed.packs[0].files.len(), 1);
+
+        assert!(parse_http_index(&index(serde_json::json!({"../escape.md": "x"}))).is_err());
+        assert!(
+            parse_http_index(&index(serde_json::json!({"knowledge/../../x.md": "x"}))).is_err()
+        );
+        assert!(parse_http_index(&index(serde_json::json!({"/etc/passwd": "x"}))).is_err());
+        assert!(parse_http_index(&index(serde_json::json!({".pack/manifest.json": "x"}))).is_err());
+    }
+
     #[test]
     fn test_registry_store_crud() {
         let temp_dir = TempDir::new().unwrap();
@@ -463,6 +882,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_list_packs_serves_fresh_cache() {
+        let temp_dir = TempDir::new().unwrap();
+        let manager = RegistryManager::new(temp_dir.path());
+
+        // Registry that can't be fetched: only the cache can answer
+        let mut store = RegistryStore::default();
+        store
+            .add(Registry::from_url("file:///nonexistent/engram-reg").unwrap())
+            .unwrap();
+        store.save(&manager.hive_dir).unwrap();
+
+        let pack = KnowledgePack::new(
+            "rust-patterns".to_string(),
+            "Test".to_string(),
+            crate::hive::pack::Author::new("Tester".to_string()),
+            "https://example.com/packs".to_string(),
+        );
+        let fetched_at = Utc::now() - chrono::Duration::hours(1);
+        manager
+            .save_listing_cache(
+                "engram-reg",
+                &ListingCache {
+                    fetched_at,
+                    packs: vec![pack],
+                },
+            )
+            .unwrap();
+
+        let listing = manager.list_packs("engram-reg", false).unwrap();
+        assert!(listing.cached);
+        assert_eq!(listing.fetched_at, fetched_at);
+        assert_eq!(listing.packs[0].name, "rust-patterns");
+
+        let results = manager.search_packs("rust", false).unwrap();
+        assert_eq!(results["engram-reg"].packs.len(), 1);
+        assert!(manager.search_packs("python", false).unwrap().is_empty());
+
+        // Removing the registry drops its cached listing
+        manager.remove("engram-reg").unwrap();
+        assert!(!manager.listing_cache_path("engram-reg").exists());
+    }
+
     #[test]
     fn test_registry_manager() {
         let temp_dir = TempDir::new().unwrap();
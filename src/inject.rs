@@ -7,18 +7,41 @@ use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
 
 use crate::extractor::knowledge::{
-    parse_session_blocks, partition_by_expiry, reconstruct_blocks, strip_private_tags, SessionBlock,
+    is_nearing_expiry, parse_session_blocks, partition_by_expiry, reconstruct_blocks,
+    strip_private_tags, SessionBlock,
 };
 use crate::hive::PackInstaller;
 
+/// Order of blocks within each category of a raw context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BlockOrder {
+    /// Highest persisted importance, then newest first; blocks close to expiry go last
+    #[default]
+    Fresh,
+    /// Keep file order (oldest first)
+    Chronological,
+}
+
 /// Build a lightweight context string from raw knowledge files (no LLM).
 /// Used as fallback when context.md doesn't exist but knowledge files do.
 /// Returns None if no knowledge files exist or all are empty/expired.
 pub fn build_raw_context(project: &str, project_knowledge_dir: &Path) -> Option<String> {
+    build_raw_context_ordered(project, project_knowledge_dir, BlockOrder::default())
+}
+
+/// [`build_raw_context`] with an explicit block order.
+pub fn build_raw_context_ordered(
+    project: &str,
+    project_knowledge_dir: &Path,
+    order: BlockOrder,
+) -> Option<String> {
     let read_and_filter = |path: &Path| -> String {
         let raw = std::fs::read_to_string(path).unwrap_or_default();
         let (preamble, blocks) = parse_session_blocks(&raw);
-        let (active, _) = partition_by_expiry(blocks);
+        let (mut active, _) = partition_by_expiry(blocks);
+        if order == BlockOrder::Fresh {
+            sort_by_freshness(&mut active);
+        }
         reconstruct_blocks(&preamble, &active)
     };
 
@@ -29,6 +52,7 @@ pub fn build_raw_context(project: &str, project_knowledge_dir: &Path) -> Option<
     let insights = read_and_filter(&project_knowledge_dir.join("insights.md"));
     let questions = read_and_filter(&project_knowledge_dir.join("questions.md"));
     let procedures = read_and_filter(&project_knowledge_dir.join("procedures.md"));
+    let workflows = read_and_filter(&project_knowledge_dir.join("workflows.md"));
 
     if decisions.trim().is_empty()
         && solutions.trim().is_empty()
@@ -37,6 +61,7 @@ pub fn build_raw_context(project: &str, project_knowledge_dir: &Path) -> Option<
         && insights.trim().is_empty()
         && questions.trim().is_empty()
         && procedures.trim().is_empty()
+        && workflows.trim().is_empty()
     {
         return None;
     }
@@ -75,10 +100,33 @@ pub fn build_raw_context(project: &str, project_knowledge_dir: &Path) -> Option<
         out.push_str(&procedures);
         out.push_str("\n\n");
     }
+    if !workflows.trim().is_empty() {
+        out.push_str(&workflows);
+        out.push_str("\n\n");
+    }
 
     Some(out)
 }
 
+/// Stable-sort blocks for [`BlockOrder::Fresh`]: blocks nearing expiry last, then by
+/// persisted `[importance:N]` tag (untagged counts as neutral 0.5), then newest first.
+/// Blocks without a parseable timestamp sort after the dated blocks sharing their
+/// expiry and importance, keeping their relative order among themselves.
+fn sort_by_freshness(blocks: &mut [SessionBlock]) {
+    let now = chrono::Utc::now();
+    blocks.sort_by_cached_key(|b| {
+        let importance = (b.importance.unwrap_or(0.5) * 1000.0).round() as i64;
+        let timestamp = chrono::DateTime::parse_from_rfc3339(&b.timestamp)
+            .map(|t| t.timestamp())
+            .unwrap_or(i64::MIN);
+        (
+            is_nearing_expiry(b, now),
+            std::cmp::Reverse(importance),
+            std::cmp::Reverse(timestamp),
+        )
+    });
+}
+
 /// Read non-preference global knowledge (decisions, solutions, patterns, bugs, insights)
 /// from the _global directory. Returns None if nothing is stored there yet.
 pub fn read_global_knowledge(memory_dir: &Path) -> Option<String> {
@@ -101,6 +149,7 @@ pub fn read_global_knowledge(memory_dir: &Path) -> Option<String> {
         ("insights", "insights.md"),
         ("bugs", "bugs.md"),
         ("procedures", "procedures.md"),
+        ("workflows", "workflows.md"),
     ];
 
     let mut sections: Vec<String> = Vec::new();
@@ -247,9 +296,11 @@ fn sort_by_importance(
     }
 
     // Find timestamp range for normalization (clone to avoid borrow conflicts)
+    // (blocks without a timestamp don't count towards the range)
     let oldest = blocks
         .iter()
         .map(|b| b.timestamp.clone())
+        .filter(|t| !t.is_empty())
         .min()
         .unwrap_or_default();
     let newest = blocks
@@ -258,10 +309,20 @@ fn sort_by_importance(
         .max()
         .unwrap_or_default();
 
+    // Learned boost from state, falling back to the importance persisted in the header
+    let block_boost = |block: &SessionBlock| -> f32 {
+        let boost = lookup_boost(boosts, &block.session_id, category, project);
+        if boost == 0.0 {
+            block.importance.map(|i| i - 0.5).unwrap_or(0.0)
+        } else {
+            boost
+        }
+    };
+
     // Sort by importance score descending
     blocks.sort_by(|a, b| {
-        let boost_a = lookup_boost(boosts, &a.session_id, category, project);
-        let boost_b = lookup_boost(boosts, &b.session_id, category, project);
+        let boost_a = block_boost(a);
+        let boost_b = block_boost(b);
 
         let score_a = compute_importance_score(&a.timestamp, boost_a, &oldest, &newest, a.strength)
             * confidence_multiplier(a.confidence.as_deref());
@@ -287,7 +348,7 @@ pub fn compact_preferences(raw_prefs: &str, memory_dir: &Path, project: &str) ->
 
     // Sort by importance so high-value sessions contribute first
     let boosts = load_importance_boosts(memory_dir, project);
-    if !boosts.is_empty() {
+    if !boosts.is_empty() || active.iter().any(|b| b.importance.is_some()) {
         sort_by_importance(&mut active, &boosts, project, Some("preferences"));
     }
 
@@ -395,10 +456,10 @@ pub fn deduplicate_values(values: &[String]) -> Vec<String> {
     items
 }
 
-/// Create a compact index of installed pack knowledge (names + entry counts).
-pub fn compact_pack_summary(memory_dir: &Path) -> crate::Result<String> {
+/// Create a compact index of the project's installed pack knowledge (names + entry counts).
+pub fn compact_pack_summary(memory_dir: &Path, project: &str) -> crate::Result<String> {
     let installer = PackInstaller::new(memory_dir);
-    let knowledge_dirs = installer.get_installed_knowledge_dirs()?;
+    let knowledge_dirs = installer.get_installed_knowledge_dirs(Some(project))?;
 
     if knowledge_dirs.is_empty() {
         return Ok(String::new());
@@ -453,7 +514,7 @@ pub fn compact_shared(
 
     // Sort by importance (boost + recency) or fallback to timestamp
     let boosts = load_importance_boosts(memory_dir, project);
-    if !boosts.is_empty() {
+    if !boosts.is_empty() || active.iter().any(|b| b.importance.is_some()) {
         sort_by_importance(&mut active, &boosts, project, Some("shared"));
     } else {
         // Fallback: timestamp descending (original behavior)
@@ -514,11 +575,11 @@ pub fn trim_to_budget(content: &str, max_lines: usize) -> String {
 pub struct SmartEntry {
     pub category: String,
     pub session_id: String,
-    pub preview: String,            // first 120 chars of content
+    pub preview: String, // first 120 chars of content
     pub content: String,
-    pub score: f32,                 // semantic relevance 0.0–1.0 (after recency decay)
-    pub selected: bool,             // toggled in TUI preview
-    pub timestamp: Option<String>,  // ISO-8601 from chunk metadata
+    pub score: f32,                // semantic relevance 0.0–1.0 (after recency decay)
+    pub selected: bool,            // toggled in TUI preview
+    pub timestamp: Option<String>, // ISO-8601 from chunk metadata
 }
 
 /// Exponential recency decay: score × exp(-λ × days_since_written).
@@ -529,9 +590,7 @@ fn decay_factor(timestamp: &str) -> f32 {
     let Ok(dt) = chrono::DateTime::parse_from_rfc3339(timestamp) else {
         return 1.0;
     };
-    let days = (chrono::Utc::now() - dt.to_utc())
-        .num_days()
-        .max(0) as f64;
+    let days = (chrono::Utc::now() - dt.to_utc()).num_days().max(0) as f64;
     (-LAMBDA * days).exp() as f32
 }
 
@@ -542,7 +601,7 @@ const GLOBAL_PENALTY: f32 = 0.85;
 impl SmartEntry {
     /// Rough token estimate (~4 chars per token).
     pub fn estimated_tokens(&self) -> usize {
-        (self.content.len() / 4).max(1)
+        estimate_tokens(&self.content)
     }
 }
 
@@ -885,6 +944,7 @@ pub fn format_smart_memory(
         "insights",
         "questions",
         "procedures",
+        "workflows",
         "context",
     ];
     for cat in &categories {
@@ -941,6 +1001,10 @@ pub fn format_smart_memory(
             results_count: Some(selected.len()),
             session_id: None,
             tokens_consumed: Some(total_tokens as u64),
+            knowledge_ids: selected
+                .iter()
+                .map(|e| format!("{}:{}", e.category, e.session_id))
+                .collect(),
         });
     }
 
@@ -1062,7 +1126,7 @@ pub fn build_compact_memory_with_budget(
     }
 
     // 5. Pack index (summary, not full content)
-    let pack_summary = compact_pack_summary(memory_dir)?;
+    let pack_summary = compact_pack_summary(memory_dir, project_name)?;
     if !pack_summary.is_empty() {
         combined.push_str("## Installed Packs\n\n");
         combined.push_str(&pack_summary);
@@ -1117,7 +1181,7 @@ pub fn build_full_memory(
         combined.push_str(&gk);
     }
 
-    let pack_content = crate::hive::get_installed_pack_knowledge(memory_dir)?;
+    let pack_content = crate::hive::get_installed_pack_knowledge(memory_dir, project_name)?;
     if !pack_content.is_empty() {
         combined.push_str("\n\n---\n\n## Installed Pack Knowledge\n\n");
         combined.push_str(&pack_content);
@@ -1126,6 +1190,175 @@ pub fn build_full_memory(
     Ok(strip_private_tags(&combined))
 }
 
+/// Rough token estimate (~4 bytes per token), matching smart inject's accounting.
+pub fn estimate_tokens(text: &str) -> usize {
+    (text.len() / 4).max(1)
+}
+
+/// Token budget used by smart inject when `--budget` is not given.
+pub const DEFAULT_SMART_BUDGET: usize = 1500;
+
+/// Trim generated MEMORY.md content to fit roughly `budget` tokens.
+///
+/// Lowest-priority content goes first: installed pack sections, then session
+/// blocks oldest-first, then (as a last resort) trailing lines. Returns the
+/// trimmed content and a human-readable description of each trim step.
+pub fn fit_to_token_budget(content: &str, budget: usize) -> (String, Vec<String>) {
+    let mut trimmed = Vec::new();
+    if estimate_tokens(content) <= budget {
+        return (content.to_string(), trimmed);
+    }
+
+    let lines: Vec<&str> = content.lines().collect();
+    let mut keep = vec![true; lines.len()];
+    let kept_text = |keep: &[bool]| -> String {
+        lines
+            .iter()
+            .zip(keep)
+            .filter(|(_, k)| **k)
+            .map(|(l, _)| *l)
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    // 1. Installed packs (summary in compact mode, full content in --full)
+    let mut pack_tokens = 0;
+    let mut i = 0;
+    while i < lines.len() {
+        if lines[i].starts_with("## Installed Pack") {
+            let start = i;
+            i += 1;
+            while i < lines.len() && !is_section_boundary(lines[i]) {
+                i += 1;
+            }
+            for k in keep.iter_mut().take(i).skip(start) {
+                *k = false;
+            }
+            pack_tokens += estimate_tokens(&lines[start..i].join("\n"));
+        } else {
+            i += 1;
+        }
+    }
+    if pack_tokens > 0 {
+        trimmed.push(format!("installed packs (~{} tokens)", pack_tokens));
+    }
+
+    // 2. Session blocks, oldest first
+    if estimate_tokens(&kept_text(&keep)) > budget {
+        let mut blocks: Vec<(String, usize, usize)> = Vec::new();
+        let mut i = 0;
+        while i < lines.len() {
+            if keep[i] && lines[i].starts_with("## Session:") {
+                let start = i;
+                i += 1;
+                while i < lines.len() && !lines[i].starts_with("## ") && lines[i].trim() != "---" {
+                    i += 1;
+                }
+                let ts = lines[start]
+                    .split_once('(')
+                    .and_then(|(_, rest)| rest.split_once(')'))
+                    .map(|(ts, _)| ts.to_string())
+                    .unwrap_or_default();
+                blocks.push((ts, start, i));
+            } else {
+                i += 1;
+            }
+        }
+        blocks.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut dropped = 0;
+        let mut dropped_tokens = 0;
+        for (_, start, end) in blocks {
+            if estimate_tokens(&kept_text(&keep)) <= budget {
+                break;
+            }
+            dropped_tokens += estimate_tokens(&lines[start..end].join("\n"));
+            for k in keep.iter_mut().take(end).skip(start) {
+                *k = false;
+            }
+            dropped += 1;
+        }
+        if dropped > 0 {
+            trimmed.push(format!(
+                "{} older knowledge blocks (~{} tokens)",
+                dropped, dropped_tokens
+            ));
+        }
+    }
+
+    // 3. Hard cut of trailing lines
+    let mut out = kept_text(&keep);
+    if estimate_tokens(&out) > budget {
+        let note = "\n\n_(Truncated to fit token budget. Run `engram recall` for full content.)_";
+        let max_bytes = (budget * 4).saturating_sub(note.len());
+        let mut cut = String::new();
+        for line in out.lines() {
+            if cut.len() + line.len() + 1 > max_bytes {
+                break;
+            }
+            cut.push_str(line);
+            cut.push('\n');
+        }
+        let removed = estimate_tokens(&out).saturating_sub(estimate_tokens(&cut));
+        trimmed.push(format!("trailing content (~{} tokens)", removed));
+        out = format!("{}{}", cut.trim_end(), note);
+    }
+
+    (out, trimmed)
+}
+
+/// End of a top-level MEMORY.md section (pack sections contain their own `##` headers).
+fn is_section_boundary(line: &str) -> bool {
+    line.trim() == "---"
+        || (line.starts_with("## ")
+            && !line.starts_with("## From pack:")
+            && !line.starts_with("## Session:"))
+}
+
+/// Start marker of the engram-managed region in MEMORY.md.
+pub const MANAGED_BEGIN: &str = "<!-- engram:begin -->";
+/// End marker of the engram-managed region in MEMORY.md.
+pub const MANAGED_END: &str = "<!-- engram:end -->";
+
+/// Splice freshly generated memory into an existing MEMORY.md.
+///
+/// Only the region between `MANAGED_BEGIN`/`MANAGED_END` is replaced; anything
+/// outside it is kept verbatim. A file without markers that starts with the
+/// engram header is treated as fully generated (pre-marker output) and replaced;
+/// any other file keeps its content and gets the managed block appended.
+pub fn merge_managed_block(existing: Option<&str>, generated: &str) -> String {
+    let block = format!(
+        "{}\n{}\n{}\n",
+        MANAGED_BEGIN,
+        generated.trim_end(),
+        MANAGED_END
+    );
+
+    let Some(existing) = existing.filter(|e| !e.trim().is_empty()) else {
+        return block;
+    };
+
+    if let Some(start) = existing.find(MANAGED_BEGIN) {
+        if let Some(end_rel) = existing[start..].find(MANAGED_END) {
+            let end = start + end_rel + MANAGED_END.len();
+            let before = &existing[..start];
+            let after = existing[end..]
+                .strip_prefix('\n')
+                .unwrap_or(&existing[end..]);
+            return format!("{}{}{}", before, block, after);
+        }
+    }
+
+    if existing
+        .trim_start()
+        .starts_with("# Project Memory (auto-injected by engram)")
+    {
+        return block;
+    }
+
+    format!("{}\n\n{}", existing.trim_end(), block)
+}
+
 #[cfg(test)]
 mod fadem_tests {
     use super::fadem_retention;
@@ -1267,9 +1500,11 @@ mod tests {
                 session_id: "recent-important".to_string(),
                 timestamp: "2024-02-12T00:00:00Z".to_string(),
                 ttl: None,
+                expires: None,
                 confidence: None,
                 strength: Some(5.0), // Very high strength keeps it alive
                 access_count: None,
+                importance: None,
                 header: "## Session: recent-important (2024-02-12T00:00:00Z)\n".to_string(),
                 content: "High-value knowledge".to_string(),
                 preview: "High-value".to_string(),
@@ -1278,9 +1513,11 @@ mod tests {
                 session_id: "recent-unimportant".to_string(),
                 timestamp: "2024-02-13T00:00:00Z".to_string(),
                 ttl: None,
+                expires: None,
                 confidence: None,
                 strength: None,
                 access_count: None,
+                importance: None,
                 header: "## Session: recent-unimportant (2024-02-13T00:00:00Z)\n".to_string(),
                 content: "Low-value recent".to_string(),
                 preview: "Low-value".to_string(),
@@ -1298,6 +1535,140 @@ mod tests {
         assert_eq!(blocks[1].session_id, "recent-unimportant");
     }
 
+    #[test]
+    fn test_fit_to_token_budget_under_budget_untouched() {
+        let content = "# Project Memory\n\nsmall\n";
+        let (out, trimmed) = fit_to_token_budget(content, 1000);
+        assert_eq!(out, content);
+        assert!(trimmed.is_empty());
+    }
+
+    #[test]
+    fn test_fit_to_token_budget_drops_packs_then_oldest_blocks() {
+        let filler = "x".repeat(400);
+        let content = format!(
+            "# Project Memory\n\n## Project: p\n\n## Session: old (2024-01-01T00:00:00Z)\n{f}\n\n## Session: new (2025-01-01T00:00:00Z)\n{f}\n\n---\n\n## Installed Packs\n\n{f}\n\n## Retrieving More Context\n\nguide\n",
+            f = filler
+        );
+
+        // Dropping packs alone is enough
+        let (out, trimmed) = fit_to_token_budget(&content, 260);
+        assert!(!out.contains("Installed Packs"));
+        assert!(out.contains("Session: old") && out.contains("Session: new"));
+        assert!(out.contains("Retrieving More Context"));
+        assert_eq!(trimmed.len(), 1);
+
+        // Tighter budget also drops the oldest block
+        let (out, trimmed) = fit_to_token_budget(&content, 160);
+        assert!(!out.contains("Session: old"));
+        assert!(out.contains("Session: new"));
+        assert_eq!(trimmed.len(), 2);
+        assert!(estimate_tokens(&out) <= 160);
+    }
+
+    #[test]
+    fn test_fit_to_token_budget_hard_truncates() {
+        let content = "line\n".repeat(500);
+        let (out, trimmed) = fit_to_token_budget(&content, 50);
+        assert!(estimate_tokens(&out) <= 50);
+        assert!(out.ends_with("for full content.)_"));
+        assert_eq!(trimmed.len(), 1);
+    }
+
+    #[test]
+    fn test_merge_managed_block_preserves_manual_notes() {
+        let existing = format!(
+            "# My notes\n\nKeep this.\n\n{}\nold generated\n{}\n\n## Footer\nAlso keep.\n",
+            MANAGED_BEGIN, MANAGED_END
+        );
+        let merged = merge_managed_block(Some(&existing), "new generated\n");
+        assert!(merged.starts_with("# My notes\n\nKeep this.\n\n"));
+        assert!(merged.contains("new generated"));
+        assert!(!merged.contains("old generated"));
+        assert!(merged.ends_with("\n## Footer\nAlso keep.\n"));
+        assert_eq!(merged.matches(MANAGED_BEGIN).count(), 1);
+    }
+
+    #[test]
+    fn test_merge_managed_block_without_markers() {
+        let merged = merge_managed_block(Some("Hand-written\n"), "generated");
+        assert!(merged.starts_with("Hand-written\n\n"));
+        assert!(merged.ends_with(&format!("{}\ngenerated\n{}\n", MANAGED_BEGIN, MANAGED_END)));
+
+        let legacy = "# Project Memory (auto-injected by engram)\n\nstale\n";
+        let merged = merge_managed_block(Some(legacy), "fresh");
+        assert!(!merged.contains("stale"));
+        assert!(merged.starts_with(MANAGED_BEGIN));
+
+        assert!(merge_managed_block(None, "fresh").starts_with(MANAGED_BEGIN));
+    }
+
+    #[test]
+    fn test_build_raw_context_orders_by_persisted_importance() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            tmp.path().join("decisions.md"),
+            "# Decisions\n\n## Session: low (2024-01-01T00:00:00Z) [importance:0.20]\n\nLow priority decision\n\n## Session: plain (2024-01-02T00:00:00Z)\n\nUntagged decision\n\n## Session: high (2024-01-03T00:00:00Z) [importance:0.90]\n\nHigh priority decision\n",
+        )
+        .unwrap();
+
+        let ctx = build_raw_context("test", tmp.path()).unwrap();
+        let high = ctx.find("High priority").unwrap();
+        let plain = ctx.find("Untagged").unwrap();
+        let low = ctx.find("Low priority").unwrap();
+        assert!(high < plain && plain < low, "{}", ctx);
+    }
+
+    #[test]
+    fn test_build_raw_context_fresh_first() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let now = chrono::Utc::now();
+        let fmt = |t: chrono::DateTime<chrono::Utc>| t.format("%Y-%m-%dT%H:%M:%SZ").to_string();
+        std::fs::write(
+            tmp.path().join("decisions.md"),
+            format!(
+                "# Decisions\n\n## Session: old (2024-01-01T00:00:00Z)\n\nOld decision\n\n\
+                 ## Session: expiring ({}) [ttl:1d]\n\nExpiring decision\n\n\
+                 ## Session: new ({})\n\nNew decision\n",
+                fmt(now - chrono::Duration::hours(23)),
+                fmt(now - chrono::Duration::hours(1)),
+            ),
+        )
+        .unwrap();
+
+        let ctx = build_raw_context("test", tmp.path()).unwrap();
+        let new = ctx.find("New decision").unwrap();
+        let old = ctx.find("Old decision").unwrap();
+        let expiring = ctx.find("Expiring decision").unwrap();
+        assert!(new < old && old < expiring, "{}", ctx);
+
+        let ctx = build_raw_context_ordered("test", tmp.path(), BlockOrder::Chronological).unwrap();
+        let new = ctx.find("New decision").unwrap();
+        let old = ctx.find("Old decision").unwrap();
+        let expiring = ctx.find("Expiring decision").unwrap();
+        assert!(old < expiring && expiring < new, "{}", ctx);
+    }
+
+    #[test]
+    fn test_fresh_order_puts_undated_blocks_last() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            tmp.path().join("decisions.md"),
+            "# Decisions\n\n## Session: manual-a\n\nUndated A\n\n\
+             ## Session: old (2024-01-01T00:00:00Z)\n\nOld decision\n\n\
+             ## Session: manual-b (not a date)\n\nUndated B\n\n\
+             ## Session: new (2024-06-01T00:00:00Z)\n\nNew decision\n",
+        )
+        .unwrap();
+
+        let ctx = build_raw_context("test", tmp.path()).unwrap();
+        let order: Vec<usize> = ["New decision", "Old decision", "Undated A", "Undated B"]
+            .iter()
+            .map(|text| ctx.find(text).unwrap())
+            .collect();
+        assert!(order.windows(2).all(|w| w[0] < w[1]), "{}", ctx);
+    }
+
     #[test]
     fn test_sort_by_importance_fallback_no_boosts() {
         let mut blocks = vec![
@@ -1305,9 +1676,11 @@ mod tests {
                 session_id: "old".to_string(),
                 timestamp: "2024-01-01T00:00:00Z".to_string(),
                 ttl: None,
+                expires: None,
                 confidence: None,
                 strength: None,
                 access_count: None,
+                importance: None,
                 header: "## Session: old (2024-01-01T00:00:00Z)\n".to_string(),
                 content: "Old".to_string(),
                 preview: "Old".to_string(),
@@ -1316,9 +1689,11 @@ mod tests {
                 session_id: "recent".to_string(),
                 timestamp: "2024-02-13T00:00:00Z".to_string(),
                 ttl: None,
+                expires: None,
                 confidence: None,
                 strength: None,
                 access_count: None,
+                importance: None,
                 header: "## Session: recent (2024-02-13T00:00:00Z)\n".to_string(),
                 content: "Recent".to_string(),
                 preview: "Recent".to_string(),
@@ -25,8 +25,57 @@ pub struct LearnedParameters {
 
     /// Graph importance weight adjustments
     pub graph_weight_boosts: HashMap<String, f32>,
+
+    /// Number of learning signals that contributed to each knowledge ID's adjustments
+    #[serde(default)]
+    pub observation_counts: HashMap<String, u32>,
+}
+
+impl LearnedParameters {
+    /// Record that one more learning signal touched `knowledge_id`.
+    pub fn record_observation(&mut self, knowledge_id: &str) {
+        *self
+            .observation_counts
+            .entry(knowledge_id.to_string())
+            .or_insert(0) += 1;
+    }
+
+    /// Number of signals backing adjustments for `knowledge_id`.
+    pub fn observations(&self, knowledge_id: &str) -> u32 {
+        self.observation_counts
+            .get(knowledge_id)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Signals backing the block `session_id` in `category`.
+    pub fn block_observations(&self, category: &str, session_id: &str) -> u32 {
+        block_entry(&self.observation_counts, category, session_id)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Learned importance boost for the block `session_id` in `category`, if any.
+    pub fn block_boost(&self, category: &str, session_id: &str) -> Option<f32> {
+        block_entry(&self.importance_boosts, category, session_id).copied()
+    }
+}
+
+/// Per-block value keyed as `category:session_id`, falling back to the bare
+/// session ID used by older learning data.
+fn block_entry<'a, T>(
+    map: &'a HashMap<String, T>,
+    category: &str,
+    session_id: &str,
+) -> Option<&'a T> {
+    map.get(&format!("{}:{}", category, session_id))
+        .or_else(|| map.get(session_id))
 }
 
+/// Minimum number of learning signals a knowledge ID needs before its
+/// importance/TTL adjustments are surfaced or applied.
+pub const DEFAULT_MIN_OBSERVATIONS: u32 = 3;
+
 /// Apply learned importance boosts to knowledge scores
 pub fn apply_importance_boosts(
     scores: &mut HashMap<String, KnowledgeScore>,
@@ -40,23 +89,34 @@ pub fn apply_importance_boosts(
     }
 }
 
-/// Apply learned parameters to a project
+/// Apply learned parameters to a project.
+///
+/// Importance and TTL adjustments are only applied for knowledge IDs backed by
+/// at least `min_observations` learning signals.
 pub fn apply_learned_parameters(
     config: &Config,
     project: &str,
     learning_state: &LearningState,
+    min_observations: u32,
 ) -> Result<ApplyResult> {
     let params = &learning_state.learned_parameters;
     let mut result = ApplyResult::default();
 
-    // 1. Apply importance boosts to analytics
-    if !params.importance_boosts.is_empty() {
-        apply_importance_boosts_to_analytics(config, project, &params.importance_boosts)?;
-        result.importance_adjustments = params.importance_boosts.len();
+    // 1. Persist importance scores onto knowledge block headers
+    let supported_boosts: HashMap<String, f32> = params
+        .importance_boosts
+        .iter()
+        .filter(|(id, _)| params.observations(id) >= min_observations)
+        .map(|(id, boost)| (id.clone(), *boost))
+        .collect();
+    if !supported_boosts.is_empty() {
+        result.importance_adjustments =
+            persist_importance_scores(config, project, &supported_boosts)?;
     }
 
     // 2. Apply TTL adjustments via Q-learning policy
-    result.ttl_adjustments = apply_ttl_adjustments(config, project, learning_state)?;
+    result.ttl_adjustments =
+        apply_ttl_adjustments(config, project, learning_state, min_observations)?;
 
     // 3. Apply graph weight boosts
     if !params.graph_weight_boosts.is_empty() {
@@ -81,15 +141,68 @@ pub struct ApplyResult {
     pub consolidation_updated: bool,
 }
 
-/// Apply importance boosts to analytics data
-fn apply_importance_boosts_to_analytics(
-    _config: &Config,
-    _project: &str,
-    _boosts: &HashMap<String, f32>,
-) -> Result<()> {
-    // Placeholder - would need to extend analytics module to support persisting scores
-    // For now, boosts are applied dynamically when scores are computed
-    Ok(())
+/// Importance score a block gets from a learned boost (neutral = 0.5).
+pub fn importance_from_boost(boost: f32) -> f32 {
+    (0.5 + boost).clamp(0.1, 1.0)
+}
+
+/// Write learned importance onto matching knowledge blocks as an `[importance:N]` header tag.
+///
+/// Boost keys may be a bare session ID or `category:session_id`.
+/// Returns the number of blocks whose header changed.
+fn persist_importance_scores(
+    config: &Config,
+    project: &str,
+    boosts: &HashMap<String, f32>,
+) -> Result<usize> {
+    use crate::extractor::knowledge::{build_header, reconstruct_blocks};
+
+    let knowledge_dir = config.memory_dir.join("knowledge").join(project);
+    let mut total_updated = 0usize;
+
+    for cat in crate::config::CATEGORIES {
+        let path = knowledge_dir.join(format!("{}.md", cat));
+        if !path.exists() {
+            continue;
+        }
+
+        let raw = std::fs::read_to_string(&path)?;
+        let (preamble, mut blocks) = parse_session_blocks(&raw);
+        let mut file_modified = false;
+
+        for block in &mut blocks {
+            let Some(boost) = block_entry(boosts, cat, &block.session_id) else {
+                continue;
+            };
+
+            let importance = importance_from_boost(*boost);
+            if block
+                .importance
+                .is_some_and(|current| (current - importance).abs() < 0.005)
+            {
+                continue;
+            }
+
+            block.importance = Some(importance);
+            block.header = build_header(
+                &block.session_id,
+                &block.timestamp,
+                block.ttl.as_deref(),
+                block.confidence.as_deref(),
+                block.strength,
+                block.access_count,
+                block.importance,
+            );
+            file_modified = true;
+            total_updated += 1;
+        }
+
+        if file_modified {
+            std::fs::write(&path, reconstruct_blocks(&preamble, &blocks))?;
+        }
+    }
+
+    Ok(total_updated)
 }
 
 /// Apply TTL adjustments derived from the Q-learning policy.
@@ -100,15 +213,22 @@ fn apply_importance_boosts_to_analytics(
 /// 3. Skip if Q-table is untrained (no data) or action would be a no-op.
 /// 4. Rewrite the block header in the knowledge file with the new TTL.
 ///
+/// Blocks backed by fewer than `min_observations` signals are left untouched.
+///
 /// Returns the number of blocks actually updated.
-fn apply_ttl_adjustments(config: &Config, project: &str, state: &LearningState) -> Result<usize> {
+fn apply_ttl_adjustments(
+    config: &Config,
+    project: &str,
+    state: &LearningState,
+    min_observations: u32,
+) -> Result<usize> {
     // Require at least some Q-table training before touching files.
     if state.ttl_q_learning.q_table.is_empty() {
         return Ok(0);
     }
 
     let knowledge_dir = config.memory_dir.join("knowledge").join(project);
-    let boosts = &state.learned_parameters.importance_boosts;
+    let params = &state.learned_parameters;
     let now = Utc::now();
     let mut total_updated = 0usize;
 
@@ -135,8 +255,15 @@ fn apply_ttl_adjustments(config: &Config, project: &str, state: &LearningState)
         let mut rebuilt = preamble.clone();
 
         for block in &active {
+            // Not enough evidence yet — keep the block as-is
+            if params.block_observations(cat, &block.session_id) < min_observations {
+                rebuilt.push_str(&block.header);
+                rebuilt.push_str(&block.content);
+                continue;
+            }
+
             // Determine TTL state for this block
-            let boost = boosts.get(&block.session_id).copied().unwrap_or(0.0);
+            let boost = params.block_boost(cat, &block.session_id).unwrap_or(0.0);
             let ttl_state = compute_ttl_state(boost, &block.timestamp, now);
 
             // Query Q-learning for recommended action
@@ -164,6 +291,7 @@ fn apply_ttl_adjustments(config: &Config, project: &str, state: &LearningState)
                 &block.timestamp,
                 new_ttl_days,
                 block.confidence.as_deref(),
+                block.importance,
             );
 
             rebuilt.push_str(&new_header);
@@ -192,6 +320,7 @@ fn build_block_header(
     timestamp: &str,
     ttl_days: Option<u32>,
     confidence: Option<&str>,
+    importance: Option<f32>,
 ) -> String {
     let ttl_part = match ttl_days {
         Some(days) => format!(" [ttl:{}d]", days),
@@ -201,9 +330,16 @@ fn build_block_header(
         Some(c) => format!(" [confidence:{}]", c),
         None => String::new(),
     };
+    let importance_part = match importance {
+        Some(i) => format!(" [importance:{:.2}]", i),
+        None => String::new(),
+    };
     format!(
-        "## Session: {} ({}){}{}",
-        session_id, timestamp, ttl_part, conf_part
+        "{}{}{}{}",
+        crate::extractor::knowledge::session_header(session_id, timestamp),
+        ttl_part,
+        conf_part,
+        importance_part
     )
 }
 
@@ -308,10 +444,14 @@ fn update_consolidation_config(
 
 /// Preview what would be changed without applying.
 /// Returns real current TTL values from knowledge files.
+///
+/// Changes backed by fewer than `min_observations` signals are not listed;
+/// they are counted in `ChangePreview::held_back` instead.
 pub fn preview_changes(
     config: &Config,
     project: &str,
     learning_state: &LearningState,
+    min_observations: u32,
 ) -> Result<ChangePreview> {
     let params = &learning_state.learned_parameters;
 
@@ -320,16 +460,24 @@ pub fn preview_changes(
         ttl_changes: Vec::new(),
         graph_changes: Vec::new(),
         consolidation_change: None,
+        held_back: 0,
+        min_observations,
     };
 
     // Preview importance boosts (no file reads needed — boosts are the source of truth)
     for (id, boost) in &params.importance_boosts {
         if boost.abs() > 0.01 {
+            let samples = params.observations(id);
+            if samples < min_observations {
+                preview.held_back += 1;
+                continue;
+            }
             preview.importance_changes.push(ImportanceChange {
                 knowledge_id: id.clone(),
                 current: 0.5,
-                proposed: (0.5 + boost).clamp(0.1, 1.0),
+                proposed: importance_from_boost(*boost),
                 boost: *boost,
+                samples,
             });
         }
     }
@@ -356,11 +504,7 @@ pub fn preview_changes(
             let (active, _) = partition_by_expiry(blocks);
 
             for block in &active {
-                let boost = params
-                    .importance_boosts
-                    .get(&block.session_id)
-                    .copied()
-                    .unwrap_or(0.0);
+                let boost = params.block_boost(cat, &block.session_id).unwrap_or(0.0);
                 let ttl_state = compute_ttl_state(boost, &block.timestamp, now);
                 let action = learning_state.ttl_q_learning.choose_action(&ttl_state);
 
@@ -374,10 +518,16 @@ pub fn preview_changes(
 
                 // Only surface changes that are actually different
                 if proposed != current_ttl_days {
+                    let samples = params.block_observations(cat, &block.session_id);
+                    if samples < min_observations {
+                        preview.held_back += 1;
+                        continue;
+                    }
                     preview.ttl_changes.push(TTLChange {
                         knowledge_id: format!("{}:{}", cat, block.session_id),
                         current: current_ttl_days,
                         proposed,
+                        samples,
                     });
                 }
             }
@@ -398,6 +548,10 @@ pub struct ChangePreview {
     pub ttl_changes: Vec<TTLChange>,
     pub graph_changes: Vec<GraphChange>,
     pub consolidation_change: Option<ConsolidationStrategy>,
+    /// Adjustments skipped because they had too few supporting signals
+    pub held_back: usize,
+    /// Sample-size threshold used to build this preview
+    pub min_observations: u32,
 }
 
 #[derive(Debug, Clone)]
@@ -406,6 +560,8 @@ pub struct ImportanceChange {
     pub current: f32,
     pub proposed: f32,
     pub boost: f32,
+    /// Number of learning signals supporting this change
+    pub samples: u32,
 }
 
 #[derive(Debug, Clone)]
@@ -413,6 +569,8 @@ pub struct TTLChange {
     pub knowledge_id: String,
     pub current: Option<u32>,
     pub proposed: Option<u32>,
+    /// Number of learning signals supporting this change
+    pub samples: u32,
 }
 
 #[derive(Debug, Clone)]
@@ -436,13 +594,21 @@ mod tests {
                 endpoint: "http://localhost:11434".to_string(),
                 model: "llama2".to_string(),
                 api_key: None,
+                oauth: false,
             },
+            prompts: Default::default(),
         }
     }
 
     #[test]
     fn test_build_block_header_with_ttl() {
-        let h = build_block_header("abc123", "2026-02-19T10:00:00Z", Some(30), Some("high"));
+        let h = build_block_header(
+            "abc123",
+            "2026-02-19T10:00:00Z",
+            Some(30),
+            Some("high"),
+            None,
+        );
         assert_eq!(
             h,
             "## Session: abc123 (2026-02-19T10:00:00Z) [ttl:30d] [confidence:high]"
@@ -451,10 +617,40 @@ mod tests {
 
     #[test]
     fn test_build_block_header_permanent() {
-        let h = build_block_header("abc123", "2026-02-19T10:00:00Z", None, None);
+        let h = build_block_header("abc123", "2026-02-19T10:00:00Z", None, None, None);
         assert_eq!(h, "## Session: abc123 (2026-02-19T10:00:00Z)");
     }
 
+    #[test]
+    fn test_apply_learned_parameters_persists_importance_tag() {
+        let tmp = TempDir::new().unwrap();
+        let config = make_config(&tmp);
+        let kdir = tmp.path().join("knowledge").join("test");
+        std::fs::create_dir_all(&kdir).unwrap();
+        std::fs::write(
+            kdir.join("decisions.md"),
+            "# Decisions\n\n## Session: s1 (2024-01-01T00:00:00Z) [confidence:high]\n\nUse tokio.\n\n## Session: s2 (2024-01-02T00:00:00Z)\n\nUse serde.\n",
+        )
+        .unwrap();
+
+        let mut state = crate::learning::progress::LearningState::new("test".to_string());
+        state
+            .learned_parameters
+            .importance_boosts
+            .insert("decisions:s1".to_string(), 0.3);
+
+        let result = apply_learned_parameters(&config, "test", &state, 0).unwrap();
+        assert_eq!(result.importance_adjustments, 1);
+
+        let written = std::fs::read_to_string(kdir.join("decisions.md")).unwrap();
+        let (_, blocks) = parse_session_blocks(&written);
+        assert_eq!(blocks.len(), 2);
+        assert!((blocks[0].importance.unwrap() - 0.8).abs() < 0.01);
+        assert_eq!(blocks[0].confidence.as_deref(), Some("high"));
+        assert_eq!(blocks[1].importance, None);
+        assert!(blocks[1].content.contains("Use serde."));
+    }
+
     #[test]
     fn test_compute_ttl_state_high_boost_recent() {
         let now = Utc::now();
@@ -496,7 +692,7 @@ mod tests {
         )
         .unwrap();
 
-        let count = apply_ttl_adjustments(&config, "test", &state).unwrap();
+        let count = apply_ttl_adjustments(&config, "test", &state, 0).unwrap();
         assert_eq!(count, 0, "Empty Q-table should produce zero changes");
     }
 
@@ -506,10 +702,119 @@ mod tests {
         let config = make_config(&tmp);
         let state = crate::learning::progress::LearningState::new("preview-test".to_string());
         // No Q-table data → preview TTL changes should be empty
-        let preview = preview_changes(&config, "preview-test", &state).unwrap();
+        let preview = preview_changes(&config, "preview-test", &state, 0).unwrap();
         assert!(preview.ttl_changes.is_empty());
     }
 
+    #[test]
+    fn test_preview_changes_holds_back_low_sample_boosts() {
+        let tmp = TempDir::new().unwrap();
+        let config = make_config(&tmp);
+        let mut state = crate::learning::progress::LearningState::new("gate".to_string());
+        let params = &mut state.learned_parameters;
+        params.importance_boosts.insert("burst".to_string(), 0.4);
+        params.record_observation("burst");
+        params.importance_boosts.insert("steady".to_string(), 0.3);
+        for _ in 0..5 {
+            params.record_observation("steady");
+        }
+
+        let preview = preview_changes(&config, "gate", &state, 3).unwrap();
+        assert_eq!(preview.importance_changes.len(), 1);
+        assert_eq!(preview.importance_changes[0].knowledge_id, "steady");
+        assert_eq!(preview.importance_changes[0].samples, 5);
+        assert_eq!(preview.held_back, 1);
+
+        // Threshold 0 surfaces everything
+        let preview = preview_changes(&config, "gate", &state, 0).unwrap();
+        assert_eq!(preview.importance_changes.len(), 2);
+        assert_eq!(preview.held_back, 0);
+    }
+
+    #[test]
+    fn test_apply_ttl_adjustments_skips_unsupported_blocks() {
+        use crate::learning::algorithms::TTLAction;
+
+        let tmp = TempDir::new().unwrap();
+        let config = make_config(&tmp);
+        let mut state = crate::learning::progress::LearningState::new("test".to_string());
+        let now = Utc::now();
+        let ttl_state = compute_ttl_state(0.0, &now.to_rfc3339(), now);
+        for _ in 0..20 {
+            state.ttl_q_learning.update(
+                ttl_state.clone(),
+                TTLAction::Extend30d,
+                1.0,
+                ttl_state.clone(),
+            );
+        }
+
+        let kdir = tmp.path().join("knowledge").join("test");
+        std::fs::create_dir_all(&kdir).unwrap();
+        let original = format!(
+            "# Decisions\n\n## Session: s1 ({}) [ttl:7d]\n\nContent.\n\n",
+            now.to_rfc3339()
+        );
+        std::fs::write(kdir.join("decisions.md"), &original).unwrap();
+
+        // No observations for s1 → gated
+        let count = apply_ttl_adjustments(&config, "test", &state, 3).unwrap();
+        assert_eq!(count, 0);
+        assert_eq!(
+            std::fs::read_to_string(kdir.join("decisions.md")).unwrap(),
+            original
+        );
+    }
+
+    #[test]
+    fn test_ttl_gate_uses_category_keyed_observations() {
+        use crate::learning::algorithms::TTLAction;
+
+        let tmp = TempDir::new().unwrap();
+        let config = make_config(&tmp);
+        let mut state = crate::learning::progress::LearningState::new("test".to_string());
+        let now = Utc::now();
+        let ttl_state = compute_ttl_state(0.0, &now.to_rfc3339(), now);
+        for _ in 0..20 {
+            state.ttl_q_learning.update(
+                ttl_state.clone(),
+                TTLAction::Extend30d,
+                1.0,
+                ttl_state.clone(),
+            );
+        }
+        // No exploration, so preview and apply pick the same action
+        state.ttl_q_learning.epsilon = 0.0;
+        // Signals are recorded under `category:session_id`, as the hooks do
+        for _ in 0..3 {
+            state.learned_parameters.record_observation("decisions:s1");
+        }
+
+        let kdir = tmp.path().join("knowledge").join("test");
+        std::fs::create_dir_all(&kdir).unwrap();
+        std::fs::write(
+            kdir.join("decisions.md"),
+            format!(
+                "# Decisions\n\n## Session: s1 ({}) [ttl:7d]\n\nContent.\n\n",
+                now.to_rfc3339()
+            ),
+        )
+        .unwrap();
+
+        let preview = preview_changes(&config, "test", &state, 3).unwrap();
+        assert_eq!(preview.ttl_changes.len(), 1);
+        assert_eq!(preview.ttl_changes[0].knowledge_id, "decisions:s1");
+        assert_eq!(preview.ttl_changes[0].samples, 3);
+        assert_eq!(preview.held_back, 0);
+
+        assert_eq!(
+            apply_ttl_adjustments(&config, "test", &state, 3).unwrap(),
+            1
+        );
+        let updated = std::fs::read_to_string(kdir.join("decisions.md")).unwrap();
+        assert!(updated.contains("[ttl:37d]"), "{}", updated);
+    }
+
     #[test]
     fn test_apply_importance_boosts() {
         use chrono::Utc;
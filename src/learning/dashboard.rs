@@ -278,8 +278,12 @@ pub fn display_preview(preview: &ChangePreview) {
             };
 
             println!(
-                "  {}: {:.2} → {:.2} {}",
-                change.knowledge_id, change.current, change.proposed, delta_formatted
+                "  {}: {:.2} → {:.2} {} {}",
+                change.knowledge_id,
+                change.current,
+                change.proposed,
+                delta_formatted,
+                format!("[n={}]", change.samples).dimmed()
             );
         }
     }
@@ -299,8 +303,11 @@ pub fn display_preview(preview: &ChangePreview) {
                 .unwrap_or_else(|| "permanent".to_string());
 
             println!(
-                "  {}: {} → {}",
-                change.knowledge_id, current_str, proposed_str
+                "  {}: {} → {} {}",
+                change.knowledge_id,
+                current_str,
+                proposed_str,
+                format!("[n={}]", change.samples).dimmed()
             );
         }
     }
@@ -316,6 +323,17 @@ pub fn display_preview(preview: &ChangePreview) {
         println!("  Size Trigger: {:.1}MB", strategy.size_trigger_mb);
     }
 
+    if preview.held_back > 0 {
+        println!(
+            "\n{}",
+            format!(
+                "{} adjustment(s) held back: fewer than {} supporting signals",
+                preview.held_back, preview.min_observations
+            )
+            .dimmed()
+        );
+    }
+
     if preview.importance_changes.is_empty()
         && preview.ttl_changes.is_empty()
         && preview.consolidation_change.is_none()
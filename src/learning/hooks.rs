@@ -62,6 +62,7 @@ pub fn post_ingest_hook(config: &Config, project: &str) -> Result<()> {
                 state.hyperparameters.importance_learning_rate,
             );
 
+            state.learned_parameters.record_observation(&knowledge_id);
             state
                 .learned_parameters
                 .importance_boosts
@@ -92,6 +93,7 @@ pub fn post_ingest_hook(config: &Config, project: &str) -> Result<()> {
                 state.hyperparameters.importance_learning_rate * 1.2, // Learn faster from outcomes
             );
 
+            state.learned_parameters.record_observation(&knowledge_id);
             state
                 .learned_parameters
                 .importance_boosts
@@ -143,6 +145,7 @@ pub fn post_recall_hook(
             state.hyperparameters.importance_learning_rate,
         );
 
+        state.learned_parameters.record_observation(knowledge_id);
         state
             .learned_parameters
             .importance_boosts
@@ -202,6 +205,7 @@ pub fn post_recall_hook(
                         block.confidence.as_deref(),
                         block.strength,
                         block.access_count,
+                        block.importance,
                     );
                     modified = true;
                     break; // Each session_id appears once per file
@@ -410,7 +414,9 @@ mod tests {
                 endpoint: "http://localhost:11434".to_string(),
                 model: "llama2".to_string(),
                 api_key: None,
+                oauth: false,
             },
+            prompts: Default::default(),
         };
 
         // Should not fail even with no data
@@ -431,7 +437,9 @@ mod tests {
                 endpoint: "http://localhost:11434".to_string(),
                 model: "llama2".to_string(),
                 api_key: None,
+                oauth: false,
             },
+            prompts: Default::default(),
         };
 
         let result = post_recall_hook(&config, "test-project", &[]);
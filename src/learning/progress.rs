@@ -1,4 +1,4 @@
-use crate::error::Result;
+use crate::error::{MemoryError, Result};
 use crate::learning::adaptation::LearnedParameters;
 use crate::learning::algorithms::{ConsolidationBandit, TTLQLearning};
 use chrono::{DateTime, Utc};
@@ -201,6 +201,70 @@ pub fn reset_state(memory_dir: &Path, project: &str) -> Result<()> {
     Ok(())
 }
 
+/// Maximum TTL (in days) accepted when importing learned parameters
+const MAX_IMPORTED_TTL_DAYS: u32 = 3650;
+
+/// Check that imported learned parameters are within the ranges the
+/// learning algorithms themselves produce.
+pub fn validate_parameters(params: &LearnedParameters) -> Result<()> {
+    for (id, boost) in &params.importance_boosts {
+        if !boost.is_finite() || !(-1.0..=1.0).contains(boost) {
+            return Err(MemoryError::Config(format!(
+                "Invalid importance boost {} for '{}' (expected -1.0..=1.0)",
+                boost, id
+            )));
+        }
+    }
+
+    for (id, ttl) in &params.ttl_adjustments {
+        if let Some(days) = ttl {
+            if *days == 0 || *days > MAX_IMPORTED_TTL_DAYS {
+                return Err(MemoryError::Config(format!(
+                    "Invalid TTL adjustment {}d for '{}' (expected 1..={})",
+                    days, id, MAX_IMPORTED_TTL_DAYS
+                )));
+            }
+        }
+    }
+
+    for (id, boost) in &params.graph_weight_boosts {
+        if !boost.is_finite() {
+            return Err(MemoryError::Config(format!(
+                "Invalid graph weight boost for '{}'",
+                id
+            )));
+        }
+    }
+
+    if let Some(strategy) = &params.consolidation_strategy {
+        if !(0.0..=1.0).contains(&strategy.similarity_threshold)
+            || !strategy.size_trigger_mb.is_finite()
+            || strategy.size_trigger_mb < 0.0
+        {
+            return Err(MemoryError::Config(
+                "Invalid consolidation strategy (threshold must be 0.0..=1.0, size >= 0)".into(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Replace a project's learned parameters with imported ones (history is kept)
+pub fn import_parameters(
+    memory_dir: &Path,
+    project: &str,
+    params: LearnedParameters,
+) -> Result<()> {
+    validate_parameters(&params)?;
+
+    let mut state = load_state(memory_dir, project)?;
+    state.learned_parameters = params;
+    state.updated_at = Utc::now();
+
+    save_state(memory_dir, &state)
+}
+
 /// Maximum metrics history to keep (sliding window)
 const MAX_METRICS_HISTORY: usize = 100;
 
@@ -289,4 +353,32 @@ mod tests {
         let rate = state.adaptation_success_rate();
         assert!((rate - 0.666).abs() < 0.01); // 2/3 successful
     }
+
+    #[test]
+    fn test_import_parameters_round_trip() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let mut params = LearnedParameters::default();
+        params.importance_boosts.insert("abc".to_string(), 0.3);
+        params.ttl_adjustments.insert("abc".to_string(), Some(30));
+        params.ttl_adjustments.insert("def".to_string(), None);
+
+        let json = serde_json::to_string(&params).unwrap();
+        let parsed: LearnedParameters = serde_json::from_str(&json).unwrap();
+        import_parameters(temp.path(), "proj", parsed).unwrap();
+
+        let state = load_state(temp.path(), "proj").unwrap();
+        assert_eq!(state.learned_parameters.importance_boosts["abc"], 0.3);
+        assert_eq!(state.learned_parameters.ttl_adjustments["abc"], Some(30));
+        assert_eq!(state.learned_parameters.ttl_adjustments["def"], None);
+    }
+
+    #[test]
+    fn test_import_parameters_rejects_out_of_range() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let mut params = LearnedParameters::default();
+        params.importance_boosts.insert("abc".to_string(), 7.5);
+
+        assert!(import_parameters(temp.path(), "proj", params).is_err());
+        assert!(!get_state_path(temp.path(), "proj").exists());
+    }
 }
@@ -194,6 +194,7 @@ mod tests {
                 results_count: None,
                 session_id: None,
                 tokens_consumed: None,
+                knowledge_ids: Vec::new(),
             },
             UsageEvent {
                 timestamp: Utc::now(),
@@ -204,6 +205,7 @@ mod tests {
                 results_count: None,
                 session_id: None,
                 tokens_consumed: None,
+                knowledge_ids: Vec::new(),
             },
             UsageEvent {
                 timestamp: Utc::now(),
@@ -214,6 +216,7 @@ mod tests {
                 results_count: None,
                 session_id: None,
                 tokens_consumed: None,
+                knowledge_ids: Vec::new(),
             },
         ];
 
@@ -17,6 +17,7 @@ pub fn simulate_recall_session(config: &Config, project: &str, recall_count: usi
             results_count: Some(3),
             session_id: Some(format!("sim-{}", i)),
             tokens_consumed: None,
+            knowledge_ids: Vec::new(),
         })?;
     }
 
@@ -43,6 +44,7 @@ pub fn simulate_mixed_usage(config: &Config, project: &str, iterations: usize) -
             results_count: Some((i % 5) + 1),
             session_id: Some(format!("sim-{}", i)),
             tokens_consumed: None,
+            knowledge_ids: Vec::new(),
         })?;
     }
 
@@ -68,6 +70,7 @@ pub fn simulate_high_frequency_knowledge(
             results_count: Some(1),
             session_id: Some(format!("high-freq-{}", i)),
             tokens_consumed: None,
+            knowledge_ids: Vec::new(),
         })?;
     }
 
@@ -89,7 +92,9 @@ mod tests {
                 endpoint: "http://localhost:11434".to_string(),
                 model: "llama2".to_string(),
                 api_key: None,
+                oauth: false,
             },
+            prompts: Default::default(),
         }
     }
 
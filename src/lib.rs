@@ -6,6 +6,7 @@ pub mod auth;
 pub mod cli;
 pub mod commands;
 pub mod config;
+pub mod daemon;
 pub mod diff;
 pub mod embeddings;
 pub mod error;
@@ -17,10 +18,12 @@ pub mod inject;
 pub mod learning;
 pub mod llm;
 pub mod mcp;
+pub mod output;
 pub mod parser;
 pub mod renderer;
 pub mod state;
 pub mod sync;
+pub mod trash;
 pub mod tui;
 pub mod vcs;
 
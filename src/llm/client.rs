@@ -1,5 +1,104 @@
-use crate::auth::providers::{Provider, ResolvedProvider};
+use crate::auth::providers::{anthropic_auth, gemini_auth, Provider, ResolvedProvider};
 use crate::error::{MemoryError, Result};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Token counts for one or more LLM calls.
+///
+/// `estimated_calls` counts the calls whose provider did not report usage and
+/// whose counts were approximated from text length instead.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TokenUsage {
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    pub calls: u64,
+    pub estimated_calls: u64,
+}
+
+impl TokenUsage {
+    pub fn total(&self) -> u64 {
+        self.prompt_tokens + self.completion_tokens
+    }
+
+    /// Whether any of the counted calls was estimated
+    pub fn is_estimated(&self) -> bool {
+        self.estimated_calls > 0
+    }
+
+    /// Usage accrued since an earlier snapshot of [`total_usage`].
+    pub fn since(&self, earlier: &TokenUsage) -> TokenUsage {
+        TokenUsage {
+            prompt_tokens: self.prompt_tokens.saturating_sub(earlier.prompt_tokens),
+            completion_tokens: self
+                .completion_tokens
+                .saturating_sub(earlier.completion_tokens),
+            calls: self.calls.saturating_sub(earlier.calls),
+            estimated_calls: self.estimated_calls.saturating_sub(earlier.estimated_calls),
+        }
+    }
+
+    /// Approximate usage (~4 chars per token) for providers that report nothing.
+    fn estimate(prompt: &str, completion: &str) -> TokenUsage {
+        TokenUsage {
+            prompt_tokens: prompt.len().div_ceil(4) as u64,
+            completion_tokens: completion.len().div_ceil(4) as u64,
+            calls: 1,
+            estimated_calls: 1,
+        }
+    }
+}
+
+static PROMPT_TOKENS: AtomicU64 = AtomicU64::new(0);
+static COMPLETION_TOKENS: AtomicU64 = AtomicU64::new(0);
+static CALLS: AtomicU64 = AtomicU64::new(0);
+static ESTIMATED_CALLS: AtomicU64 = AtomicU64::new(0);
+
+fn record_usage(usage: &TokenUsage) {
+    PROMPT_TOKENS.fetch_add(usage.prompt_tokens, Ordering::Relaxed);
+    COMPLETION_TOKENS.fetch_add(usage.completion_tokens, Ordering::Relaxed);
+    CALLS.fetch_add(usage.calls, Ordering::Relaxed);
+    ESTIMATED_CALLS.fetch_add(usage.estimated_calls, Ordering::Relaxed);
+}
+
+/// Running total of every `LlmClient::chat` call made by this process.
+///
+/// Commands snapshot this before and after their LLM work and report the difference.
+pub fn total_usage() -> TokenUsage {
+    TokenUsage {
+        prompt_tokens: PROMPT_TOKENS.load(Ordering::Relaxed),
+        completion_tokens: COMPLETION_TOKENS.load(Ordering::Relaxed),
+        calls: CALLS.load(Ordering::Relaxed),
+        estimated_calls: ESTIMATED_CALLS.load(Ordering::Relaxed),
+    }
+}
+
+/// Read provider-reported usage from a response body, if present.
+///
+/// Handles Anthropic (`usage.input_tokens`), OpenAI-compatible
+/// (`usage.prompt_tokens`) and Gemini (`usageMetadata.promptTokenCount`) shapes.
+fn parse_usage(provider: Provider, json: &serde_json::Value) -> Option<TokenUsage> {
+    let (usage, prompt_key, completion_key) = match provider {
+        Provider::Anthropic => (json.get("usage")?, "input_tokens", "output_tokens"),
+        Provider::Gemini => (
+            json.get("usageMetadata")?,
+            "promptTokenCount",
+            "candidatesTokenCount",
+        ),
+        Provider::OpenAI | Provider::Ollama | Provider::VSCode | Provider::OpenRouter => {
+            (json.get("usage")?, "prompt_tokens", "completion_tokens")
+        }
+    };
+    let prompt_tokens = usage.get(prompt_key).and_then(|v| v.as_u64());
+    let completion_tokens = usage.get(completion_key).and_then(|v| v.as_u64());
+    if prompt_tokens.is_none() && completion_tokens.is_none() {
+        return None;
+    }
+    Some(TokenUsage {
+        prompt_tokens: prompt_tokens.unwrap_or(0),
+        completion_tokens: completion_tokens.unwrap_or(0),
+        calls: 1,
+        estimated_calls: 0,
+    })
+}
 
 /// Multi-provider LLM client
 pub struct LlmClient {
@@ -7,6 +106,7 @@ pub struct LlmClient {
     endpoint: String,
     model: String,
     api_key: Option<String>,
+    oauth: bool,
     client: reqwest::Client,
 }
 
@@ -17,6 +117,7 @@ impl LlmClient {
             endpoint: resolved.endpoint.clone(),
             model: resolved.model.clone(),
             api_key: resolved.api_key.clone(),
+            oauth: resolved.oauth,
             client: reqwest::Client::builder()
                 .timeout(std::time::Duration::from_secs(120))
                 .build()
@@ -26,13 +127,26 @@ impl LlmClient {
 
     /// Send a chat completion request and return the response text
     pub async fn chat(&self, system: &str, user: &str) -> Result<String> {
-        match self.provider {
-            Provider::Anthropic => self.chat_anthropic(system, user, 2048).await,
+        self.chat_with_usage(system, user)
+            .await
+            .map(|(text, _usage)| text)
+    }
+
+    /// Like [`chat`](Self::chat), but also return the token usage of this call.
+    ///
+    /// Usage is also added to the process-wide [`total_usage`].
+    pub async fn chat_with_usage(&self, system: &str, user: &str) -> Result<(String, TokenUsage)> {
+        let (json, text) = match self.provider {
+            Provider::Anthropic => self.chat_anthropic(system, user, 2048).await?,
             Provider::OpenAI | Provider::Ollama | Provider::VSCode | Provider::OpenRouter => {
-                self.chat_openai_compat(system, user, 2048).await
+                self.chat_openai_compat(system, user, 2048).await?
             }
-            Provider::Gemini => self.chat_gemini(system, user, 2048).await,
-        }
+            Provider::Gemini => self.chat_gemini(system, user, 2048).await?,
+        };
+        let usage = parse_usage(self.provider, &json)
+            .unwrap_or_else(|| TokenUsage::estimate(&format!("{}\n{}", system, user), &text));
+        record_usage(&usage);
+        Ok((text, usage))
     }
 
     /// Minimal connectivity test — "hi" → any response, max 10 tokens.
@@ -44,10 +158,16 @@ impl LlmClient {
             }
             Provider::Gemini => self.chat_gemini("", user, 10).await,
         }
+        .map(|(_json, text)| text)
     }
 
     /// Anthropic Messages API
-    async fn chat_anthropic(&self, system: &str, user: &str, max_tokens: u32) -> Result<String> {
+    async fn chat_anthropic(
+        &self,
+        system: &str,
+        user: &str,
+        max_tokens: u32,
+    ) -> Result<(serde_json::Value, String)> {
         let url = format!("{}/v1/messages", self.endpoint);
 
         let body = serde_json::json!({
@@ -63,9 +183,7 @@ impl LlmClient {
         let mut req = self.client.post(&url).json(&body);
 
         if let Some(ref key) = self.api_key {
-            req = req
-                .header("x-api-key", key)
-                .header("anthropic-version", "2023-06-01");
+            req = anthropic_auth(req, key, self.oauth);
         }
 
         let response = req.send().await?;
@@ -80,12 +198,14 @@ impl LlmClient {
 
         let json: serde_json::Value = response.json().await?;
 
-        json.get("content")
+        let text = json
+            .get("content")
             .and_then(|c| c.get(0))
             .and_then(|b| b.get("text"))
             .and_then(|t| t.as_str())
             .map(|s| s.to_string())
-            .ok_or(MemoryError::LlmEmptyResponse)
+            .ok_or(MemoryError::LlmEmptyResponse)?;
+        Ok((json, text))
     }
 
     /// OpenAI-compatible API (OpenAI, Ollama, etc.)
@@ -94,7 +214,7 @@ impl LlmClient {
         system: &str,
         user: &str,
         max_tokens: u32,
-    ) -> Result<String> {
+    ) -> Result<(serde_json::Value, String)> {
         let url = format!("{}/chat/completions", self.endpoint);
 
         let body = serde_json::json!({
@@ -131,26 +251,31 @@ impl LlmClient {
 
         let json: serde_json::Value = response.json().await?;
 
-        json.get("choices")
+        let text = json
+            .get("choices")
             .and_then(|c| c.get(0))
             .and_then(|c| c.get("message"))
             .and_then(|m| m.get("content"))
             .and_then(|c| c.as_str())
             .map(|s| s.to_string())
-            .ok_or(MemoryError::LlmEmptyResponse)
+            .ok_or(MemoryError::LlmEmptyResponse)?;
+        Ok((json, text))
     }
 
     /// Google Gemini API
-    async fn chat_gemini(&self, system: &str, user: &str, max_tokens: u32) -> Result<String> {
+    async fn chat_gemini(
+        &self,
+        system: &str,
+        user: &str,
+        max_tokens: u32,
+    ) -> Result<(serde_json::Value, String)> {
         // Gemini API uses a different URL structure with the API key as a query param
+        // (OAuth tokens go in a Bearer header instead)
         let api_key = self.api_key.as_deref().ok_or_else(|| {
             MemoryError::Config("Gemini API key required (set GEMINI_API_KEY)".into())
         })?;
 
-        let url = format!(
-            "{}/models/{}:generateContent?key={}",
-            self.endpoint, self.model, api_key
-        );
+        let url = format!("{}/models/{}:generateContent", self.endpoint, self.model);
 
         // Combine system and user messages into a single prompt
         let combined_prompt = format!("{}\n\n{}", system, user);
@@ -167,7 +292,7 @@ impl LlmClient {
             }
         });
 
-        let req = self.client.post(&url).json(&body);
+        let req = gemini_auth(self.client.post(&url).json(&body), api_key, self.oauth);
 
         let response = req.send().await?;
         let status = response.status();
@@ -181,7 +306,8 @@ impl LlmClient {
 
         let json: serde_json::Value = response.json().await?;
 
-        json.get("candidates")
+        let text = json
+            .get("candidates")
             .and_then(|c| c.get(0))
             .and_then(|c| c.get("content"))
             .and_then(|c| c.get("parts"))
@@ -189,6 +315,60 @@ impl LlmClient {
             .and_then(|p| p.get("text"))
             .and_then(|t| t.as_str())
             .map(|s| s.to_string())
-            .ok_or(MemoryError::LlmEmptyResponse)
+            .ok_or(MemoryError::LlmEmptyResponse)?;
+        Ok((json, text))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_usage_per_provider() {
+        let anthropic = serde_json::json!({"usage": {"input_tokens": 120, "output_tokens": 30}});
+        let usage = parse_usage(Provider::Anthropic, &anthropic).unwrap();
+        assert_eq!((usage.prompt_tokens, usage.completion_tokens), (120, 30));
+        assert!(!usage.is_estimated());
+
+        let openai = serde_json::json!({"usage": {"prompt_tokens": 50, "completion_tokens": 7}});
+        assert_eq!(parse_usage(Provider::OpenAI, &openai).unwrap().total(), 57);
+
+        let gemini = serde_json::json!({
+            "usageMetadata": {"promptTokenCount": 10, "candidatesTokenCount": 5}
+        });
+        assert_eq!(parse_usage(Provider::Gemini, &gemini).unwrap().total(), 15);
+    }
+
+    #[test]
+    fn test_parse_usage_missing() {
+        let json = serde_json::json!({"choices": []});
+        assert!(parse_usage(Provider::Ollama, &json).is_none());
+        let empty = serde_json::json!({"usage": {}});
+        assert!(parse_usage(Provider::OpenAI, &empty).is_none());
+    }
+
+    #[test]
+    fn test_estimate_and_since() {
+        let usage = TokenUsage::estimate("12345678", "abc");
+        assert_eq!((usage.prompt_tokens, usage.completion_tokens), (2, 1));
+        assert!(usage.is_estimated());
+
+        let later = TokenUsage {
+            prompt_tokens: 100,
+            completion_tokens: 40,
+            calls: 3,
+            estimated_calls: 1,
+        };
+        let earlier = TokenUsage {
+            prompt_tokens: 60,
+            completion_tokens: 10,
+            calls: 1,
+            estimated_calls: 1,
+        };
+        let delta = later.since(&earlier);
+        assert_eq!((delta.total(), delta.calls), (70, 2));
+        // The estimated call happened before the snapshot
+        assert!(!delta.is_estimated());
     }
 }
@@ -1,3 +1,7 @@
+use std::path::Path;
+
+use crate::error::Result;
+
 pub const SYSTEM_KNOWLEDGE_EXTRACTOR: &str = r#"You are a knowledge extraction assistant. You analyze software development conversations and extract structured knowledge. Be concise and factual. Only extract what is clearly stated or demonstrated in the conversation."#;
 
 pub fn decisions_prompt(conversation_text: &str) -> String {
@@ -224,6 +228,200 @@ Summary:"#,
     )
 }
 
+/// Built-in template for context.md synthesis.
+///
+/// Placeholders are `{name}` tokens; every one in [`CONTEXT_PLACEHOLDERS`] must
+/// survive in a user override (`prompts/context.md`). The `*_section` values are
+/// empty when the category has no content.
+pub const CONTEXT_TEMPLATE: &str = r#"Generate a concise project context summary for "{project_name}" to give Claude context in future sessions.
+
+Format as markdown with these sections (omit any section that has no content):
+## What This Project Is
+(1-2 sentences)
+
+## Key Decisions
+(3-5 bullet points, most impactful only)
+
+## Current State
+(What's working, what's in progress — 3-5 bullets)
+
+## Patterns & Conventions
+(Non-obvious codebase patterns to know — 3-5 bullets)
+
+## Known Issues & Solutions
+(Recurring problems and fixes — only if substantive)
+
+## Workflows & Procedures
+(Repeatable multi-step workflows — only if any exist)
+
+## Open Questions
+(Unresolved questions — only if any exist)
+
+Rules: be terse. Each bullet max 20 words. Skip generic advice. Only include what would change how a developer approaches this project.
+
+---
+DECISIONS:
+{decisions}
+
+SOLUTIONS:
+{solutions}
+
+PATTERNS:
+{patterns}
+{bugs_section}{insights_section}{procedures_section}{questions_section}
+SESSION SUMMARIES:
+{summaries}
+---
+
+Generate context for {project_name}:"#;
+
+/// Placeholders a context template must contain.
+pub const CONTEXT_PLACEHOLDERS: &[&str] = &[
+    "project_name",
+    "decisions",
+    "solutions",
+    "patterns",
+    "bugs_section",
+    "insights_section",
+    "procedures_section",
+    "questions_section",
+    "summaries",
+];
+
+/// Substitute `{name}` placeholders in one pass, so braces inside the
+/// substituted knowledge are never expanded again. Unknown `{...}` is kept as-is.
+fn render_context_template(template: &str, vars: &[(&str, &str)]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        out.push_str(&rest[..open]);
+        let after = &rest[open + 1..];
+        let value = after.find('}').and_then(|close| {
+            let name = &after[..close];
+            vars.iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| (*value, close))
+        });
+        match value {
+            Some((value, close)) => {
+                out.push_str(value);
+                rest = &after[close + 1..];
+            }
+            None => {
+                out.push('{');
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Prompts used for extraction and context synthesis.
+///
+/// Defaults to the built-in constants; `prompts/extractor.md` and
+/// `prompts/context.md` in the memory dir override them when present.
+#[derive(Debug, Clone)]
+pub struct PromptSet {
+    /// System prompt for every extraction and synthesis call
+    pub extractor: String,
+    /// Template for context.md synthesis
+    pub context: String,
+}
+
+impl Default for PromptSet {
+    fn default() -> Self {
+        Self {
+            extractor: SYSTEM_KNOWLEDGE_EXTRACTOR.to_string(),
+            context: CONTEXT_TEMPLATE.to_string(),
+        }
+    }
+}
+
+impl PromptSet {
+    /// Load overrides from `<memory_dir>/prompts/`. An empty extractor prompt
+    /// or a context template missing placeholders is reported and the
+    /// built-in prompt is used instead.
+    pub fn load(memory_dir: &Path) -> Result<Self> {
+        let dir = memory_dir.join("prompts");
+        let mut prompts = Self::default();
+
+        let extractor_path = dir.join("extractor.md");
+        if extractor_path.exists() {
+            let text = std::fs::read_to_string(&extractor_path)?;
+            if text.trim().is_empty() {
+                tracing::warn!(
+                    "{} is empty; using the built-in extraction prompt",
+                    extractor_path.display()
+                );
+            } else {
+                prompts.extractor = text.trim().to_string();
+            }
+        }
+
+        let context_path = dir.join("context.md");
+        if context_path.exists() {
+            let text = std::fs::read_to_string(&context_path)?;
+            match validate_context_template(&text) {
+                Ok(()) => prompts.context = text,
+                Err(missing) => tracing::warn!(
+                    "{} is missing placeholders {}; using the built-in context template",
+                    context_path.display(),
+                    missing
+                        .iter()
+                        .map(|p| format!("{{{}}}", p))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            }
+        }
+
+        Ok(prompts)
+    }
+
+    /// Context-synthesis prompt built from this set's template.
+    #[allow(clippy::too_many_arguments)]
+    pub fn context_prompt(
+        &self,
+        project_name: &str,
+        decisions: &str,
+        solutions: &str,
+        patterns: &str,
+        bugs: &str,
+        insights: &str,
+        questions: &str,
+        procedures: &str,
+        summaries: &str,
+    ) -> String {
+        context_prompt_from_template(
+            &self.context,
+            project_name,
+            decisions,
+            solutions,
+            patterns,
+            bugs,
+            insights,
+            questions,
+            procedures,
+            summaries,
+        )
+    }
+}
+
+/// Check that every required placeholder is present; returns the missing ones.
+pub fn validate_context_template(template: &str) -> std::result::Result<(), Vec<&'static str>> {
+    let missing: Vec<&'static str> = CONTEXT_PLACEHOLDERS
+        .iter()
+        .copied()
+        .filter(|p| !template.contains(&format!("{{{}}}", p)))
+        .collect();
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(missing)
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn context_prompt(
     project_name: &str,
@@ -259,6 +457,34 @@ pub fn context_prompt_with_procedures(
     questions: &str,
     procedures: &str,
     summaries: &str,
+) -> String {
+    context_prompt_from_template(
+        CONTEXT_TEMPLATE,
+        project_name,
+        decisions,
+        solutions,
+        patterns,
+        bugs,
+        insights,
+        questions,
+        procedures,
+        summaries,
+    )
+}
+
+/// Render a context-synthesis template (see [`CONTEXT_TEMPLATE`] for the placeholders).
+#[allow(clippy::too_many_arguments)]
+pub fn context_prompt_from_template(
+    template: &str,
+    project_name: &str,
+    decisions: &str,
+    solutions: &str,
+    patterns: &str,
+    bugs: &str,
+    insights: &str,
+    questions: &str,
+    procedures: &str,
+    summaries: &str,
 ) -> String {
     // Build optional sections only when there's non-trivial content
     let bugs_section = if bugs.trim().is_empty() || bugs.trim() == "No bugs encountered." {
@@ -285,48 +511,19 @@ pub fn context_prompt_with_procedures(
             format!("\nWORKFLOWS & PROCEDURES FROM SESSIONS:\n{procedures}\n")
         };
 
-    format!(
-        r#"Generate a concise project context summary for "{project_name}" to give Claude context in future sessions.
-
-Format as markdown with these sections (omit any section that has no content):
-## What This Project Is
-(1-2 sentences)
-
-## Key Decisions
-(3-5 bullet points, most impactful only)
-
-## Current State
-(What's working, what's in progress — 3-5 bullets)
-
-## Patterns & Conventions
-(Non-obvious codebase patterns to know — 3-5 bullets)
-
-## Known Issues & Solutions
-(Recurring problems and fixes — only if substantive)
-
-## Workflows & Procedures
-(Repeatable multi-step workflows — only if any exist)
-
-## Open Questions
-(Unresolved questions — only if any exist)
-
-Rules: be terse. Each bullet max 20 words. Skip generic advice. Only include what would change how a developer approaches this project.
-
----
-DECISIONS:
-{decisions}
-
-SOLUTIONS:
-{solutions}
-
-PATTERNS:
-{patterns}
-{bugs_section}{insights_section}{procedures_section}{questions_section}
-SESSION SUMMARIES:
-{summaries}
----
-
-Generate context for {project_name}:"#
+    render_context_template(
+        template,
+        &[
+            ("project_name", project_name),
+            ("decisions", decisions),
+            ("solutions", solutions),
+            ("patterns", patterns),
+            ("bugs_section", &bugs_section),
+            ("insights_section", &insights_section),
+            ("procedures_section", &procedures_section),
+            ("questions_section", &questions_section),
+            ("summaries", summaries),
+        ],
     )
 }
 
@@ -478,6 +675,27 @@ pub fn query_expand_prompt(query: &str) -> String {
     )
 }
 
+/// System prompt for re-ranking semantic search results
+pub const SYSTEM_RERANKER: &str =
+    "You are a search relevance judge. Given a query and numbered candidate passages from a \
+     developer's memory system, order the passages from most to least relevant to the query. \
+     Judge by whether a passage actually answers the query, not by shared keywords.";
+
+/// Generate a re-ranking prompt for numbered candidate passages
+pub fn rerank_prompt(query: &str, candidates: &[&str]) -> String {
+    let passages: String = candidates
+        .iter()
+        .enumerate()
+        .map(|(i, text)| format!("[{}] {}\n\n", i + 1, text.trim()))
+        .collect();
+    format!(
+        "QUERY: {query}\n\n\
+         PASSAGES:\n{passages}\
+         Respond with only the passage numbers, most relevant first, comma-separated \
+         (e.g. 3, 1, 2)."
+    )
+}
+
 /// System prompt for the Update Resolver
 pub const SYSTEM_UPDATE_RESOLVER: &str =
     "You are a knowledge base update resolver. You decide whether new information should be \
@@ -545,4 +763,69 @@ mod tests {
         assert!(p.contains("ADD"));
         assert!(p.contains("NOOP"));
     }
+
+    #[test]
+    fn test_builtin_context_template_is_valid() {
+        assert!(validate_context_template(CONTEXT_TEMPLATE).is_ok());
+        let p = context_prompt("proj", "D1", "S1", "P1", "", "", "", "SUM");
+        assert!(p.contains("\"proj\""));
+        assert!(p.contains("DECISIONS:\nD1"));
+        assert!(!p.contains("KNOWN BUGS"));
+        assert!(!p.contains("{summaries}"));
+    }
+
+    #[test]
+    fn test_render_does_not_expand_braces_in_values() {
+        let out = render_context_template("{a} and {b} and {unknown}", &[("a", "{b}"), ("b", "x")]);
+        assert_eq!(out, "{b} and x and {unknown}");
+    }
+
+    #[test]
+    fn test_prompt_set_load_overrides() {
+        let dir = tempfile::TempDir::new().unwrap();
+        assert_eq!(
+            PromptSet::load(dir.path()).unwrap().extractor,
+            SYSTEM_KNOWLEDGE_EXTRACTOR
+        );
+
+        let prompts_dir = dir.path().join("prompts");
+        std::fs::create_dir_all(&prompts_dir).unwrap();
+        std::fs::write(prompts_dir.join("extractor.md"), "Extract infra facts.\n").unwrap();
+        let template = CONTEXT_PLACEHOLDERS
+            .iter()
+            .map(|p| format!("{{{}}}", p))
+            .collect::<Vec<_>>()
+            .join("\n");
+        std::fs::write(
+            prompts_dir.join("context.md"),
+            format!("Custom\n{}", template),
+        )
+        .unwrap();
+
+        let prompts = PromptSet::load(dir.path()).unwrap();
+        assert_eq!(prompts.extractor, "Extract infra facts.");
+        let p = prompts.context_prompt("proj", "D1", "", "", "", "", "", "", "");
+        assert!(p.starts_with("Custom\nproj\nD1"));
+    }
+
+    #[test]
+    fn test_prompt_set_falls_back_on_invalid_overrides() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let prompts_dir = dir.path().join("prompts");
+        std::fs::create_dir_all(&prompts_dir).unwrap();
+        std::fs::write(prompts_dir.join("extractor.md"), "  \n").unwrap();
+        std::fs::write(prompts_dir.join("context.md"), "Only {project_name} here").unwrap();
+
+        let prompts = PromptSet::load(dir.path()).unwrap();
+        let defaults = PromptSet::default();
+        assert_eq!(prompts.extractor, defaults.extractor);
+        assert_eq!(prompts.context, defaults.context);
+    }
+
+    #[test]
+    fn test_validate_context_template_lists_missing() {
+        let missing = validate_context_template("Only {project_name} here").unwrap_err();
+        assert!(missing.contains(&"decisions"));
+        assert!(!missing.contains(&"project_name"));
+    }
 }
@@ -16,14 +16,16 @@ mod inject;
 mod learning;
 mod llm;
 mod mcp;
+mod output;
 mod parser;
 mod renderer;
 mod state;
 mod sync;
+mod trash;
 mod tui;
 mod vcs;
 
-use std::path::{Path, PathBuf};
+use std::path::PathBuf;
 
 use clap::Parser;
 use cli::{
@@ -33,44 +35,54 @@ use cli::{
 use colored::Colorize;
 use config::Config;
 use error::Result;
+use output::status;
 
 use commands::ask::{cmd_ask, cmd_ask_hybrid, cmd_ask_recursive};
 use commands::auth::{
     cmd_auth_embed, cmd_auth_embed_model, cmd_auth_list, cmd_auth_login, cmd_auth_logout,
     cmd_auth_model, cmd_auth_models, cmd_auth_status, cmd_auth_test,
 };
-use commands::consolidate::{cmd_consolidate, cmd_doctor};
+use commands::backup::cmd_import;
+use commands::consolidate::{cmd_consolidate, cmd_doctor, cmd_doctor_trend};
 use commands::core::{
     cmd_context, cmd_entities, cmd_export, cmd_ingest, cmd_mcp, cmd_projects, cmd_recall,
     cmd_search, cmd_status,
 };
-use commands::diff::{cmd_analytics, cmd_diff};
+use commands::diff::{cmd_analytics, cmd_diff, cmd_diff_all, cmd_prune_versions};
 use commands::embeddings::{cmd_embed, cmd_search_semantic};
 use commands::graph::{
-    cmd_graph_build, cmd_graph_hubs, cmd_graph_path, cmd_graph_query, cmd_graph_viz,
+    cmd_graph_build, cmd_graph_build_all, cmd_graph_hubs, cmd_graph_merge, cmd_graph_path,
+    cmd_graph_query, cmd_graph_viz,
 };
 use commands::heal::cmd_heal;
 use commands::hive::cmd_hive;
-use commands::hooks::{cmd_hooks_install, cmd_hooks_status, cmd_hooks_uninstall};
-use commands::knowledge::{cmd_forget, cmd_regen};
+use commands::hooks::{
+    cmd_hooks_install, cmd_hooks_status, cmd_hooks_uninstall, find_claude_project_dirs,
+};
+use commands::knowledge::{cmd_forget, cmd_regen, cmd_restore};
 use commands::learning::{
-    cmd_learn_dashboard, cmd_learn_feedback, cmd_learn_optimize, cmd_learn_reset,
-    cmd_learn_simulate,
+    cmd_learn_dashboard, cmd_learn_export, cmd_learn_feedback, cmd_learn_import,
+    cmd_learn_optimize, cmd_learn_reset, cmd_learn_simulate,
+};
+use commands::manual::{
+    cmd_add, cmd_drain, cmd_lookup, cmd_promote, cmd_review, cmd_stats, cmd_timeline,
 };
-use commands::manual::{cmd_add, cmd_drain, cmd_lookup, cmd_promote, cmd_review};
 use commands::observe::cmd_observe;
 use commands::reflect::{cmd_reflect, cmd_reflect_all};
 use commands::sync::{
     cmd_sync_clone, cmd_sync_history, cmd_sync_init_repo, cmd_sync_list, cmd_sync_pull,
-    cmd_sync_pull_repo, cmd_sync_push, cmd_sync_push_repo,
+    cmd_sync_pull_repo, cmd_sync_push, cmd_sync_push_repo, cmd_sync_status,
 };
 use commands::vcs::{
     cmd_mem_branch, cmd_mem_checkout, cmd_mem_commit, cmd_mem_diff, cmd_mem_init, cmd_mem_log,
-    cmd_mem_show, cmd_mem_stage, cmd_mem_status,
+    cmd_mem_show, cmd_mem_stage, cmd_mem_status, cmd_mem_tag,
 };
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
+    output::set_quiet(cli.quiet);
+    output::configure_color(cli.no_color);
+    output::init_logging(cli.verbose, cli.quiet);
 
     // Auth commands operate on auth.json directly — no Config needed
     if let Commands::Auth { command } = cli.command {
@@ -78,8 +90,28 @@ fn main() -> Result<()> {
             AuthCommand::Login {
                 provider,
                 set_default,
-            } => cmd_auth_login(provider, set_default),
-            AuthCommand::List => cmd_auth_list(),
+                endpoint,
+                device_code,
+                client_id,
+                device_url,
+                token_url,
+                scope,
+            } => {
+                // clap guarantees the endpoints are present with --device-code
+                let device_code = match (device_code, client_id, device_url, token_url) {
+                    (true, Some(client_id), Some(device_authorization_url), Some(token_url)) => {
+                        Some(auth::oauth::OAuthConfig {
+                            client_id,
+                            device_authorization_url,
+                            token_url,
+                            scope,
+                        })
+                    }
+                    _ => None,
+                };
+                cmd_auth_login(provider, set_default, endpoint, device_code)
+            }
+            AuthCommand::List { show_models } => cmd_auth_list(show_models),
             AuthCommand::Logout { provider } => cmd_auth_logout(&provider),
             AuthCommand::Status => cmd_auth_status(),
             AuthCommand::Test { provider } => cmd_auth_test(provider),
@@ -104,6 +136,9 @@ fn main() -> Result<()> {
         budget,
         lines,
         measure_tokens,
+        append,
+        all_matches,
+        chronological,
     } = cli.command
     {
         return cmd_inject(
@@ -114,6 +149,9 @@ fn main() -> Result<()> {
             budget,
             lines,
             measure_tokens,
+            append,
+            all_matches,
+            chronological,
         );
     }
 
@@ -121,10 +159,38 @@ fn main() -> Result<()> {
     if let Commands::Lookup {
         project,
         query,
+        label,
         all,
+        max_results,
     } = cli.command
     {
-        return cmd_lookup(&project, &query, all);
+        return cmd_lookup(
+            &project,
+            query.as_deref(),
+            label.as_deref(),
+            all,
+            max_results,
+        );
+    }
+
+    // Restore operates on knowledge files — no Config/LLM auth needed
+    if let Commands::Restore { project, id } = cli.command {
+        return cmd_restore(&project, id.as_deref());
+    }
+
+    // Stats operates on knowledge files — no Config/LLM auth needed
+    if let Commands::Stats { project, json } = cli.command {
+        return cmd_stats(&project, json);
+    }
+
+    // Timeline operates on knowledge files — no Config/LLM auth needed
+    if let Commands::Timeline {
+        project,
+        days,
+        json,
+    } = cli.command
+    {
+        return cmd_timeline(project.as_deref(), days, json);
     }
 
     // Add operates on knowledge files — no Config/LLM auth needed
@@ -136,7 +202,7 @@ fn main() -> Result<()> {
         ttl,
     } = cli.command
     {
-        return cmd_add(&project, &category, &content, &label, ttl.as_deref());
+        return cmd_add(&project, category, &content, &label, ttl.as_deref());
     }
 
     // Review operates on knowledge files — no Config/LLM auth needed
@@ -158,7 +224,7 @@ fn main() -> Result<()> {
                     .and_then(|p| p.file_name().map(|n| n.to_string_lossy().to_string()))
             })
             .unwrap_or_else(|| "default".to_string());
-        return cmd_drain(&project_name, dry_run, category.as_deref());
+        return cmd_drain(&project_name, dry_run, category);
     }
 
     // Promote operates on knowledge files — no Config/LLM auth needed
@@ -174,7 +240,7 @@ fn main() -> Result<()> {
         return cmd_promote(
             &project,
             &session_id,
-            &category,
+            category,
             global,
             &label,
             ttl.as_deref(),
@@ -186,33 +252,39 @@ fn main() -> Result<()> {
         project,
         session_id,
         topic,
+        label,
         all,
         purge,
         expired,
         stale,
         auto: auto_approve,
         summarize,
+        older_than,
+        dry_run,
     } = cli.command
     {
         return cmd_forget(
             &project,
             session_id,
             topic,
+            label,
             all,
             purge,
             expired,
             stale,
             auto_approve,
             summarize,
+            older_than,
+            dry_run,
         );
     }
 
     // Hooks operate on settings files — no Config/LLM auth needed
     if let Commands::Hooks { command } = cli.command {
         return match command {
-            HooksCommand::Install => cmd_hooks_install(),
+            HooksCommand::Install { events } => cmd_hooks_install(&events),
             HooksCommand::Uninstall => cmd_hooks_uninstall(),
-            HooksCommand::Status => cmd_hooks_status(),
+            HooksCommand::Status => cmd_hooks_status(cli.verbose > 0),
         };
     }
 
@@ -234,8 +306,14 @@ fn main() -> Result<()> {
     // Mem (VCS) - filesystem only, no Config/LLM needed
     if let Commands::Mem { command } = cli.command {
         return match command {
-            MemCommand::Init { project } => cmd_mem_init(project.as_deref()),
-            MemCommand::Status { project } => cmd_mem_status(project.as_deref()),
+            MemCommand::Init {
+                project,
+                project_flag,
+            } => cmd_mem_init(project.or(project_flag).as_deref()),
+            MemCommand::Status {
+                project,
+                project_flag,
+            } => cmd_mem_status(project.or(project_flag).as_deref()),
             MemCommand::Stage {
                 project,
                 sessions,
@@ -250,9 +328,8 @@ fn main() -> Result<()> {
             MemCommand::Log {
                 project,
                 limit,
-                verbose,
                 grep,
-            } => cmd_mem_log(&project, limit, verbose, grep.as_deref()),
+            } => cmd_mem_log(&project, limit, cli.verbose > 0, grep.as_deref()),
             MemCommand::Show {
                 project,
                 target,
@@ -263,6 +340,12 @@ fn main() -> Result<()> {
                 create,
                 delete,
             } => cmd_mem_branch(&project, create.as_deref(), delete.as_deref()),
+            MemCommand::Tag {
+                project,
+                name,
+                at,
+                delete,
+            } => cmd_mem_tag(&project, name.as_deref(), at.as_deref(), delete.as_deref()),
             MemCommand::Checkout {
                 project,
                 target,
@@ -273,11 +356,13 @@ fn main() -> Result<()> {
                 project,
                 from,
                 to,
+                staged,
                 category,
             } => cmd_mem_diff(
                 &project,
                 from.as_deref(),
                 to.as_deref(),
+                staged,
                 category.as_deref(),
             ),
         };
@@ -287,6 +372,7 @@ fn main() -> Result<()> {
     let provider_override = match &cli.command {
         Commands::Ingest { provider, .. }
         | Commands::Regen { provider, .. }
+        | Commands::Consolidate { provider, .. }
         | Commands::Mcp { provider, .. }
         | Commands::Ask { provider, .. }
         | Commands::Graph {
@@ -295,7 +381,21 @@ fn main() -> Result<()> {
         _ => None,
     };
 
-    let config = Config::load(provider_override)?;
+    // Per-run model override; the stored default is left untouched
+    let model_override = match &cli.command {
+        Commands::Regen { model, .. }
+        | Commands::Consolidate { model, .. }
+        | Commands::Ask { model, .. }
+        | Commands::Graph {
+            command: GraphCommand::Build { model, .. },
+        } => model.clone(),
+        _ => None,
+    };
+
+    let mut config = Config::load(provider_override)?;
+    if let Some(model) = model_override {
+        config.llm.model = model;
+    }
 
     // MCP server command
     if let Commands::Mcp { .. } = cli.command {
@@ -308,6 +408,9 @@ fn main() -> Result<()> {
         format,
         output,
         include_conversations,
+        categories,
+        since,
+        anonymize,
     } = cli.command
     {
         return cmd_export(
@@ -316,9 +419,23 @@ fn main() -> Result<()> {
             &format,
             output.as_deref(),
             include_conversations,
+            &categories,
+            since.as_deref(),
+            anonymize,
         );
     }
 
+    // Import command
+    if let Commands::Import {
+        project,
+        input,
+        format,
+        dry_run,
+    } = cli.command
+    {
+        return cmd_import(&config, &project, &input, format.as_deref(), dry_run);
+    }
+
     // Sync command
     if let Commands::Sync { command } = cli.command {
         return match command {
@@ -337,6 +454,11 @@ fn main() -> Result<()> {
             SyncCommand::History { gist_id, version } => {
                 cmd_sync_history(&gist_id, version.as_deref())
             }
+            SyncCommand::Status {
+                project,
+                gist_id,
+                repo,
+            } => cmd_sync_status(&config, &project, gist_id.as_deref(), repo.as_deref()),
             SyncCommand::PushRepo {
                 project,
                 repo,
@@ -348,7 +470,17 @@ fn main() -> Result<()> {
                 repo,
                 fetch_remote,
                 branch,
-            } => cmd_sync_pull_repo(&config, &project, &repo, fetch_remote, &branch),
+                merge,
+                force,
+            } => cmd_sync_pull_repo(
+                &config,
+                &project,
+                &repo,
+                fetch_remote,
+                &branch,
+                merge,
+                force,
+            ),
             SyncCommand::InitRepo { repo } => cmd_sync_init_repo(&repo),
         };
     }
@@ -356,7 +488,16 @@ fn main() -> Result<()> {
     // Graph command
     if let Commands::Graph { command } = cli.command {
         return match command {
-            GraphCommand::Build { project, .. } => cmd_graph_build(&config, &project),
+            GraphCommand::Build {
+                project,
+                all,
+                concurrency,
+                ..
+            } => match project {
+                Some(project) if !all => cmd_graph_build(&config, &project),
+                _ => cmd_graph_build_all(&config, concurrency as usize),
+            },
+            GraphCommand::Merge { projects } => cmd_graph_merge(&config, &projects),
             GraphCommand::Query {
                 project,
                 concept,
@@ -374,16 +515,40 @@ fn main() -> Result<()> {
                 output.as_deref(),
                 root.as_deref(),
             ),
-            GraphCommand::Path { project, from, to } => {
-                cmd_graph_path(&config, &project, &from, &to)
-            }
+            GraphCommand::Path {
+                project,
+                from,
+                to,
+                weighted,
+            } => cmd_graph_path(&config, &project, &from, &to, weighted),
             GraphCommand::Hubs { project, top } => cmd_graph_hubs(&config, &project, top),
         };
     }
 
     // Embed command
-    if let Commands::Embed { project, provider } = &cli.command {
-        return cmd_embed(&config, project, provider.as_deref(), cli.verbose);
+    if let Commands::Embed {
+        project,
+        provider,
+        model,
+        endpoint,
+        chunk_strategy,
+        window_size,
+        window_overlap,
+    } = &cli.command
+    {
+        let strategy = chunk_strategy
+            .as_deref()
+            .map(|name| embeddings::ChunkStrategy::parse(name, *window_size, *window_overlap))
+            .transpose()?;
+        return cmd_embed(
+            &config,
+            project,
+            provider.as_deref(),
+            model.as_deref(),
+            endpoint.as_deref(),
+            strategy,
+            cli.verbose > 0,
+        );
     }
 
     // SearchSemantic command
@@ -395,6 +560,11 @@ fn main() -> Result<()> {
         since,
         category,
         file,
+        explain,
+        rerank,
+        provider,
+        model,
+        endpoint,
     } = &cli.command
     {
         return cmd_search_semantic(
@@ -402,11 +572,16 @@ fn main() -> Result<()> {
             query,
             project.as_deref(),
             *top,
-            *threshold,
-            cli.verbose,
+            threshold,
+            cli.verbose > 0,
             since.as_deref(),
             category.as_deref(),
             file.as_deref(),
+            *explain,
+            *rerank,
+            provider.as_deref(),
+            model.as_deref(),
+            endpoint.as_deref(),
         );
     }
 
@@ -416,6 +591,7 @@ fn main() -> Result<()> {
         threshold,
         auto_merge,
         find_contradictions,
+        ..
     } = &cli.command
     {
         return cmd_consolidate(
@@ -451,10 +627,14 @@ fn main() -> Result<()> {
     if let Commands::Doctor {
         project,
         fix,
-        verbose,
+        trend,
+        last,
     } = &cli.command
     {
-        return cmd_doctor(&config, project.as_deref(), *fix, *verbose);
+        if *trend {
+            return cmd_doctor_trend(&config, project.as_deref(), *last);
+        }
+        return cmd_doctor(&config, project.as_deref(), *fix, cli.verbose > 0);
     }
 
     // Analytics command (no Config needed for reading usage data)
@@ -476,7 +656,42 @@ fn main() -> Result<()> {
         history,
     } = &cli.command
     {
-        return cmd_diff(&config, project, category, version.as_deref(), *history);
+        if project == "all" {
+            if version.is_some() || *history {
+                return Err(error::MemoryError::Config(
+                    "--version and --history need a single project".into(),
+                ));
+            }
+            return cmd_diff_all(&config, category.map(|c| c.as_str()));
+        }
+        let Some(category) = category else {
+            return Err(error::MemoryError::Config(
+                "A category is required unless the project is 'all'".into(),
+            ));
+        };
+        return cmd_diff(
+            &config,
+            project,
+            category.as_str(),
+            version.as_deref(),
+            *history,
+        );
+    }
+
+    if let Commands::PruneVersions {
+        project,
+        keep,
+        older_than,
+        dry_run,
+    } = &cli.command
+    {
+        return cmd_prune_versions(
+            &config,
+            project.as_deref(),
+            *keep,
+            older_than.as_deref(),
+            *dry_run,
+        );
     }
 
     // Learn command
@@ -487,8 +702,13 @@ fn main() -> Result<()> {
                 project,
                 dry_run,
                 auto,
-            } => cmd_learn_optimize(&config, &project, dry_run, auto),
+                min_samples,
+            } => cmd_learn_optimize(&config, &project, dry_run, auto, min_samples),
             LearnCommand::Reset { project } => cmd_learn_reset(&config, &project),
+            LearnCommand::Export { project, output } => {
+                cmd_learn_export(&config, &project, output.as_deref())
+            }
+            LearnCommand::Import { project, input } => cmd_learn_import(&config, &project, &input),
             LearnCommand::Simulate {
                 project,
                 sessions,
@@ -522,6 +742,7 @@ fn main() -> Result<()> {
         project,
         top_k,
         threshold,
+        budget,
         use_graph,
         concise,
         recursive,
@@ -542,12 +763,12 @@ fn main() -> Result<()> {
                 &project_name,
                 *top_k,
                 *threshold,
-                cli.verbose,
+                cli.verbose > 0,
                 *concise,
             );
         }
         if *recursive {
-            return cmd_ask_recursive(&config, query, &project_name, cli.verbose, *concise);
+            return cmd_ask_recursive(&config, query, &project_name, cli.verbose > 0, *concise);
         }
         return cmd_ask(
             &config,
@@ -555,7 +776,8 @@ fn main() -> Result<()> {
             &project_name,
             *top_k,
             *threshold,
-            cli.verbose,
+            *budget,
+            cli.verbose > 0,
             *use_graph,
             *concise,
         );
@@ -569,6 +791,12 @@ fn main() -> Result<()> {
             since,
             skip_knowledge,
             ttl,
+            compact,
+            only_new_projects,
+            no_secret_scan,
+            summary_style,
+            summary_code,
+            no_summary_code,
             ..
         } => {
             cmd_ingest(
@@ -579,7 +807,14 @@ fn main() -> Result<()> {
                 since,
                 skip_knowledge,
                 ttl,
-                cli.verbose,
+                compact,
+                only_new_projects,
+                !no_secret_scan,
+                summary_style,
+                summary_code
+                    .then_some(true)
+                    .or(no_summary_code.then_some(false)),
+                cli.verbose > 0,
             )?;
         }
         Commands::Search {
@@ -588,32 +823,52 @@ fn main() -> Result<()> {
             knowledge,
             context,
             global,
+            files_only,
+            max_results,
+            turns,
         } => {
             let effective_project = if global {
                 Some(crate::config::GLOBAL_DIR.to_string())
             } else {
                 project
             };
-            cmd_search(&config, &query, effective_project, knowledge, context)?;
+            cmd_search(
+                &config,
+                &query,
+                effective_project,
+                knowledge,
+                context,
+                files_only,
+                max_results,
+                turns,
+            )?;
         }
-        Commands::Recall { project } => {
-            cmd_recall(&config, &project, cli.verbose)?;
+        Commands::Recall {
+            project,
+            chronological,
+        } => {
+            cmd_recall(&config, &project, chronological, cli.verbose > 0)?;
         }
         Commands::Context { project } => {
-            cmd_context(&config, &project, cli.verbose)?;
+            cmd_context(&config, &project, cli.verbose > 0)?;
         }
         Commands::Status => {
             cmd_status(&config)?;
         }
-        Commands::Projects => {
-            cmd_projects(&config)?;
+        Commands::Projects {
+            sort,
+            filter,
+            with_knowledge,
+        } => {
+            cmd_projects(&config, sort.as_deref(), filter.as_deref(), with_knowledge)?;
         }
         Commands::Regen {
             project,
             persist_cleanup,
+            dry_run,
             ..
         } => {
-            cmd_regen(&config, &project, persist_cleanup, cli.verbose)?;
+            cmd_regen(&config, &project, persist_cleanup, dry_run, cli.verbose > 0)?;
         }
         Commands::Auth { .. }
         | Commands::Tui
@@ -621,12 +876,16 @@ fn main() -> Result<()> {
         | Commands::Hooks { .. }
         | Commands::Forget { .. }
         | Commands::Lookup { .. }
+        | Commands::Stats { .. }
+        | Commands::Timeline { .. }
+        | Commands::Restore { .. }
         | Commands::Add { .. }
         | Commands::Review { .. }
         | Commands::Drain { .. }
         | Commands::Promote { .. }
         | Commands::Mcp { .. }
         | Commands::Export { .. }
+        | Commands::Import { .. }
         | Commands::Sync { .. }
         | Commands::Graph { .. }
         | Commands::Embed { .. }
@@ -635,6 +894,7 @@ fn main() -> Result<()> {
         | Commands::Doctor { .. }
         | Commands::Analytics { .. }
         | Commands::Diff { .. }
+        | Commands::PruneVersions { .. }
         | Commands::Learn { .. }
         | Commands::Hive { .. }
         | Commands::Daemon { .. }
@@ -662,6 +922,7 @@ fn cmd_daemon(command: DaemonCommand) -> Result<()> {
         }
         DaemonCommand::Stop => daemon::cmd_daemon_stop(&config),
         DaemonCommand::Status => daemon::cmd_daemon_status(&config),
+        DaemonCommand::SetInterval { minutes } => daemon::cmd_daemon_set_interval(&config, minutes),
         DaemonCommand::Logs { lines, follow } => daemon::cmd_daemon_logs(&config, lines, follow),
         DaemonCommand::Run { interval, provider } => {
             daemon::cmd_daemon_run(&config, interval, provider.as_deref())
@@ -686,14 +947,55 @@ fn cmd_tui() -> Result<()> {
 
 // ── Inject command ──────────────────────────────────────────────────────
 
+/// Resolve several Claude Code project directories sharing `project_name`:
+/// ask which to use on a terminal, and refuse to guess otherwise.
+fn choose_project_dirs(project_name: &str, matches: Vec<PathBuf>) -> Result<Vec<PathBuf>> {
+    use std::io::IsTerminal;
+
+    if !std::io::stdin().is_terminal() {
+        let list: Vec<String> = matches
+            .iter()
+            .map(|p| format!("  {}", p.display()))
+            .collect();
+        return Err(error::MemoryError::Config(format!(
+            "'{}' matches {} Claude Code project directories:\n{}\nRun inject from the project directory or pass --all-matches",
+            project_name,
+            matches.len(),
+            list.join("\n")
+        )));
+    }
+
+    let mut items: Vec<String> = matches.iter().map(|p| p.display().to_string()).collect();
+    items.push("All of them".to_string());
+    let selection = dialoguer::Select::new()
+        .with_prompt(format!(
+            "'{}' matches several Claude Code projects; inject into",
+            project_name
+        ))
+        .items(&items)
+        .default(0)
+        .interact()
+        .map_err(|e| error::MemoryError::Config(format!("Selection cancelled: {}", e)))?;
+
+    Ok(if selection == matches.len() {
+        matches
+    } else {
+        vec![matches[selection].clone()]
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
 fn cmd_inject(
     project: Option<String>,
     full: bool,
     no_auto_clean: bool,
     smart: bool,
-    budget: usize,
+    budget: Option<usize>,
     lines: Option<usize>,
     measure_tokens: bool,
+    append: bool,
+    all_matches: bool,
+    chronological: bool,
 ) -> Result<()> {
     let home = dirs::home_dir()
         .ok_or_else(|| error::MemoryError::Config("Could not determine home directory".into()))?;
@@ -732,7 +1034,16 @@ fn cmd_inject(
     let context_content = if context_path.exists() {
         std::fs::read_to_string(&context_path)?
     } else {
-        match inject::build_raw_context(&project_name, &knowledge_dir.join(&project_name)) {
+        let order = if chronological {
+            inject::BlockOrder::Chronological
+        } else {
+            inject::BlockOrder::Fresh
+        };
+        match inject::build_raw_context_ordered(
+            &project_name,
+            &knowledge_dir.join(&project_name),
+            order,
+        ) {
             Some(raw) => raw,
             None => {
                 eprintln!(
@@ -762,16 +1073,22 @@ fn cmd_inject(
     };
 
     // Find matching Claude Code project directory
-    let claude_projects_dir = home.join(".claude").join("projects");
-    let project_dir = find_claude_project_dir(&claude_projects_dir, &project_name)?;
+    let claude_projects_dir = config::claude_projects_dir(&home)?;
+    let cwd = std::env::current_dir().ok();
+    let matches = find_claude_project_dirs(&claude_projects_dir, &project_name, cwd.as_deref())?;
 
-    let Some(project_dir) = project_dir else {
+    if matches.is_empty() {
         eprintln!(
             "{} No matching Claude Code project directory found for '{}'.",
             "Not found:".yellow(),
             project_name
         );
         return Ok(());
+    }
+    let project_dirs = if matches.len() > 1 && !all_matches {
+        choose_project_dirs(&project_name, matches)?
+    } else {
+        matches
     };
 
     let (combined, mode) = if smart {
@@ -781,6 +1098,7 @@ fn cmd_inject(
             "Smart:".cyan(),
             &signal[..signal.len().min(100)]
         );
+        let smart_budget = budget.unwrap_or(inject::DEFAULT_SMART_BUDGET);
         let entries = inject::smart_search_sync(&project_name, &memory_dir, &signal, 20, 0.45)?;
         if entries.is_empty() {
             println!(
@@ -812,10 +1130,16 @@ fn cmd_inject(
                 selected,
                 entries.len(),
                 tokens,
-                budget
+                smart_budget
             );
             (
-                inject::format_smart_memory(&project_name, &signal, &entries, budget, &memory_dir)?,
+                inject::format_smart_memory(
+                    &project_name,
+                    &signal,
+                    &entries,
+                    smart_budget,
+                    &memory_dir,
+                )?,
                 "smart",
             )
         }
@@ -844,21 +1168,57 @@ fn cmd_inject(
         )
     };
 
+    // Enforce the token budget by dropping lowest-priority content
+    let combined = match budget {
+        Some(max_tokens) => {
+            let (fitted, trimmed) = inject::fit_to_token_budget(&combined, max_tokens);
+            if !trimmed.is_empty() {
+                println!(
+                    "{} Trimmed to fit ~{} tokens:",
+                    "Budget:".cyan(),
+                    max_tokens
+                );
+                for step in &trimmed {
+                    println!("  - {}", step);
+                }
+            }
+            fitted
+        }
+        None => combined,
+    };
+
     // Write to MEMORY.md
-    let memory_path = project_dir.join("memory");
-    std::fs::create_dir_all(&memory_path)?;
-    let memory_file = memory_path.join("MEMORY.md");
     let line_count = combined.lines().count();
-    std::fs::write(&memory_file, &combined)?;
+    for project_dir in &project_dirs {
+        let memory_path = project_dir.join("memory");
+        std::fs::create_dir_all(&memory_path)?;
+        let memory_file = memory_path.join("MEMORY.md");
+        // --append keeps hand-written notes outside the managed markers
+        let existing = if append {
+            std::fs::read_to_string(&memory_file).ok()
+        } else {
+            None
+        };
+        let output = inject::merge_managed_block(existing.as_deref(), &combined);
+        std::fs::write(&memory_file, &output)?;
 
-    println!(
-        "{} Injected {} knowledge for '{}' ({} lines) into {}",
-        "Done!".green().bold(),
-        mode,
-        project_name,
-        line_count,
-        memory_file.display()
-    );
+        status!(
+            "{} Injected {} knowledge for '{}' ({} lines) into {}",
+            "Done!".green().bold(),
+            mode,
+            project_name,
+            line_count,
+            memory_file.display()
+        );
+    }
+    if project_dirs.len() > 1 {
+        status!(
+            "{} '{}' matched {} Claude Code project directories; injected into all of them",
+            "Summary:".cyan(),
+            project_name,
+            project_dirs.len()
+        );
+    }
 
     // Track 3: Token efficiency measurement
     if measure_tokens {
@@ -891,27 +1251,3 @@ fn cmd_inject(
 
     Ok(())
 }
-
-/// Scan ~/.claude/projects/ and find the directory matching a project name.
-fn find_claude_project_dir(
-    claude_projects_dir: &Path,
-    project_name: &str,
-) -> Result<Option<PathBuf>> {
-    if !claude_projects_dir.exists() {
-        return Ok(None);
-    }
-
-    for entry in std::fs::read_dir(claude_projects_dir)? {
-        let entry = entry?;
-        if !entry.file_type()?.is_dir() {
-            continue;
-        }
-        let dir_name = entry.file_name().to_string_lossy().to_string();
-        let decoded = parser::discovery::decode_project_name(&dir_name);
-        if decoded == project_name {
-            return Ok(Some(entry.path()));
-        }
-    }
-
-    Ok(None)
-}
@@ -225,7 +225,7 @@ impl McpServer {
                         "category": {
                             "type": "string",
                             "description": "Category: decisions, solutions, patterns, bugs, insights, questions, procedures, or preferences",
-                            "enum": ["decisions", "solutions", "patterns", "bugs", "insights", "questions", "procedures", "preferences"]
+                            "enum": ["decisions", "solutions", "patterns", "bugs", "insights", "questions", "procedures", "workflows", "preferences"]
                         },
                         "content": {
                             "type": "string",
@@ -327,7 +327,7 @@ impl McpServer {
                         },
                         "categories": {
                             "type": "array",
-                            "items": { "type": "string", "enum": ["decisions", "solutions", "patterns", "bugs", "insights", "questions", "procedures"] },
+                            "items": { "type": "string", "enum": ["decisions", "solutions", "patterns", "bugs", "insights", "questions", "procedures", "workflows"] },
                             "description": "Which categories to extract. Defaults to all seven if omitted."
                         }
                     },
@@ -346,7 +346,7 @@ impl McpServer {
                         },
                         "category": {
                             "type": "string",
-                            "enum": ["decisions", "solutions", "patterns", "bugs", "insights", "questions", "procedures"],
+                            "enum": ["decisions", "solutions", "patterns", "bugs", "insights", "questions", "procedures", "workflows"],
                             "description": "Knowledge category file to search"
                         },
                         "label": {
@@ -373,7 +373,7 @@ impl McpServer {
                         },
                         "category": {
                             "type": "string",
-                            "enum": ["decisions", "solutions", "patterns", "bugs", "insights", "questions", "procedures"],
+                            "enum": ["decisions", "solutions", "patterns", "bugs", "insights", "questions", "procedures", "workflows"],
                             "description": "Knowledge category file containing the entry"
                         },
                         "label": {
@@ -975,7 +975,7 @@ impl McpServer {
         let label = args["label"].as_str();
 
         // Delegate to CLI command (simpler than reimplementing)
-        let mut cmd = std::process::Command::new("engram");
+        let mut cmd = std::process::Command::new(crate::config::engram_exe());
         cmd.args(["add", project, category, content]);
         if let Some(l) = label {
             cmd.args(["--label", l]);
@@ -994,7 +994,7 @@ impl McpServer {
         let days = args["days"].as_u64().unwrap_or(30);
 
         // Delegate to CLI
-        let mut cmd = std::process::Command::new("engram");
+        let mut cmd = std::process::Command::new(crate::config::engram_exe());
         cmd.args(["analytics"]);
 
         if let Some(proj) = args["project"].as_str() {
@@ -1019,7 +1019,7 @@ impl McpServer {
         let limit = args["limit"].as_u64().unwrap_or(10);
 
         // Delegate to CLI
-        let mut cmd = std::process::Command::new("engram");
+        let mut cmd = std::process::Command::new(crate::config::engram_exe());
         cmd.args(["search-semantic", query]);
 
         if let Some(proj) = args["project"].as_str() {
@@ -1058,7 +1058,7 @@ impl McpServer {
             .as_str()
             .ok_or_else(|| MemoryError::Config("Missing concept parameter".into()))?;
 
-        let output = std::process::Command::new("engram")
+        let output = std::process::Command::new(crate::config::engram_exe())
             .args(["graph", "query", project, concept])
             .output()?;
 
@@ -1141,7 +1141,7 @@ impl McpServer {
                 {
                     Ok(r) => r,
                     Err(e) => {
-                        eprintln!("reflect/{}: LLM error: {}", cat, e);
+                        tracing::warn!(category = %cat, error = %e, "reflect LLM call failed");
                         continue;
                     }
                 };
@@ -1455,7 +1455,7 @@ impl McpServer {
             .as_str()
             .ok_or_else(|| MemoryError::Config("Missing project".into()))?;
 
-        let output = std::process::Command::new("engram")
+        let output = std::process::Command::new(crate::config::engram_exe())
             .args(["regen", project])
             .output()?;
 
@@ -1630,7 +1630,7 @@ impl McpServer {
             )));
         }
 
-        let output = std::process::Command::new("engram")
+        let output = std::process::Command::new(crate::config::engram_exe())
             .args(["forget", project, "--stale", older_than, "--auto"])
             .output()?;
 
@@ -1792,6 +1792,7 @@ impl McpServer {
                             content: block.content,
                             score: 0.5,
                             selected: true,
+                            timestamp: Some(block.timestamp),
                         });
                     }
                 }
@@ -2022,6 +2023,7 @@ impl McpServer {
                             content: block.content,
                             score: 0.5,
                             selected: true,
+                            timestamp: Some(block.timestamp),
                         });
                     }
                 }
@@ -0,0 +1,138 @@
+//! Output verbosity and color settings shared by all commands.
+//!
+//! `--quiet` suppresses decorative stdout (success banners, "run X next" hints)
+//! so that piped output contains only the command's data. Errors still go to stderr.
+//! `--no-color`, `NO_COLOR` and non-TTY stdout disable ANSI colors.
+//! Diagnostics (warnings, failed hooks, per-session errors) are `tracing`
+//! events on stderr; `-v` raises the level and `RUST_LOG` overrides it.
+
+use std::io::IsTerminal;
+
+use crate::error::{MemoryError, Result};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+/// Enable or disable quiet mode for the rest of the process.
+pub fn set_quiet(quiet: bool) {
+    QUIET.store(quiet, Ordering::Relaxed);
+}
+
+/// Whether decorative output is suppressed.
+pub fn is_quiet() -> bool {
+    QUIET.load(Ordering::Relaxed)
+}
+
+/// Log level for engram's own events: warnings by default, errors only with
+/// `--quiet`, and one step more detail per `-v`.
+fn log_level(verbose: u8, quiet: bool) -> &'static str {
+    match (quiet, verbose) {
+        (true, 0) => "error",
+        (_, 0) => "warn",
+        (_, 1) => "info",
+        (_, 2) => "debug",
+        _ => "trace",
+    }
+}
+
+/// Install the stderr log subscriber. `RUST_LOG`, when set, takes precedence
+/// over the level derived from `-v`/`--quiet`.
+pub fn init_logging(verbose: u8, quiet: bool) {
+    use tracing_subscriber::EnvFilter;
+
+    let filter = EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| EnvFilter::new(format!("warn,engram={}", log_level(verbose, quiet))));
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .with_ansi(std::io::stderr().is_terminal())
+        .with_target(verbose >= 2)
+        .without_time()
+        .try_init();
+}
+
+/// Disable colored output when requested by flag, the `NO_COLOR` convention, or a non-TTY stdout.
+/// The TUI renders through ratatui and is unaffected.
+pub fn configure_color(no_color_flag: bool) {
+    let no_color_env = std::env::var("NO_COLOR").ok();
+    if color_disabled(
+        no_color_flag,
+        no_color_env.as_deref(),
+        std::io::stdout().is_terminal(),
+    ) {
+        colored::control::set_override(false);
+    }
+}
+
+/// `NO_COLOR` only counts when set to a non-empty value (see no-color.org).
+fn color_disabled(no_color_flag: bool, no_color_env: Option<&str>, stdout_is_tty: bool) -> bool {
+    no_color_flag || no_color_env.is_some_and(|v| !v.is_empty()) || !stdout_is_tty
+}
+
+/// Resolve an `--output` value: missing or `-` means stdout (`None`), anything else is a file path.
+pub fn output_file(output: Option<&str>) -> Option<&str> {
+    output.filter(|path| *path != "-")
+}
+
+/// Like [`output_file`], for formats that can only be written to a file (svg, pdf).
+pub fn require_output_file<'a>(output: Option<&'a str>, format: &str) -> Result<&'a str> {
+    output_file(output).ok_or_else(|| {
+        MemoryError::Config(format!(
+            "{} output cannot be written to stdout; pass --output <file>",
+            format
+        ))
+    })
+}
+
+/// `println!` for decorative status lines and hints; prints nothing under `--quiet`.
+macro_rules! status {
+    ($($arg:tt)*) => {
+        if !$crate::output::is_quiet() {
+            println!($($arg)*);
+        }
+    };
+}
+
+pub(crate) use status;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quiet_toggle() {
+        assert!(!is_quiet());
+        set_quiet(true);
+        assert!(is_quiet());
+        set_quiet(false);
+        assert!(!is_quiet());
+    }
+
+    #[test]
+    fn test_color_disabled() {
+        assert!(!color_disabled(false, None, true));
+        assert!(color_disabled(true, None, true));
+        assert!(color_disabled(false, Some("1"), true));
+        assert!(!color_disabled(false, Some(""), true));
+        assert!(color_disabled(false, None, false));
+    }
+
+    #[test]
+    fn test_log_level() {
+        assert_eq!(log_level(0, false), "warn");
+        assert_eq!(log_level(0, true), "error");
+        assert_eq!(log_level(1, false), "info");
+        assert_eq!(log_level(1, true), "info");
+        assert_eq!(log_level(2, false), "debug");
+        assert_eq!(log_level(5, false), "trace");
+    }
+
+    #[test]
+    fn test_output_file_dash_means_stdout() {
+        assert_eq!(output_file(None), None);
+        assert_eq!(output_file(Some("-")), None);
+        assert_eq!(output_file(Some("out.md")), Some("out.md"));
+        assert!(require_output_file(Some("-"), "svg").is_err());
+        assert_eq!(require_output_file(Some("g.svg"), "svg").unwrap(), "g.svg");
+    }
+}
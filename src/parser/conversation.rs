@@ -38,45 +38,74 @@ pub fn build_conversation(
     session_id: &str,
     project: &str,
 ) -> Conversation {
-    let mut turns = Vec::new();
-    let mut current_user: Option<&UserEntry> = None;
-    let mut assistant_chunks: Vec<&AssistantEntry> = Vec::new();
-    let mut start_time: Option<String> = None;
-    let mut end_time: Option<String> = None;
-    let mut model: Option<String> = None;
-    let mut total_input_tokens: u64 = 0;
-    let mut total_output_tokens: u64 = 0;
+    let mut builder = ConversationBuilder::default();
+    for entry in entries {
+        if matches!(entry, JournalEntry::User(_) | JournalEntry::Assistant(_)) {
+            builder.push(entry.clone());
+        }
+    }
+    builder.finish(session_id, project)
+}
 
+/// Build a conversation model while streaming JSONL entries.
+///
+/// Only the entries of the turn in progress are held at once; finished turns
+/// keep just their rendered text and tool summaries, so memory stays flat for
+/// very large sessions. Stops at the first I/O error.
+pub fn build_conversation_streaming<I>(
+    entries: I,
+    session_id: &str,
+    project: &str,
+) -> crate::error::Result<Conversation>
+where
+    I: IntoIterator<Item = crate::error::Result<JournalEntry>>,
+{
+    let mut builder = ConversationBuilder::default();
     for entry in entries {
+        builder.push(entry?);
+    }
+    Ok(builder.finish(session_id, project))
+}
+
+/// Accumulates entries into turns, flushing each turn when the next user
+/// message arrives
+#[derive(Default)]
+struct ConversationBuilder {
+    turns: Vec<Turn>,
+    current_user: Option<UserEntry>,
+    assistant_chunks: Vec<AssistantEntry>,
+    start_time: Option<String>,
+    end_time: Option<String>,
+    model: Option<String>,
+    total_input_tokens: u64,
+    total_output_tokens: u64,
+}
+
+impl ConversationBuilder {
+    fn push(&mut self, entry: JournalEntry) {
         match entry {
             JournalEntry::User(user) => {
                 // If we have a pending user + assistant, flush the turn
-                if let Some(prev_user) = current_user.take() {
-                    if !assistant_chunks.is_empty() {
-                        let turn = build_turn(prev_user, &assistant_chunks);
-                        turns.push(turn);
-                        assistant_chunks.clear();
-                    }
-                }
-                current_user = Some(user);
+                self.flush_turn();
 
-                if start_time.is_none() {
-                    start_time = user.timestamp.clone();
+                if self.start_time.is_none() {
+                    self.start_time = user.timestamp.clone();
                 }
-                end_time = user.timestamp.clone();
+                self.end_time = user.timestamp.clone();
+                self.current_user = Some(user);
             }
             JournalEntry::Assistant(assistant) => {
                 // Track model and tokens
-                if model.is_none() {
-                    model = assistant.message.model.clone();
+                if self.model.is_none() {
+                    self.model = assistant.message.model.clone();
                 }
                 if let Some(ref usage) = assistant.message.usage {
-                    total_input_tokens += usage.input_tokens.unwrap_or(0);
-                    total_output_tokens += usage.output_tokens.unwrap_or(0);
+                    self.total_input_tokens += usage.input_tokens.unwrap_or(0);
+                    self.total_output_tokens += usage.output_tokens.unwrap_or(0);
                 }
 
-                end_time = assistant.timestamp.clone();
-                assistant_chunks.push(assistant);
+                self.end_time = assistant.timestamp.clone();
+                self.assistant_chunks.push(assistant);
             }
             JournalEntry::FileHistorySnapshot(_) | JournalEntry::Progress(_) => {
                 // Skip these
@@ -84,23 +113,32 @@ pub fn build_conversation(
         }
     }
 
-    // Flush the last turn
-    if let Some(user) = current_user {
-        if !assistant_chunks.is_empty() {
-            let turn = build_turn(user, &assistant_chunks);
-            turns.push(turn);
+    /// Turn the pending user message and its assistant chunks into a turn.
+    /// A user message with no reply yet is dropped, as before streaming.
+    fn flush_turn(&mut self) {
+        if let Some(user) = self.current_user.take() {
+            if !self.assistant_chunks.is_empty() {
+                let chunks: Vec<&AssistantEntry> = self.assistant_chunks.iter().collect();
+                self.turns.push(build_turn(&user, &chunks));
+                self.assistant_chunks.clear();
+            }
         }
     }
 
-    Conversation {
-        session_id: session_id.to_string(),
-        project: project.to_string(),
-        turns,
-        start_time,
-        end_time,
-        model,
-        total_input_tokens,
-        total_output_tokens,
+    fn finish(mut self, session_id: &str, project: &str) -> Conversation {
+        // Flush the last turn
+        self.flush_turn();
+
+        Conversation {
+            session_id: session_id.to_string(),
+            project: project.to_string(),
+            turns: self.turns,
+            start_time: self.start_time,
+            end_time: self.end_time,
+            model: self.model,
+            total_input_tokens: self.total_input_tokens,
+            total_output_tokens: self.total_output_tokens,
+        }
     }
 }
 
@@ -278,3 +316,179 @@ fn truncate(s: &str, max_len: usize) -> String {
         format!("{}...", &s[..end])
     }
 }
+
+/// Which part of a turn a rendered conversation.md section holds
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TurnRole {
+    User,
+    ToolCalls,
+    Assistant,
+}
+
+impl TurnRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TurnRole::User => "user",
+            TurnRole::ToolCalls => "tool calls",
+            TurnRole::Assistant => "assistant",
+        }
+    }
+}
+
+/// A section of a turn in a rendered conversation.md, as a line range
+/// (0-based, `start..end`, heading excluded)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TurnSection {
+    pub turn: usize,
+    pub role: TurnRole,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Map a conversation.md (as written by `renderer::markdown::render_conversation`)
+/// back to its turns, so a line can be traced to the message it belongs to.
+pub fn locate_turn_sections(markdown: &str) -> Vec<TurnSection> {
+    let lines: Vec<&str> = markdown.lines().collect();
+    let mut sections = Vec::new();
+    let mut open: Option<TurnSection> = None;
+    let mut turn = None;
+
+    // Surrounding blank lines and the `---` turn separator aren't part of the message
+    let close = |open: &mut Option<TurnSection>, sections: &mut Vec<TurnSection>, end: usize| {
+        if let Some(mut section) = open.take() {
+            section.end = end;
+            while section.start < end && lines[section.start].trim().is_empty() {
+                section.start += 1;
+            }
+            while section.end > section.start && matches!(lines[section.end - 1].trim(), "" | "---")
+            {
+                section.end -= 1;
+            }
+            sections.push(section);
+        }
+    };
+
+    for (i, line) in lines.iter().enumerate() {
+        if let Some(n) = line
+            .strip_prefix("## Turn ")
+            .and_then(|n| n.trim().parse::<usize>().ok())
+        {
+            close(&mut open, &mut sections, i);
+            turn = Some(n);
+            continue;
+        }
+        let role = match *line {
+            "### User" => TurnRole::User,
+            "### Tool Calls" => TurnRole::ToolCalls,
+            "### Assistant" => TurnRole::Assistant,
+            _ => continue,
+        };
+        let Some(turn) = turn else { continue };
+        close(&mut open, &mut sections, i);
+        open = Some(TurnSection {
+            turn,
+            role,
+            start: i + 1,
+            end: i + 1,
+        });
+    }
+    close(&mut open, &mut sections, lines.len());
+
+    sections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_streaming_matches_slice_build() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("session.jsonl");
+        let lines = [
+            r#"{"type":"user","timestamp":"t1","message":{"role":"user","content":"How do I retry?"}}"#,
+            r#"{"type":"progress","data":{"step":1}}"#,
+            r#"{"type":"assistant","timestamp":"t2","message":{"role":"assistant","model":"m1","usage":{"input_tokens":10,"output_tokens":5},"content":[{"type":"text","text":"Use backoff."},{"type":"tool_use","id":"a","name":"Bash","input":{"command":"ls"}}]}}"#,
+            "not json",
+            r#"{"type":"assistant","timestamp":"t3","message":{"role":"assistant","usage":{"input_tokens":1,"output_tokens":2},"content":"With jitter."}}"#,
+            "",
+            r#"{"type":"user","timestamp":"t4","message":{"role":"user","content":"Unanswered"}}"#,
+            r#"{"type":"user","timestamp":"t5","message":{"role":"user","content":"Thanks"}}"#,
+            r#"{"type":"assistant","timestamp":"t6","message":{"role":"assistant","content":"Sure"}}"#,
+        ];
+        std::fs::write(&path, lines.join("\n")).unwrap();
+
+        let entries = crate::parser::jsonl::parse_jsonl(&path).unwrap();
+        let sliced = build_conversation(&entries, "s1", "demo");
+        let stream = crate::parser::jsonl::stream_jsonl(&path).unwrap();
+        let streamed = build_conversation_streaming(stream, "s1", "demo").unwrap();
+
+        assert_eq!(streamed.turns.len(), 2);
+        assert_eq!(streamed.turns.len(), sliced.turns.len());
+        for (a, b) in streamed.turns.iter().zip(&sliced.turns) {
+            assert_eq!(a.user_text, b.user_text);
+            assert_eq!(a.assistant_text, b.assistant_text);
+            assert_eq!(a.tool_interactions.len(), b.tool_interactions.len());
+        }
+        assert_eq!(
+            streamed.turns[0].assistant_text,
+            "Use backoff.\nWith jitter."
+        );
+        assert_eq!(streamed.turns[1].user_text, "Thanks");
+        assert_eq!(streamed.model.as_deref(), Some("m1"));
+        assert_eq!(streamed.total_input_tokens, 11);
+        assert_eq!(streamed.total_output_tokens, 7);
+        assert_eq!(streamed.start_time.as_deref(), Some("t1"));
+        assert_eq!(streamed.end_time.as_deref(), Some("t6"));
+    }
+
+    #[test]
+    fn test_locate_turn_sections() {
+        let conv = Conversation {
+            session_id: "s1".to_string(),
+            project: "demo".to_string(),
+            turns: vec![
+                Turn {
+                    user_text: "How do I retry?".to_string(),
+                    assistant_text: "Use backoff.\n\n### Notes\nwith jitter".to_string(),
+                    tool_interactions: Vec::new(),
+                    timestamp: None,
+                },
+                Turn {
+                    user_text: "Thanks".to_string(),
+                    assistant_text: "Sure".to_string(),
+                    tool_interactions: Vec::new(),
+                    timestamp: None,
+                },
+            ],
+            start_time: None,
+            end_time: None,
+            model: None,
+            total_input_tokens: 0,
+            total_output_tokens: 0,
+        };
+        let markdown = crate::renderer::markdown::render_conversation(
+            &conv,
+            &crate::renderer::markdown::RenderOptions::default(),
+        );
+        let lines: Vec<&str> = markdown.lines().collect();
+        let sections = locate_turn_sections(&markdown);
+
+        let roles: Vec<_> = sections.iter().map(|s| (s.turn, s.role)).collect();
+        assert_eq!(
+            roles,
+            [
+                (1, TurnRole::User),
+                (1, TurnRole::Assistant),
+                (2, TurnRole::User),
+                (2, TurnRole::Assistant)
+            ]
+        );
+
+        let body = |s: &TurnSection| lines[s.start..s.end].join("\n");
+        assert_eq!(body(&sections[0]).trim(), "How do I retry?");
+        // Markdown headings inside a message stay in that message
+        assert!(body(&sections[1]).ends_with("with jitter"));
+        assert_eq!(body(&sections[3]).trim(), "Sure");
+    }
+}
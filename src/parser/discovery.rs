@@ -19,6 +19,27 @@ pub struct Project {
     pub sessions: Vec<SessionFile>,
 }
 
+impl Project {
+    /// Combined size of all session files in bytes
+    pub fn total_size(&self) -> u64 {
+        self.sessions.iter().map(|s| s.size).sum()
+    }
+
+    /// Most recent session modification time
+    pub fn last_modified(&self) -> Option<DateTime<Utc>> {
+        self.sessions.iter().map(|s| s.modified).max()
+    }
+}
+
+/// Sort projects by `name` (A–Z), `size` (largest first) or `recent` (newest first)
+pub fn sort_projects(projects: &mut [Project], key: &str) {
+    match key {
+        "size" => projects.sort_by_key(|p| std::cmp::Reverse(p.total_size())),
+        "recent" => projects.sort_by_key(|p| std::cmp::Reverse(p.last_modified())),
+        _ => projects.sort_by(|a, b| a.name.cmp(&b.name)),
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct SessionFile {
     /// Session UUID extracted from filename
@@ -82,7 +103,7 @@ pub fn discover_projects(projects_dir: &Path) -> Result<Vec<Project>> {
         }
 
         // Sort sessions by modified time (newest first)
-        sessions.sort_by(|a, b| b.modified.cmp(&a.modified));
+        sessions.sort_by_key(|s| std::cmp::Reverse(s.modified));
 
         if !sessions.is_empty() {
             projects.push(Project {
@@ -100,6 +121,37 @@ pub fn discover_projects(projects_dir: &Path) -> Result<Vec<Project>> {
     Ok(projects)
 }
 
+/// Whether `value` looks like a session ID (a UUID such as
+/// "0f5c2a1e-8d3b-4c6f-9a7e-1b2c3d4e5f60") rather than a duration like "2h".
+pub fn looks_like_session_id(value: &str) -> bool {
+    value.len() == 36
+        && value.char_indices().all(|(i, c)| match i {
+            8 | 13 | 18 | 23 => c == '-',
+            _ => c.is_ascii_hexdigit(),
+        })
+}
+
+/// Find a session by ID across all projects.
+pub fn find_session<'a>(projects: &'a [Project], session_id: &str) -> Option<&'a SessionFile> {
+    projects
+        .iter()
+        .flat_map(|p| &p.sessions)
+        .find(|s| s.session_id.eq_ignore_ascii_case(session_id))
+}
+
+/// Encode a project path the way Claude Code names its directory under
+/// `~/.claude/projects`: every character other than an ASCII letter or digit
+/// becomes '-'.
+/// e.g., "/Users/algimantask/Projects/claudius" -> "-Users-algimantask-Projects-claudius"
+///
+/// This is lossy, so [`decode_project_name`] only recovers the original
+/// project name when it contained nothing but letters, digits and dashes.
+pub fn encode_project_name(path: &str) -> String {
+    path.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect()
+}
+
 /// Decode project directory name to human-readable name
 /// e.g., "-Users-algimantask-Projects-claudius" -> "claudius"
 pub fn decode_project_name(dir_name: &str) -> String {
@@ -158,4 +210,95 @@ mod tests {
         assert_eq!(decode_project_name("-Users-algimantask"), "home");
         assert_eq!(decode_project_name("-private-tmp-sm"), "private-tmp-sm");
     }
+
+    #[test]
+    fn test_looks_like_session_id() {
+        assert!(looks_like_session_id(
+            "0f5c2a1e-8d3b-4c6f-9a7e-1b2c3d4e5f60"
+        ));
+        assert!(looks_like_session_id(
+            "0F5C2A1E-8D3B-4C6F-9A7E-1B2C3D4E5F60"
+        ));
+        assert!(!looks_like_session_id("2h"));
+        assert!(!looks_like_session_id("0f5c2a1e8d3b4c6f9a7e1b2c3d4e5f60"));
+        assert!(!looks_like_session_id(
+            "0f5c2a1e-8d3b-4c6f-9a7e-1b2c3d4e5f6z"
+        ));
+    }
+
+    #[test]
+    fn test_find_session() {
+        let projects = vec![project("a", &[10, 20], 0), project("b", &[30], 0)];
+        assert_eq!(find_session(&projects, "b-0").unwrap().size, 30);
+        assert_eq!(find_session(&projects, "A-1").unwrap().size, 20);
+        assert!(find_session(&projects, "c-0").is_none());
+    }
+
+    #[test]
+    fn test_encode_project_name_round_trip() {
+        let round_trip = |path: &str| decode_project_name(&encode_project_name(path));
+
+        assert_eq!(
+            encode_project_name("/Users/algimantask/Projects/claudius"),
+            "-Users-algimantask-Projects-claudius"
+        );
+        assert_eq!(
+            round_trip("/Users/algimantask/Projects/claudius"),
+            "claudius"
+        );
+        // Dashes survive
+        assert_eq!(
+            round_trip("/Users/algimantask/Personal/memory-palace"),
+            "memory-palace"
+        );
+        // Dots, underscores and spaces all become dashes
+        assert_eq!(
+            encode_project_name("/Users/me/Projects/my_site.io v2"),
+            "-Users-me-Projects-my-site-io-v2"
+        );
+        assert_eq!(round_trip("/Users/me/Projects/my_site.io"), "my-site-io");
+        // Each non-ASCII character becomes a single dash
+        assert_eq!(
+            encode_project_name("/Users/me/Projects/café"),
+            "-Users-me-Projects-caf-"
+        );
+        assert_eq!(round_trip("/Users/me/Projects/café"), "caf");
+    }
+
+    fn project(name: &str, sizes: &[u64], days_ago: i64) -> Project {
+        Project {
+            name: name.to_string(),
+            dir_name: name.to_string(),
+            path: PathBuf::from(name),
+            sessions: sizes
+                .iter()
+                .enumerate()
+                .map(|(i, size)| SessionFile {
+                    session_id: format!("{}-{}", name, i),
+                    path: PathBuf::from(format!("{}-{}.jsonl", name, i)),
+                    size: *size,
+                    modified: Utc::now() - chrono::Duration::days(days_ago + i as i64),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_sort_projects() {
+        let mut projects = vec![
+            project("beta", &[10, 10], 5),
+            project("alpha", &[5], 1),
+            project("gamma", &[100], 30),
+        ];
+        let names = |ps: &[Project]| ps.iter().map(|p| p.name.clone()).collect::<Vec<_>>();
+
+        sort_projects(&mut projects, "name");
+        assert_eq!(names(&projects), ["alpha", "beta", "gamma"]);
+
+        sort_projects(&mut projects, "size");
+        assert_eq!(names(&projects), ["gamma", "beta", "alpha"]);
+
+        sort_projects(&mut projects, "recent");
+        assert_eq!(names(&projects), ["alpha", "beta", "gamma"]);
+    }
 }
@@ -149,26 +149,52 @@ impl MessageContent {
 
 /// Parse a JSONL file into journal entries, streaming line-by-line
 pub fn parse_jsonl(path: &Path) -> Result<Vec<JournalEntry>> {
+    stream_jsonl(path)?.collect()
+}
+
+/// Open a JSONL file as a lazy iterator of journal entries.
+///
+/// Lines are read through a buffered reader and parsed one at a time, so
+/// callers that consume entries as they arrive (see
+/// [`crate::parser::conversation::build_conversation_streaming`]) never hold
+/// the whole session in memory. Malformed lines are skipped; I/O errors are
+/// yielded.
+pub fn stream_jsonl(path: &Path) -> Result<JournalEntries> {
     let file = std::fs::File::open(path)?;
     let reader = std::io::BufReader::with_capacity(64 * 1024, file);
-    let mut entries = Vec::new();
+    Ok(JournalEntries {
+        lines: reader.lines(),
+    })
+}
 
-    for line in reader.lines() {
-        let line = line?;
-        let line = line.trim();
-        if line.is_empty() {
-            continue;
-        }
+/// Iterator over the entries of a JSONL file, created by [`stream_jsonl`]
+pub struct JournalEntries {
+    lines: std::io::Lines<std::io::BufReader<std::fs::File>>,
+}
 
-        // Try to parse; skip malformed lines
-        match serde_json::from_str::<JournalEntry>(line) {
-            Ok(entry) => entries.push(entry),
-            Err(_) => {
-                // Some lines may have unknown types; skip silently
+impl Iterator for JournalEntries {
+    type Item = Result<JournalEntry>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for line in self.lines.by_ref() {
+            let line = match line {
+                Ok(line) => line,
+                Err(e) => return Some(Err(e.into())),
+            };
+            let line = line.trim();
+            if line.is_empty() {
                 continue;
             }
+
+            // Try to parse; skip malformed lines
+            match serde_json::from_str::<JournalEntry>(line) {
+                Ok(entry) => return Some(Ok(entry)),
+                Err(_) => {
+                    // Some lines may have unknown types; skip silently
+                    continue;
+                }
+            }
         }
+        None
     }
-
-    Ok(entries)
 }
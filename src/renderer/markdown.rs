@@ -13,8 +13,49 @@ fn truncate_str(s: &str, max_bytes: usize) -> &str {
     &s[..end]
 }
 
+/// Tool outputs longer than this many lines are collapsed by `RenderOptions::compact`.
+pub const COMPACT_TOOL_OUTPUT_LINES: usize = 8;
+
+/// Turns listed in a detailed summary before the rest are elided.
+const DETAILED_SUMMARY_TURNS: usize = 40;
+
+/// Lines kept per code snippet in a summary.
+const SUMMARY_SNIPPET_LINES: usize = 20;
+
+/// How much of a session `render_summary` writes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SummaryStyle {
+    /// Header, initial request and tool counts
+    #[default]
+    Brief,
+    /// Brief plus a one-line outline of every turn
+    Detailed,
+}
+
+/// Options controlling how a conversation is rendered
+#[derive(Debug, Clone, Default)]
+pub struct RenderOptions {
+    /// Collapse tool outputs longer than this many lines into a marker
+    pub max_tool_output_lines: Option<usize>,
+    /// Verbosity of the per-session summary
+    pub summary_style: SummaryStyle,
+    /// Include fenced code blocks from assistant replies in the summary
+    pub summary_code: bool,
+}
+
+impl RenderOptions {
+    /// Options for `ingest --compact`: collapse long tool outputs
+    pub fn compact() -> Self {
+        Self {
+            max_tool_output_lines: Some(COMPACT_TOOL_OUTPUT_LINES),
+            ..Self::default()
+        }
+    }
+}
+
 /// Render a conversation to clean markdown
-pub fn render_conversation(conv: &Conversation) -> String {
+pub fn render_conversation(conv: &Conversation, options: &RenderOptions) -> String {
     let mut out = String::with_capacity(8192);
 
     // Header
@@ -66,7 +107,16 @@ pub fn render_conversation(conv: &Conversation) -> String {
                         tool.input_summary.replace('`', "'")
                     ));
                 }
-                if !tool.output_summary.is_empty() {
+                let output_lines = tool.output_summary.lines().count();
+                if options
+                    .max_tool_output_lines
+                    .is_some_and(|max| output_lines > max)
+                {
+                    out.push_str(&format!(
+                        "**Output:** *[tool output truncated: {} lines]*\n\n",
+                        output_lines
+                    ));
+                } else if !tool.output_summary.is_empty() {
                     out.push_str("**Output:**\n```\n");
                     out.push_str(&tool.output_summary);
                     out.push_str("\n```\n\n");
@@ -94,27 +144,97 @@ pub fn render_conversation(conv: &Conversation) -> String {
     out
 }
 
+/// Version of the `meta.json` layout written by [`render_meta`]. Bump it when
+/// a field changes meaning and migrate the old shape in [`SessionMeta::parse`].
+pub const META_SCHEMA_VERSION: u32 = 1;
+
+/// Per-session metadata stored as `meta.json` next to `conversation.md`.
+///
+/// Fields are declared in alphabetical order, the key order meta.json has
+/// always been written in, so re-rendering old sessions only adds
+/// `schema_version`.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SessionMeta {
+    #[serde(default)]
+    pub end_time: Option<String>,
+    #[serde(default)]
+    pub model: Option<String>,
+    #[serde(default)]
+    pub project: String,
+    /// Missing in files written before versioning, which read as version 0
+    #[serde(default)]
+    pub schema_version: u32,
+    #[serde(default)]
+    pub session_id: String,
+    #[serde(default)]
+    pub start_time: Option<String>,
+    /// (tool name, call count), most used first
+    #[serde(default)]
+    pub tool_usage: Vec<(String, usize)>,
+    #[serde(default)]
+    pub total_input_tokens: u64,
+    #[serde(default)]
+    pub total_output_tokens: u64,
+    #[serde(default)]
+    pub turn_count: usize,
+}
+
+impl SessionMeta {
+    pub fn from_conversation(conv: &Conversation) -> Self {
+        Self {
+            end_time: conv.end_time.clone(),
+            model: conv.model.clone(),
+            project: conv.project.clone(),
+            schema_version: META_SCHEMA_VERSION,
+            session_id: conv.session_id.clone(),
+            start_time: conv.start_time.clone(),
+            tool_usage: count_tools(conv),
+            total_input_tokens: conv.total_input_tokens,
+            total_output_tokens: conv.total_output_tokens,
+            turn_count: conv.turns.len(),
+        }
+    }
+
+    /// Parse meta.json content, migrating older schema versions to the
+    /// current one. Files from a newer engram are read best-effort: known
+    /// fields are kept and a warning is logged.
+    pub fn parse(content: &str) -> crate::error::Result<Self> {
+        let mut meta: SessionMeta = serde_json::from_str(content)?;
+        if meta.schema_version > META_SCHEMA_VERSION {
+            tracing::warn!(
+                session = %meta.session_id,
+                version = meta.schema_version,
+                supported = META_SCHEMA_VERSION,
+                "meta.json was written by a newer engram; reading known fields only"
+            );
+            return Ok(meta);
+        }
+        // Version 0 (unversioned) has the same fields as version 1
+        meta.schema_version = META_SCHEMA_VERSION;
+        Ok(meta)
+    }
+
+    /// Read a session's meta.json; None (with a warning if it exists but is
+    /// unreadable) when it can't be used
+    pub fn load(path: &std::path::Path) -> Option<Self> {
+        let content = std::fs::read_to_string(path).ok()?;
+        match Self::parse(&content) {
+            Ok(meta) => Some(meta),
+            Err(e) => {
+                tracing::warn!(path = %path.display(), error = %e, "invalid meta.json");
+                None
+            }
+        }
+    }
+}
+
 /// Render machine-readable metadata JSON
 pub fn render_meta(conv: &Conversation) -> String {
-    let tool_counts = count_tools(conv);
-
-    let meta = serde_json::json!({
-        "session_id": conv.session_id,
-        "project": conv.project,
-        "model": conv.model,
-        "start_time": conv.start_time,
-        "end_time": conv.end_time,
-        "total_input_tokens": conv.total_input_tokens,
-        "total_output_tokens": conv.total_output_tokens,
-        "turn_count": conv.turns.len(),
-        "tool_usage": tool_counts,
-    });
-
-    serde_json::to_string_pretty(&meta).unwrap_or_default()
+    serde_json::to_string_pretty(&SessionMeta::from_conversation(conv)).unwrap_or_default()
 }
 
 /// Render a brief summary of the conversation
-pub fn render_summary(conv: &Conversation) -> String {
+pub fn render_summary(conv: &Conversation, options: &RenderOptions) -> String {
     let mut out = String::new();
 
     out.push_str(&format!("# {} - {}\n\n", conv.project, conv.session_id));
@@ -151,17 +271,267 @@ pub fn render_summary(conv: &Conversation) -> String {
         out.push('\n');
     }
 
+    if options.summary_style == SummaryStyle::Detailed && !conv.turns.is_empty() {
+        out.push_str("\n**Turns:**\n\n");
+        for (i, turn) in conv.turns.iter().take(DETAILED_SUMMARY_TURNS).enumerate() {
+            let first_line = |text: &str| {
+                let line = text.lines().find(|l| !l.trim().is_empty()).unwrap_or("");
+                truncate_str(line.trim(), 200).to_string()
+            };
+            out.push_str(&format!(
+                "{}. {} → {}\n",
+                i + 1,
+                first_line(&turn.user_text),
+                first_line(&turn.assistant_text)
+            ));
+        }
+        if conv.turns.len() > DETAILED_SUMMARY_TURNS {
+            out.push_str(&format!(
+                "\n*[{} more turns]*\n",
+                conv.turns.len() - DETAILED_SUMMARY_TURNS
+            ));
+        }
+    }
+
+    if options.summary_code {
+        let limit = match options.summary_style {
+            SummaryStyle::Brief => 2,
+            SummaryStyle::Detailed => 5,
+        };
+        let snippets: Vec<String> = conv
+            .turns
+            .iter()
+            .flat_map(|t| code_blocks(&t.assistant_text))
+            .take(limit)
+            .collect();
+        if !snippets.is_empty() {
+            out.push_str("\n**Code snippets:**\n\n");
+            for snippet in snippets {
+                out.push_str(&snippet);
+                out.push_str("\n\n");
+            }
+        }
+    }
+
     out
 }
 
+/// Fenced code blocks in `text`, each cut to `SUMMARY_SNIPPET_LINES` lines
+fn code_blocks(text: &str) -> Vec<String> {
+    let mut blocks = Vec::new();
+    let mut fence: Option<&str> = None;
+    let mut body: Vec<&str> = Vec::new();
+
+    for line in text.lines() {
+        let trimmed = line.trim_start();
+        if !trimmed.starts_with("```") {
+            if fence.is_some() {
+                body.push(line);
+            }
+            continue;
+        }
+
+        let Some(open) = fence.take() else {
+            fence = Some(trimmed);
+            continue;
+        };
+        if body.iter().all(|l| l.trim().is_empty()) {
+            body.clear();
+            continue;
+        }
+
+        let mut block = format!("{}\n", open);
+        for l in body.iter().take(SUMMARY_SNIPPET_LINES) {
+            block.push_str(l);
+            block.push('\n');
+        }
+        if body.len() > SUMMARY_SNIPPET_LINES {
+            block.push_str("// ...\n");
+        }
+        block.push_str("```");
+        blocks.push(block);
+        body.clear();
+    }
+
+    blocks
+}
+
+/// Tool call counts, most used first and ties by name, so re-rendering the
+/// same session is byte-identical
 fn count_tools(conv: &Conversation) -> Vec<(String, usize)> {
-    let mut counts = std::collections::HashMap::new();
+    let mut counts = std::collections::BTreeMap::new();
     for turn in &conv.turns {
         for tool in &turn.tool_interactions {
             *counts.entry(tool.tool_name.clone()).or_insert(0) += 1;
         }
     }
     let mut sorted: Vec<(String, usize)> = counts.into_iter().collect();
-    sorted.sort_by(|a, b| b.1.cmp(&a.1));
+    // Stable sort keeps the BTreeMap's name order among equal counts
+    sorted.sort_by_key(|s| std::cmp::Reverse(s.1));
     sorted
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::conversation::{ToolInteraction, Turn};
+
+    #[test]
+    fn test_compact_collapses_long_tool_output() {
+        let tool = |output: &str| ToolInteraction {
+            tool_name: "Bash".to_string(),
+            input_summary: "ls".to_string(),
+            output_summary: output.to_string(),
+            is_error: false,
+        };
+        let long_output = (1..=20).map(|i| format!("file{}", i)).collect::<Vec<_>>();
+        let conv = Conversation {
+            session_id: "s1".to_string(),
+            project: "demo".to_string(),
+            turns: vec![Turn {
+                user_text: "List files".to_string(),
+                assistant_text: "Done".to_string(),
+                tool_interactions: vec![tool(&long_output.join("\n")), tool("Cargo.toml")],
+                timestamp: None,
+            }],
+            start_time: None,
+            end_time: None,
+            model: None,
+            total_input_tokens: 0,
+            total_output_tokens: 0,
+        };
+
+        let full = render_conversation(&conv, &RenderOptions::default());
+        assert!(full.contains("file20"));
+        assert!(!full.contains("tool output truncated"));
+
+        let compact = render_conversation(&conv, &RenderOptions::compact());
+        assert!(!compact.contains("file20"));
+        assert!(compact.contains("*[tool output truncated: 20 lines]*"));
+        // Short outputs are kept as-is
+        assert!(compact.contains("```\nCargo.toml\n```"));
+    }
+
+    #[test]
+    fn test_rendering_is_deterministic() {
+        let tool = |name: &str| ToolInteraction {
+            tool_name: name.to_string(),
+            input_summary: String::new(),
+            output_summary: String::new(),
+            is_error: false,
+        };
+        // Many tools with tied counts: any hash-order dependence shows up
+        let names = [
+            "Write", "Bash", "Read", "Grep", "Edit", "Glob", "Task", "WebFetch",
+        ];
+        let mut tools: Vec<ToolInteraction> = names.iter().map(|n| tool(n)).collect();
+        tools.push(tool("Read"));
+        let conv = Conversation {
+            session_id: "s1".to_string(),
+            project: "demo".to_string(),
+            turns: vec![Turn {
+                user_text: "Refactor".to_string(),
+                assistant_text: "Done".to_string(),
+                tool_interactions: tools,
+                timestamp: Some("2026-01-01T00:00:00Z".to_string()),
+            }],
+            start_time: Some("2026-01-01T00:00:00Z".to_string()),
+            end_time: None,
+            model: Some("m".to_string()),
+            total_input_tokens: 1,
+            total_output_tokens: 2,
+        };
+        let options = RenderOptions {
+            summary_style: SummaryStyle::Detailed,
+            ..RenderOptions::default()
+        };
+
+        let render = || {
+            (
+                render_conversation(&conv, &options),
+                render_meta(&conv),
+                render_summary(&conv, &options),
+            )
+        };
+        let first = render();
+        for _ in 0..10 {
+            assert_eq!(render(), first);
+        }
+        assert!(first
+            .1
+            .contains(&format!("\"schema_version\": {}", META_SCHEMA_VERSION)));
+
+        assert!(first.2.contains(
+            "**Tools used:** Read(2), Bash(1), Edit(1), Glob(1), Grep(1), Task(1), WebFetch(1), Write(1)"
+        ));
+        // meta.json keys are sorted
+        let keys: Vec<&str> = first
+            .1
+            .lines()
+            .filter_map(|l| l.strip_prefix("  \""))
+            .filter_map(|l| l.split('"').next())
+            .collect();
+        let mut sorted = keys.clone();
+        sorted.sort();
+        assert_eq!(keys, sorted);
+    }
+
+    #[test]
+    fn test_session_meta_versions() {
+        // Unversioned files are migrated
+        let old =
+            r#"{"project":"demo","session_id":"s1","turn_count":3,"tool_usage":[["Bash",2]]}"#;
+        let meta = SessionMeta::parse(old).unwrap();
+        assert_eq!(meta.schema_version, META_SCHEMA_VERSION);
+        assert_eq!(meta.turn_count, 3);
+        assert_eq!(meta.tool_usage, vec![("Bash".to_string(), 2)]);
+
+        // Newer files keep their version and known fields; unknown fields are ignored
+        let newer = r#"{"schema_version":99,"turn_count":4,"future_field":true}"#;
+        let meta = SessionMeta::parse(newer).unwrap();
+        assert_eq!(meta.schema_version, 99);
+        assert_eq!(meta.turn_count, 4);
+
+        assert!(SessionMeta::parse("not json").is_err());
+    }
+
+    #[test]
+    fn test_summary_style_and_code() {
+        let turn = |user: &str, assistant: &str| Turn {
+            user_text: user.to_string(),
+            assistant_text: assistant.to_string(),
+            tool_interactions: Vec::new(),
+            timestamp: None,
+        };
+        let conv = Conversation {
+            session_id: "s1".to_string(),
+            project: "demo".to_string(),
+            turns: vec![
+                turn("How do I retry?", "Use backoff:\n```rust\nretry(3)\n```"),
+                turn("\nAnd jitter?", "Add randomness."),
+            ],
+            start_time: None,
+            end_time: None,
+            model: None,
+            total_input_tokens: 0,
+            total_output_tokens: 0,
+        };
+
+        let brief = render_summary(&conv, &RenderOptions::default());
+        assert!(brief.contains("**Initial request:** How do I retry?"));
+        assert!(!brief.contains("**Turns:**\n\n"));
+        assert!(!brief.contains("retry(3)"));
+
+        let detailed = render_summary(
+            &conv,
+            &RenderOptions {
+                summary_style: SummaryStyle::Detailed,
+                summary_code: true,
+                ..RenderOptions::default()
+            },
+        );
+        assert!(detailed.contains("1. How do I retry? → Use backoff:"));
+        assert!(detailed.contains("2. And jitter? → Add randomness."));
+        assert!(detailed.contains("**Code snippets:**\n\n```rust\nretry(3)\n```"));
+    }
+}
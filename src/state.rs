@@ -4,11 +4,25 @@ use std::path::{Path, PathBuf};
 use sha2::{Digest, Sha256};
 
 use crate::error::Result;
+use crate::renderer::markdown::SessionMeta;
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Default)]
 pub struct Manifest {
     /// Map of file path -> SHA-256 hash of the file at time of processing
     pub processed: HashMap<String, String>,
+    /// Sessions skipped because the same content was already processed under
+    /// another project: file path -> the session it duplicates
+    #[serde(default)]
+    pub duplicates: HashMap<String, DuplicateSession>,
+}
+
+/// A session file whose content matches one processed under another project
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DuplicateSession {
+    /// SHA-256 hash of the file when the duplicate was recorded
+    pub hash: String,
+    /// Path of the session that was processed instead
+    pub original: String,
 }
 
 impl Manifest {
@@ -33,18 +47,19 @@ impl Manifest {
         Ok(())
     }
 
-    /// Check if a file has already been processed (and hasn't changed)
+    /// Check if a file has already been processed and its content hash still
+    /// matches, so sessions appended to since the last ingest are picked up again.
+    /// Recorded duplicates count as processed until their content changes.
     pub fn is_processed(&self, path: &Path) -> bool {
         let key = path.to_string_lossy().to_string();
-        match self.processed.get(&key) {
-            Some(stored_hash) => {
-                // Quick check: compare file size/hash
-                match hash_file(path) {
-                    Ok(current_hash) => &current_hash == stored_hash,
-                    Err(_) => false,
-                }
-            }
-            None => false,
+        let stored_hash = match (self.processed.get(&key), self.duplicates.get(&key)) {
+            (Some(hash), _) => hash,
+            (None, Some(duplicate)) => &duplicate.hash,
+            (None, None) => return false,
+        };
+        match hash_file(path) {
+            Ok(current_hash) => &current_hash == stored_hash,
+            Err(_) => false,
         }
     }
 
@@ -52,10 +67,32 @@ impl Manifest {
     pub fn mark_processed(&mut self, path: &Path) -> Result<()> {
         let key = path.to_string_lossy().to_string();
         let hash = hash_file(path)?;
+        self.duplicates.remove(&key);
         self.processed.insert(key, hash);
         Ok(())
     }
 
+    /// Record that `path` duplicates the already-processed session `original`
+    pub fn mark_duplicate(&mut self, path: &Path, hash: String, original: &str) {
+        let key = path.to_string_lossy().to_string();
+        self.processed.remove(&key);
+        self.duplicates.insert(
+            key,
+            DuplicateSession {
+                hash,
+                original: original.to_string(),
+            },
+        );
+    }
+
+    /// Map of content hash -> path for every processed session
+    pub fn paths_by_hash(&self) -> HashMap<String, String> {
+        self.processed
+            .iter()
+            .map(|(path, hash)| (hash.clone(), path.clone()))
+            .collect()
+    }
+
     /// Number of processed sessions
     pub fn processed_count(&self) -> usize {
         self.processed.len()
@@ -67,7 +104,7 @@ impl Manifest {
 }
 
 /// Compute SHA-256 hash of a file
-fn hash_file(path: &Path) -> Result<String> {
+pub fn hash_file(path: &Path) -> Result<String> {
     use std::io::Read;
 
     let mut file = std::fs::File::open(path)?;
@@ -84,3 +121,271 @@ fn hash_file(path: &Path) -> Result<String> {
 
     Ok(format!("{:x}", hasher.finalize()))
 }
+
+/// One archived session in the conversation index
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ConversationEntry {
+    pub project: String,
+    pub session_id: String,
+    pub turn_count: usize,
+    /// Session start time from meta.json
+    pub timestamp: Option<String>,
+    /// Total bytes of the files in the session directory
+    pub size: u64,
+    /// Newest modification time in the session directory
+    pub modified: chrono::DateTime<chrono::Utc>,
+    /// File names in the session directory (conversation.md, meta.json, ...)
+    pub files: Vec<String>,
+}
+
+/// Lightweight index of `conversations/<project>/<session>/`, so status and
+/// the TUI can list archives without walking every session directory.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct ConversationIndex {
+    pub sessions: Vec<ConversationEntry>,
+}
+
+impl ConversationIndex {
+    /// Load the index, or `None` if it hasn't been built yet (or is unreadable)
+    pub fn load(memory_dir: &Path) -> Option<Self> {
+        let content = std::fs::read_to_string(Self::index_path(memory_dir)).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    /// Load the index, building and saving it first if missing
+    pub fn load_or_rebuild(memory_dir: &Path) -> Result<Self> {
+        if let Some(index) = Self::load(memory_dir) {
+            return Ok(index);
+        }
+        let index = Self::rebuild(memory_dir)?;
+        if memory_dir.join("conversations").is_dir() {
+            index.save(memory_dir)?;
+        }
+        Ok(index)
+    }
+
+    /// Save index to disk
+    pub fn save(&self, memory_dir: &Path) -> Result<()> {
+        let path = Self::index_path(memory_dir);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(&path, content)?;
+        Ok(())
+    }
+
+    /// Scan every session directory on disk
+    pub fn rebuild(memory_dir: &Path) -> Result<Self> {
+        let mut index = Self::default();
+        for (project, session_id) in session_dirs(memory_dir) {
+            index.upsert(memory_dir, &project, &session_id)?;
+        }
+        Ok(index)
+    }
+
+    /// Add or refresh one session from its directory and meta.json
+    pub fn upsert(&mut self, memory_dir: &Path, project: &str, session_id: &str) -> Result<()> {
+        let dir = memory_dir
+            .join("conversations")
+            .join(project)
+            .join(session_id);
+
+        let mut size = 0;
+        let mut modified = chrono::DateTime::UNIX_EPOCH;
+        let mut files = Vec::new();
+        for entry in std::fs::read_dir(&dir)?.flatten() {
+            let Ok(meta) = entry.metadata() else { continue };
+            if !meta.is_file() {
+                continue;
+            }
+            size += meta.len();
+            if let Ok(mtime) = meta.modified() {
+                modified = modified.max(mtime.into());
+            }
+            files.push(entry.file_name().to_string_lossy().to_string());
+        }
+        files.sort();
+
+        let meta = SessionMeta::load(&dir.join("meta.json")).unwrap_or_default();
+
+        let entry = ConversationEntry {
+            project: project.to_string(),
+            session_id: session_id.to_string(),
+            turn_count: meta.turn_count,
+            timestamp: meta.start_time,
+            size,
+            modified,
+            files,
+        };
+
+        self.remove(project, Some(session_id));
+        self.sessions.push(entry);
+        self.sessions
+            .sort_by(|a, b| (&a.project, &a.session_id).cmp(&(&b.project, &b.session_id)));
+        Ok(())
+    }
+
+    /// Drop one session, or every session of `project` when `session_id` is None
+    pub fn remove(&mut self, project: &str, session_id: Option<&str>) {
+        self.sessions
+            .retain(|e| e.project != project || session_id.is_some_and(|sid| e.session_id != sid));
+    }
+
+    /// Whether the index disagrees with the session directories on disk
+    pub fn is_stale(&self, memory_dir: &Path) -> bool {
+        let mut on_disk = session_dirs(memory_dir);
+        on_disk.sort();
+        let indexed: Vec<(String, String)> = self
+            .sessions
+            .iter()
+            .map(|e| (e.project.clone(), e.session_id.clone()))
+            .collect();
+        on_disk != indexed
+    }
+
+    /// Number of distinct projects with archived sessions
+    pub fn project_count(&self) -> usize {
+        self.sessions
+            .iter()
+            .map(|e| e.project.as_str())
+            .collect::<std::collections::HashSet<_>>()
+            .len()
+    }
+
+    /// Remove sessions from the saved index (no-op if it doesn't exist yet)
+    pub fn forget(memory_dir: &Path, project: &str, session_id: Option<&str>) -> Result<()> {
+        if let Some(mut index) = Self::load(memory_dir) {
+            index.remove(project, session_id);
+            index.save(memory_dir)?;
+        }
+        Ok(())
+    }
+
+    fn index_path(memory_dir: &Path) -> PathBuf {
+        memory_dir.join("conversations").join("index.json")
+    }
+}
+
+/// (project, session_id) for every session directory under conversations/
+fn session_dirs(memory_dir: &Path) -> Vec<(String, String)> {
+    let mut dirs = Vec::new();
+    let Ok(projects) = std::fs::read_dir(memory_dir.join("conversations")) else {
+        return dirs;
+    };
+    for project in projects.flatten() {
+        if !project.file_type().is_ok_and(|t| t.is_dir()) {
+            continue;
+        }
+        let project_name = project.file_name().to_string_lossy().to_string();
+        for session in std::fs::read_dir(project.path())
+            .into_iter()
+            .flatten()
+            .flatten()
+        {
+            if session.file_type().is_ok_and(|t| t.is_dir()) {
+                dirs.push((
+                    project_name.clone(),
+                    session.file_name().to_string_lossy().to_string(),
+                ));
+            }
+        }
+    }
+    dirs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_session(memory_dir: &Path, project: &str, session_id: &str, turns: usize) {
+        let dir = memory_dir
+            .join("conversations")
+            .join(project)
+            .join(session_id);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("conversation.md"), "# Session\n").unwrap();
+        std::fs::write(
+            dir.join("meta.json"),
+            serde_json::json!({"turn_count": turns, "start_time": "2024-05-01T10:00:00Z"})
+                .to_string(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_manifest_reprocesses_appended_session() {
+        let temp = TempDir::new().unwrap();
+        let session = temp.path().join("s1.jsonl");
+        std::fs::write(&session, "{\"turn\": 1}\n").unwrap();
+
+        let mut manifest = Manifest::default();
+        assert!(!manifest.is_processed(&session));
+        manifest.mark_processed(&session).unwrap();
+        assert!(manifest.is_processed(&session));
+
+        // Appending a turn changes the content hash, so the session is picked up again
+        let mut file = std::fs::OpenOptions::new()
+            .append(true)
+            .open(&session)
+            .unwrap();
+        std::io::Write::write_all(&mut file, b"{\"turn\": 2}\n").unwrap();
+        drop(file);
+        assert!(!manifest.is_processed(&session));
+
+        manifest.mark_processed(&session).unwrap();
+        assert!(manifest.is_processed(&session));
+    }
+
+    #[test]
+    fn test_manifest_duplicates_count_as_processed() {
+        let temp = TempDir::new().unwrap();
+        let original = temp.path().join("api/s1.jsonl");
+        let copy = temp.path().join("api-old/s1.jsonl");
+        for path in [&original, &copy] {
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, "{}\n").unwrap();
+        }
+
+        let mut manifest = Manifest::default();
+        manifest.mark_processed(&original).unwrap();
+        let hash = hash_file(&copy).unwrap();
+        let by_hash = manifest.paths_by_hash();
+        assert_eq!(by_hash[&hash], original.to_string_lossy());
+
+        let original = by_hash[&hash].clone();
+        manifest.mark_duplicate(&copy, hash, &original);
+        assert!(manifest.is_processed(&copy));
+        assert_eq!(manifest.processed_count(), 1);
+
+        // A changed duplicate is picked up again
+        std::fs::write(&copy, "{\"changed\": true}\n").unwrap();
+        assert!(!manifest.is_processed(&copy));
+    }
+
+    #[test]
+    fn test_conversation_index_rebuild_and_staleness() {
+        let temp = TempDir::new().unwrap();
+        let memory_dir = temp.path();
+        write_session(memory_dir, "api", "s1", 3);
+        write_session(memory_dir, "web", "s2", 5);
+
+        let index = ConversationIndex::load_or_rebuild(memory_dir).unwrap();
+        assert_eq!(index.sessions.len(), 2);
+        assert_eq!(index.project_count(), 2);
+        assert_eq!(index.sessions[0].turn_count, 3);
+        assert_eq!(index.sessions[0].files, ["conversation.md", "meta.json"]);
+        assert!(index.sessions[0].size > 0);
+        assert!(!index.is_stale(memory_dir));
+        assert!(ConversationIndex::load(memory_dir).is_some());
+
+        write_session(memory_dir, "api", "s3", 1);
+        assert!(index.is_stale(memory_dir));
+
+        ConversationIndex::forget(memory_dir, "web", None).unwrap();
+        let loaded = ConversationIndex::load(memory_dir).unwrap();
+        assert_eq!(loaded.sessions.len(), 1);
+        assert_eq!(loaded.project_count(), 1);
+    }
+}
@@ -286,6 +286,38 @@ pub fn read_knowledge_files(
     memory_dir: &std::path::Path,
     project: &str,
 ) -> Result<HashMap<String, String>> {
+    let (mut files, total_secrets_redacted) = redacted_knowledge_files(memory_dir, project)?;
+
+    // Warn if secrets were found
+    if total_secrets_redacted > 0 {
+        eprintln!(
+            "{} Redacted {} secrets before upload",
+            "⚠️  Security:".yellow(),
+            total_secrets_redacted
+        );
+    }
+
+    // Add metadata
+    let metadata = serde_json::json!({
+        "project": project,
+        "synced_at": chrono::Utc::now().to_rfc3339(),
+        "tool": "engram",
+        "version": env!("CARGO_PKG_VERSION"),
+    });
+    files.insert(
+        "metadata.json".to_string(),
+        serde_json::to_string_pretty(&metadata)?,
+    );
+
+    Ok(files)
+}
+
+/// The synced knowledge files exactly as a push would upload them, plus the
+/// number of secrets redacted.
+fn redacted_knowledge_files(
+    memory_dir: &std::path::Path,
+    project: &str,
+) -> Result<(HashMap<String, String>, usize)> {
     use crate::hive::SecretDetector;
 
     let knowledge_dir = memory_dir.join("knowledge").join(project);
@@ -313,30 +345,105 @@ pub fn read_knowledge_files(
         }
     }
 
-    // Warn if secrets were found
-    if total_secrets_redacted > 0 {
-        eprintln!(
-            "{} Redacted {} secrets before upload",
-            "⚠️  Security:".yellow(),
-            total_secrets_redacted
-        );
-    }
+    Ok((files, total_secrets_redacted))
+}
 
-    // Add metadata
-    let metadata = serde_json::json!({
-        "project": project,
-        "synced_at": chrono::Utc::now().to_rfc3339(),
-        "tool": "engram",
-        "version": env!("CARGO_PKG_VERSION"),
-    });
-    files.insert(
-        "metadata.json".to_string(),
-        serde_json::to_string_pretty(&metadata)?,
-    );
+// ── Sync Status ────────────────────────────────────────────────────────
+
+/// Session-level comparison of one knowledge file between local and remote.
+#[derive(Debug, Default, PartialEq)]
+pub struct FileSyncStatus {
+    pub file: String,
+    pub local_only: usize,
+    pub remote_only: usize,
+    pub diverged: usize,
+    pub in_sync: usize,
+}
 
+/// Local knowledge as it would be pushed, for comparison with a remote copy.
+pub fn local_sync_files(
+    memory_dir: &std::path::Path,
+    project: &str,
+) -> Result<HashMap<String, String>> {
+    Ok(redacted_knowledge_files(memory_dir, project)?.0)
+}
+
+/// Knowledge files stored for `project` in a git sync repository's working tree.
+pub fn read_repo_knowledge_files(
+    repo_path: &std::path::Path,
+    project: &str,
+) -> Result<HashMap<String, String>> {
+    let project_dir = repo_path.join(project);
+    let mut files = HashMap::new();
+    if !project_dir.exists() {
+        return Ok(files);
+    }
+    for entry in std::fs::read_dir(&project_dir)? {
+        let path = entry?.path();
+        if path.is_file() && path.extension().and_then(|e| e.to_str()) == Some("md") {
+            let name = path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default();
+            files.insert(name, std::fs::read_to_string(&path)?);
+        }
+    }
     Ok(files)
 }
 
+/// Compare session blocks file by file. Files without session blocks on
+/// either side (e.g. the generated context.md) are skipped.
+pub fn compare_knowledge(
+    local: &HashMap<String, String>,
+    remote: &HashMap<String, String>,
+) -> Vec<FileSyncStatus> {
+    use crate::extractor::knowledge::parse_session_blocks;
+
+    let sessions = |content: Option<&String>| -> HashMap<String, String> {
+        content
+            .map(|c| parse_session_blocks(c).1)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|b| (b.session_id, b.content.trim().to_string()))
+            .collect()
+    };
+
+    let mut names: Vec<&String> = local
+        .keys()
+        .chain(remote.keys())
+        .filter(|n| n.ends_with(".md"))
+        .collect();
+    names.sort();
+    names.dedup();
+
+    let mut statuses = Vec::new();
+    for name in names {
+        let local_sessions = sessions(local.get(name));
+        let remote_sessions = sessions(remote.get(name));
+        if local_sessions.is_empty() && remote_sessions.is_empty() {
+            continue;
+        }
+
+        let mut status = FileSyncStatus {
+            file: name.clone(),
+            ..Default::default()
+        };
+        for (id, content) in &local_sessions {
+            match remote_sessions.get(id) {
+                None => status.local_only += 1,
+                Some(other) if other != content => status.diverged += 1,
+                Some(_) => status.in_sync += 1,
+            }
+        }
+        status.remote_only = remote_sessions
+            .keys()
+            .filter(|id| !local_sessions.contains_key(*id))
+            .count();
+        statuses.push(status);
+    }
+    statuses
+}
+
 // ── Git Repository Operations ──────────────────────────────────────────
 
 /// Initialize a git repository for knowledge sharing
@@ -383,14 +490,35 @@ pub fn init_git_repo(repo_path: &std::path::Path) -> Result<()> {
     Ok(())
 }
 
-/// Push knowledge to a git repository
+/// Run git in `repo_path`, returning stdout or a `MemoryError` carrying git's stderr.
+fn run_git(repo_path: &std::path::Path, args: &[&str]) -> Result<String> {
+    let output = std::process::Command::new("git")
+        .args(args)
+        .current_dir(repo_path)
+        .output()?;
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    } else {
+        Err(MemoryError::Config(format!(
+            "git {} failed: {}",
+            args.first().copied().unwrap_or(""),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )))
+    }
+}
+
+/// Push knowledge to a git repository.
+///
+/// Only the project's subtree is staged and committed, and the repo must be on
+/// a branch with no other uncommitted changes to tracked files. Returns `false`
+/// when there was nothing new to commit.
 pub fn push_to_git_repo(
     memory_dir: &std::path::Path,
     project: &str,
     repo_path: &std::path::Path,
     commit_message: Option<&str>,
     push_remote: bool,
-) -> Result<()> {
+) -> Result<bool> {
     if !repo_path.join(".git").exists() {
         return Err(MemoryError::Config(format!(
             "Not a git repository: {}. Run 'engram sync init-repo' first.",
@@ -398,61 +526,175 @@ pub fn push_to_git_repo(
         )));
     }
 
+    if run_git(repo_path, &["symbolic-ref", "-q", "HEAD"]).is_err() {
+        return Err(MemoryError::Config(format!(
+            "{} is in detached HEAD state. Check out a branch before pushing.",
+            repo_path.display()
+        )));
+    }
+
+    let project_prefix = format!("{}/", project);
+    let dirty: Vec<String> = run_git(
+        repo_path,
+        &["status", "--porcelain", "--untracked-files=no"],
+    )?
+    .lines()
+    .filter_map(|l| l.get(3..))
+    .filter(|path| !path.starts_with(&project_prefix))
+    .map(|path| path.to_string())
+    .collect();
+    if !dirty.is_empty() {
+        return Err(MemoryError::Config(format!(
+            "{} has uncommitted changes outside '{}': {}. Commit or stash them first.",
+            repo_path.display(),
+            project,
+            dirty.join(", ")
+        )));
+    }
+
     // Create project directory in repo
     let project_dir = repo_path.join(project);
     std::fs::create_dir_all(&project_dir)?;
 
     // Read knowledge files
     let files = read_knowledge_files(memory_dir, project)?;
+    let session_count: usize = files
+        .values()
+        .map(|content| {
+            crate::extractor::knowledge::parse_session_blocks(content)
+                .1
+                .len()
+        })
+        .sum();
+
+    // Write knowledge files first; metadata.json only carries a timestamp and is
+    // written once we know there is something to commit
+    for (filename, content) in files.iter().filter(|(name, _)| *name != "metadata.json") {
+        std::fs::write(project_dir.join(filename), content)?;
+    }
 
-    // Write files to repo
-    for (filename, content) in &files {
-        let target = project_dir.join(filename);
-        std::fs::write(target, content)?;
+    run_git(repo_path, &["add", "--", project])?;
+    let has_changes = run_git(repo_path, &["diff", "--cached", "--quiet", "--", project]).is_err();
+    if !has_changes {
+        return Ok(false);
     }
 
-    // Git add
-    std::process::Command::new("git")
-        .args(["add", project])
-        .current_dir(repo_path)
-        .status()?;
+    if let Some(metadata) = files.get("metadata.json") {
+        std::fs::write(project_dir.join("metadata.json"), metadata)?;
+        run_git(repo_path, &["add", "--", project])?;
+    }
 
-    // Git commit
-    let default_message = format!("Update {} knowledge", project);
+    let default_message = format!("Update {} knowledge ({} sessions)", project, session_count);
     let message = commit_message.unwrap_or(&default_message);
-    let status = std::process::Command::new("git")
-        .args(["commit", "-m", message])
-        .current_dir(repo_path)
-        .status()?;
+    if let Err(e) = run_git(repo_path, &["commit", "-m", message, "--", project]) {
+        // Leave the index as we found it
+        let _ = run_git(repo_path, &["reset", "-q", "--", project]);
+        return Err(e);
+    }
 
-    // Commit might fail if no changes, that's OK
-    let has_changes = status.success();
+    if push_remote {
+        // Explain the common failures; anything else (e.g. git missing) propagates as is
+        run_git(repo_path, &["push"]).map_err(|e| match e {
+            MemoryError::Config(msg)
+                if msg.contains("rejected") || msg.contains("non-fast-forward") =>
+            {
+                MemoryError::Config(format!(
+                    "Push rejected: the remote has commits you don't have. Run 'engram sync pull-repo {} {} --fetch-remote', then push again.",
+                    project,
+                    repo_path.display()
+                ))
+            }
+            MemoryError::Config(msg) => MemoryError::Config(format!(
+                "Committed locally, but push failed. Check git remote configuration. ({})",
+                msg
+            )),
+            other => other,
+        })?;
+    }
 
-    // Git push if requested and there are changes
-    if push_remote && has_changes {
-        let status = std::process::Command::new("git")
-            .args(["push"])
-            .current_dir(repo_path)
-            .status()?;
+    Ok(true)
+}
+
+/// How `pull_from_git_repo` treats a local file that differs from the repo copy.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PullMode {
+    /// Import only when no local session would be lost
+    Safe,
+    /// Union session blocks, keeping the local version of diverged sessions
+    Merge,
+    /// Overwrite local files with the repo copy
+    Force,
+}
 
-        if !status.success() {
-            return Err(MemoryError::Config(
-                "Failed to push to remote. Check git remote configuration.".into(),
-            ));
+/// What happened to one file during a repo pull.
+#[derive(Debug, PartialEq)]
+pub enum PullAction {
+    Unchanged,
+    Copied,
+    Merged {
+        added: usize,
+        conflicts: usize,
+    },
+    /// Left untouched because importing would drop local sessions
+    Skipped {
+        local_only: usize,
+        diverged: usize,
+    },
+}
+
+/// Union of two session-block files: local blocks in order, followed by remote
+/// blocks missing locally. Sessions present on both sides with different
+/// content keep the local version and count as conflicts.
+/// Returns `(merged, added, conflicts)`.
+pub fn merge_session_blocks(local: &str, remote: &str) -> (String, usize, usize) {
+    use crate::extractor::knowledge::parse_session_blocks;
+
+    let (preamble, local_blocks) = parse_session_blocks(local);
+    let (_, remote_blocks) = parse_session_blocks(remote);
+
+    let mut merged = crate::extractor::knowledge::reconstruct_blocks(&preamble, &local_blocks);
+    let mut added = 0;
+    let mut conflicts = 0;
+
+    for block in &remote_blocks {
+        match local_blocks
+            .iter()
+            .find(|b| b.session_id == block.session_id)
+        {
+            Some(existing) => {
+                if existing.content.trim() != block.content.trim() {
+                    conflicts += 1;
+                }
+            }
+            None => {
+                if !merged.ends_with('\n') {
+                    merged.push('\n');
+                }
+                if !merged.ends_with("\n\n") {
+                    merged.push('\n');
+                }
+                merged.push_str(&block.header);
+                merged.push_str(&block.content);
+                added += 1;
+            }
         }
     }
 
-    Ok(())
+    (merged, added, conflicts)
 }
 
-/// Pull knowledge from a git repository
+/// Pull knowledge from a git repository.
+///
+/// Local files that differ from the repo copy are handled according to `mode`;
+/// the per-file outcome is returned so callers can report conflicts.
 pub fn pull_from_git_repo(
     memory_dir: &std::path::Path,
     project: &str,
     repo_path: &std::path::Path,
     fetch_remote: bool,
     branch: &str,
-) -> Result<()> {
+    mode: PullMode,
+) -> Result<Vec<(String, PullAction)>> {
     if !repo_path.join(".git").exists() {
         return Err(MemoryError::Config(format!(
             "Not a git repository: {}",
@@ -490,19 +732,66 @@ pub fn pull_from_git_repo(
     let knowledge_dir = memory_dir.join("knowledge").join(project);
     std::fs::create_dir_all(&knowledge_dir)?;
 
-    // Copy knowledge files
-    for entry in std::fs::read_dir(&project_dir)? {
-        let entry = entry?;
-        let filename = entry.file_name();
-        let source = entry.path();
+    let mut report = Vec::new();
+    let mut entries: Vec<_> = std::fs::read_dir(&project_dir)?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.is_file())
+        .collect();
+    entries.sort();
+
+    for source in entries {
+        let filename = source
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
         let target = knowledge_dir.join(&filename);
+        let incoming = std::fs::read_to_string(&source)?;
 
-        if source.is_file() {
-            std::fs::copy(&source, &target)?;
+        let Ok(local) = std::fs::read_to_string(&target) else {
+            std::fs::write(&target, &incoming)?;
+            report.push((filename, PullAction::Copied));
+            continue;
+        };
+
+        if local == incoming {
+            report.push((filename, PullAction::Unchanged));
+            continue;
         }
+
+        let local_map: HashMap<String, String> = HashMap::from([(filename.clone(), local.clone())]);
+        let remote_map: HashMap<String, String> =
+            HashMap::from([(filename.clone(), incoming.clone())]);
+        let diff = compare_knowledge(&local_map, &remote_map);
+        let (local_only, diverged) = diff
+            .first()
+            .map(|d| (d.local_only, d.diverged))
+            .unwrap_or((0, 0));
+
+        let action = match mode {
+            PullMode::Force => {
+                std::fs::write(&target, &incoming)?;
+                PullAction::Copied
+            }
+            // Nothing local would be lost (or the file has no sessions, e.g. context.md)
+            _ if local_only == 0 && diverged == 0 => {
+                std::fs::write(&target, &incoming)?;
+                PullAction::Copied
+            }
+            PullMode::Merge => {
+                let (merged, added, conflicts) = merge_session_blocks(&local, &incoming);
+                std::fs::write(&target, merged)?;
+                PullAction::Merged { added, conflicts }
+            }
+            PullMode::Safe => PullAction::Skipped {
+                local_only,
+                diverged,
+            },
+        };
+        report.push((filename, action));
     }
 
-    Ok(())
+    Ok(report)
 }
 
 /// Write knowledge files for a project
@@ -527,3 +816,199 @@ pub fn write_knowledge_files(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn files(entries: &[(&str, &str)]) -> HashMap<String, String> {
+        entries
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_compare_knowledge_counts_sessions() {
+        let local = files(&[(
+            "decisions.md",
+            "# Decisions\n\n## Session: a (2024-01-01T00:00:00Z)\nsame\n\n## Session: b (2024-01-02T00:00:00Z)\nlocal edit\n\n## Session: c (2024-01-03T00:00:00Z)\nnew here\n",
+        )]);
+        let remote = files(&[
+            (
+                "decisions.md",
+                "# Decisions\n\n## Session: a (2024-01-01T00:00:00Z)\nsame\n\n## Session: b (2024-01-02T00:00:00Z)\nremote edit\n\n## Session: d (2024-01-04T00:00:00Z)\nnew there\n",
+            ),
+            ("context.md", "# Context\n\nsummary only\n"),
+            ("metadata.json", "{}"),
+        ]);
+
+        let statuses = compare_knowledge(&local, &remote);
+        assert_eq!(
+            statuses,
+            vec![FileSyncStatus {
+                file: "decisions.md".to_string(),
+                local_only: 1,
+                remote_only: 1,
+                diverged: 1,
+                in_sync: 1,
+            }]
+        );
+    }
+
+    fn git(repo: &std::path::Path, args: &[&str]) -> String {
+        run_git(repo, args).unwrap()
+    }
+
+    /// A repo with one commit and a local identity, plus a memory dir with two sessions.
+    fn setup_repo() -> (tempfile::TempDir, std::path::PathBuf, std::path::PathBuf) {
+        let dir = tempfile::TempDir::new().unwrap();
+        let repo = dir.path().join("repo");
+        std::fs::create_dir_all(&repo).unwrap();
+        git(&repo, &["init", "-q", "-b", "main"]);
+        git(&repo, &["config", "user.name", "Test"]);
+        git(&repo, &["config", "user.email", "test@example.com"]);
+        std::fs::write(repo.join("README.md"), "shared\n").unwrap();
+        git(&repo, &["add", "README.md"]);
+        git(&repo, &["commit", "-q", "-m", "init"]);
+
+        let memory = dir.path().join("memory");
+        let knowledge = memory.join("knowledge").join("demo");
+        std::fs::create_dir_all(&knowledge).unwrap();
+        std::fs::write(
+            knowledge.join("decisions.md"),
+            "# Decisions\n\n## Session: a (2024-01-01T00:00:00Z)\nUse sqlx\n\n## Session: b (2024-01-02T00:00:00Z)\nUse axum\n",
+        )
+        .unwrap();
+        (dir, repo, memory)
+    }
+
+    #[test]
+    fn test_push_to_git_repo_commits_only_project_subtree() {
+        let (_dir, repo, memory) = setup_repo();
+        std::fs::write(repo.join("notes.txt"), "untracked\n").unwrap();
+
+        assert!(push_to_git_repo(&memory, "demo", &repo, None, false).unwrap());
+
+        let subject = git(&repo, &["log", "-1", "--format=%s"]);
+        assert_eq!(subject.trim(), "Update demo knowledge (2 sessions)");
+        let changed = git(&repo, &["show", "--name-only", "--format=", "HEAD"]);
+        assert!(changed.lines().all(|l| l.starts_with("demo/")));
+        assert!(changed.contains("demo/decisions.md"));
+        // Unrelated untracked file stays out of the commit
+        assert!(git(&repo, &["status", "--porcelain"]).contains("?? notes.txt"));
+
+        // Nothing new to commit the second time
+        assert!(!push_to_git_repo(&memory, "demo", &repo, None, false).unwrap());
+    }
+
+    #[test]
+    fn test_push_to_git_repo_reports_failed_push() {
+        let (_dir, repo, memory) = setup_repo();
+
+        // No remote configured: the commit lands locally but the push must not
+        // be reported as a success
+        let err = push_to_git_repo(&memory, "demo", &repo, None, true).unwrap_err();
+        assert!(err.to_string().contains("push failed"), "{}", err);
+        let subject = git(&repo, &["log", "-1", "--format=%s"]);
+        assert_eq!(subject.trim(), "Update demo knowledge (2 sessions)");
+    }
+
+    #[test]
+    fn test_push_to_git_repo_rejects_dirty_or_detached_repo() {
+        let (_dir, repo, memory) = setup_repo();
+
+        std::fs::write(repo.join("README.md"), "edited\n").unwrap();
+        let err = push_to_git_repo(&memory, "demo", &repo, None, false).unwrap_err();
+        assert!(err.to_string().contains("uncommitted changes"));
+
+        git(&repo, &["checkout", "-q", "README.md"]);
+        git(&repo, &["checkout", "-q", "--detach"]);
+        let err = push_to_git_repo(&memory, "demo", &repo, None, false).unwrap_err();
+        assert!(err.to_string().contains("detached HEAD"));
+    }
+
+    fn pull_setup(
+        local: &str,
+        remote: &str,
+    ) -> (tempfile::TempDir, std::path::PathBuf, std::path::PathBuf) {
+        let dir = tempfile::TempDir::new().unwrap();
+        let repo = dir.path().join("repo");
+        std::fs::create_dir_all(repo.join(".git")).unwrap();
+        std::fs::create_dir_all(repo.join("demo")).unwrap();
+        std::fs::write(repo.join("demo").join("decisions.md"), remote).unwrap();
+
+        let memory = dir.path().join("memory");
+        let knowledge = memory.join("knowledge").join("demo");
+        std::fs::create_dir_all(&knowledge).unwrap();
+        std::fs::write(knowledge.join("decisions.md"), local).unwrap();
+        (dir, repo, memory)
+    }
+
+    const LOCAL: &str = "# Decisions\n\n## Session: a (2024-01-01T00:00:00Z)\nlocal a\n\n## Session: b (2024-01-02T00:00:00Z)\nonly local\n";
+    const REMOTE: &str = "# Decisions\n\n## Session: a (2024-01-01T00:00:00Z)\nremote a\n\n## Session: c (2024-01-03T00:00:00Z)\nonly remote\n";
+
+    #[test]
+    fn test_pull_from_git_repo_safe_mode_keeps_local_edits() {
+        let (_dir, repo, memory) = pull_setup(LOCAL, REMOTE);
+        let report =
+            pull_from_git_repo(&memory, "demo", &repo, false, "main", PullMode::Safe).unwrap();
+        assert_eq!(
+            report,
+            vec![(
+                "decisions.md".to_string(),
+                PullAction::Skipped {
+                    local_only: 1,
+                    diverged: 1
+                }
+            )]
+        );
+        let local = std::fs::read_to_string(memory.join("knowledge/demo/decisions.md")).unwrap();
+        assert_eq!(local, LOCAL);
+    }
+
+    #[test]
+    fn test_pull_from_git_repo_merge_unions_sessions() {
+        let (_dir, repo, memory) = pull_setup(LOCAL, REMOTE);
+        let report =
+            pull_from_git_repo(&memory, "demo", &repo, false, "main", PullMode::Merge).unwrap();
+        assert_eq!(
+            report[0].1,
+            PullAction::Merged {
+                added: 1,
+                conflicts: 1
+            }
+        );
+
+        let merged = std::fs::read_to_string(memory.join("knowledge/demo/decisions.md")).unwrap();
+        let (_, blocks) = crate::extractor::knowledge::parse_session_blocks(&merged);
+        let ids: Vec<&str> = blocks.iter().map(|b| b.session_id.as_str()).collect();
+        assert_eq!(ids, ["a", "b", "c"]);
+        assert!(merged.contains("local a"));
+        assert!(!merged.contains("remote a"));
+    }
+
+    #[test]
+    fn test_pull_from_git_repo_copies_when_remote_is_ahead() {
+        let local = "# Decisions\n\n## Session: a (2024-01-01T00:00:00Z)\nsame\n";
+        let remote = "# Decisions\n\n## Session: a (2024-01-01T00:00:00Z)\nsame\n\n## Session: c (2024-01-03T00:00:00Z)\nnew\n";
+        let (_dir, repo, memory) = pull_setup(local, remote);
+        let report =
+            pull_from_git_repo(&memory, "demo", &repo, false, "main", PullMode::Safe).unwrap();
+        assert_eq!(report[0].1, PullAction::Copied);
+        let pulled = std::fs::read_to_string(memory.join("knowledge/demo/decisions.md")).unwrap();
+        assert_eq!(pulled, remote);
+    }
+
+    #[test]
+    fn test_compare_knowledge_missing_remote_file() {
+        let local = files(&[(
+            "patterns.md",
+            "# Patterns\n\n## Session: p1 (2024-01-01T00:00:00Z)\nuse builders\n",
+        )]);
+        let statuses = compare_knowledge(&local, &HashMap::new());
+        assert_eq!(statuses.len(), 1);
+        assert_eq!(statuses[0].local_only, 1);
+        assert_eq!(statuses[0].remote_only, 0);
+    }
+}
@@ -0,0 +1,456 @@
+//! Trash for knowledge removed by destructive commands.
+//!
+//! `forget` and `consolidate --auto-merge` copy every session block they remove
+//! into `memory/trash/<project>/<id>.md` before rewriting the knowledge files:
+//! a [`TrashBatch`] queues the rewrites and only applies them once the trash
+//! entry is safely on disk.
+//! Each trash file starts with a small front matter (project, time, reason)
+//! followed by the removed blocks, grouped under `<!-- file: <path> -->` markers
+//! naming the knowledge file (relative to `memory/knowledge`) they came from.
+//! `engram restore` puts them back; entries older than the retention window
+//! (`trash_retention_days` in config.json, default 30) are purged automatically.
+
+use std::path::{Path, PathBuf};
+
+use crate::error::{MemoryError, Result};
+use crate::extractor::knowledge::{parse_session_blocks, remove_session_blocks, SessionBlock};
+
+/// Days trash entries are kept when config.json doesn't say otherwise
+pub const DEFAULT_RETENTION_DAYS: u32 = 30;
+
+const FILE_MARKER_PREFIX: &str = "<!-- file: ";
+const FILE_MARKER_SUFFIX: &str = " -->";
+
+/// Directory holding trash entries for `project`
+pub fn trash_dir(memory_dir: &Path, project: &str) -> PathBuf {
+    memory_dir.join("trash").join(project)
+}
+
+/// Retention window from config.json, falling back to [`DEFAULT_RETENTION_DAYS`]
+pub fn retention() -> chrono::Duration {
+    let days = crate::config::ConfigFile::load()
+        .ok()
+        .and_then(|c| c.trash_retention_days)
+        .unwrap_or(DEFAULT_RETENTION_DAYS);
+    chrono::Duration::days(days as i64)
+}
+
+/// Blocks removed by one command, written to a single trash entry on [`commit`](Self::commit).
+pub struct TrashBatch {
+    memory_dir: PathBuf,
+    project: String,
+    reason: String,
+    removed: Vec<(String, Vec<SessionBlock>)>,
+    /// Knowledge file rewrites applied after the trash entry is written
+    pending: Vec<(PathBuf, String)>,
+}
+
+impl TrashBatch {
+    pub fn new(memory_dir: &Path, project: &str, reason: impl Into<String>) -> Self {
+        Self {
+            memory_dir: memory_dir.to_path_buf(),
+            project: project.to_string(),
+            reason: reason.into(),
+            removed: Vec::new(),
+            pending: Vec::new(),
+        }
+    }
+
+    /// Remove the sessions `ids` from the knowledge file at `path`, keeping a
+    /// copy of the removed blocks. The file is rewritten on
+    /// [`commit`](Self::commit). Returns whether the file will change.
+    pub fn remove_sessions(&mut self, path: &Path, ids: &[&str]) -> Result<bool> {
+        if !path.exists() {
+            return Ok(false);
+        }
+        let content = std::fs::read_to_string(path)?;
+        let Some(cleaned) = remove_session_blocks(&content, ids) else {
+            return Ok(false);
+        };
+        let (_preamble, blocks) = parse_session_blocks(&content);
+        self.record(
+            path,
+            blocks
+                .into_iter()
+                .filter(|b| ids.contains(&b.session_id.as_str()))
+                .collect(),
+        );
+        self.pending.push((path.to_path_buf(), cleaned));
+        Ok(true)
+    }
+
+    /// Replace the knowledge file at `path` with `content` on
+    /// [`commit`](Self::commit), keeping a copy of the `removed` blocks.
+    pub fn replace(&mut self, path: &Path, content: String, removed: Vec<SessionBlock>) {
+        self.record(path, removed);
+        self.pending.push((path.to_path_buf(), content));
+    }
+
+    /// Keep a copy of `blocks` that the caller removes from `path` itself,
+    /// after [`commit`](Self::commit) has succeeded.
+    pub fn record(&mut self, path: &Path, blocks: Vec<SessionBlock>) {
+        if blocks.is_empty() {
+            return;
+        }
+        let knowledge_root = self.memory_dir.join("knowledge");
+        let relative = path
+            .strip_prefix(&knowledge_root)
+            .unwrap_or(path)
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        match self.removed.iter_mut().find(|(p, _)| *p == relative) {
+            Some((_, existing)) => existing.extend(blocks),
+            None => self.removed.push((relative, blocks)),
+        }
+    }
+
+    /// Number of blocks recorded so far
+    pub fn len(&self) -> usize {
+        self.removed.iter().map(|(_, blocks)| blocks.len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Write the trash entry (if anything was removed), then apply the queued
+    /// knowledge file rewrites and purge expired entries. Nothing is rewritten
+    /// if the trash entry can't be written. Returns the entry's ID.
+    pub fn commit(self) -> Result<Option<String>> {
+        let id = self.write_entry()?;
+        for (path, content) in &self.pending {
+            std::fs::write(path, content)?;
+        }
+        if let Err(e) = purge_expired(&self.memory_dir, retention()) {
+            tracing::warn!(error = %e, "failed to purge old trash entries");
+        }
+        Ok(id)
+    }
+
+    fn write_entry(&self) -> Result<Option<String>> {
+        use std::io::Write;
+
+        if self.is_empty() {
+            return Ok(None);
+        }
+
+        let dir = trash_dir(&self.memory_dir, &self.project);
+        std::fs::create_dir_all(&dir)?;
+        let now = chrono::Utc::now();
+        let base = now.format("%Y%m%d-%H%M%S").to_string();
+        let mut id = base.clone();
+        let mut n = 1;
+        while dir.join(format!("{}.md", id)).exists() {
+            n += 1;
+            id = format!("{}-{}", base, n);
+        }
+
+        let mut out = format!(
+            "---\nproject: {}\ndeleted_at: {}\nreason: {}\nentries: {}\n---\n",
+            self.project,
+            now.format("%Y-%m-%dT%H:%M:%SZ"),
+            self.reason.replace('\n', " "),
+            self.len()
+        );
+        for (relative, blocks) in &self.removed {
+            out.push_str(&format!(
+                "\n{}{}{}\n",
+                FILE_MARKER_PREFIX, relative, FILE_MARKER_SUFFIX
+            ));
+            for block in blocks {
+                push_block(&mut out, block);
+            }
+        }
+        // Flushed to disk before any knowledge is rewritten or deleted
+        let mut file = std::fs::File::create(dir.join(format!("{}.md", id)))?;
+        file.write_all(out.as_bytes())?;
+        file.sync_all()?;
+        Ok(Some(id))
+    }
+}
+
+/// A trash entry as listed by `engram restore`
+#[derive(Debug, Clone)]
+pub struct TrashEntry {
+    pub id: String,
+    pub path: PathBuf,
+    pub deleted_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub reason: String,
+    pub entries: usize,
+}
+
+/// Trash entries for `project`, oldest first
+pub fn list(memory_dir: &Path, project: &str) -> Result<Vec<TrashEntry>> {
+    let dir = trash_dir(memory_dir, project);
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut entries = Vec::new();
+    for entry in std::fs::read_dir(&dir)?.flatten() {
+        let path = entry.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("md") {
+            continue;
+        }
+        let content = std::fs::read_to_string(&path)?;
+        let field = |name: &str| {
+            front_matter(&content)
+                .find_map(|(k, v)| (k == name).then(|| v.to_string()))
+                .unwrap_or_default()
+        };
+        entries.push(TrashEntry {
+            id: path
+                .file_stem()
+                .map(|s| s.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+            deleted_at: chrono::DateTime::parse_from_rfc3339(&field("deleted_at"))
+                .ok()
+                .map(|t| t.with_timezone(&chrono::Utc)),
+            reason: field("reason"),
+            entries: field("entries").parse().unwrap_or(0),
+            path,
+        });
+    }
+    entries.sort_by(|a, b| a.id.cmp(&b.id));
+    Ok(entries)
+}
+
+/// Put the blocks of trash entry `id` back into their knowledge files and
+/// delete the entry. Sessions already present in a file are left alone.
+/// Returns the number of blocks restored.
+pub fn restore(memory_dir: &Path, project: &str, id: &str) -> Result<usize> {
+    let path = trash_dir(memory_dir, project).join(format!("{}.md", id));
+    if id.contains(['/', '\\']) || id.contains("..") || !path.exists() {
+        return Err(MemoryError::Config(format!(
+            "No trash entry '{}' for '{}'. Run 'engram restore {}' to list entries.",
+            id, project, project
+        )));
+    }
+    let content = std::fs::read_to_string(&path)?;
+    let knowledge_root = memory_dir.join("knowledge");
+
+    let mut restored = 0;
+    for (relative, section) in file_sections(&content) {
+        if relative
+            .split('/')
+            .any(|part| part == ".." || part.is_empty())
+        {
+            return Err(MemoryError::Config(format!(
+                "Invalid file '{}' in trash entry '{}'",
+                relative, id
+            )));
+        }
+        let target = knowledge_root.join(relative);
+        let (_, blocks) = parse_session_blocks(section);
+        let existing = if target.exists() {
+            std::fs::read_to_string(&target)?
+        } else {
+            format!("# {}\n", title_for(&target))
+        };
+        let (preamble, mut current) = parse_session_blocks(&existing);
+        let before = current.len();
+        for block in blocks {
+            if !current.iter().any(|b| b.session_id == block.session_id) {
+                current.push(block);
+            }
+        }
+        if current.len() == before {
+            continue;
+        }
+        restored += current.len() - before;
+
+        let mut out = preamble;
+        for block in &current {
+            push_block(&mut out, block);
+        }
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&target, out)?;
+    }
+
+    std::fs::remove_file(&path)?;
+    Ok(restored)
+}
+
+/// Delete trash entries older than `max_age` for every project.
+/// Returns the number of entries removed.
+pub fn purge_expired(memory_dir: &Path, max_age: chrono::Duration) -> Result<usize> {
+    let root = memory_dir.join("trash");
+    if !root.exists() {
+        return Ok(0);
+    }
+    let cutoff = chrono::Utc::now() - max_age;
+    let mut purged = 0;
+    for project_dir in std::fs::read_dir(&root)?.flatten() {
+        let Some(project) = project_dir.file_name().to_str().map(str::to_string) else {
+            continue;
+        };
+        for entry in list(memory_dir, &project)? {
+            if entry.deleted_at.is_some_and(|t| t < cutoff) {
+                std::fs::remove_file(&entry.path)?;
+                purged += 1;
+            }
+        }
+    }
+    Ok(purged)
+}
+
+/// Append `block` to `out`, separating it from what came before by a blank line
+fn push_block(out: &mut String, block: &SessionBlock) {
+    let trimmed = out.trim_end_matches('\n').len();
+    out.truncate(trimmed);
+    if !out.is_empty() {
+        out.push_str("\n\n");
+    }
+    out.push_str(&block.header);
+    out.push_str(block.content.trim_end_matches('\n'));
+    out.push('\n');
+}
+
+/// `key: value` pairs from a trash file's front matter
+fn front_matter(content: &str) -> impl Iterator<Item = (&str, &str)> {
+    content
+        .strip_prefix("---\n")
+        .and_then(|rest| rest.split_once("\n---\n"))
+        .map(|(front, _)| front)
+        .unwrap_or("")
+        .lines()
+        .filter_map(|line| line.split_once(": "))
+}
+
+/// The removed blocks of a trash file, split by source file
+fn file_sections(content: &str) -> Vec<(&str, &str)> {
+    // (relative path, marker line start, blocks start)
+    let mut markers: Vec<(&str, usize, usize)> = Vec::new();
+    let mut offset = 0;
+    for line in content.split_inclusive('\n') {
+        if let Some(relative) = line
+            .trim_end()
+            .strip_prefix(FILE_MARKER_PREFIX)
+            .and_then(|r| r.strip_suffix(FILE_MARKER_SUFFIX))
+        {
+            markers.push((relative, offset, offset + line.len()));
+        }
+        offset += line.len();
+    }
+
+    markers
+        .iter()
+        .enumerate()
+        .map(|(i, (relative, _, start))| {
+            let end = markers.get(i + 1).map_or(content.len(), |next| next.1);
+            (*relative, &content[*start..end])
+        })
+        .collect()
+}
+
+/// `# Title` for a knowledge file recreated on restore, e.g. `Decisions`
+fn title_for(path: &Path) -> String {
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let mut chars = stem.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => stem,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const DECISIONS: &str = "# Decisions\n\n## Session: a1 (2024-01-01T00:00:00Z)\n\nUse sqlx\n\n\
+                             ## Session: b2 (2024-01-02T00:00:00Z) [ttl:7d]\n\nTabs over spaces\n";
+
+    fn setup() -> (TempDir, PathBuf) {
+        let dir = TempDir::new().unwrap();
+        let project_dir = dir.path().join("knowledge").join("demo");
+        std::fs::create_dir_all(&project_dir).unwrap();
+        let path = project_dir.join("decisions.md");
+        std::fs::write(&path, DECISIONS).unwrap();
+        (dir, path)
+    }
+
+    #[test]
+    fn test_trash_and_restore_round_trip() {
+        let (dir, path) = setup();
+
+        let mut batch = TrashBatch::new(dir.path(), "demo", "forget a1 b2");
+        assert!(batch.remove_sessions(&path, &["a1", "b2"]).unwrap());
+        assert!(!batch.remove_sessions(&path, &["missing"]).unwrap());
+        let id = batch.commit().unwrap().unwrap();
+        assert!(
+            parse_session_blocks(&std::fs::read_to_string(&path).unwrap())
+                .1
+                .is_empty()
+        );
+
+        let entries = list(dir.path(), "demo").unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].id, id);
+        assert_eq!(entries[0].entries, 2);
+        assert_eq!(entries[0].reason, "forget a1 b2");
+
+        // Restore also recreates files that were deleted outright
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(restore(dir.path(), "demo", &id).unwrap(), 2);
+        let restored = std::fs::read_to_string(&path).unwrap();
+        let (_, blocks) = parse_session_blocks(&restored);
+        assert_eq!(blocks.len(), 2);
+        assert!(restored.starts_with("# Decisions\n"));
+        assert!(restored.contains("## Session: b2 (2024-01-02T00:00:00Z) [ttl:7d]"));
+        assert!(list(dir.path(), "demo").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_failed_commit_leaves_knowledge_untouched() {
+        let (dir, path) = setup();
+        // A file where the trash directory should be makes the entry unwritable
+        std::fs::write(dir.path().join("trash"), "").unwrap();
+
+        let mut batch = TrashBatch::new(dir.path(), "demo", "forget a1");
+        assert!(batch.remove_sessions(&path, &["a1"]).unwrap());
+        let (_, mut blocks) = parse_session_blocks(DECISIONS);
+        batch.replace(&path, "# Decisions\n".into(), blocks.split_off(1));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), DECISIONS);
+
+        assert!(batch.commit().is_err());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), DECISIONS);
+    }
+
+    #[test]
+    fn test_restore_skips_sessions_already_present() {
+        let (dir, path) = setup();
+        let mut batch = TrashBatch::new(dir.path(), "demo", "test");
+        batch.remove_sessions(&path, &["a1"]).unwrap();
+        let id = batch.commit().unwrap().unwrap();
+        std::fs::write(&path, DECISIONS).unwrap();
+
+        assert_eq!(restore(dir.path(), "demo", &id).unwrap(), 0);
+        let (_, blocks) = parse_session_blocks(&std::fs::read_to_string(&path).unwrap());
+        assert_eq!(blocks.len(), 2);
+    }
+
+    #[test]
+    fn test_purge_expired() {
+        let (dir, path) = setup();
+        let mut batch = TrashBatch::new(dir.path(), "demo", "test");
+        batch.remove_sessions(&path, &["a1"]).unwrap();
+        batch.commit().unwrap();
+
+        assert_eq!(
+            purge_expired(dir.path(), chrono::Duration::days(1)).unwrap(),
+            0
+        );
+        assert_eq!(
+            purge_expired(dir.path(), chrono::Duration::seconds(-1)).unwrap(),
+            1
+        );
+        assert!(list(dir.path(), "demo").unwrap().is_empty());
+    }
+}
@@ -0,0 +1,58 @@
+//! Copy text to the system clipboard by piping it to a platform tool.
+//!
+//! Shelling out keeps engram free of native clipboard bindings. In headless
+//! sessions (SSH, containers) no tool is found and the caller gets an error
+//! message to show instead of a panic.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Clipboard commands to try in order, as (program, args).
+fn candidates() -> Vec<(&'static str, &'static [&'static str])> {
+    if cfg!(target_os = "macos") {
+        vec![("pbcopy", &[])]
+    } else if cfg!(target_os = "windows") {
+        vec![("clip", &[])]
+    } else {
+        let mut list: Vec<(&'static str, &'static [&'static str])> = Vec::new();
+        if std::env::var_os("WAYLAND_DISPLAY").is_some() {
+            list.push(("wl-copy", &[]));
+        }
+        if std::env::var_os("DISPLAY").is_some() {
+            list.push(("xclip", &["-selection", "clipboard"]));
+            list.push(("xsel", &["--clipboard", "--input"]));
+        }
+        list
+    }
+}
+
+/// Copy `text` to the clipboard. Returns the tool used, or a user-facing error.
+pub fn copy(text: &str) -> Result<&'static str, String> {
+    let tools = candidates();
+    if tools.is_empty() {
+        return Err("No clipboard available (headless session)".to_string());
+    }
+
+    for (program, args) in tools {
+        let Ok(mut child) = Command::new(program)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+        else {
+            continue;
+        };
+
+        let written = child
+            .stdin
+            .take()
+            .map(|mut stdin| stdin.write_all(text.as_bytes()).is_ok())
+            .unwrap_or(false);
+        if written && child.wait().map(|s| s.success()).unwrap_or(false) {
+            return Ok(program);
+        }
+    }
+
+    Err("No clipboard tool found (install wl-copy, xclip or xsel)".to_string())
+}
@@ -73,37 +73,22 @@ pub fn load_tree(memory_dir: &Path) -> MemoryTree {
     let mut project_map: std::collections::BTreeMap<String, Vec<MemoryItem>> =
         std::collections::BTreeMap::new();
 
-    // Scan conversations/
-    let conv_dir = memory_dir.join("conversations");
-    if conv_dir.is_dir() {
-        if let Ok(entries) = fs::read_dir(&conv_dir) {
-            for entry in entries.flatten() {
-                if !entry.file_type().map(|t| t.is_dir()).unwrap_or(false) {
-                    continue;
-                }
-                let project_name = entry.file_name().to_string_lossy().to_string();
-                let items = project_map.entry(project_name).or_default();
-
-                if let Ok(sessions) = fs::read_dir(entry.path()) {
-                    for session in sessions.flatten() {
-                        if !session.file_type().map(|t| t.is_dir()).unwrap_or(false) {
-                            continue;
-                        }
-                        let session_id = session.file_name().to_string_lossy().to_string();
-                        let session_path = session.path();
-
-                        // Compute total size & newest mtime in this session dir
-                        let (size, date) = dir_stats(&session_path);
-
-                        items.push(MemoryItem::Session {
-                            path: session_path,
-                            session_id,
-                            date,
-                            size,
-                        });
-                    }
-                }
-            }
+    // Archived sessions come from the conversation index
+    if let Ok(index) = crate::state::ConversationIndex::load_or_rebuild(memory_dir) {
+        for session in index.sessions {
+            let path = memory_dir
+                .join("conversations")
+                .join(&session.project)
+                .join(&session.session_id);
+            project_map
+                .entry(session.project)
+                .or_default()
+                .push(MemoryItem::Session {
+                    path,
+                    session_id: session.session_id,
+                    date: session.modified,
+                    size: session.size,
+                });
         }
     }
 
@@ -163,34 +148,21 @@ pub fn load_tree(memory_dir: &Path) -> MemoryTree {
 }
 
 /// Delete a memory entry (file or directory).
-pub fn delete_entry(path: &Path) -> io::Result<()> {
+pub fn delete_entry(memory_dir: &Path, path: &Path) -> io::Result<()> {
     if path.is_dir() {
-        fs::remove_dir_all(path)
+        fs::remove_dir_all(path)?;
     } else {
-        fs::remove_file(path)
+        fs::remove_file(path)?;
     }
-}
 
-/// Compute total size and latest modification time for a directory.
-fn dir_stats(dir: &Path) -> (u64, DateTime<Utc>) {
-    let mut total_size = 0u64;
-    let mut latest: DateTime<Utc> = DateTime::UNIX_EPOCH;
-
-    if let Ok(entries) = fs::read_dir(dir) {
-        for entry in entries.flatten() {
-            if let Ok(meta) = entry.metadata() {
-                total_size += meta.len();
-                if let Ok(modified) = meta.modified() {
-                    let dt: DateTime<Utc> = modified.into();
-                    if dt > latest {
-                        latest = dt;
-                    }
-                }
-            }
+    // Keep the conversation index in sync when a session is removed
+    if let Ok(rel) = path.strip_prefix(memory_dir.join("conversations")) {
+        let parts: Vec<_> = rel.iter().map(|p| p.to_string_lossy()).collect();
+        if let [project, session_id] = parts.as_slice() {
+            let _ = crate::state::ConversationIndex::forget(memory_dir, project, Some(session_id));
         }
     }
-
-    (total_size, latest)
+    Ok(())
 }
 
 /// Installed pack entry for TUI display
@@ -203,6 +175,9 @@ pub struct PackEntry {
     pub categories: Vec<String>,
     pub keywords: Vec<String>,
     pub installed_at: DateTime<Utc>,
+    pub path: PathBuf,
+    /// Project the pack is scoped to (None = global)
+    pub project: Option<String>,
 }
 
 #[derive(Deserialize)]
@@ -217,6 +192,8 @@ struct InstalledPackMetadata {
     version: String,
     installed_at: String,
     path: PathBuf,
+    #[serde(default)]
+    project: Option<String>,
 }
 
 #[derive(Deserialize)]
@@ -266,6 +243,8 @@ pub fn load_packs(memory_dir: &Path) -> Vec<PackEntry> {
                     categories: manifest.categories,
                     keywords: manifest.keywords,
                     installed_at,
+                    path: installed.path,
+                    project: installed.project,
                 });
             }
         }
@@ -278,7 +257,7 @@ pub fn load_packs(memory_dir: &Path) -> Vec<PackEntry> {
 }
 
 /// Render detailed pack information for TUI display
-pub fn render_pack_detail(pack: &PackEntry, memory_dir: &Path) -> String {
+pub fn render_pack_detail(pack: &PackEntry) -> String {
     let mut output = String::new();
 
     // Header
@@ -293,6 +272,12 @@ pub fn render_pack_detail(pack: &PackEntry, memory_dir: &Path) -> String {
         "**Installed:** {}\n",
         pack.installed_at.format("%Y-%m-%d %H:%M:%S")
     ));
+    output.push_str(&format!(
+        "**Scope:** {}\n",
+        pack.project
+            .as_deref()
+            .map_or("global".to_string(), |p| format!("project '{}'", p))
+    ));
     output.push_str(&format!("**Categories:** {}\n", pack.categories.join(", ")));
     if !pack.keywords.is_empty() {
         output.push_str(&format!("**Keywords:** {}\n", pack.keywords.join(", ")));
@@ -300,7 +285,7 @@ pub fn render_pack_detail(pack: &PackEntry, memory_dir: &Path) -> String {
     output.push('\n');
 
     // Load manifest for full details
-    let pack_path = memory_dir.join("packs/installed").join(&pack.name);
+    let pack_path = &pack.path;
     let manifest_path = pack_path.join(".pack/manifest.json");
 
     if let Ok(manifest_content) = fs::read_to_string(&manifest_path) {
@@ -493,6 +478,14 @@ pub fn load_analytics(memory_dir: &Path, project: &str, days: u32) -> String {
             ));
             output.push_str(&format!("Usage Trend: {}\n\n", insights.usage_trend));
 
+            if insights.llm_tokens_used > 0 {
+                output.push_str(&format!("LLM Tokens: {}\n", insights.llm_tokens_used));
+                output.push_str(&crate::analytics::insights::format_llm_trend(
+                    &insights.llm_tokens_by_day,
+                ));
+                output.push('\n');
+            }
+
             if !insights.top_knowledge.is_empty() {
                 output.push_str("Top Knowledge:\n");
                 for item in &insights.top_knowledge {
@@ -864,6 +857,34 @@ pub fn load_daemon_status(memory_dir: &Path) -> String {
     };
 
     output.push_str(&format!("Log:     {}\n", log_file.display()));
+
+    // Heartbeat written by the daemon loop (daemon.status)
+    if let Some(hb) = crate::daemon::load_heartbeat(memory_dir) {
+        output.push_str(&format!("Every:   {} minutes\n", hb.interval));
+        if running {
+            output.push_str(&format!("State:   {}\n", hb.state));
+        }
+        if let Some(last) = hb.last_ingest {
+            let result = hb.last_result.as_deref().unwrap_or("unknown");
+            output.push_str(&format!(
+                "Last:    {} ({})\n",
+                last.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M"),
+                result
+            ));
+        }
+        if let (true, Some(next)) = (running, hb.next_run) {
+            let overdue = crate::daemon::is_overdue(&hb, Utc::now());
+            output.push_str(&format!(
+                "Next:    {}{}\n",
+                next.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M"),
+                if overdue {
+                    "  (overdue — check log)"
+                } else {
+                    ""
+                }
+            ));
+        }
+    }
     output.push('\n');
 
     // Show last 20 lines of log
@@ -886,6 +907,7 @@ pub fn load_daemon_status(memory_dir: &Path) -> String {
         output.push_str("  Press [s] to start daemon (15 min interval)\n");
         output.push_str("  Press [+/-] to adjust interval before starting\n");
         output.push_str("  Or: engram daemon start --interval 30\n");
+        output.push_str("  Change later: engram daemon set-interval 30\n");
     }
 
     output
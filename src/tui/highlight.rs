@@ -0,0 +1,319 @@
+//! Lightweight syntax highlighting for fenced code blocks in the viewer.
+//!
+//! Not a full lexer: it colors comments, strings, numbers and keywords using the
+//! language hint after the opening fence. Good enough to make code in
+//! conversations readable without pulling in a grammar engine.
+
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+
+/// Files larger than this are shown as plain text to keep the viewer responsive.
+pub const MAX_HIGHLIGHT_BYTES: usize = 256 * 1024;
+
+/// Render markdown into styled lines, highlighting fenced code blocks.
+pub fn highlight_markdown(content: &str) -> Vec<Line<'static>> {
+    if content.len() > MAX_HIGHLIGHT_BYTES {
+        return content.lines().map(|l| Line::raw(l.to_string())).collect();
+    }
+
+    let fence_style = Style::default().fg(Color::DarkGray);
+    let mut lines = Vec::new();
+    let mut in_code: Option<Lang> = None;
+
+    for line in content.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") {
+            in_code = match in_code {
+                Some(_) => None,
+                None => Some(Lang::from_hint(trimmed.trim_start_matches('`').trim())),
+            };
+            lines.push(Line::styled(line.to_string(), fence_style));
+            continue;
+        }
+
+        match &in_code {
+            Some(lang) => lines.push(highlight_code_line(line, lang)),
+            None => lines.push(Line::raw(line.to_string())),
+        }
+    }
+
+    lines
+}
+
+/// Per-language tokenization rules.
+struct Lang {
+    keywords: &'static [&'static str],
+    line_comment: &'static [&'static str],
+}
+
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "false",
+    "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref",
+    "return", "self", "Self", "static", "struct", "super", "trait", "true", "type", "unsafe",
+    "use", "where", "while",
+];
+const PYTHON_KEYWORDS: &[&str] = &[
+    "and", "as", "assert", "async", "await", "break", "class", "continue", "def", "del", "elif",
+    "else", "except", "False", "finally", "for", "from", "if", "import", "in", "is", "lambda",
+    "None", "not", "or", "pass", "raise", "return", "self", "True", "try", "while", "with",
+    "yield",
+];
+const JS_KEYWORDS: &[&str] = &[
+    "async",
+    "await",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "default",
+    "else",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "from",
+    "function",
+    "if",
+    "import",
+    "in",
+    "instanceof",
+    "interface",
+    "let",
+    "new",
+    "null",
+    "of",
+    "return",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "type",
+    "typeof",
+    "undefined",
+    "var",
+    "while",
+];
+const GO_KEYWORDS: &[&str] = &[
+    "break",
+    "case",
+    "chan",
+    "const",
+    "continue",
+    "defer",
+    "else",
+    "false",
+    "for",
+    "func",
+    "go",
+    "if",
+    "import",
+    "interface",
+    "map",
+    "nil",
+    "package",
+    "range",
+    "return",
+    "select",
+    "struct",
+    "switch",
+    "true",
+    "type",
+    "var",
+];
+const SHELL_KEYWORDS: &[&str] = &[
+    "case", "do", "done", "elif", "else", "esac", "export", "fi", "for", "function", "if", "in",
+    "local", "return", "then", "while",
+];
+const GENERIC_KEYWORDS: &[&str] = &["true", "false", "null", "None", "nil"];
+
+impl Lang {
+    fn from_hint(hint: &str) -> Lang {
+        let name = hint.split_whitespace().next().unwrap_or("").to_lowercase();
+        match name.as_str() {
+            "rust" | "rs" => Lang {
+                keywords: RUST_KEYWORDS,
+                line_comment: &["//"],
+            },
+            "python" | "py" => Lang {
+                keywords: PYTHON_KEYWORDS,
+                line_comment: &["#"],
+            },
+            "javascript" | "js" | "typescript" | "ts" | "tsx" | "jsx" => Lang {
+                keywords: JS_KEYWORDS,
+                line_comment: &["//"],
+            },
+            "go" | "golang" => Lang {
+                keywords: GO_KEYWORDS,
+                line_comment: &["//"],
+            },
+            "sh" | "bash" | "zsh" | "shell" | "console" => Lang {
+                keywords: SHELL_KEYWORDS,
+                line_comment: &["#"],
+            },
+            "toml" | "yaml" | "yml" => Lang {
+                keywords: GENERIC_KEYWORDS,
+                line_comment: &["#"],
+            },
+            _ => Lang {
+                keywords: GENERIC_KEYWORDS,
+                line_comment: &["//", "#"],
+            },
+        }
+    }
+}
+
+fn highlight_code_line(line: &str, lang: &Lang) -> Line<'static> {
+    let comment_style = Style::default().fg(Color::DarkGray);
+    let string_style = Style::default().fg(Color::Green);
+    let number_style = Style::default().fg(Color::Magenta);
+    let keyword_style = Style::default()
+        .fg(Color::Yellow)
+        .add_modifier(Modifier::BOLD);
+
+    let chars: Vec<char> = line.chars().collect();
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    let mut plain = String::new();
+    let mut i = 0;
+
+    let flush = |plain: &mut String, spans: &mut Vec<Span<'static>>| {
+        if !plain.is_empty() {
+            spans.push(Span::raw(std::mem::take(plain)));
+        }
+    };
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if lang
+            .line_comment
+            .iter()
+            .any(|m| starts_with_at(&chars, i, m))
+        {
+            flush(&mut plain, &mut spans);
+            spans.push(Span::styled(
+                chars[i..].iter().collect::<String>(),
+                comment_style,
+            ));
+            break;
+        }
+
+        if let Some(end) = string_end(&chars, i) {
+            flush(&mut plain, &mut spans);
+            spans.push(Span::styled(
+                chars[i..end].iter().collect::<String>(),
+                string_style,
+            ));
+            i = end;
+            continue;
+        }
+
+        if c.is_alphanumeric() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            let word: String = chars[start..i].iter().collect();
+            if word.chars().next().is_some_and(|ch| ch.is_ascii_digit()) {
+                flush(&mut plain, &mut spans);
+                spans.push(Span::styled(word, number_style));
+            } else if lang.keywords.contains(&word.as_str()) {
+                flush(&mut plain, &mut spans);
+                spans.push(Span::styled(word, keyword_style));
+            } else {
+                plain.push_str(&word);
+            }
+            continue;
+        }
+
+        plain.push(c);
+        i += 1;
+    }
+    flush(&mut plain, &mut spans);
+
+    Line::from(spans)
+}
+
+/// End (exclusive) of a string literal opening at `i`, if there is one.
+///
+/// The closing quote must be on the same line, and a `'` right after a word
+/// character (`don't`, `it's`) is an apostrophe rather than an opening quote.
+fn string_end(chars: &[char], i: usize) -> Option<usize> {
+    let quote = chars[i];
+    if !matches!(quote, '"' | '\'' | '`') {
+        return None;
+    }
+    if quote == '\'' && i > 0 && (chars[i - 1].is_alphanumeric() || chars[i - 1] == '_') {
+        return None;
+    }
+    let mut j = i + 1;
+    while j < chars.len() {
+        match chars[j] {
+            '\\' => j += 2,
+            c if c == quote => return Some(j + 1),
+            _ => j += 1,
+        }
+    }
+    None
+}
+
+fn starts_with_at(chars: &[char], i: usize, marker: &str) -> bool {
+    let mut rest = chars[i..].iter();
+    marker.chars().all(|m| rest.next() == Some(&m))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span_text(line: &Line) -> Vec<String> {
+        line.spans.iter().map(|s| s.content.to_string()).collect()
+    }
+
+    #[test]
+    fn test_only_fenced_code_is_tokenized() {
+        let md = "Use fn here\n```rust\nfn main() { let x = \"hi\"; } // done\n```\nafter";
+        let lines = highlight_markdown(md);
+        assert_eq!(lines.len(), 5);
+        assert_eq!(span_text(&lines[0]), ["Use fn here"]);
+
+        let code = span_text(&lines[2]);
+        assert_eq!(code[0], "fn");
+        assert!(code.contains(&"\"hi\"".to_string()));
+        assert_eq!(code.last().unwrap(), "// done");
+        assert_eq!(span_text(&lines[4]), ["after"]);
+    }
+
+    #[test]
+    fn test_language_hint_selects_comment_marker() {
+        let lines = highlight_markdown("```python\nx = 1  # note\n```");
+        let code = span_text(&lines[1]);
+        assert_eq!(code.last().unwrap(), "# note");
+        assert!(code.contains(&"1".to_string()));
+    }
+
+    #[test]
+    fn test_unmatched_quotes_are_not_strings() {
+        let lines = highlight_markdown("```sh\necho it's ok; x=1\n```");
+        let code = span_text(&lines[1]);
+        assert!(code.iter().any(|s| s.contains("it's ok")), "{:?}", code);
+        assert!(code.contains(&"1".to_string()), "{:?}", code);
+
+        let lines = highlight_markdown("```rust\nlet c = 'x'; let s = \"open\n```");
+        let code = span_text(&lines[1]);
+        assert!(code.contains(&"'x'".to_string()), "{:?}", code);
+        assert!(!code.iter().any(|s| s.starts_with('"')), "{:?}", code);
+    }
+
+    #[test]
+    fn test_large_content_is_plain() {
+        let big = format!("```rust\n{}\n```", "let x = 1;\n".repeat(30_000));
+        let lines = highlight_markdown(&big);
+        assert!(lines.iter().all(|l| l.spans.len() <= 1));
+    }
+}
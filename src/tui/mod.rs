@@ -1,6 +1,9 @@
+mod clipboard;
 pub mod data;
+mod highlight;
 mod ui;
 
+use std::collections::HashMap;
 use std::io::{self, Write};
 use std::path::PathBuf;
 use std::process::Command;
@@ -31,6 +34,7 @@ enum Screen {
     Help,
     Vcs,
     Reflect,
+    Diff,
 }
 
 #[derive(Clone, PartialEq)]
@@ -53,6 +57,26 @@ pub enum TuiAction {
     DaemonStop,
 }
 
+/// Overlay state for promoting an inbox entry from the browser.
+pub struct PromoteDialog {
+    project: String,
+    entries: Vec<(String, String)>, // (session_id, first content line)
+    entry_index: usize,
+    category_index: usize,
+    global: bool,
+}
+
+impl PromoteDialog {
+    fn category(&self) -> crate::config::Category {
+        crate::commands::manual::PROMOTE_CATEGORIES[self.category_index]
+    }
+
+    /// Preferences live in `_global` only, so they always promote globally.
+    fn effective_global(&self) -> bool {
+        self.global || self.category() == crate::config::Category::Preferences
+    }
+}
+
 pub struct App {
     screen: Screen,
     tree: MemoryTree,
@@ -61,7 +85,9 @@ pub struct App {
     focus_left: bool,
     scroll_offset: u16,
     viewer_content: String,
+    viewer_lines: Vec<ratatui::text::Line<'static>>, // highlighted once per open
     show_delete: bool,
+    promote_dialog: Option<PromoteDialog>,
     memory_dir: PathBuf,
     // Search state
     search_mode: bool,
@@ -69,6 +95,9 @@ pub struct App {
     search_matches: Vec<(usize, usize, i64)>, // (project_idx, item_idx, score)
     search_match_index: usize,
     fuzzy_matcher: SkimMatcherV2,
+    search_content: bool, // Tab in search mode: also match inside file contents
+    search_content_hits: usize,
+    content_cache: HashMap<PathBuf, String>, // lowercased file contents
     // Packs state
     packs: Vec<data::PackEntry>,
     pack_index: usize,
@@ -119,7 +148,8 @@ pub struct App {
     config_model_list_mode: bool,
     config_model_list_index: usize,
     config_model_list_scroll: usize,
-    pending_model_fetch: Option<crate::auth::providers::Provider>,
+    /// Provider to list models for, and whether to bypass the model cache
+    pending_model_fetch: Option<(crate::auth::providers::Provider, bool)>,
     // Inject preview state
     pub inject_entries: Vec<crate::inject::SmartEntry>,
     inject_preview_index: usize,
@@ -143,6 +173,8 @@ pub struct App {
 
     // VCS state
     vcs_commits: Vec<crate::vcs::CommitObject>,
+    vcs_branches: Vec<crate::vcs::BranchRef>,
+    vcs_tags: Vec<crate::vcs::TagRef>,
     vcs_commit_index: usize,
     vcs_commit_scroll: usize,
     vcs_snapshot_content: String,
@@ -152,6 +184,16 @@ pub struct App {
     // Reflect state
     reflect_content: String,
     reflect_scroll: u16,
+
+    // Diff state
+    diff_project: String,
+    diff_category: String,
+    diff_versions: Vec<crate::diff::version_tracker::KnowledgeVersion>,
+    diff_index: usize,
+    diff_marked: Vec<usize>, // up to two version indices to compare
+    diff_result: Option<crate::diff::knowledge_diff::KnowledgeDiff>,
+    diff_title: String,
+    diff_scroll: u16,
 }
 
 impl App {
@@ -166,13 +208,18 @@ impl App {
             focus_left: true,
             scroll_offset: 0,
             viewer_content: String::new(),
+            viewer_lines: Vec::new(),
             show_delete: false,
+            promote_dialog: None,
             memory_dir: memory_dir.clone(),
             search_mode: false,
             search_query: String::new(),
             search_matches: Vec::new(),
             search_match_index: 0,
             fuzzy_matcher: SkimMatcherV2::default(),
+            search_content: false,
+            search_content_hits: 0,
+            content_cache: HashMap::new(),
             packs,
             pack_index: 0,
             pack_detail_content: String::new(),
@@ -225,6 +272,8 @@ impl App {
             pending_ask: None,
             ask_scroll: 0,
             vcs_commits: Vec::new(),
+            vcs_branches: Vec::new(),
+            vcs_tags: Vec::new(),
             vcs_commit_index: 0,
             vcs_commit_scroll: 0,
             vcs_snapshot_content: String::new(),
@@ -232,6 +281,14 @@ impl App {
             vcs_status_line: String::new(),
             reflect_content: String::new(),
             reflect_scroll: 0,
+            diff_project: String::new(),
+            diff_category: String::new(),
+            diff_versions: Vec::new(),
+            diff_index: 0,
+            diff_marked: Vec::new(),
+            diff_result: None,
+            diff_title: String::new(),
+            diff_scroll: 0,
         }
     }
 
@@ -262,16 +319,18 @@ impl App {
             None => {
                 self.vcs_status_line = "No project selected".to_string();
                 self.vcs_commits.clear();
+                self.vcs_branches.clear();
+                self.vcs_tags.clear();
                 return;
             }
         };
         let vcs = crate::vcs::MemoryVcs::new(&self.memory_dir, &project);
         if !vcs.is_initialized() {
-            self.vcs_status_line = format!(
-                "VCS not initialized — run: engram mem init --project {}",
-                project
-            );
+            self.vcs_status_line =
+                format!("VCS not initialized — run: engram mem init {}", project);
             self.vcs_commits.clear();
+            self.vcs_branches.clear();
+            self.vcs_tags.clear();
             return;
         }
         // Load status line
@@ -293,7 +352,9 @@ impl App {
             }
             Err(e) => format!("Error: {}", e),
         };
-        // Load commit log
+        // Load refs and commit log
+        self.vcs_branches = vcs.list_branches().unwrap_or_default();
+        self.vcs_tags = vcs.list_tags().unwrap_or_default();
         self.vcs_commits = vcs.log(None, 50, None).unwrap_or_default();
         self.vcs_commit_index = 0;
         self.vcs_commit_scroll = 0;
@@ -322,6 +383,7 @@ impl App {
 
     fn reload_tree(&mut self) {
         self.tree = data::load_tree(&self.memory_dir);
+        self.content_cache.clear();
         // Clamp indices
         if self.project_index >= self.tree.projects.len() && !self.tree.projects.is_empty() {
             self.project_index = self.tree.projects.len() - 1;
@@ -334,6 +396,7 @@ impl App {
 
     fn compute_search_matches(&mut self) {
         self.search_matches.clear();
+        self.search_content_hits = 0;
         if self.search_query.is_empty() {
             return;
         }
@@ -372,14 +435,56 @@ impl App {
             }
         }
 
+        if self.search_content {
+            self.add_content_matches();
+        }
+
         // Sort by score (highest first)
-        self.search_matches.sort_by(|a, b| b.2.cmp(&a.2));
+        self.search_matches.sort_by_key(|m| std::cmp::Reverse(m.2));
 
         if self.search_match_index >= self.search_matches.len() {
             self.search_match_index = 0;
         }
     }
 
+    /// Match items whose file content contains every search term (case-insensitive).
+    /// Contents are read once and cached until the tree is reloaded.
+    fn add_content_matches(&mut self) {
+        let query = self.search_query.to_lowercase();
+        let terms: Vec<&str> = query.split_whitespace().collect();
+        if terms.is_empty() {
+            return;
+        }
+
+        for (pi, project) in self.tree.projects.iter().enumerate() {
+            for (ii, item) in project.items.iter().enumerate() {
+                let path = item_content_path(item);
+                let content = self.content_cache.entry(path.clone()).or_insert_with(|| {
+                    std::fs::read_to_string(&path)
+                        .unwrap_or_default()
+                        .to_lowercase()
+                });
+
+                if !terms.iter().all(|t| content.contains(t)) {
+                    continue;
+                }
+                let hits: usize = terms.iter().map(|t| content.matches(t).count()).sum();
+                self.search_content_hits += hits;
+
+                // Content hits rank by occurrence count; label matches keep their score
+                let score = hits as i64;
+                match self
+                    .search_matches
+                    .iter_mut()
+                    .find(|(p, i, _)| *p == pi && *i == ii)
+                {
+                    Some(existing) => existing.2 = existing.2.max(score),
+                    None => self.search_matches.push((pi, ii, score)),
+                }
+            }
+        }
+    }
+
     fn jump_to_match(&mut self) {
         if let Some(&(pi, ii, _score)) = self.search_matches.get(self.search_match_index) {
             self.project_index = pi;
@@ -400,14 +505,17 @@ impl App {
             .any(|(pi, _, _)| *pi == project_idx)
     }
 
+    fn set_viewer_content(&mut self, content: String) {
+        self.viewer_lines = highlight::highlight_markdown(&content);
+        self.viewer_content = content;
+    }
+
     fn open_viewer(&mut self) {
         if let Some(item) = self.current_item() {
-            let path = match item {
-                MemoryItem::Session { path, .. } => path.join("conversation.md"),
-                MemoryItem::KnowledgeFile { path, .. } => path.clone(),
-            };
-            self.viewer_content = std::fs::read_to_string(&path)
+            let path = item_content_path(item);
+            let content = std::fs::read_to_string(&path)
                 .unwrap_or_else(|e| format!("Error reading {}: {}", path.display(), e));
+            self.set_viewer_content(content);
             self.scroll_offset = 0;
             self.screen = Screen::Viewer;
         }
@@ -454,6 +562,7 @@ impl App {
                 Screen::Help => ui::render_help(f, self),
                 Screen::Vcs => ui::render_vcs(f, self),
                 Screen::Reflect => ui::render_reflect(f, self),
+                Screen::Diff => ui::render_diff(f, self),
             })?;
 
             // Execute pending config test (blocking HTTP call)
@@ -471,36 +580,44 @@ impl App {
             }
 
             // Execute pending model fetch (blocking HTTP call to /v1/models)
-            if let Some(provider) = self.pending_model_fetch.take() {
-                match crate::commands::provider_test::fetch_models_sync(provider) {
-                    Ok(models) if !models.is_empty() => {
-                        self.config_model_list = models;
-                        self.config_model_list_index = 0;
-                        self.config_model_list_scroll = 0;
-                        self.config_model_list_mode = true;
+            if let Some((provider, refresh)) = self.pending_model_fetch.take() {
+                match crate::commands::provider_test::fetch_models_cached(provider, refresh) {
+                    Ok(listing) if !listing.models.is_empty() => {
+                        let source = if listing.cached {
+                            format!(
+                                "cached {}",
+                                listing
+                                    .fetched_at
+                                    .with_timezone(&chrono::Local)
+                                    .format("%H:%M")
+                            )
+                        } else {
+                            "fetched".to_string()
+                        };
+                        let count = listing.models.len();
+                        self.open_model_list(listing.models);
                         self.config_status = format!(
-                            "{} models available — j/k: nav  Enter: select  Esc: cancel",
-                            self.config_model_list.len()
+                            "{} models available ({}) — Enter: select  r: refresh  e: type name  Esc: cancel",
+                            count, source
                         );
                     }
-                    Ok(_) => {
-                        // Empty list — fall back to text input
-                        self.config_model_input = self.current_config_model();
-                        self.config_model_input_mode = true;
-                        self.config_status = "No models returned — enter name manually".to_string();
-                    }
-                    Err(e) => {
-                        // Error — fall back to text input
-                        self.config_model_input = self.current_config_model();
-                        self.config_model_input_mode = true;
-                        self.config_status = format!("Fetch failed ({}) — enter name manually", e);
-                    }
+                    Ok(_) => self.open_known_models(provider, "No models returned"),
+                    Err(e) => self.open_known_models(provider, &format!("Fetch failed ({})", e)),
                 }
             }
 
             // Execute pending ask query
             if let Some((project, query)) = self.pending_ask.take() {
-                let args = vec!["ask", query.as_str(), "--project", project.as_str()];
+                // `--` keeps queries starting with '-' from being read as flags;
+                // `--quiet` keeps CLI hints out of the answer shown on screen
+                let args = vec![
+                    "ask",
+                    "--quiet",
+                    "--project",
+                    project.as_str(),
+                    "--",
+                    query.as_str(),
+                ];
                 let (output, _) = self.run_cli_command(terminal, &args)?;
                 self.ask_result = output;
                 self.ask_loading = false;
@@ -579,6 +696,8 @@ impl App {
                             self.handle_search_keys(key.code);
                         } else if self.show_delete {
                             self.handle_delete_keys(key.code);
+                        } else if self.promote_dialog.is_some() {
+                            self.handle_promote_keys(key.code);
                         } else if self.handle_browser_keys(key.code, key.modifiers) {
                             return Ok(());
                         }
@@ -636,6 +755,9 @@ impl App {
                     Screen::Reflect => {
                         self.handle_reflect_keys(key.code, terminal)?;
                     }
+                    Screen::Diff => {
+                        self.handle_diff_keys(key.code, terminal)?;
+                    }
                 }
             }
         }
@@ -653,6 +775,10 @@ impl App {
                     self.jump_to_match();
                 }
             }
+            KeyCode::Tab => {
+                self.search_content = !self.search_content;
+                self.compute_search_matches();
+            }
             KeyCode::Backspace => {
                 self.search_query.pop();
                 self.compute_search_matches();
@@ -679,22 +805,17 @@ impl App {
             }
 
             // Next/prev match
-            KeyCode::Char('n') => {
-                if !self.search_matches.is_empty() {
-                    self.search_match_index =
-                        (self.search_match_index + 1) % self.search_matches.len();
-                    self.jump_to_match();
-                }
+            KeyCode::Char('n') if !self.search_matches.is_empty() => {
+                self.search_match_index = (self.search_match_index + 1) % self.search_matches.len();
+                self.jump_to_match();
             }
-            KeyCode::Char('N') => {
-                if !self.search_matches.is_empty() {
-                    self.search_match_index = if self.search_match_index == 0 {
-                        self.search_matches.len() - 1
-                    } else {
-                        self.search_match_index - 1
-                    };
-                    self.jump_to_match();
-                }
+            KeyCode::Char('N') if !self.search_matches.is_empty() => {
+                self.search_match_index = if self.search_match_index == 0 {
+                    self.search_matches.len() - 1
+                } else {
+                    self.search_match_index - 1
+                };
+                self.jump_to_match();
             }
 
             // Navigation
@@ -721,10 +842,10 @@ impl App {
             }
 
             // Panel switching
-            KeyCode::Tab | KeyCode::Char('l') | KeyCode::Right => {
-                if self.focus_left && self.project_item_count() > 0 {
-                    self.focus_left = false;
-                }
+            KeyCode::Tab | KeyCode::Char('l') | KeyCode::Right
+                if self.focus_left && self.project_item_count() > 0 =>
+            {
+                self.focus_left = false;
             }
             KeyCode::BackTab | KeyCode::Char('h') | KeyCode::Left => {
                 self.focus_left = true;
@@ -741,12 +862,34 @@ impl App {
             }
 
             // Delete
-            KeyCode::Char('d') => {
-                if !self.focus_left && self.current_item().is_some() {
-                    self.show_delete = true;
+            KeyCode::Char('d') if !self.focus_left && self.current_item().is_some() => {
+                self.show_delete = true;
+            }
+
+            // Copy the selected item to the clipboard
+            KeyCode::Char('y') if !self.focus_left => {
+                if let Some(item) = self.current_item() {
+                    let path = item_content_path(item);
+                    match std::fs::read_to_string(&path) {
+                        Ok(content) => self.copy_to_clipboard(&content),
+                        Err(e) => {
+                            self.action_message =
+                                Some((format!("Copy failed: {}: {}", path.display(), e), true));
+                        }
+                    }
                 }
             }
 
+            // Diff versions of the selected knowledge file
+            KeyCode::Char('v') if !self.focus_left => {
+                self.open_diff_screen();
+            }
+
+            // Promote an inbox entry
+            KeyCode::Char('P') if !self.focus_left => {
+                self.open_promote_dialog();
+            }
+
             // Switch to Packs screen
             KeyCode::Char('p') => {
                 self.screen = Screen::Packs;
@@ -959,7 +1102,7 @@ impl App {
 
     fn open_pack_detail(&mut self) {
         if let Some(pack) = self.packs.get(self.pack_index) {
-            self.pack_detail_content = data::render_pack_detail(pack, &self.memory_dir);
+            self.pack_detail_content = data::render_pack_detail(pack);
             self.pack_detail_scroll = 0;
             self.screen = Screen::PackDetail;
         }
@@ -1013,7 +1156,7 @@ impl App {
             KeyCode::Char('y') | KeyCode::Char('Y') => {
                 if let Some(item) = self.current_item() {
                     let path = item.path().to_path_buf();
-                    let _ = data::delete_entry(&path);
+                    let _ = data::delete_entry(&self.memory_dir, &path);
                     self.show_delete = false;
                     self.reload_tree();
                 }
@@ -1025,6 +1168,123 @@ impl App {
         }
     }
 
+    /// Copy text to the system clipboard and report the result as an action message.
+    fn copy_to_clipboard(&mut self, text: &str) {
+        self.action_message = Some(match clipboard::copy(text) {
+            Ok(tool) => (
+                format!(
+                    "Copied {} lines to clipboard ({})",
+                    text.lines().count(),
+                    tool
+                ),
+                false,
+            ),
+            Err(e) => (e, true),
+        });
+    }
+
+    fn open_promote_dialog(&mut self) {
+        let Some(MemoryItem::KnowledgeFile { path, name, .. }) = self.current_item() else {
+            return;
+        };
+        if name != "inbox.md" {
+            self.action_message = Some(("P only works on inbox.md".to_string(), true));
+            return;
+        }
+        let Some(project) = self.current_project_name() else {
+            return;
+        };
+
+        let content = std::fs::read_to_string(path).unwrap_or_default();
+        let (_, blocks) = crate::extractor::knowledge::parse_session_blocks(&content);
+        if blocks.is_empty() {
+            self.action_message = Some(("Inbox is empty".to_string(), false));
+            return;
+        }
+
+        let entries = blocks
+            .into_iter()
+            .map(|b| {
+                let preview = b
+                    .content
+                    .lines()
+                    .map(str::trim)
+                    .find(|l| !l.is_empty())
+                    .unwrap_or("")
+                    .to_string();
+                (b.session_id, preview)
+            })
+            .collect();
+
+        self.promote_dialog = Some(PromoteDialog {
+            project,
+            entries,
+            entry_index: 0,
+            category_index: 0,
+            global: false,
+        });
+    }
+
+    fn handle_promote_keys(&mut self, code: KeyCode) {
+        let Some(dialog) = self.promote_dialog.as_mut() else {
+            return;
+        };
+        let category_count = crate::commands::manual::PROMOTE_CATEGORIES.len();
+
+        match code {
+            KeyCode::Char('j') | KeyCode::Down if dialog.entry_index + 1 < dialog.entries.len() => {
+                dialog.entry_index += 1;
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                dialog.entry_index = dialog.entry_index.saturating_sub(1);
+            }
+            KeyCode::Char('l') | KeyCode::Right | KeyCode::Tab => {
+                dialog.category_index = (dialog.category_index + 1) % category_count;
+            }
+            KeyCode::Char('h') | KeyCode::Left | KeyCode::BackTab => {
+                dialog.category_index =
+                    (dialog.category_index + category_count - 1) % category_count;
+            }
+            KeyCode::Char('g') => {
+                dialog.global = !dialog.global;
+            }
+            KeyCode::Enter => {
+                let Some(dialog) = self.promote_dialog.take() else {
+                    return;
+                };
+                let (session_id, _) = &dialog.entries[dialog.entry_index];
+                let global = dialog.effective_global();
+                let result = crate::commands::manual::promote_inbox_entry(
+                    &self.memory_dir,
+                    &dialog.project,
+                    session_id,
+                    dialog.category(),
+                    global,
+                    "promoted",
+                    None,
+                );
+                self.action_message = Some(match result {
+                    Ok(crate::commands::manual::PromoteOutcome::Promoted { target_file }) => (
+                        format!(
+                            "Promoted '{}' to {}/{}",
+                            session_id,
+                            if global { "_global" } else { &dialog.project },
+                            target_file
+                        ),
+                        false,
+                    ),
+                    Ok(_) => (format!("'{}' is no longer in the inbox", session_id), true),
+                    Err(e) => (format!("Promote failed: {}", e), true),
+                });
+                self.reload_tree();
+            }
+            KeyCode::Esc | KeyCode::Char('q') => {
+                self.promote_dialog = None;
+            }
+            _ => {}
+        }
+    }
+
     fn handle_viewer_keys(
         &mut self,
         code: KeyCode,
@@ -1034,9 +1294,14 @@ impl App {
         let total_lines = self.viewer_content.lines().count() as u16;
 
         match code {
+            KeyCode::Char('y') => {
+                let content = self.viewer_content.clone();
+                self.copy_to_clipboard(&content);
+            }
             KeyCode::Esc | KeyCode::Char('q') => {
                 self.screen = Screen::Browser;
                 self.viewer_content.clear();
+                self.viewer_lines.clear();
             }
             KeyCode::Char('j') | KeyCode::Down => {
                 if self.scroll_offset < total_lines {
@@ -1224,16 +1489,14 @@ impl App {
     fn handle_ask_keys(&mut self, code: KeyCode) {
         if self.ask_input_mode {
             match code {
-                KeyCode::Enter => {
-                    if !self.ask_query.is_empty() {
-                        self.ask_result = "Querying\u{2026}".to_string();
-                        self.ask_loading = true;
-                        let project = self
-                            .current_project_name()
-                            .unwrap_or_else(|| "default".to_string());
-                        self.pending_ask = Some((project, self.ask_query.clone()));
-                        self.ask_input_mode = false;
-                    }
+                KeyCode::Enter if !self.ask_query.is_empty() => {
+                    self.ask_result = "Querying\u{2026}".to_string();
+                    self.ask_loading = true;
+                    let project = self
+                        .current_project_name()
+                        .unwrap_or_else(|| "default".to_string());
+                    self.pending_ask = Some((project, self.ask_query.clone()));
+                    self.ask_input_mode = false;
                 }
                 KeyCode::Esc => {
                     self.ask_input_mode = false;
@@ -1274,6 +1537,15 @@ impl App {
             KeyCode::Char('q') | KeyCode::Esc => {
                 self.screen = Screen::Browser;
             }
+            KeyCode::Char('y') => {
+                if let Some(content) = self
+                    .inject_entries
+                    .get(self.inject_preview_index)
+                    .map(|e| e.content.clone())
+                {
+                    self.copy_to_clipboard(&content);
+                }
+            }
             KeyCode::Char('j') | KeyCode::Down => {
                 if self.inject_preview_index + 1 < self.inject_entries.len() {
                     self.inject_preview_index += 1;
@@ -1328,7 +1600,7 @@ impl App {
                         let claude_dir = home.join(".claude").join("projects");
                         let selected = entries.iter().filter(|e| e.selected).count();
                         // Write via engram inject --smart (spawn process to handle dir finding)
-                        let result = std::process::Command::new("engram")
+                        let result = std::process::Command::new(crate::config::engram_exe())
                             .args([
                                 "inject",
                                 "--smart",
@@ -1451,11 +1723,9 @@ impl App {
                 if provider.supports_model_list() {
                     self.config_status =
                         format!("Fetching models from {}...", provider.display_name());
-                    self.pending_model_fetch = Some(provider);
+                    self.pending_model_fetch = Some((provider, false));
                 } else {
-                    // Anthropic / Gemini — no /models endpoint, use text input
-                    self.config_model_input = self.current_config_model();
-                    self.config_model_input_mode = true;
+                    self.open_known_models(provider, "No /models endpoint");
                 }
             }
             KeyCode::Char('q') | KeyCode::Esc => {
@@ -1481,22 +1751,29 @@ impl App {
                     self.config_model_list_mode = false;
                 }
             }
-            KeyCode::Char('j') | KeyCode::Down => {
-                if self.config_model_list_index + 1 < count {
-                    self.config_model_list_index += 1;
-                    // Scroll window down when cursor approaches bottom
-                    if self.config_model_list_index >= self.config_model_list_scroll + 18 {
-                        self.config_model_list_scroll += 1;
-                    }
+            KeyCode::Char('e') => {
+                self.config_model_list_mode = false;
+                self.config_model_input = self.current_config_model();
+                self.config_model_input_mode = true;
+            }
+            KeyCode::Char('r') if self.current_config_provider().supports_model_list() => {
+                let provider = self.current_config_provider();
+                self.config_model_list_mode = false;
+                self.config_status =
+                    format!("Refreshing models from {}...", provider.display_name());
+                self.pending_model_fetch = Some((provider, true));
+            }
+            KeyCode::Char('j') | KeyCode::Down if self.config_model_list_index + 1 < count => {
+                self.config_model_list_index += 1;
+                // Scroll window down when cursor approaches bottom
+                if self.config_model_list_index >= self.config_model_list_scroll + 18 {
+                    self.config_model_list_scroll += 1;
                 }
             }
-            KeyCode::Char('k') | KeyCode::Up => {
-                if self.config_model_list_index > 0 {
-                    self.config_model_list_index -= 1;
-                    if self.config_model_list_index < self.config_model_list_scroll {
-                        self.config_model_list_scroll =
-                            self.config_model_list_scroll.saturating_sub(1);
-                    }
+            KeyCode::Char('k') | KeyCode::Up if self.config_model_list_index > 0 => {
+                self.config_model_list_index -= 1;
+                if self.config_model_list_index < self.config_model_list_scroll {
+                    self.config_model_list_scroll = self.config_model_list_scroll.saturating_sub(1);
                 }
             }
             KeyCode::PageDown => {
@@ -1526,6 +1803,9 @@ impl App {
                         key: String::new(),
                         endpoint: None,
                         model: Some(model.to_string()),
+                        refresh_token: None,
+                        expires_at: None,
+                        oauth: None,
                     },
                 );
             }
@@ -1552,6 +1832,33 @@ impl App {
         crate::auth::providers::Provider::all()[self.config_llm_index]
     }
 
+    /// Show `models` in the picker with the cursor on the configured model.
+    fn open_model_list(&mut self, models: Vec<String>) {
+        let current = self.current_config_model();
+        self.config_model_list_index = models.iter().position(|m| *m == current).unwrap_or(0);
+        self.config_model_list_scroll = self.config_model_list_index.saturating_sub(17);
+        self.config_model_list = models;
+        self.config_model_list_mode = true;
+    }
+
+    /// Fall back to the bundled model table when live listing isn't possible,
+    /// or to free-text entry when there is no table for this provider.
+    fn open_known_models(&mut self, provider: crate::auth::providers::Provider, reason: &str) {
+        let known = provider.known_models();
+        if known.is_empty() {
+            self.config_model_input = self.current_config_model();
+            self.config_model_input_mode = true;
+            self.config_status = format!("{} — enter name manually", reason);
+            return;
+        }
+        self.open_model_list(known.iter().map(|m| m.to_string()).collect());
+        self.config_status = format!(
+            "{} — showing {} known models  Enter: select  e: type name  Esc: cancel",
+            reason,
+            known.len()
+        );
+    }
+
     fn current_config_model(&self) -> String {
         use crate::auth::AuthStore;
         let provider = self.current_config_provider();
@@ -1610,6 +1917,9 @@ impl App {
                         key: String::new(),
                         endpoint: None,
                         model: Some(model.clone()),
+                        refresh_token: None,
+                        expires_at: None,
+                        oauth: None,
                     },
                 );
             }
@@ -1989,6 +2299,149 @@ impl App {
         }
     }
 
+    fn open_diff_screen(&mut self) {
+        let Some(MemoryItem::KnowledgeFile { name, .. }) = self.current_item() else {
+            return;
+        };
+        let Some(category) = name.strip_suffix(".md").map(str::to_string) else {
+            return;
+        };
+        let Some(project) = self.current_project_name() else {
+            return;
+        };
+        self.diff_project = project;
+        self.diff_category = category;
+        self.load_diff_data();
+        self.screen = Screen::Diff;
+    }
+
+    fn load_diff_data(&mut self) {
+        let tracker = crate::diff::VersionTracker::new(&self.memory_dir, &self.diff_project);
+        self.diff_versions = tracker
+            .get_versions(&self.diff_category)
+            .unwrap_or_default();
+        self.diff_index = 0;
+        self.diff_marked.clear();
+        self.compute_diff_view();
+    }
+
+    /// Diff the two marked versions (older → newer), or the selected version
+    /// against the current file when fewer than two are marked.
+    fn compute_diff_view(&mut self) {
+        self.diff_scroll = 0;
+        self.diff_result = None;
+        if self.diff_versions.is_empty() {
+            self.diff_title = format!(
+                "No version history for {}/{}",
+                self.diff_project, self.diff_category
+            );
+            return;
+        }
+
+        let tracker = crate::diff::VersionTracker::new(&self.memory_dir, &self.diff_project);
+        let (old_idx, new_idx) = match self.diff_marked[..] {
+            // Versions are newest first, so the larger index is the older one
+            [a, b] => (a.max(b), Some(a.min(b))),
+            _ => (self.diff_index, None),
+        };
+
+        let old_version = &self.diff_versions[old_idx];
+        let old = tracker
+            .get_version_content(&old_version.version_id)
+            .unwrap_or_default();
+        let (new, new_label) = match new_idx {
+            Some(i) => {
+                let v = &self.diff_versions[i];
+                (
+                    tracker
+                        .get_version_content(&v.version_id)
+                        .unwrap_or_default(),
+                    v.version_id.clone(),
+                )
+            }
+            None => {
+                let current = self
+                    .memory_dir
+                    .join("knowledge")
+                    .join(&self.diff_project)
+                    .join(format!("{}.md", self.diff_category));
+                (
+                    std::fs::read_to_string(current).unwrap_or_default(),
+                    "current".to_string(),
+                )
+            }
+        };
+
+        let result = crate::diff::compute_diff(&old, &new, &self.diff_category);
+        self.diff_title = format!(
+            "{} → {} ({})",
+            old_version.version_id,
+            new_label,
+            result.summary()
+        );
+        self.diff_result = Some(result);
+    }
+
+    fn handle_diff_keys(
+        &mut self,
+        code: KeyCode,
+        terminal: &Terminal<CrosstermBackend<io::Stdout>>,
+    ) -> io::Result<()> {
+        let page_size = terminal.size()?.height.saturating_sub(4);
+
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => {
+                self.screen = Screen::Browser;
+            }
+            KeyCode::Char('r') => {
+                self.load_diff_data();
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                if self.diff_index + 1 < self.diff_versions.len() {
+                    self.diff_index += 1;
+                    if self.diff_marked.len() < 2 {
+                        self.compute_diff_view();
+                    }
+                }
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                if self.diff_index > 0 {
+                    self.diff_index -= 1;
+                    if self.diff_marked.len() < 2 {
+                        self.compute_diff_view();
+                    }
+                }
+            }
+            KeyCode::Char(' ') if !self.diff_versions.is_empty() => {
+                if let Some(pos) = self.diff_marked.iter().position(|&i| i == self.diff_index) {
+                    self.diff_marked.remove(pos);
+                } else {
+                    if self.diff_marked.len() == 2 {
+                        self.diff_marked.remove(0);
+                    }
+                    self.diff_marked.push(self.diff_index);
+                }
+                self.compute_diff_view();
+            }
+            KeyCode::Char('J') => {
+                self.diff_scroll = self.diff_scroll.saturating_add(3);
+            }
+            KeyCode::Char('K') => {
+                self.diff_scroll = self.diff_scroll.saturating_sub(3);
+            }
+            KeyCode::PageDown => {
+                self.diff_scroll = self.diff_scroll.saturating_add(page_size);
+            }
+            KeyCode::PageUp => {
+                self.diff_scroll = self.diff_scroll.saturating_sub(page_size);
+            }
+            _ => {
+                self.handle_tab_switch(code);
+            }
+        }
+        Ok(())
+    }
+
     fn handle_help_keys(&mut self, code: KeyCode) -> io::Result<()> {
         match code {
             KeyCode::Esc | KeyCode::Char('q') => {
@@ -2037,7 +2490,9 @@ impl App {
         print!("\n  Running: engram {}\n\n", args.join(" "));
         io::stdout().flush()?;
 
-        let result = Command::new("engram").args(args).output();
+        let result = Command::new(crate::config::engram_exe())
+            .args(args)
+            .output();
 
         let (output_text, success) = match result {
             Ok(output) => {
@@ -2141,30 +2596,29 @@ impl App {
             KeyCode::Esc => {
                 self.screen = Screen::Browser;
             }
-            KeyCode::Char('j') | KeyCode::Down => {
-                if self.timeline_index + 1 < self.timeline_items.len() {
-                    self.timeline_index += 1;
-                    // Auto-scroll
-                    if self.timeline_index >= self.timeline_scroll + 20 {
-                        self.timeline_scroll += 1;
-                    }
+            KeyCode::Char('j') | KeyCode::Down
+                if self.timeline_index + 1 < self.timeline_items.len() =>
+            {
+                self.timeline_index += 1;
+                // Auto-scroll
+                if self.timeline_index >= self.timeline_scroll + 20 {
+                    self.timeline_scroll += 1;
                 }
             }
-            KeyCode::Char('k') | KeyCode::Up => {
-                if self.timeline_index > 0 {
-                    self.timeline_index -= 1;
-                    if self.timeline_index < self.timeline_scroll {
-                        self.timeline_scroll = self.timeline_index;
-                    }
+            KeyCode::Char('k') | KeyCode::Up if self.timeline_index > 0 => {
+                self.timeline_index -= 1;
+                if self.timeline_index < self.timeline_scroll {
+                    self.timeline_scroll = self.timeline_index;
                 }
             }
             KeyCode::Enter => {
                 // Open viewer for current entry
                 if let Some(entry) = self.timeline_items.get(self.timeline_index) {
-                    self.viewer_content = format!(
+                    let content = format!(
                         "# {} / {} — {}\n\n{}\n",
                         entry.project, entry.category, entry.session_id, entry.content
                     );
+                    self.set_viewer_content(content);
                     self.scroll_offset = 0;
                     self.screen = Screen::Viewer;
                 }
@@ -2179,6 +2633,14 @@ impl App {
 }
 
 /// Entry point: set up terminal, run app, restore terminal.
+/// File backing a browser item (what the viewer shows and content search scans).
+fn item_content_path(item: &MemoryItem) -> PathBuf {
+    match item {
+        MemoryItem::Session { path, .. } => path.join("conversation.md"),
+        MemoryItem::KnowledgeFile { path, .. } => path.clone(),
+    }
+}
+
 pub fn run_tui(memory_dir: PathBuf) -> io::Result<()> {
     if !io::IsTerminal::is_terminal(&io::stdin()) {
         return Err(io::Error::other(
@@ -6,7 +6,7 @@ use ratatui::{
     Frame,
 };
 
-use super::{App, TuiAction};
+use super::{App, PromoteDialog, TuiAction};
 use crate::tui::data::MemoryItem;
 
 /// Render the screen tab bar at the top, highlighting the active screen.
@@ -81,6 +81,9 @@ pub fn render_browser(f: &mut Frame, app: &App) {
     if app.show_delete {
         render_delete_dialog(f, app);
     }
+    if let Some(dialog) = &app.promote_dialog {
+        render_promote_dialog(f, dialog);
+    }
 
     // Status bar at bottom
     render_status_bar(f, app, layout[2]);
@@ -202,7 +205,17 @@ fn render_item_list(f: &mut Frame, app: &App, area: Rect) {
 fn render_status_bar(f: &mut Frame, app: &App, bar_area: Rect) {
     if app.search_mode {
         let match_count = app.search_matches.len();
-        let text = format!(" Search: {}_ ({} matches)", app.search_query, match_count);
+        let text = if app.search_content {
+            format!(
+                " Search [labels+content]: {}_ ({} matches, {} content hits) | Tab: labels only",
+                app.search_query, match_count, app.search_content_hits
+            )
+        } else {
+            format!(
+                " Search: {}_ ({} matches) | Tab: include file contents",
+                app.search_query, match_count
+            )
+        };
         let bar = Paragraph::new(Line::from(vec![Span::styled(
             text,
             Style::default().fg(Color::Yellow).bg(Color::DarkGray),
@@ -217,6 +230,11 @@ fn render_status_bar(f: &mut Frame, app: &App, bar_area: Rect) {
             " y: confirm delete | n/Esc: cancel ",
             Style::default().fg(Color::Black),
         ))
+    } else if app.promote_dialog.is_some() {
+        Line::from(Span::styled(
+            " j/k: entry | h/l: category | g: global | Enter: promote | Esc: cancel ",
+            Style::default().fg(Color::Black),
+        ))
     } else if !app.search_matches.is_empty() {
         Line::from(Span::styled(
             " j/k: nav | /: search | n/N: match | Enter: view | d: del | q: quit ",
@@ -232,7 +250,11 @@ fn render_status_bar(f: &mut Frame, app: &App, bar_area: Rect) {
             Span::styled("Enter", Style::default().fg(Color::Cyan)),
             Span::raw(": view  "),
             Span::styled("d", Style::default().fg(Color::Cyan)),
-            Span::raw(": del  │  "),
+            Span::raw(": del  "),
+            Span::styled("y", Style::default().fg(Color::Cyan)),
+            Span::raw(": copy  "),
+            Span::styled("P", Style::default().fg(Color::Cyan)),
+            Span::raw(": promote  │  "),
             Span::styled("i", Style::default().fg(Color::Yellow)),
             Span::raw(": ingest  "),
             Span::styled("R", Style::default().fg(Color::Yellow)),
@@ -269,7 +291,21 @@ pub fn render_viewer(f: &mut Frame, app: &App) {
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Cyan));
 
-    let paragraph = Paragraph::new(app.viewer_content.as_str())
+    // Borrow the highlighted spans instead of cloning every line each frame
+    let lines: Vec<Line> = app
+        .viewer_lines
+        .iter()
+        .map(|line| {
+            Line::from(
+                line.spans
+                    .iter()
+                    .map(|span| Span::styled(span.content.as_ref(), span.style))
+                    .collect::<Vec<_>>(),
+            )
+            .style(line.style)
+        })
+        .collect();
+    let paragraph = Paragraph::new(lines)
         .block(block)
         .wrap(Wrap { trim: false })
         .scroll((app.scroll_offset, 0));
@@ -278,11 +314,13 @@ pub fn render_viewer(f: &mut Frame, app: &App) {
 
     // Status bar
     let help = format!(
-        " Esc/q: back | PgUp/PgDn/j/k: scroll | Line {} ",
+        " Esc/q: back | PgUp/PgDn/j/k: scroll | y: copy | Line {} ",
         app.scroll_offset + 1
     );
     let bar = Paragraph::new(help).style(Style::default().fg(Color::Black).bg(Color::DarkGray));
     f.render_widget(bar, layout[2]);
+
+    render_action_overlays(f, app);
 }
 
 /// Render a centered delete confirmation dialog over the browser.
@@ -987,9 +1025,12 @@ pub fn render_help(f: &mut Frame, _app: &App) {
         Line::from("  Esc           - Go back"),
         Line::from(""),
         Line::from("Browser Screen:"),
-        Line::from("  /             - Search"),
+        Line::from("  /             - Search (Tab toggles matching inside file contents)"),
         Line::from("  n/N           - Next/previous search match"),
         Line::from("  d             - Delete item"),
+        Line::from("  y             - Copy item to clipboard (also in viewer and inject preview)"),
+        Line::from("  P             - Promote an inbox entry (on inbox.md)"),
+        Line::from("  v             - Diff versions of a knowledge file"),
         Line::from("  i             - Ingest knowledge from conversations"),
         Line::from("  R             - Regenerate context for project"),
         Line::from("  I             - Inject memory into Claude Code"),
@@ -1218,11 +1259,13 @@ pub fn render_inject_preview(f: &mut Frame, app: &App) {
     // Footer
     f.render_widget(
         Paragraph::new(
-            " j/k: navigate  Space: toggle  a: all/none  Enter: inject selected  q: back  +/-: budget",
+            " j/k: navigate  Space: toggle  a: all/none  y: copy  Enter: inject selected  q: back  +/-: budget",
         )
         .style(Style::default().bg(Color::DarkGray).fg(Color::White)),
         layout[3],
     );
+
+    render_action_overlays(f, app);
 }
 
 pub fn render_config(f: &mut Frame, app: &App) {
@@ -1431,8 +1474,10 @@ fn render_model_list_overlay(f: &mut Frame, app: &App) {
 
     f.render_stateful_widget(List::new(items).block(block), inner[0], &mut state);
 
-    let help = Paragraph::new(" j/k: navigate  PgUp/PgDn: page  Enter: select  Esc: cancel ")
-        .style(Style::default().bg(Color::DarkGray).fg(Color::White));
+    let help = Paragraph::new(
+        " j/k: navigate  PgUp/PgDn: page  Enter: select  r: refresh  e: type name  Esc: cancel ",
+    )
+    .style(Style::default().bg(Color::DarkGray).fg(Color::White));
     f.render_widget(help, inner[1]);
 }
 
@@ -1470,6 +1515,75 @@ fn render_model_input_overlay(f: &mut Frame, app: &App) {
     f.render_widget(paragraph, popup_area);
 }
 
+/// Render the inbox promote dialog: entry list plus a category selector.
+fn render_promote_dialog(f: &mut Frame, dialog: &PromoteDialog) {
+    let area = f.area();
+    let popup_width = 70u16.min(area.width.saturating_sub(4));
+    let popup_height = ((dialog.entries.len() as u16) + 8)
+        .min(20)
+        .min(area.height.saturating_sub(2));
+    let x = (area.width.saturating_sub(popup_width)) / 2;
+    let y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+    let global = dialog.effective_global();
+    let target = if global { "_global" } else { &dialog.project };
+    let mut text = vec![
+        Line::from(vec![
+            Span::raw(" Category: "),
+            Span::styled(
+                format!("◀ {} ▶", dialog.category()),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("   Target: "),
+            Span::styled(target.to_string(), Style::default().fg(Color::Cyan)),
+        ]),
+        Line::from(Span::styled(
+            if dialog.category() == crate::config::Category::Preferences {
+                " (preferences are always promoted to _global)"
+            } else {
+                " g: toggle global"
+            },
+            Style::default().fg(Color::DarkGray),
+        )),
+        Line::from(""),
+    ];
+
+    // Keep the selected entry visible when the inbox is longer than the popup
+    let visible = (popup_height as usize).saturating_sub(7).max(1);
+    let skip = dialog.entry_index.saturating_sub(visible - 1);
+    for (i, (session_id, preview)) in dialog.entries.iter().enumerate().skip(skip).take(visible) {
+        let style = if i == dialog.entry_index {
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Cyan)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+        text.push(Line::from(Span::styled(
+            format!(" {}  {}", session_id, preview),
+            style,
+        )));
+    }
+
+    text.push(Line::from(""));
+    text.push(Line::from(Span::styled(
+        " j/k: entry  h/l: category  Enter: promote  Esc: cancel",
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let block = Block::default()
+        .title(" Promote Inbox Entry ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Green));
+
+    f.render_widget(Clear, popup_area);
+    f.render_widget(Paragraph::new(text).block(block), popup_area);
+}
+
 /// Render action confirmation dialog and action result message overlays.
 fn render_action_overlays(f: &mut Frame, app: &App) {
     if let Some(action) = &app.show_action_confirm {
@@ -1744,13 +1858,61 @@ pub fn render_vcs(f: &mut Frame, app: &App) {
     )
     .split(layout[1]);
 
-    // ── Left: commit list ─────────────────────────────────────────────────
+    // ── Left: branches & tags above the commit list ───────────────────────
+    let ref_rows = (app.vcs_branches.len() + usize::from(!app.vcs_tags.is_empty())).clamp(1, 6);
+    let left_layout =
+        Layout::vertical([Constraint::Length(ref_rows as u16 + 2), Constraint::Min(3)])
+            .split(content_layout[0]);
+
+    let refs_block = Block::default()
+        .title(" Branches ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+    let inner_refs = refs_block.inner(left_layout[0]);
+    f.render_widget(refs_block, left_layout[0]);
+
+    let mut ref_lines: Vec<Line> = app
+        .vcs_branches
+        .iter()
+        .map(|b| {
+            let hash = b
+                .hash
+                .as_deref()
+                .map(|h| &h[..h.len().min(8)])
+                .unwrap_or("-");
+            if b.is_current {
+                Line::styled(
+                    format!("* {} {}", b.name, hash),
+                    Style::default()
+                        .fg(Color::Green)
+                        .add_modifier(Modifier::BOLD),
+                )
+            } else {
+                Line::from(format!("  {} {}", b.name, hash))
+            }
+        })
+        .collect();
+    if !app.vcs_tags.is_empty() {
+        let names: Vec<&str> = app.vcs_tags.iter().map(|t| t.name.as_str()).collect();
+        ref_lines.push(Line::styled(
+            format!("  tags: {}", names.join(", ")),
+            Style::default().fg(Color::Magenta),
+        ));
+    }
+    if ref_lines.is_empty() {
+        ref_lines.push(Line::styled(
+            "(no branches yet)",
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    f.render_widget(Paragraph::new(ref_lines), inner_refs);
+
     let left_block = Block::default()
         .title(" Commits ")
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Cyan));
-    let inner_left = left_block.inner(content_layout[0]);
-    f.render_widget(left_block, content_layout[0]);
+    let inner_left = left_block.inner(left_layout[1]);
+    f.render_widget(left_block, left_layout[1]);
 
     if app.vcs_commits.is_empty() {
         let msg = Paragraph::new("No commits yet.\n\nPress 'c' to make\nthe first commit.")
@@ -1773,7 +1935,13 @@ pub fn render_vcs(f: &mut Frame, app: &App) {
             let hash = &commit.hash[..commit.hash.len().min(8)];
             let msg: String = commit.message.chars().take(25).collect();
             let sessions = commit.session_ids.len();
-            let line_text = format!("{} {} ({}) {}", hash, msg, sessions, date);
+            let tags: String = app
+                .vcs_tags
+                .iter()
+                .filter(|t| t.hash == commit.hash)
+                .map(|t| format!(" [{}]", t.name))
+                .collect();
+            let line_text = format!("{}{} {} ({}) {}", hash, tags, msg, sessions, date);
             let style = if is_selected {
                 Style::default()
                     .fg(Color::Yellow)
@@ -1853,6 +2021,141 @@ pub fn render_vcs(f: &mut Frame, app: &App) {
     render_action_overlays(f, app);
 }
 
+/// Render the knowledge diff screen: version list on the left, colored diff on the right.
+pub fn render_diff(f: &mut Frame, app: &App) {
+    use crate::diff::knowledge_diff::DiffType;
+
+    let layout = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(3),
+        Constraint::Length(1),
+    ])
+    .split(f.area());
+
+    render_screen_tabs(f, "Browser", layout[0]);
+
+    let content_layout =
+        Layout::horizontal([Constraint::Percentage(35), Constraint::Percentage(65)])
+            .split(layout[1]);
+
+    // ── Left: version list ────────────────────────────────────────────────
+    let left_block = Block::default()
+        .title(format!(
+            " Versions: {}/{} ",
+            app.diff_project, app.diff_category
+        ))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+    let inner_left = left_block.inner(content_layout[0]);
+    f.render_widget(left_block, content_layout[0]);
+
+    if app.diff_versions.is_empty() {
+        let msg =
+            Paragraph::new("No versions yet.\n\nVersions are created\nwhen knowledge is updated.")
+                .style(Style::default().fg(Color::DarkGray));
+        f.render_widget(msg, inner_left);
+    } else {
+        let visible = inner_left.height as usize;
+        let skip = app.diff_index.saturating_sub(visible.saturating_sub(1));
+        let lines: Vec<Line> = app
+            .diff_versions
+            .iter()
+            .enumerate()
+            .skip(skip)
+            .take(visible)
+            .map(|(i, v)| {
+                let is_selected = i == app.diff_index;
+                let mark = if app.diff_marked.contains(&i) {
+                    "●"
+                } else {
+                    " "
+                };
+                let prefix = if is_selected { "▶" } else { " " };
+                let style = if is_selected {
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                };
+                Line::styled(
+                    format!(
+                        "{}{} {} {}B",
+                        prefix,
+                        mark,
+                        v.timestamp.format("%Y-%m-%d %H:%M"),
+                        v.size_bytes
+                    ),
+                    style,
+                )
+            })
+            .collect();
+        f.render_widget(Paragraph::new(lines), inner_left);
+    }
+
+    // ── Right: diff ───────────────────────────────────────────────────────
+    let right_block = Block::default()
+        .title(format!(" {} ", app.diff_title))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Green));
+    let inner_right = right_block.inner(content_layout[1]);
+    f.render_widget(right_block, content_layout[1]);
+
+    let added = Style::default().fg(Color::Green);
+    let removed = Style::default().fg(Color::Red);
+    let unchanged = Style::default().fg(Color::DarkGray);
+    let mut lines: Vec<Line> = Vec::new();
+    if let Some(result) = &app.diff_result {
+        if result.is_empty() {
+            lines.push(Line::styled(
+                "No changes detected",
+                Style::default().fg(Color::Yellow),
+            ));
+        } else {
+            for line in &result.lines {
+                let old = line.old_content.as_deref().unwrap_or("").trim_end();
+                let new = line.new_content.as_deref().unwrap_or("").trim_end();
+                match line.diff_type {
+                    DiffType::Added => lines.push(Line::styled(format!("+ {}", new), added)),
+                    DiffType::Removed => lines.push(Line::styled(format!("- {}", old), removed)),
+                    DiffType::Modified => {
+                        lines.push(Line::styled(format!("- {}", old), removed));
+                        lines.push(Line::styled(format!("+ {}", new), added));
+                    }
+                    DiffType::Unchanged => {
+                        lines.push(Line::styled(format!("  {}", old), unchanged))
+                    }
+                }
+            }
+        }
+    }
+    f.render_widget(
+        Paragraph::new(lines).scroll((app.diff_scroll, 0)),
+        inner_right,
+    );
+
+    // ── Footer ────────────────────────────────────────────────────────────
+    let status = Line::from(vec![
+        Span::raw(" ["),
+        Span::styled("j/k", Style::default().fg(Color::Cyan)),
+        Span::raw("] Select  ["),
+        Span::styled("Space", Style::default().fg(Color::Cyan)),
+        Span::raw("] Mark two to compare  ["),
+        Span::styled("J/K PgUp/PgDn", Style::default().fg(Color::Cyan)),
+        Span::raw("] Scroll diff  ["),
+        Span::styled("r", Style::default().fg(Color::Cyan)),
+        Span::raw("] Reload  ["),
+        Span::styled("Esc", Style::default().fg(Color::Cyan)),
+        Span::raw("] Back"),
+    ]);
+    f.render_widget(
+        Paragraph::new(status).style(Style::default().bg(Color::DarkGray)),
+        layout[2],
+    );
+
+    render_action_overlays(f, app);
+}
+
 pub fn render_ask(f: &mut Frame, app: &App) {
     let area = f.area();
     let chunks = Layout::vertical([
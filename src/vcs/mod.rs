@@ -56,6 +56,13 @@ pub struct BranchRef {
     pub is_current: bool,
 }
 
+/// A named, immutable pointer to a commit (`refs/tags/<name>`).
+#[derive(Debug)]
+pub struct TagRef {
+    pub name: String,
+    pub hash: String,
+}
+
 #[derive(Debug)]
 pub struct CheckoutResult {
     pub previous_branch: String,
@@ -105,7 +112,7 @@ impl MemoryVcs {
     fn require_init(&self) -> Result<()> {
         if !self.is_initialized() {
             return Err(MemoryError::Vcs(format!(
-                "VCS not initialized for project '{}'. Run: engram mem init --project {}",
+                "VCS not initialized for project '{}'. Run: engram mem init {}",
                 self.project, self.project
             )));
         }
@@ -370,15 +377,45 @@ impl MemoryVcs {
         Ok(format!("{:x}", hasher.finalize())[..16].to_string())
     }
 
-    fn category_hash(&self, category: &str) -> Result<Option<String>> {
-        let path = self.knowledge_dir.join(format!("{}.md", category));
-        if !path.exists() {
-            return Ok(None);
-        }
-        let content = std::fs::read_to_string(&path)?;
+    fn category_hash(content: &str) -> String {
         let mut hasher = Sha256::new();
         hasher.update(content.as_bytes());
-        Ok(Some(format!("{:x}", hasher.finalize())[..16].to_string()))
+        format!("{:x}", hasher.finalize())[..16].to_string()
+    }
+
+    /// Category file contents a commit records. With `committed` set (staged
+    /// or explicit sessions), that is `parent`'s snapshot with those sessions'
+    /// working-tree blocks merged in — exactly what `diff --staged` shows.
+    /// Otherwise it is the whole working tree.
+    fn commit_contents(
+        &self,
+        parent: Option<&str>,
+        committed: Option<&[String]>,
+    ) -> Result<Vec<(&'static str, String)>> {
+        let mut contents = Vec::new();
+        for cat in CATEGORIES {
+            let path = self.knowledge_dir.join(format!("{}.md", cat));
+            match committed {
+                None => {
+                    if path.exists() {
+                        contents.push((*cat, std::fs::read_to_string(&path)?));
+                    }
+                }
+                Some(ids) => {
+                    let base = match parent {
+                        Some(h) => self.read_snapshot(h, cat)?,
+                        None => String::new(),
+                    };
+                    let merged = merge_session_blocks(&base, &read_or_empty(&path)?, |id| {
+                        ids.iter().any(|i| i == id)
+                    });
+                    if !merged.is_empty() {
+                        contents.push((*cat, merged));
+                    }
+                }
+            }
+        }
+        Ok(contents)
     }
 
     /// Create a commit.
@@ -394,6 +431,8 @@ impl MemoryVcs {
     ) -> Result<CommitObject> {
         self.require_init()?;
 
+        // Sessions added on top of HEAD (None for snapshot mode)
+        let mut committed: Option<Vec<String>> = None;
         let all_ids: Vec<String> = if all_new {
             // Snapshot mode: exactly the current working sessions.
             // Deletions (via `forget`) are naturally included.
@@ -434,6 +473,7 @@ impl MemoryVcs {
                 }
             }
             merged.sort();
+            committed = Some(new_ids);
             merged
         };
 
@@ -454,12 +494,11 @@ impl MemoryVcs {
             branch_ref.clone()
         };
 
-        let mut category_hashes = HashMap::new();
-        for cat in CATEGORIES {
-            if let Some(h) = self.category_hash(cat)? {
-                category_hashes.insert(cat.to_string(), h);
-            }
-        }
+        let contents = self.commit_contents(parent.as_deref(), committed.as_deref())?;
+        let category_hashes = contents
+            .iter()
+            .map(|(cat, content)| (cat.to_string(), Self::category_hash(content)))
+            .collect();
 
         let commit = CommitObject {
             hash: hash.clone(),
@@ -473,7 +512,7 @@ impl MemoryVcs {
         };
 
         self.save_commit(&commit)?;
-        self.save_snapshot(&hash)?;
+        self.save_snapshot(&hash, &contents)?;
 
         if !detached {
             self.write_branch_hash(&branch_ref, &hash)?;
@@ -485,14 +524,11 @@ impl MemoryVcs {
         Ok(commit)
     }
 
-    fn save_snapshot(&self, hash: &str) -> Result<()> {
+    fn save_snapshot(&self, hash: &str, contents: &[(&str, String)]) -> Result<()> {
         let dir = self.vcs_dir.join("snapshots").join(hash);
         std::fs::create_dir_all(&dir)?;
-        for cat in CATEGORIES {
-            let src = self.knowledge_dir.join(format!("{}.md", cat));
-            if src.exists() {
-                std::fs::copy(&src, dir.join(format!("{}.md", cat)))?;
-            }
+        for (cat, content) in contents {
+            std::fs::write(dir.join(format!("{}.md", cat)), content)?;
         }
         Ok(())
     }
@@ -566,12 +602,7 @@ impl MemoryVcs {
 
     pub fn create_branch(&self, name: &str, from_hash: Option<&str>) -> Result<()> {
         self.require_init()?;
-        if name.contains('/') || name.contains(' ') {
-            return Err(MemoryError::Vcs(format!(
-                "Invalid branch name '{}': cannot contain '/' or spaces.",
-                name
-            )));
-        }
+        validate_ref_name("branch", name)?;
         let path = self.vcs_dir.join("refs").join("heads").join(name);
         if path.exists() {
             return Err(MemoryError::Vcs(format!(
@@ -609,12 +640,76 @@ impl MemoryVcs {
         Ok(())
     }
 
+    // ── Tags ───────────────────────────────────────────────────────────────
+
+    /// File holding tag `name`. The name is validated so it can't point
+    /// outside `refs/tags` (e.g. `../heads/main`).
+    fn tag_path(&self, name: &str) -> Result<PathBuf> {
+        validate_ref_name("tag", name)?;
+        Ok(self.vcs_dir.join("refs").join("tags").join(name))
+    }
+
+    pub fn list_tags(&self) -> Result<Vec<TagRef>> {
+        self.require_init()?;
+        let tags_dir = self.vcs_dir.join("refs").join("tags");
+        let mut tags = Vec::new();
+        if tags_dir.exists() {
+            for entry in std::fs::read_dir(&tags_dir)? {
+                let entry = entry?;
+                let hash = std::fs::read_to_string(entry.path())?;
+                tags.push(TagRef {
+                    name: entry.file_name().to_string_lossy().to_string(),
+                    hash: hash.trim().to_string(),
+                });
+            }
+        }
+        tags.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(tags)
+    }
+
+    /// Tag `target` (branch, tag or commit hash; default: HEAD) as `name`.
+    /// Returns the tagged commit hash.
+    pub fn create_tag(&self, name: &str, target: Option<&str>) -> Result<String> {
+        self.require_init()?;
+        let path = self.tag_path(name)?;
+        if path.exists() {
+            return Err(MemoryError::Vcs(format!("Tag '{}' already exists.", name)));
+        }
+        let hash = match target {
+            Some(t) => self.resolve_target(t)?,
+            None => self.head_hash()?.ok_or_else(|| {
+                MemoryError::Vcs(
+                    "No commits yet — make an initial commit before tagging.".to_string(),
+                )
+            })?,
+        };
+        std::fs::create_dir_all(self.vcs_dir.join("refs").join("tags"))?;
+        std::fs::write(&path, &hash)?;
+        Ok(hash)
+    }
+
+    pub fn delete_tag(&self, name: &str) -> Result<()> {
+        self.require_init()?;
+        let path = self.tag_path(name)?;
+        if !path.exists() {
+            return Err(MemoryError::Vcs(format!("Tag '{}' not found.", name)));
+        }
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
+
     // ── Checkout ───────────────────────────────────────────────────────────
 
+    /// Resolve a branch name, tag name or commit hash to a commit hash.
     pub fn resolve_target(&self, target: &str) -> Result<String> {
+        // Branches, tags and hashes are all single path segments
+        validate_ref_name("ref", target)?;
         if let Ok(Some(hash)) = self.read_branch_hash(target) {
             return Ok(hash);
         }
+        if let Some(tag_path) = self.tag_path(target).ok().filter(|p| p.is_file()) {
+            return Ok(std::fs::read_to_string(&tag_path)?.trim().to_string());
+        }
         let commit_path = self
             .vcs_dir
             .join("commits")
@@ -623,7 +718,7 @@ impl MemoryVcs {
             return Ok(target.to_string());
         }
         Err(MemoryError::Vcs(format!(
-            "Unknown ref '{}' — not a branch name or commit hash.",
+            "Unknown ref '{}' — not a branch, tag or commit hash.",
             target
         )))
     }
@@ -893,6 +988,7 @@ impl MemoryVcs {
 
     // ── Diff ───────────────────────────────────────────────────────────────
 
+    /// Diff two refs. `from` defaults to HEAD, `to` to the working knowledge files.
     pub fn diff(
         &self,
         from: Option<&str>,
@@ -912,69 +1008,272 @@ impl MemoryVcs {
 
         let read_content = |hash_opt: Option<&str>, cat: &str| -> Result<String> {
             match hash_opt {
-                Some(h) => {
-                    let p = self
-                        .vcs_dir
-                        .join("snapshots")
-                        .join(h)
-                        .join(format!("{}.md", cat));
-                    if p.exists() {
-                        Ok(std::fs::read_to_string(&p)?)
-                    } else {
-                        Ok(String::new())
-                    }
-                }
-                None => {
-                    let p = self.knowledge_dir.join(format!("{}.md", cat));
-                    if p.exists() {
-                        Ok(std::fs::read_to_string(&p)?)
-                    } else {
-                        Ok(String::new())
-                    }
-                }
+                Some(h) => self.read_snapshot(h, cat),
+                None => read_or_empty(&self.knowledge_dir.join(format!("{}.md", cat))),
             }
         };
 
-        // Validate category filter and build list
-        let single_buf: [&str; 1];
-        let cats: &[&str] = if let Some(cat) = category_filter {
-            if !CATEGORIES.contains(&cat) {
-                return Err(MemoryError::Vcs(format!(
-                    "Unknown category '{}'. Valid: {}",
-                    cat,
-                    CATEGORIES.join(", ")
-                )));
-            }
-            single_buf = [cat];
-            &single_buf
-        } else {
-            CATEGORIES
-        };
+        render_category_diffs(category_filter, |cat| {
+            Ok((
+                read_content(from_hash.as_deref(), cat)?,
+                read_content(to_hash.as_deref(), cat)?,
+            ))
+        })
+    }
 
-        let mut output = String::new();
-        for cat in cats {
-            let from_content = read_content(from_hash.as_deref(), cat)?;
-            let to_content = read_content(to_hash.as_deref(), cat)?;
-            if from_content == to_content {
-                continue;
-            }
-            let diff_result = TextDiff::from_lines(&from_content, &to_content);
-            output.push_str(&format!("--- a/{}\n+++ b/{}\n", cat, cat));
-            for change in diff_result.iter_all_changes() {
-                let sign = match change.tag() {
-                    ChangeTag::Delete => "-",
-                    ChangeTag::Insert => "+",
-                    ChangeTag::Equal => " ",
-                };
-                output.push_str(&format!("{}{}", sign, change));
-            }
-            output.push('\n');
+    /// Diff HEAD against what the next `mem commit` would add: HEAD's snapshot
+    /// with each staged session's working-tree blocks merged in.
+    pub fn diff_staged(&self, category_filter: Option<&str>) -> Result<String> {
+        self.require_init()?;
+        let head = self.head_hash()?;
+        let staging = self.load_staging()?;
+
+        render_category_diffs(category_filter, |cat| {
+            let head_content = match head.as_deref() {
+                Some(h) => self.read_snapshot(h, cat)?,
+                None => String::new(),
+            };
+            let working = read_or_empty(&self.knowledge_dir.join(format!("{}.md", cat)))?;
+            let staged = merge_session_blocks(&head_content, &working, |id| {
+                staging.entries.contains_key(id)
+            });
+            Ok((head_content, staged))
+        })
+    }
+
+    fn read_snapshot(&self, hash: &str, category: &str) -> Result<String> {
+        read_or_empty(
+            &self
+                .vcs_dir
+                .join("snapshots")
+                .join(hash)
+                .join(format!("{}.md", category)),
+        )
+    }
+}
+
+/// Ref names become file names under `refs/`, so keep them to a single segment.
+fn validate_ref_name(kind: &str, name: &str) -> Result<()> {
+    if name.is_empty()
+        || name.starts_with('.')
+        || name.contains('/')
+        || name.contains('\\')
+        || name.contains(' ')
+    {
+        return Err(MemoryError::Vcs(format!(
+            "Invalid {} name '{}': cannot be empty, start with '.', or contain '/', '\\' or spaces.",
+            kind, name
+        )));
+    }
+    Ok(())
+}
+
+fn read_or_empty(path: &Path) -> Result<String> {
+    if path.exists() {
+        Ok(std::fs::read_to_string(path)?)
+    } else {
+        Ok(String::new())
+    }
+}
+
+/// Overlay the working-tree blocks of the `selected` sessions onto `base`,
+/// replacing a block with the same session ID in place and appending new ones.
+fn merge_session_blocks(base: &str, working: &str, selected: impl Fn(&str) -> bool) -> String {
+    let (base_preamble, mut blocks) = parse_session_blocks(base);
+    let (working_preamble, working_blocks) = parse_session_blocks(working);
+
+    for block in working_blocks {
+        if !selected(&block.session_id) {
+            continue;
         }
+        match blocks.iter_mut().find(|b| b.session_id == block.session_id) {
+            Some(existing) => *existing = block,
+            None => blocks.push(block),
+        }
+    }
+
+    if blocks.is_empty() {
+        return base.to_string();
+    }
+    let mut out = if base.is_empty() {
+        working_preamble
+    } else {
+        base_preamble
+    };
+    for block in blocks {
+        out.push_str(&block.header);
+        out.push_str(&block.content);
+    }
+    out
+}
 
-        if output.is_empty() {
-            output = "No differences found.\n".to_string();
+/// Render a diff for each category (or just `category_filter`) whose two sides,
+/// as returned by `sides`, differ.
+fn render_category_diffs(
+    category_filter: Option<&str>,
+    mut sides: impl FnMut(&str) -> Result<(String, String)>,
+) -> Result<String> {
+    // Validate category filter and build list
+    let single_buf: [&str; 1];
+    let cats: &[&str] = if let Some(cat) = category_filter {
+        if !CATEGORIES.contains(&cat) {
+            return Err(MemoryError::Vcs(format!(
+                "Unknown category '{}'. Valid: {}",
+                cat,
+                CATEGORIES.join(", ")
+            )));
+        }
+        single_buf = [cat];
+        &single_buf
+    } else {
+        CATEGORIES
+    };
+
+    let mut output = String::new();
+    for cat in cats {
+        let (from_content, to_content) = sides(cat)?;
+        if from_content == to_content {
+            continue;
+        }
+        let diff_result = TextDiff::from_lines(&from_content, &to_content);
+        output.push_str(&format!("--- a/{}\n+++ b/{}\n", cat, cat));
+        for change in diff_result.iter_all_changes() {
+            let sign = match change.tag() {
+                ChangeTag::Delete => "-",
+                ChangeTag::Insert => "+",
+                ChangeTag::Equal => " ",
+            };
+            output.push_str(&format!("{}{}", sign, change));
         }
+        output.push('\n');
+    }
+
+    if output.is_empty() {
+        output = "No differences found.\n".to_string();
+    }
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_decisions(memory_dir: &Path, content: &str) {
+        let dir = memory_dir.join("knowledge").join("demo");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("decisions.md"), content).unwrap();
+    }
+
+    #[test]
+    fn test_diff_staged_shows_only_staged_sessions() {
+        let tmp = tempfile::tempdir().unwrap();
+        let vcs = MemoryVcs::new(tmp.path(), "demo");
+        vcs.init().unwrap();
+
+        write_decisions(
+            tmp.path(),
+            "# Decisions\n\n## Session: s1 (2024-01-01T00:00:00Z)\nUse sqlite\n\n",
+        );
+        vcs.commit("first", None, true).unwrap();
+
+        write_decisions(
+            tmp.path(),
+            "# Decisions\n\n## Session: s1 (2024-01-01T00:00:00Z)\nUse sqlite\n\n\
+             ## Session: s2 (2024-01-02T00:00:00Z)\nAdd caching\n\n\
+             ## Session: s3 (2024-01-03T00:00:00Z)\nDrop redis\n\n",
+        );
+        assert_eq!(vcs.diff_staged(None).unwrap(), "No differences found.\n");
+
+        vcs.stage_sessions(&["s2"]).unwrap();
+        let staged = vcs.diff_staged(None).unwrap();
+        assert!(staged.contains("--- a/decisions"));
+        assert!(staged.contains("+Add caching"));
+        assert!(!staged.contains("Drop redis"));
+
+        // The working-tree diff still sees both new sessions
+        let working = vcs.diff(None, None, Some("decisions")).unwrap();
+        assert!(working.contains("+Add caching"));
+        assert!(working.contains("+Drop redis"));
+    }
+
+    #[test]
+    fn test_commit_records_what_diff_staged_shows() {
+        let tmp = tempfile::tempdir().unwrap();
+        let vcs = MemoryVcs::new(tmp.path(), "demo");
+        vcs.init().unwrap();
+
+        write_decisions(
+            tmp.path(),
+            "# Decisions\n\n## Session: s1 (2024-01-01T00:00:00Z)\nUse sqlite\n\n",
+        );
+        let first = vcs.commit("first", None, true).unwrap();
+
+        write_decisions(
+            tmp.path(),
+            "# Decisions\n\n## Session: s1 (2024-01-01T00:00:00Z)\nUse sqlite\n\n\
+             ## Session: s2 (2024-01-02T00:00:00Z)\nAdd caching\n\n\
+             ## Session: s3 (2024-01-03T00:00:00Z)\nDrop redis\n\n",
+        );
+        vcs.stage_sessions(&["s2"]).unwrap();
+        let staged = vcs.diff_staged(None).unwrap();
+
+        let second = vcs.commit("second", None, false).unwrap();
+        assert_eq!(second.session_ids, vec!["s1", "s2"]);
+        let committed = vcs
+            .diff(Some(&first.hash), Some(&second.hash), None)
+            .unwrap();
+        assert_eq!(committed, staged);
+        assert!(!committed.contains("Drop redis"));
+
+        // The unstaged session is still there to commit later
+        let status = vcs.status().unwrap();
+        assert_eq!(status.unstaged_new.len(), 1);
+        assert_eq!(status.unstaged_new[0].session_id, "s3");
+    }
 
-        Ok(output)
+    #[test]
+    fn test_tags_resolve_and_survive_new_commits() {
+        let tmp = tempfile::tempdir().unwrap();
+        let vcs = MemoryVcs::new(tmp.path(), "demo");
+        vcs.init().unwrap();
+        assert!(vcs.create_tag("v1", None).is_err(), "nothing to tag yet");
+
+        write_decisions(
+            tmp.path(),
+            "# Decisions\n\n## Session: s1 (2024-01-01T00:00:00Z)\nUse sqlite\n\n",
+        );
+        let first = vcs.commit("first", None, true).unwrap();
+        assert_eq!(vcs.create_tag("v1", None).unwrap(), first.hash);
+        assert!(vcs.create_tag("v1", None).is_err());
+        assert!(vcs.create_tag("../v2", None).is_err());
+
+        write_decisions(
+            tmp.path(),
+            "# Decisions\n\n## Session: s1 (2024-01-01T00:00:00Z)\nUse sqlite\n\n\
+             ## Session: s2 (2024-01-02T00:00:00Z)\nAdd caching\n\n",
+        );
+        let second = vcs.commit("second", None, true).unwrap();
+
+        assert_eq!(vcs.resolve_target("v1").unwrap(), first.hash);
+        assert_eq!(vcs.resolve_target("main").unwrap(), second.hash);
+        let tags = vcs.list_tags().unwrap();
+        assert_eq!(tags.len(), 1);
+        assert_eq!(tags[0].name, "v1");
+
+        // Checking out a tag detaches HEAD at the tagged commit
+        vcs.checkout("v1", false, false).unwrap();
+        let status = vcs.status().unwrap();
+        assert!(status.detached_head);
+        assert_eq!(status.head_hash.as_deref(), Some(first.hash.as_str()));
+
+        vcs.delete_tag("v1").unwrap();
+        assert!(vcs.resolve_target("v1").is_err());
+
+        // Tag names can't reach other refs
+        assert!(vcs.delete_tag("../heads/main").is_err());
+        assert!(tmp.path().join("vcs/demo/refs/heads/main").exists());
+        assert_eq!(vcs.resolve_target("main").unwrap(), second.hash);
+        assert!(vcs.resolve_target("../heads/main").is_err());
     }
 }
@@ -14,7 +14,9 @@ fn create_test_config(temp: &TempDir) -> Config {
             endpoint: "http://localhost:11434".to_string(),
             model: "llama2".to_string(),
             api_key: None,
+            oauth: false,
         },
+        prompts: Default::default(),
     }
 }
 
@@ -19,7 +19,9 @@ fn create_test_config(temp: &TempDir) -> Config {
             endpoint: "http://localhost:11434".to_string(),
             model: "llama2".to_string(),
             api_key: None,
+            oauth: false,
         },
+        prompts: Default::default(),
     }
 }
 
@@ -76,6 +78,7 @@ fn red_queen_malformed_knowledge_ids() {
                 results_count: Some(1),
                 session_id: Some(format!("evil-{}", i)),
                 tokens_consumed: None,
+                knowledge_ids: Vec::new(),
             })
             .unwrap();
     }
@@ -107,6 +110,7 @@ fn red_queen_events_without_categories() {
                 results_count: Some(1),
                 session_id: Some(format!("no-cat-{}", i)),
                 tokens_consumed: None,
+                knowledge_ids: Vec::new(),
             })
             .unwrap();
     }
@@ -140,6 +144,7 @@ fn red_queen_extremely_high_frequency() {
                 results_count: Some(1),
                 session_id: Some(format!("spam-{}", i)),
                 tokens_consumed: None,
+                knowledge_ids: Vec::new(),
             })
             .unwrap();
     }
@@ -25,7 +25,9 @@ fn create_test_config(temp: &TempDir) -> Config {
             endpoint: "http://localhost:11434".to_string(),
             model: "llama2".to_string(),
             api_key: None,
+            oauth: false,
         },
+        prompts: Default::default(),
     }
 }
 
@@ -50,6 +52,7 @@ fn red_pill_memory_poisoning_cascade() {
                     results_count: Some(1),
                     session_id: Some(format!("legit-{}", i)),
                     tokens_consumed: None,
+                    knowledge_ids: Vec::new(),
                 })
                 .unwrap();
         }
@@ -71,6 +74,7 @@ fn red_pill_memory_poisoning_cascade() {
                 results_count: Some(1),
                 session_id: Some("poison".to_string()),
                 tokens_consumed: None,
+                knowledge_ids: Vec::new(),
             })
             .unwrap();
     }
@@ -138,6 +142,7 @@ fn red_pill_false_memory_reinforcement() {
                     results_count: Some(1),
                     session_id: Some(format!("cycle-{}-{}", cycle, i)),
                     tokens_consumed: None,
+                    knowledge_ids: Vec::new(),
                 })
                 .unwrap();
         }
@@ -260,6 +265,7 @@ fn red_pill_conflicting_signals() {
                 results_count: Some(1),
                 session_id: Some("high-freq".to_string()),
                 tokens_consumed: None,
+                knowledge_ids: Vec::new(),
             })
             .unwrap();
     }
@@ -285,6 +291,7 @@ fn red_pill_conflicting_signals() {
                 results_count: Some(1),
                 session_id: Some(format!("other-{}", i)),
                 tokens_consumed: None,
+                knowledge_ids: Vec::new(),
             })
             .unwrap();
     }
@@ -456,6 +463,7 @@ fn red_pill_zero_divided_by_zero() {
                 results_count: Some(0), // Zero results!
                 session_id: Some(format!("zero-{}", i)),
                 tokens_consumed: None,
+                knowledge_ids: Vec::new(),
             })
             .unwrap();
     }